@@ -77,14 +77,24 @@ pub mod wrappers;
 
 mod stream_ext;
 pub use stream_ext::{collect::FromStream, StreamExt};
+cfg_sync! {
+    pub use stream_ext::ForwardTo;
+}
 /// Adapters for [`Stream`]s created by methods in [`StreamExt`].
 pub mod adapters {
     pub use crate::stream_ext::{
-        Chain, Filter, FilterMap, Fuse, Map, MapWhile, Merge, Peekable, Skip, SkipWhile, Take,
-        TakeWhile, Then,
+        BufferUnordered, Buffered, Chain, CombineLatest, DedupByKey, DistinctUntilChanged, Filter,
+        FilterAsync, FilterMap, Fuse, GroupBy, GroupStream, Map, MapWhile, Merge, Partition,
+        Peekable, ScanAsync, Skip, SkipWhile, SlidingWindow, SplitPrefix, SplitRemainder,
+        SwitchMap, Take, TakeUntil, TakeWhile, Then, ThenConcurrent, TryBufferUnordered, TryFilter,
+        TryForEachConcurrent,
     };
     cfg_time! {
-        pub use crate::stream_ext::{ChunksTimeout, Timeout, TimeoutRepeating};
+        pub use crate::stream_ext::{
+            ChunksExactTimeout, ChunksTimeout, DeadlineBy, Timeout, TimeoutRepeating,
+        };
+        #[cfg(feature = "sync")]
+        pub use crate::stream_ext::RateLimit;
     }
 }
 
@@ -101,17 +111,29 @@ pub use empty::{empty, Empty};
 mod iter;
 pub use iter::{iter, Iter};
 
+mod merge_all;
+pub use merge_all::{merge_all, MergeAll};
+
 mod once;
 pub use once::{once, Once};
 
+mod once_future;
+pub use once_future::{once_future, OnceFuture};
+
 mod pending;
 pub use pending::{pending, Pending};
 
+mod repeat_with_async;
+pub use repeat_with_async::{repeat_with_async, RepeatWithAsync};
+
 mod stream_map;
-pub use stream_map::StreamMap;
+pub use stream_map::{StreamMap, TryInsertError};
 
 mod stream_close;
 pub use stream_close::StreamNotifyClose;
 
+mod unfold;
+pub use unfold::{unfold, Unfold};
+
 #[doc(no_inline)]
 pub use futures_core::Stream;
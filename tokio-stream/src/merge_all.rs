@@ -0,0 +1,106 @@
+use crate::Stream;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Combine a collection of homogeneous streams into one, interleaving their
+/// output as it is produced.
+///
+/// Unlike folding nested [`merge`] calls, which adds a poll layer (and its
+/// cost) per stream, this polls the whole collection directly. The starting
+/// stream is rotated between polls so that no stream can starve the others.
+///
+/// The merged stream completes once **all** source streams complete;
+/// exhausted streams are dropped along the way.
+///
+/// [`merge`]: crate::StreamExt::merge
+///
+/// # Examples
+///
+/// ```
+/// use tokio_stream::{self as stream, StreamExt};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let streams = vec![
+///     stream::iter(vec![0, 3]),
+///     stream::iter(vec![1, 4]),
+///     stream::iter(vec![2, 5]),
+/// ];
+///
+/// let mut values: Vec<_> = stream::merge_all(streams).collect().await;
+/// values.sort_unstable();
+///
+/// assert_eq!(values, vec![0, 1, 2, 3, 4, 5]);
+/// # }
+/// ```
+pub fn merge_all<S: Stream + Unpin>(streams: Vec<S>) -> MergeAll<S> {
+    MergeAll { streams, start: 0 }
+}
+
+/// Stream returned by the [`merge_all`] function.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct MergeAll<S> {
+    streams: Vec<S>,
+    start: usize,
+}
+
+impl<S: Stream + Unpin> Stream for MergeAll<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let me = self.get_mut();
+
+        if me.streams.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        // Rotate the starting stream between polls for fairness.
+        me.start = (me.start + 1) % me.streams.len();
+
+        let mut idx = me.start;
+        let mut remaining = me.streams.len();
+
+        while remaining > 0 {
+            if idx >= me.streams.len() {
+                idx = 0;
+            }
+
+            match Pin::new(&mut me.streams[idx]).poll_next(cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                Poll::Ready(None) => {
+                    // Drop the exhausted stream; the stream swapped into its
+                    // place is polled on the next iteration.
+                    me.streams.swap_remove(idx);
+                }
+                Poll::Pending => idx += 1,
+            }
+
+            remaining -= 1;
+        }
+
+        if me.streams.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut lower = 0usize;
+        let mut upper = Some(0usize);
+
+        for stream in &self.streams {
+            let (stream_lower, stream_upper) = stream.size_hint();
+
+            lower = lower.saturating_add(stream_lower);
+            upper = match (upper, stream_upper) {
+                (Some(upper), Some(stream_upper)) => upper.checked_add(stream_upper),
+                _ => None,
+            };
+        }
+
+        (lower, upper)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`once_future`](fn@once_future) function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct OnceFuture<F> {
+        #[pin]
+        future: Option<F>,
+    }
+}
+
+/// Creates a stream that emits the output of a future exactly once.
+///
+/// The returned stream yields once the provided future completes and then
+/// ends. This is the asynchronous counterpart of [`once`](crate::once).
+///
+/// # Examples
+///
+/// ```
+/// use tokio_stream::{self as stream, StreamExt};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let one = stream::once_future(async { 1 });
+///     tokio::pin!(one);
+///
+///     assert_eq!(Some(1), one.next().await);
+///     assert_eq!(None, one.next().await);
+/// }
+/// ```
+pub fn once_future<F: Future>(future: F) -> OnceFuture<F> {
+    OnceFuture {
+        future: Some(future),
+    }
+}
+
+impl<F: Future> Stream for OnceFuture<F> {
+    type Item = F::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<F::Output>> {
+        let mut me = self.project();
+
+        let value = match me.future.as_mut().as_pin_mut() {
+            Some(future) => ready!(future.poll(cx)),
+            None => return Poll::Ready(None),
+        };
+
+        me.future.set(None);
+        Poll::Ready(Some(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.future.is_some() {
+            (1, Some(1))
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<F> fmt::Debug for OnceFuture<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnceFuture")
+            .field("done", &self.future.is_none())
+            .finish()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`repeat_with_async`](fn@repeat_with_async) function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RepeatWithAsync<F, Fut> {
+        f: F,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+/// Creates a stream that yields values endlessly by awaiting the futures
+/// produced by a closure.
+///
+/// The closure is called each time the previous future completes, so at most
+/// one future is in flight at a time. The stream never ends; use an adapter
+/// such as [`take`](crate::StreamExt::take) to bound it.
+///
+/// # Examples
+///
+/// ```
+/// use tokio_stream::{self as stream, StreamExt};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut count = 0;
+///     let stream = stream::repeat_with_async(move || {
+///         count += 1;
+///         async move { count }
+///     })
+///     .take(3);
+///     tokio::pin!(stream);
+///
+///     assert_eq!(Some(1), stream.next().await);
+///     assert_eq!(Some(2), stream.next().await);
+///     assert_eq!(Some(3), stream.next().await);
+///     assert_eq!(None, stream.next().await);
+/// }
+/// ```
+pub fn repeat_with_async<F, Fut>(f: F) -> RepeatWithAsync<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    RepeatWithAsync { f, future: None }
+}
+
+impl<F, Fut> Stream for RepeatWithAsync<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Fut::Output>> {
+        let mut me = self.project();
+
+        if me.future.is_none() {
+            me.future.set(Some((me.f)()));
+        }
+
+        let value = ready!(me.future.as_mut().as_pin_mut().unwrap().poll(cx));
+        me.future.set(None);
+
+        Poll::Ready(Some(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<F, Fut> fmt::Debug for RepeatWithAsync<F, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RepeatWithAsync").finish()
+    }
+}
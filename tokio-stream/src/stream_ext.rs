@@ -7,24 +7,54 @@ use all::AllFuture;
 mod any;
 use any::AnyFuture;
 
+mod buffer_unordered;
+pub use buffer_unordered::BufferUnordered;
+
+mod buffered;
+pub use buffered::{Buffered, ThenConcurrent};
+
 mod chain;
 pub use chain::Chain;
 
 pub(crate) mod collect;
 use collect::{Collect, FromStream};
 
+mod combine_latest;
+pub use combine_latest::CombineLatest;
+
+mod dedup_by_key;
+pub use dedup_by_key::DedupByKey;
+
+mod distinct_until_changed;
+pub use distinct_until_changed::DistinctUntilChanged;
+
 mod filter;
 pub use filter::Filter;
 
+mod filter_async;
+pub use filter_async::FilterAsync;
+
 mod filter_map;
 pub use filter_map::FilterMap;
 
 mod fold;
 use fold::FoldFuture;
 
+mod fold_async;
+use fold_async::FoldAsyncFuture;
+
+cfg_sync! {
+    mod forward_to;
+    pub use forward_to::ForwardTo;
+    use tokio::sync::mpsc::Sender;
+}
+
 mod fuse;
 pub use fuse::Fuse;
 
+mod group_by;
+pub use group_by::{GroupBy, GroupStream};
+
 mod map;
 pub use map::Map;
 
@@ -37,21 +67,48 @@ pub use merge::Merge;
 mod next;
 use next::Next;
 
+mod partition;
+pub use partition::Partition;
+
+mod scan_async;
+pub use scan_async::ScanAsync;
+
 mod skip;
 pub use skip::Skip;
 
 mod skip_while;
 pub use skip_while::SkipWhile;
 
+mod sliding_window;
+pub use sliding_window::SlidingWindow;
+
+mod split_when;
+pub use split_when::{SplitPrefix, SplitRemainder};
+
+mod switch_map;
+pub use switch_map::SwitchMap;
+
 mod take;
 pub use take::Take;
 
+mod take_until;
+pub use take_until::TakeUntil;
+
 mod take_while;
 pub use take_while::TakeWhile;
 
 mod then;
 pub use then::Then;
 
+mod try_buffer_unordered;
+pub use try_buffer_unordered::TryBufferUnordered;
+
+mod try_filter;
+pub use try_filter::TryFilter;
+
+mod try_for_each_concurrent;
+pub use try_for_each_concurrent::TryForEachConcurrent;
+
 mod try_next;
 use try_next::TryNext;
 
@@ -63,11 +120,24 @@ cfg_time! {
     pub(crate) mod timeout_repeating;
     pub use timeout::Timeout;
     pub use timeout_repeating::TimeoutRepeating;
+    mod deadline_by;
+    pub use deadline_by::DeadlineBy;
+    use tokio::time::Instant;
     use tokio::time::{Duration, Interval};
     mod throttle;
     use throttle::{throttle, Throttle};
     mod chunks_timeout;
     pub use chunks_timeout::ChunksTimeout;
+    mod chunks_exact_timeout;
+    pub use chunks_exact_timeout::ChunksExactTimeout;
+    #[cfg(feature = "sync")]
+    mod rate_limit;
+    #[cfg(feature = "sync")]
+    pub use rate_limit::RateLimit;
+    #[cfg(feature = "sync")]
+    use std::sync::Arc;
+    #[cfg(feature = "sync")]
+    use tokio::sync::RateLimiter;
 }
 
 /// An extension trait for the [`Stream`] trait that provides a variety of
@@ -189,6 +259,154 @@ pub trait StreamExt: Stream {
         TryNext::new(self)
     }
 
+    /// Filters the `Ok` values of this fallible stream according to the
+    /// provided predicate, passing errors through.
+    ///
+    /// As with [`filter`](StreamExt::filter), values for which `f` returns
+    /// `false` are discarded; `Err` values are yielded unchanged without
+    /// running the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Ok(2), Err("nope"), Ok(3)]);
+    /// let mut evens = stream.try_filter(|&x| x % 2 == 0);
+    ///
+    /// assert_eq!(Some(Ok(2)), evens.next().await);
+    /// assert_eq!(Some(Err("nope")), evens.next().await);
+    /// assert_eq!(None, evens.next().await);
+    /// # }
+    /// ```
+    fn try_filter<F, T, E>(self, f: F) -> TryFilter<Self, F>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        F: FnMut(&T) -> bool,
+    {
+        TryFilter::new(self, f)
+    }
+
+    /// Drains this fallible stream into a collection, short-circuiting on the
+    /// first error.
+    ///
+    /// Equivalent to:
+    ///
+    /// ```ignore
+    /// async fn try_collect<U>(self) -> Result<U, E>;
+    /// ```
+    ///
+    /// This is [`collect`](StreamExt::collect) specialized to streams of
+    /// [`Result`]s: the `Ok` values are collected into `U`, and the first
+    /// `Err` aborts the collection and is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Ok(2), Ok(3)]);
+    /// let values: Result<Vec<i32>, &str> = stream.try_collect().await;
+    /// assert_eq!(Ok(vec![1, 2, 3]), values);
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("nope"), Ok(3)]);
+    /// let values: Result<Vec<i32>, &str> = stream.try_collect().await;
+    /// assert_eq!(Err("nope"), values);
+    /// # }
+    /// ```
+    fn try_collect<T, E, U>(self) -> Collect<Self, Result<U, E>>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        Result<U, E>: FromStream<Result<T, E>>,
+    {
+        Collect::new(self)
+    }
+
+    /// Runs a future for each `Ok` value of this fallible stream, with up to
+    /// `max` futures in flight at once, stopping at the first error.
+    ///
+    /// The returned future resolves to `Ok(())` once the stream is exhausted
+    /// and every spawned future has completed. If the stream yields an `Err`,
+    /// or any future resolves to one, that error is returned immediately and
+    /// the futures still in flight are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// async fn check(x: i32) -> Result<(), &'static str> {
+    ///     if x % 2 == 0 { Ok(()) } else { Err("odd") }
+    /// }
+    ///
+    /// let stream = stream::iter(vec![Ok(2), Ok(4), Ok(6)]);
+    /// assert_eq!(Ok(()), stream.try_for_each_concurrent(check, 2).await);
+    ///
+    /// let stream = stream::iter(vec![Ok(2), Ok(3), Ok(4)]);
+    /// assert_eq!(Err("odd"), stream.try_for_each_concurrent(check, 2).await);
+    /// # }
+    /// ```
+    fn try_for_each_concurrent<F, Fut, T, E>(
+        self,
+        f: F,
+        max: usize,
+    ) -> TryForEachConcurrent<Self, Fut, F>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+    {
+        TryForEachConcurrent::new(self, f, max)
+    }
+
+    /// Runs the `Ok` futures of this fallible stream concurrently, with up to
+    /// `max` in flight at once, yielding outputs as they complete.
+    ///
+    /// Like [`buffer_unordered`](StreamExt::buffer_unordered), outputs are
+    /// yielded in completion order. An `Err` from the stream, or from one of
+    /// the futures, is yielded as an item; the futures already in flight keep
+    /// running, so the consumer decides whether to stop or keep draining.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// async fn fetch(x: i32) -> Result<i32, &'static str> {
+    ///     Ok(x * 10)
+    /// }
+    ///
+    /// let stream = stream::iter(vec![Ok(fetch(1)), Ok(fetch(2))]);
+    /// let mut results: Vec<_> = stream.try_buffer_unordered(2).collect().await;
+    /// results.sort();
+    ///
+    /// assert_eq!(vec![Ok(10), Ok(20)], results);
+    /// # }
+    /// ```
+    fn try_buffer_unordered<Fut, T, E>(self, max: usize) -> TryBufferUnordered<Self, Fut>
+    where
+        Self: Stream<Item = Result<Fut, E>> + Sized,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        TryBufferUnordered::new(self, max)
+    }
+
     /// Maps this stream's items to a different type, returning a new stream of
     /// the resulting type.
     ///
@@ -265,6 +483,50 @@ pub trait StreamExt: Stream {
         MapWhile::new(self, f)
     }
 
+    /// Combinator similar to [`fold_async`] that holds internal state and
+    /// produces a new stream.
+    ///
+    /// For each item, the closure receives the current state and the item, and
+    /// returns a future resolving to `Some((new_state, output))` to yield
+    /// `output` and continue, or `None` to end the stream early. The future
+    /// can await between items, e.g. to persist the running state. Items are
+    /// not pulled from the stream while a state-update future is in flight.
+    ///
+    /// [`fold_async`]: StreamExt::fold_async
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// // Running sum that stops when it exceeds 5.
+    /// let sums = stream::iter(vec![1, 2, 3, 4]).scan_async(0, |acc, x| async move {
+    ///     let acc = acc + x;
+    ///     if acc > 5 {
+    ///         None
+    ///     } else {
+    ///         Some((acc, acc))
+    ///     }
+    /// });
+    /// tokio::pin!(sums);
+    ///
+    /// assert_eq!(sums.next().await, Some(1));
+    /// assert_eq!(sums.next().await, Some(3));
+    /// // 1 + 2 + 3 exceeds 5, so the stream ends early.
+    /// assert_eq!(sums.next().await, None);
+    /// # }
+    /// ```
+    fn scan_async<S, B, Fut, F>(self, initial_state: S, f: F) -> ScanAsync<Self, S, Fut, F>
+    where
+        F: FnMut(S, Self::Item) -> Fut,
+        Fut: Future<Output = Option<(S, B)>>,
+        Self: Sized,
+    {
+        ScanAsync::new(self, initial_state, f)
+    }
+
     /// Maps this stream's items asynchronously to a different type, returning a
     /// new stream of the resulting type.
     ///
@@ -310,6 +572,175 @@ pub trait StreamExt: Stream {
         Then::new(self, f)
     }
 
+    /// Runs up to `n` futures from this stream concurrently, yielding their
+    /// results in the order the futures were produced by the stream.
+    ///
+    /// The first `n` futures are run concurrently; as the future at the front
+    /// completes and its output is yielded, another future is started in its
+    /// place. A slow future at the front delays the outputs of later futures
+    /// that have already completed — use [`buffer_unordered`] when completion
+    /// order is acceptable and lower latency matters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// [`buffer_unordered`]: StreamExt::buffer_unordered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// async fn do_async_work(value: i32) -> i32 {
+    ///     value + 3
+    /// }
+    ///
+    /// let stream = stream::iter(1..=3).map(do_async_work).buffered(2);
+    ///
+    /// tokio::pin!(stream);
+    ///
+    /// assert_eq!(stream.next().await, Some(4));
+    /// assert_eq!(stream.next().await, Some(5));
+    /// assert_eq!(stream.next().await, Some(6));
+    /// # }
+    /// ```
+    fn buffered(self, n: usize) -> Buffered<Self, Self::Item>
+    where
+        Self::Item: Future,
+        Self: Sized,
+    {
+        Buffered::new(self, n)
+    }
+
+    /// Runs up to `n` futures from this stream concurrently, yielding their
+    /// results in the order the futures complete.
+    ///
+    /// The first `n` futures are run concurrently; whenever one completes its
+    /// output is yielded and another future is started in its place. Use
+    /// [`buffered`] if the outputs must keep the order in which the stream
+    /// produced the futures.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// [`buffered`]: StreamExt::buffered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// async fn do_async_work(value: i32) -> i32 {
+    ///     value + 3
+    /// }
+    ///
+    /// let stream = stream::iter(1..=3).map(do_async_work).buffer_unordered(2);
+    ///
+    /// tokio::pin!(stream);
+    ///
+    /// let mut results = Vec::new();
+    /// while let Some(value) = stream.next().await {
+    ///     results.push(value);
+    /// }
+    ///
+    /// results.sort_unstable();
+    /// assert_eq!(results, vec![4, 5, 6]);
+    /// # }
+    /// ```
+    fn buffer_unordered(self, n: usize) -> BufferUnordered<Self, Self::Item>
+    where
+        Self::Item: Future,
+        Self: Sized,
+    {
+        BufferUnordered::new(self, n)
+    }
+
+    /// Maps this stream with an asynchronous closure, running up to `n` of
+    /// the returned futures concurrently.
+    ///
+    /// This is the concurrent variant of [`then`], which awaits each future
+    /// to completion before starting the next. Outputs are yielded in the
+    /// order the input values arrived, like `map(f).buffered(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// [`then`]: StreamExt::then
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// async fn do_async_work(value: i32) -> i32 {
+    ///     value + 3
+    /// }
+    ///
+    /// let stream = stream::iter(1..=3).then_concurrent(do_async_work, 2);
+    ///
+    /// tokio::pin!(stream);
+    ///
+    /// assert_eq!(stream.next().await, Some(4));
+    /// assert_eq!(stream.next().await, Some(5));
+    /// assert_eq!(stream.next().await, Some(6));
+    /// # }
+    /// ```
+    fn then_concurrent<F, Fut>(self, f: F, n: usize) -> ThenConcurrent<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        Buffered::new(Map::new(self, f), n)
+    }
+
+    /// Maps each value of this stream to a future, keeping only the most
+    /// recent one in flight.
+    ///
+    /// Whenever the stream produces a new value, the future obtained from the
+    /// previous value is dropped — cancelling it — and `f` is run on the new
+    /// value instead, so only outputs of futures that finish before being
+    /// superseded are yielded. This is useful when only the latest request
+    /// matters, such as type-ahead searches or config watchers, where
+    /// [`then`](StreamExt::then) would process every stale value in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// async fn lookup(query: i32) -> i32 {
+    ///     query * 10
+    /// }
+    ///
+    /// // Both values are ready at once, so the first one is superseded
+    /// // before its lookup runs.
+    /// let stream = stream::iter(vec![1, 2]).switch_map(lookup);
+    /// let results: Vec<_> = stream.collect().await;
+    ///
+    /// assert_eq!(vec![20], results);
+    /// # }
+    /// ```
+    fn switch_map<F, Fut>(self, f: F) -> SwitchMap<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        SwitchMap::new(self, f)
+    }
+
     /// Combine two streams into one by interleaving the output of both as it
     /// is produced.
     ///
@@ -394,12 +825,161 @@ pub trait StreamExt: Stream {
     ///    assert!(rx.next().await.is_none());
     /// }
     /// ```
-    fn merge<U>(self, other: U) -> Merge<Self, U>
+    fn merge<U>(self, other: U) -> Merge<Self, U>
+    where
+        U: Stream<Item = Self::Item>,
+        Self: Sized,
+    {
+        Merge::new(self, other)
+    }
+
+    /// Combines this stream with another, yielding the most recent value from
+    /// each whenever either stream yields.
+    ///
+    /// Unlike `zip`-style pairing, no value is lost when the streams produce
+    /// at different rates: a fast stream simply sees the slow stream's last
+    /// value repeated. Nothing is yielded until both streams have produced at
+    /// least one value, and the combined stream ends once both streams have
+    /// ended (or as soon as either ends without yielding anything, since no
+    /// tuple can be formed).
+    ///
+    /// This is useful for merging feeds where only the latest value matters,
+    /// such as pairing sensor readings with the current configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let readings = stream::iter(vec![1, 2, 3]);
+    /// let config = stream::once("v1");
+    ///
+    /// let combined: Vec<(i32, &str)> = readings.combine_latest(config).collect().await;
+    ///
+    /// // Every reading is paired with the latest configuration.
+    /// assert!(combined.contains(&(3, "v1")));
+    /// assert_eq!(combined.last(), Some(&(3, "v1")));
+    /// # }
+    /// ```
+    fn combine_latest<U>(self, other: U) -> CombineLatest<Self, U>
+    where
+        U: Stream,
+        Self: Sized,
+    {
+        CombineLatest::new(self, other)
+    }
+
+    /// Splits the values produced by this stream into per-key sub-streams.
+    ///
+    /// `f` is run on each value to compute its key. The first time a key is
+    /// seen, the returned stream yields the key together with a
+    /// [`GroupStream`] producing every value with that key, in stream order.
+    /// The returned stream completes once the underlying stream does; each
+    /// sub-stream completes after draining its remaining values.
+    ///
+    /// Each sub-stream buffers at most `capacity` values. When a buffer is
+    /// full, the underlying stream is not polled again until the sub-stream's
+    /// consumer frees a slot, so a sub-stream that is never polled eventually
+    /// exerts backpressure on every other group. Dropping a sub-stream lifts
+    /// that backpressure: further values with its key are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 4, 2, 3, 6]);
+    /// let mut groups = stream.group_by(|x| x % 2, 16);
+    ///
+    /// let (key, odds) = groups.next().await.unwrap();
+    /// assert_eq!(key, 1);
+    ///
+    /// let (key, evens) = groups.next().await.unwrap();
+    /// assert_eq!(key, 0);
+    ///
+    /// assert!(groups.next().await.is_none());
+    /// assert_eq!(vec![1, 3], odds.collect::<Vec<_>>().await);
+    /// assert_eq!(vec![4, 2, 6], evens.collect::<Vec<_>>().await);
+    /// # }
+    /// ```
+    fn group_by<F, K>(self, f: F, capacity: usize) -> GroupBy<Self, F, K>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: Clone + Eq + std::hash::Hash,
+        Self: Sized,
+    {
+        GroupBy::new(self, f, capacity)
+    }
+
+    /// Splits the values produced by this stream into two streams according
+    /// to the provided predicate.
+    ///
+    /// The first returned stream yields the values for which the predicate
+    /// returns `true`, the second the rest, each in stream order. Polling
+    /// either half advances the underlying stream; values destined for the
+    /// other half are buffered until it is polled, without limit. Dropping
+    /// one half discards its buffered and future values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=6);
+    /// let (evens, odds) = stream.partition(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(vec![2, 4, 6], evens.collect::<Vec<_>>().await);
+    /// assert_eq!(vec![1, 3, 5], odds.collect::<Vec<_>>().await);
+    /// # }
+    /// ```
+    fn partition<F>(self, f: F) -> (Partition<Self, F>, Partition<Self, F>)
+    where
+        F: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        partition::new(self, f)
+    }
+
+    /// Splits this stream into a prefix and a remainder at the first value
+    /// matching the provided predicate.
+    ///
+    /// The prefix stream yields values until the predicate returns `true`,
+    /// then ends. The remainder stream stays pending while the prefix is
+    /// running and then yields the remaining values, starting with the one
+    /// that matched. Dropping the prefix before a match hands the rest of the
+    /// stream to the remainder, so a processing stage can be resumed after the
+    /// split point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=6);
+    /// let (prefix, rest) = stream.split_when(|&x| x == 4);
+    ///
+    /// assert_eq!(vec![1, 2, 3], prefix.collect::<Vec<_>>().await);
+    /// assert_eq!(vec![4, 5, 6], rest.collect::<Vec<_>>().await);
+    /// # }
+    /// ```
+    fn split_when<F>(self, f: F) -> (SplitPrefix<Self, F>, SplitRemainder<Self, F>)
     where
-        U: Stream<Item = Self::Item>,
+        F: FnMut(&Self::Item) -> bool,
         Self: Sized,
     {
-        Merge::new(self, other)
+        split_when::new(self, f)
     }
 
     /// Filters the values produced by this stream according to the provided
@@ -440,6 +1020,48 @@ pub trait StreamExt: Stream {
         Filter::new(self, f)
     }
 
+    /// Filters the values produced by this stream according to the provided
+    /// asynchronous predicate.
+    ///
+    /// As values of this stream are made available, the provided predicate is
+    /// run on the value and its future awaited. If the future resolves to
+    /// `true`, then the stream yields the value, otherwise the value is
+    /// discarded. The predicates run one at a time, in stream order.
+    ///
+    /// Note that the predicate borrows the value, so a predicate that needs
+    /// to move data into its future must clone or copy it first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// async fn is_even(value: i32) -> bool {
+    ///     value % 2 == 0
+    /// }
+    ///
+    /// let stream = stream::iter(1..=8);
+    /// let evens = stream.filter_async(|x| is_even(*x));
+    /// tokio::pin!(evens);
+    ///
+    /// assert_eq!(Some(2), evens.next().await);
+    /// assert_eq!(Some(4), evens.next().await);
+    /// assert_eq!(Some(6), evens.next().await);
+    /// assert_eq!(Some(8), evens.next().await);
+    /// assert_eq!(None, evens.next().await);
+    /// # }
+    /// ```
+    fn filter_async<F, Fut>(self, f: F) -> FilterAsync<Self, Fut, F>
+    where
+        F: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+        Self: Sized,
+    {
+        FilterAsync::new(self, f)
+    }
+
     /// Filters the values produced by this stream while simultaneously mapping
     /// them to a different type according to the provided closure.
     ///
@@ -478,6 +1100,68 @@ pub trait StreamExt: Stream {
         FilterMap::new(self, f)
     }
 
+    /// Suppresses consecutive duplicate values produced by this stream.
+    ///
+    /// A value is yielded only if it compares unequal to the last value that
+    /// was yielded; repeats are discarded. Equal values that are separated by
+    /// a different value are yielded again, so the result is not globally
+    /// unique. This is useful for change-detection pipelines where only
+    /// transitions matter, such as streams built on a watch channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 1, 2, 2, 2, 1, 3, 3]);
+    /// let values: Vec<_> = stream.distinct_until_changed().collect().await;
+    ///
+    /// assert_eq!(vec![1, 2, 1, 3], values);
+    /// # }
+    /// ```
+    fn distinct_until_changed(self) -> DistinctUntilChanged<Self>
+    where
+        Self::Item: Clone + PartialEq,
+        Self: Sized,
+    {
+        DistinctUntilChanged::new(self)
+    }
+
+    /// Suppresses consecutive values of this stream that map to the same key.
+    ///
+    /// `f` is run on each value to compute its key; a value is yielded only
+    /// if its key differs from the previous value's key. This behaves like
+    /// [`distinct_until_changed`](StreamExt::distinct_until_changed), but
+    /// compares keys rather than the values themselves, so the values do not
+    /// need to implement [`PartialEq`] or [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["apple", "avocado", "banana", "cherry", "clementine"]);
+    /// let values: Vec<_> = stream
+    ///     .dedup_by_key(|s| s.chars().next())
+    ///     .collect()
+    ///     .await;
+    ///
+    /// assert_eq!(vec!["apple", "banana", "cherry"], values);
+    /// # }
+    /// ```
+    fn dedup_by_key<F, K>(self, f: F) -> DedupByKey<Self, F, K>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+        Self: Sized,
+    {
+        DedupByKey::new(self, f)
+    }
+
     /// Creates a stream which ends after the first `None`.
     ///
     /// After a stream returns `None`, behavior is undefined. Future calls to
@@ -600,6 +1284,45 @@ pub trait StreamExt: Stream {
         TakeWhile::new(self, f)
     }
 
+    /// Takes elements from this stream until the provided future resolves.
+    ///
+    /// This function will take elements from the stream until the provided
+    /// shutdown future `fut` resolves. Once it resolves, or once the
+    /// underlying stream ends, the returned stream will always report that it
+    /// is done. The future is checked before the stream on every poll, so no
+    /// further elements are yielded after it resolves.
+    ///
+    /// This is the standard building block for graceful shutdown: the future
+    /// is typically something like
+    /// `tokio_util::sync::CancellationToken::cancelled_owned`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use tokio::sync::oneshot;
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let (tx, rx) = oneshot::channel::<()>();
+    ///
+    /// let mut stream = stream::iter(1..=3).take_until(rx);
+    ///
+    /// assert_eq!(Some(1), stream.next().await);
+    ///
+    /// // Signal shutdown; no further elements are yielded.
+    /// tx.send(()).unwrap();
+    /// assert_eq!(None, stream.next().await);
+    /// # }
+    /// ```
+    fn take_until<Fut>(self, fut: Fut) -> TakeUntil<Self, Fut>
+    where
+        Fut: Future,
+        Self: Sized,
+    {
+        TakeUntil::new(self, fut)
+    }
+
     /// Creates a new stream that will skip the `n` first items of the
     /// underlying stream.
     ///
@@ -656,6 +1379,41 @@ pub trait StreamExt: Stream {
         SkipWhile::new(self, f)
     }
 
+    /// Batches the values of this stream into overlapping windows of length
+    /// `size`.
+    ///
+    /// The first window is yielded once `size` values have been produced;
+    /// every subsequent value then yields a new window containing the `size`
+    /// most recent values, so consecutive windows overlap in all but one
+    /// position. A stream that produces fewer than `size` values yields no
+    /// windows. The values are cloned into each window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=4).sliding_window(3);
+    ///
+    /// assert_eq!(Some(vec![1, 2, 3]), stream.next().await);
+    /// assert_eq!(Some(vec![2, 3, 4]), stream.next().await);
+    /// assert_eq!(None, stream.next().await);
+    /// # }
+    /// ```
+    fn sliding_window(self, size: usize) -> SlidingWindow<Self>
+    where
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        SlidingWindow::new(self, size)
+    }
+
     /// Tests if every element of the stream matches a predicate.
     ///
     /// Equivalent to:
@@ -841,6 +1599,43 @@ pub trait StreamExt: Stream {
         FoldFuture::new(self, init, f)
     }
 
+    /// A combinator that asynchronously applies a function to every element in
+    /// a stream producing a single, final value.
+    ///
+    /// This is the asynchronous version of [`fold`](StreamExt::fold): the
+    /// accumulator closure returns a future, so it can await (e.g. perform a
+    /// database write) for each item. Items are not pulled from the stream
+    /// while the accumulator future is in flight.
+    ///
+    /// Equivalent to:
+    ///
+    /// ```ignore
+    /// async fn fold_async<B, F>(self, init: B, f: F) -> B;
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, *};
+    ///
+    /// let s = stream::iter(vec![1u8, 2, 3]);
+    /// let sum = s.fold_async(0, |acc, x| async move { acc + x }).await;
+    ///
+    /// assert_eq!(sum, 6);
+    /// # }
+    /// ```
+    fn fold_async<B, Fut, F>(self, init: B, f: F) -> FoldAsyncFuture<Self, B, Fut, F>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        FoldAsyncFuture::new(self, init, f)
+    }
+
     /// Drain stream pushing all emitted values into a collection.
     ///
     /// Equivalent to:
@@ -920,6 +1715,53 @@ pub trait StreamExt: Stream {
         Collect::new(self)
     }
 
+    /// Forwards every value of this stream into the given [`mpsc::Sender`],
+    /// waiting for channel capacity before pulling each value so that a slow
+    /// receiver exerts backpressure on the stream.
+    ///
+    /// The returned future resolves to `Ok(())` once the stream is exhausted,
+    /// or to an error if the channel is closed because the receiver was
+    /// dropped. This replaces the common
+    /// `while let Some(x) = stream.next().await { tx.send(x).await?; }`
+    /// forwarding task; values can be transformed or dropped on the way by
+    /// composing adapters such as [`map`](StreamExt::map) and
+    /// [`filter`](StreamExt::filter) before calling `forward_to`.
+    ///
+    /// [`mpsc::Sender`]: tokio::sync::mpsc::Sender
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+    ///
+    /// tokio::spawn(async move {
+    ///     stream::iter(1..=3)
+    ///         .map(|x| x * 2)
+    ///         .forward_to(tx)
+    ///         .await
+    ///         .expect("receiver dropped");
+    /// });
+    ///
+    /// assert_eq!(Some(2), rx.recv().await);
+    /// assert_eq!(Some(4), rx.recv().await);
+    /// assert_eq!(Some(6), rx.recv().await);
+    /// assert_eq!(None, rx.recv().await);
+    /// # }
+    /// ```
+    #[cfg(feature = "sync")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    fn forward_to(self, sender: Sender<Self::Item>) -> ForwardTo<Self>
+    where
+        Self::Item: Send + 'static,
+        Self: Sized,
+    {
+        ForwardTo::new(self, sender)
+    }
+
     /// Applies a per-item timeout to the passed stream.
     ///
     /// `timeout()` takes a `Duration` that represents the maximum amount of
@@ -1093,6 +1935,54 @@ pub trait StreamExt: Stream {
         TimeoutRepeating::new(self, interval)
     }
 
+    /// Applies a per-item deadline to this stream, where the deadline for the
+    /// *next* item is extracted from the previous one.
+    ///
+    /// Whenever the stream yields an item, `f` is called with a reference to
+    /// it to compute the [`Instant`] by which the following item must arrive.
+    /// If the deadline elapses first, an error is yielded and the deadline is
+    /// disarmed until the stream produces another item. No deadline applies
+    /// before the first item.
+    ///
+    /// This is useful for protocols that embed their own expiry in each
+    /// message, such as heartbeats carrying a lease duration — something
+    /// neither [`timeout`](StreamExt::timeout), with its fixed duration, nor
+    /// [`timeout_repeating`](StreamExt::timeout_repeating), with its fixed
+    /// interval, can express.
+    ///
+    /// [`Instant`]: struct@tokio::time::Instant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    /// use tokio::time::Instant;
+    /// use tokio_stream::{StreamExt, wrappers::IntervalStream};
+    ///
+    /// // A heartbeat arrives every 10 seconds, and each one promises the
+    /// // next within 15 seconds.
+    /// let heartbeats = IntervalStream::new(tokio::time::interval(Duration::from_secs(10)));
+    /// let watched = heartbeats.deadline_by(|_| Instant::now() + Duration::from_secs(15));
+    /// tokio::pin!(watched);
+    ///
+    /// // The heartbeats keep arriving in time, so no error is produced.
+    /// assert!(watched.try_next().await.is_ok());
+    /// assert!(watched.try_next().await.is_ok());
+    /// assert!(watched.try_next().await.is_ok());
+    /// # }
+    /// ```
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    fn deadline_by<F>(self, f: F) -> DeadlineBy<Self, F>
+    where
+        F: FnMut(&Self::Item) -> Instant,
+        Self: Sized,
+    {
+        DeadlineBy::new(self, f)
+    }
+
     /// Slows down a stream by enforcing a delay between items.
     ///
     /// The underlying timer behind this utility has a granularity of one millisecond.
@@ -1180,6 +2070,100 @@ pub trait StreamExt: Stream {
         ChunksTimeout::new(self, max_size, duration)
     }
 
+    /// Batches the items in the given stream into chunks of exactly `chunk_size`,
+    /// padding with default values when the duration elapses first.
+    ///
+    /// This behaves like [`chunks_timeout`](StreamExt::chunks_timeout), except
+    /// that a batch cut short — because the time since its first item exceeded
+    /// the given duration, or because the stream ended — is padded with
+    /// [`Default::default`] up to `chunk_size`, so every batch has the same
+    /// length. Empty batches will not be emitted if no items are received
+    /// upstream.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `chunk_size` is zero
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tokio::time;
+    /// use tokio_stream::{self as stream, StreamExt};
+    /// use futures::FutureExt;
+    ///
+    /// #[tokio::main]
+    /// # async fn _unused() {}
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// async fn main() {
+    ///     let iter = vec![1, 2, 3, 4].into_iter();
+    ///     let stream0 = stream::iter(iter);
+    ///
+    ///     let iter = vec![5].into_iter();
+    ///     let stream1 = stream::iter(iter)
+    ///          .then(move |n| time::sleep(Duration::from_secs(5)).map(move |_| n));
+    ///
+    ///     let chunk_stream = stream0
+    ///         .chain(stream1)
+    ///         .chunks_exact_timeout(3, Duration::from_secs(2));
+    ///     tokio::pin!(chunk_stream);
+    ///
+    ///     // a full batch was received
+    ///     assert_eq!(chunk_stream.next().await, Some(vec![1, 2, 3]));
+    ///     // deadline was reached before chunk_size was reached; the batch is padded
+    ///     assert_eq!(chunk_stream.next().await, Some(vec![4, 0, 0]));
+    ///     // last element in the stream
+    ///     assert_eq!(chunk_stream.next().await, Some(vec![5, 0, 0]));
+    /// }
+    /// ```
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    #[track_caller]
+    fn chunks_exact_timeout(self, chunk_size: usize, duration: Duration) -> ChunksExactTimeout<Self>
+    where
+        Self::Item: Default,
+        Self: Sized,
+    {
+        assert!(chunk_size > 0, "`chunk_size` must be non-zero.");
+        ChunksExactTimeout::new(self, chunk_size, duration)
+    }
+
+    /// Limits the rate at which this stream yields values by acquiring a
+    /// permit from the given [`RateLimiter`] for each value.
+    ///
+    /// A value is pulled from the underlying stream first and then held back
+    /// until a permit is acquired, so an idle stream does not consume quota.
+    /// Because the limiter is shared through an [`Arc`], several streams can
+    /// be throttled against one quota, unlike
+    /// [`throttle`](StreamExt::throttle) which paces each stream separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::sync::RateLimiter;
+    /// use tokio_stream::{self as stream, StreamExt};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// let limiter = Arc::new(RateLimiter::new(100, Duration::from_secs(1)));
+    ///
+    /// // Both streams draw from the same 100 values/s quota.
+    /// let first = stream::iter(0..10).rate_limit(limiter.clone());
+    /// let second = stream::iter(10..20).rate_limit(limiter);
+    /// # let _ = (first, second);
+    /// # }
+    /// ```
+    #[cfg(all(feature = "time", feature = "sync"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "time", feature = "sync"))))]
+    fn rate_limit(self, limiter: Arc<RateLimiter>) -> RateLimit<Self>
+    where
+        Self: Sized,
+    {
+        RateLimit::new(self, limiter)
+    }
+
     /// Turns the stream into a peekable stream, whose next element can be peeked at without being
     /// consumed.
     /// ```rust
@@ -1204,6 +2188,38 @@ pub trait StreamExt: Stream {
     {
         Peekable::new(self)
     }
+
+    /// Wraps every value of this stream in `Some`, yielding a final `None`
+    /// exactly once when the stream finishes.
+    ///
+    /// This is a convenience for [`StreamNotifyClose::new`] and is mostly
+    /// useful with [`StreamMap`], where the end-of-stream marker lets the
+    /// consumer detect and remove completed streams without a sentinel value.
+    ///
+    /// [`StreamNotifyClose::new`]: crate::StreamNotifyClose::new
+    /// [`StreamMap`]: crate::StreamMap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let mut stream = stream::iter(vec![0, 1]).notify_close();
+    ///
+    /// assert_eq!(Some(Some(0)), stream.next().await);
+    /// assert_eq!(Some(Some(1)), stream.next().await);
+    /// assert_eq!(Some(None), stream.next().await);
+    /// assert_eq!(None, stream.next().await);
+    /// # }
+    /// ```
+    fn notify_close(self) -> crate::StreamNotifyClose<Self>
+    where
+        Self: Sized,
+    {
+        crate::StreamNotifyClose::new(self)
+    }
 }
 
 impl<St: ?Sized> StreamExt for St where St: Stream {}
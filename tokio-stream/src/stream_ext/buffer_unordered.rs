@@ -0,0 +1,90 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`buffer_unordered`](super::StreamExt::buffer_unordered) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct BufferUnordered<St, Fut> {
+        #[pin]
+        stream: St,
+        in_flight: Vec<Pin<Box<Fut>>>,
+        max: usize,
+        done: bool,
+    }
+}
+
+impl<St, Fut> fmt::Debug for BufferUnordered<St, Fut>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferUnordered")
+            .field("stream", &self.stream)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<St, Fut> BufferUnordered<St, Fut> {
+    pub(super) fn new(stream: St, max: usize) -> Self {
+        assert!(max > 0, "`max` must be non-zero.");
+
+        BufferUnordered {
+            stream,
+            in_flight: Vec::with_capacity(max),
+            max,
+            done: false,
+        }
+    }
+}
+
+impl<St, Fut> Stream for BufferUnordered<St, Fut>
+where
+    St: Stream<Item = Fut>,
+    Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Fut::Output>> {
+        let mut me = self.project();
+
+        // Fill the set of in-flight futures up to the concurrency limit.
+        while !*me.done && me.in_flight.len() < *me.max {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(fut)) => me.in_flight.push(Box::pin(fut)),
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        let mut idx = 0;
+        while idx < me.in_flight.len() {
+            if let Poll::Ready(output) = me.in_flight[idx].as_mut().poll(cx) {
+                me.in_flight.swap_remove(idx);
+                return Poll::Ready(Some(output));
+            }
+            idx += 1;
+        }
+
+        if me.in_flight.is_empty() && *me.done {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let in_flight = self.in_flight.len();
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = lower.saturating_add(in_flight);
+        let upper = upper.and_then(|upper| upper.checked_add(in_flight));
+
+        (lower, upper)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+use std::collections::VecDeque;
+
+/// Stream for the [`then_concurrent`](super::StreamExt::then_concurrent) method.
+pub type ThenConcurrent<St, Fut, F> = Buffered<super::Map<St, F>, Fut>;
+
+enum Slot<Fut: Future> {
+    Running(Pin<Box<Fut>>),
+    Done(Fut::Output),
+}
+
+pin_project! {
+    /// Stream for the [`buffered`](super::StreamExt::buffered) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Buffered<St, Fut>
+    where
+        Fut: Future,
+    {
+        #[pin]
+        stream: St,
+        queue: VecDeque<Slot<Fut>>,
+        max: usize,
+        done: bool,
+    }
+}
+
+impl<St, Fut> fmt::Debug for Buffered<St, Fut>
+where
+    St: fmt::Debug,
+    Fut: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Buffered")
+            .field("stream", &self.stream)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<St, Fut> Buffered<St, Fut>
+where
+    Fut: Future,
+{
+    pub(super) fn new(stream: St, max: usize) -> Self {
+        assert!(max > 0, "`max` must be non-zero.");
+
+        Buffered {
+            stream,
+            queue: VecDeque::with_capacity(max),
+            max,
+            done: false,
+        }
+    }
+}
+
+impl<St, Fut> Stream for Buffered<St, Fut>
+where
+    St: Stream<Item = Fut>,
+    Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Fut::Output>> {
+        let mut me = self.project();
+
+        // Fill the queue up to the concurrency limit.
+        while !*me.done && me.queue.len() < *me.max {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(fut)) => me.queue.push_back(Slot::Running(Box::pin(fut))),
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        // Drive every in-flight future, not just the one at the front;
+        // completions behind the front are stored until it is their turn.
+        for slot in me.queue.iter_mut() {
+            if let Slot::Running(fut) = slot {
+                if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+                    *slot = Slot::Done(output);
+                }
+            }
+        }
+
+        if let Some(Slot::Done(_)) = me.queue.front() {
+            let output = match me.queue.pop_front() {
+                Some(Slot::Done(output)) => output,
+                _ => unreachable!(),
+            };
+            return Poll::Ready(Some(output));
+        }
+
+        if me.queue.is_empty() && *me.done {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queue_len = self.queue.len();
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = lower.saturating_add(queue_len);
+        let upper = upper.and_then(|upper| upper.checked_add(queue_len));
+
+        (lower, upper)
+    }
+}
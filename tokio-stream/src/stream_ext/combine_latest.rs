@@ -0,0 +1,120 @@
+use crate::stream_ext::Fuse;
+use crate::Stream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`combine_latest`](super::StreamExt::combine_latest) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct CombineLatest<T, U>
+    where
+        T: Stream,
+        U: Stream,
+    {
+        #[pin]
+        a: Fuse<T>,
+        #[pin]
+        b: Fuse<U>,
+        latest_a: Option<T::Item>,
+        latest_b: Option<U::Item>,
+    }
+}
+
+impl<T, U> CombineLatest<T, U>
+where
+    T: Stream,
+    U: Stream,
+{
+    pub(super) fn new(a: T, b: U) -> CombineLatest<T, U> {
+        CombineLatest {
+            a: Fuse::new(a),
+            b: Fuse::new(b),
+            latest_a: None,
+            latest_b: None,
+        }
+    }
+}
+
+impl<T, U> Stream for CombineLatest<T, U>
+where
+    T: Stream,
+    U: Stream,
+    T::Item: Clone,
+    U::Item: Clone,
+{
+    type Item = (T::Item, U::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            let mut done = true;
+            let mut updated = false;
+
+            match me.a.as_mut().poll_next(cx) {
+                Poll::Ready(Some(val)) => {
+                    *me.latest_a = Some(val);
+                    updated = true;
+                }
+                Poll::Ready(None) => {
+                    // A side that ends without yielding can never be part of
+                    // a tuple, so the combined stream ends right away.
+                    if me.latest_a.is_none() {
+                        return Poll::Ready(None);
+                    }
+                }
+                Poll::Pending => done = false,
+            }
+
+            match me.b.as_mut().poll_next(cx) {
+                Poll::Ready(Some(val)) => {
+                    *me.latest_b = Some(val);
+                    updated = true;
+                }
+                Poll::Ready(None) => {
+                    if me.latest_b.is_none() {
+                        return Poll::Ready(None);
+                    }
+                }
+                Poll::Pending => done = false,
+            }
+
+            if updated {
+                if let (Some(a), Some(b)) = (me.latest_a.as_ref(), me.latest_b.as_ref()) {
+                    return Poll::Ready(Some((a.clone(), b.clone())));
+                }
+                // Only one side has yielded so far; keep polling until the
+                // other side produces its first value or parks.
+                continue;
+            }
+
+            return if done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each item from either stream produces at most one tuple, and items
+        // arriving before both sides have yielded produce none.
+        super::merge_size_hints((0, self.a.size_hint().1), (0, self.b.size_hint().1))
+    }
+}
+
+impl<T, U> fmt::Debug for CombineLatest<T, U>
+where
+    T: Stream + fmt::Debug,
+    U: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CombineLatest")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::stream_ext::Fuse;
+use crate::{Elapsed, Stream};
+use tokio::time::{Instant, Sleep};
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+use std::fmt;
+
+pin_project! {
+    /// Stream returned by the [`deadline_by`](super::StreamExt::deadline_by) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DeadlineBy<S, F> {
+        #[pin]
+        stream: Fuse<S>,
+        #[pin]
+        deadline: Sleep,
+        f: F,
+        poll_deadline: bool,
+    }
+}
+
+impl<S, F> fmt::Debug for DeadlineBy<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeadlineBy")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S: Stream, F> DeadlineBy<S, F>
+where
+    F: FnMut(&S::Item) -> Instant,
+{
+    pub(super) fn new(stream: S, f: F) -> Self {
+        DeadlineBy {
+            stream: Fuse::new(stream),
+            // Placeholder; there is no deadline until the first item arrives.
+            deadline: tokio::time::sleep_until(Instant::now()),
+            f,
+            poll_deadline: false,
+        }
+    }
+}
+
+impl<S: Stream, F> Stream for DeadlineBy<S, F>
+where
+    F: FnMut(&S::Item) -> Instant,
+{
+    type Item = Result<S::Item, Elapsed>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        match me.stream.poll_next(cx) {
+            Poll::Ready(v) => {
+                if let Some(item) = &v {
+                    let next = (me.f)(item);
+                    me.deadline.reset(next);
+                    *me.poll_deadline = true;
+                }
+                return Poll::Ready(v.map(Ok));
+            }
+            Poll::Pending => {}
+        };
+
+        if *me.poll_deadline {
+            ready!(me.deadline.poll(cx));
+            *me.poll_deadline = false;
+            return Poll::Ready(Some(Err(Elapsed::new())));
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+
+        // At most one error is inserted after each item, and none before the
+        // first item, so the upper bound is 2x.
+        (lower, upper.and_then(|upper| upper.checked_mul(2)))
+    }
+}
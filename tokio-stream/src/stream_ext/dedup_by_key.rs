@@ -0,0 +1,69 @@
+use crate::Stream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`dedup_by_key`](super::StreamExt::dedup_by_key) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DedupByKey<St, F, K> {
+        #[pin]
+        stream: St,
+        f: F,
+        last_key: Option<K>,
+    }
+}
+
+impl<St, F, K> fmt::Debug for DedupByKey<St, F, K>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupByKey")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St, F, K> DedupByKey<St, F, K> {
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self {
+            stream,
+            f,
+            last_key: None,
+        }
+    }
+}
+
+impl<St, F, K> Stream for DedupByKey<St, F, K>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut me = self.project();
+
+        loop {
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let key = (me.f)(&item);
+                    if me.last_key.as_ref() != Some(&key) {
+                        *me.last_key = Some(key);
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Can't know a lower bound, due to the deduplication.
+        (0, self.stream.size_hint().1)
+    }
+}
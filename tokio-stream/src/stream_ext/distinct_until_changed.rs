@@ -0,0 +1,68 @@
+use crate::Stream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`distinct_until_changed`](super::StreamExt::distinct_until_changed) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DistinctUntilChanged<St>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: St,
+        last: Option<St::Item>,
+    }
+}
+
+impl<St> fmt::Debug for DistinctUntilChanged<St>
+where
+    St: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DistinctUntilChanged")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St> DistinctUntilChanged<St>
+where
+    St: Stream,
+{
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, last: None }
+    }
+}
+
+impl<St> Stream for DistinctUntilChanged<St>
+where
+    St: Stream,
+    St::Item: Clone + PartialEq,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut me = self.project();
+
+        loop {
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    if me.last.as_ref() != Some(&item) {
+                        *me.last = Some(item.clone());
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Can't know a lower bound, due to the deduplication.
+        (0, self.stream.size_hint().1)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`filter_async`](super::StreamExt::filter_async) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FilterAsync<St, Fut, F>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: St,
+        #[pin]
+        future: Option<Fut>,
+        pending_item: Option<St::Item>,
+        f: F,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for FilterAsync<St, Fut, F>
+where
+    St: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilterAsync")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> FilterAsync<St, Fut, F>
+where
+    St: Stream,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self {
+            stream,
+            future: None,
+            pending_item: None,
+            f,
+        }
+    }
+}
+
+impl<St, Fut, F> Stream for FilterAsync<St, Fut, F>
+where
+    St: Stream,
+    Fut: Future<Output = bool>,
+    F: FnMut(&St::Item) -> Fut,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if let Some(future) = me.future.as_mut().as_pin_mut() {
+                match future.poll(cx) {
+                    Poll::Ready(keep) => {
+                        me.future.set(None);
+                        let item = me.pending_item.take().unwrap();
+                        if keep {
+                            return Poll::Ready(Some(item));
+                        }
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    me.future.set(Some((me.f)(&item)));
+                    *me.pending_item = Some(item);
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Can't know a lower bound, due to the predicate.
+        let pending = usize::from(self.pending_item.is_some());
+        let upper = self.stream.size_hint().1;
+
+        (0, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
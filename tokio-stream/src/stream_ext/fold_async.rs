@@ -0,0 +1,66 @@
+use crate::Stream;
+
+use core::future::Future;
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future returned by the [`fold_async`](super::StreamExt::fold_async) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FoldAsyncFuture<St, B, Fut, F> {
+        #[pin]
+        stream: St,
+        acc: Option<B>,
+        f: F,
+        #[pin]
+        future: Option<Fut>,
+        // Make this future `!Unpin` for compatibility with async trait methods.
+        #[pin]
+        _pin: PhantomPinned,
+    }
+}
+
+impl<St, B, Fut, F> FoldAsyncFuture<St, B, Fut, F> {
+    pub(super) fn new(stream: St, init: B, f: F) -> Self {
+        Self {
+            stream,
+            acc: Some(init),
+            f,
+            future: None,
+            _pin: PhantomPinned,
+        }
+    }
+}
+
+impl<St, B, Fut, F> Future for FoldAsyncFuture<St, B, Fut, F>
+where
+    St: Stream,
+    F: FnMut(B, St::Item) -> Fut,
+    Fut: Future<Output = B>,
+{
+    type Output = B;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut me = self.project();
+        loop {
+            // Finish the in-flight accumulator future before pulling the next
+            // item.
+            if let Some(fut) = me.future.as_mut().as_pin_mut() {
+                let acc = ready!(fut.poll(cx));
+                *me.acc = Some(acc);
+                me.future.set(None);
+            }
+
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(v) => {
+                    let old = me.acc.take().unwrap();
+                    me.future.set(Some((me.f)(old, v)));
+                }
+                None => return Poll::Ready(me.acc.take().unwrap()),
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::Stream;
+use tokio::sync::mpsc::Sender;
+use tokio_util::sync::{PollSendError, PollSender};
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future returned by the [`forward_to`](super::StreamExt::forward_to) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ForwardTo<St>
+    where
+        St: Stream,
+        St::Item: Send,
+    {
+        #[pin]
+        stream: St,
+        sender: PollSender<St::Item>,
+        // An item that has been pulled from the stream but not yet sent.
+        buffered: Option<St::Item>,
+    }
+}
+
+impl<St> fmt::Debug for ForwardTo<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: Send,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ForwardTo")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St> ForwardTo<St>
+where
+    St: Stream,
+    St::Item: Send + 'static,
+{
+    pub(super) fn new(stream: St, sender: Sender<St::Item>) -> Self {
+        ForwardTo {
+            stream,
+            sender: PollSender::new(sender),
+            buffered: None,
+        }
+    }
+}
+
+impl<St> Future for ForwardTo<St>
+where
+    St: Stream,
+    St::Item: Send + 'static,
+{
+    type Output = Result<(), PollSendError<St::Item>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut me = self.project();
+
+        loop {
+            // Wait for channel capacity before pulling the next item, so a
+            // slow receiver exerts backpressure on the stream.
+            if me.buffered.is_some() {
+                ready!(me.sender.poll_reserve(cx))?;
+                me.sender.send_item(me.buffered.take().unwrap())?;
+            }
+
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(item) => *me.buffered = Some(item),
+                None => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use crate::Stream;
+
+use core::fmt;
+use core::hash::Hash;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Stream for the [`group_by`](super::StreamExt::group_by) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupBy<St, F, K>
+where
+    St: Stream,
+    K: Eq + Hash,
+{
+    inner: Arc<Mutex<Inner<St, F, K>>>,
+}
+
+/// Stream of the values sharing one key, yielded by [`GroupBy`].
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupStream<St, F, K>
+where
+    St: Stream,
+    K: Eq + Hash,
+{
+    inner: Arc<Mutex<Inner<St, F, K>>>,
+    key: K,
+}
+
+struct Inner<St, F, K>
+where
+    St: Stream,
+{
+    stream: St,
+    f: F,
+    capacity: usize,
+    groups: HashMap<K, Group<St::Item>>,
+    // Keys that have been discovered but whose `GroupStream` has not been
+    // yielded by the root stream yet.
+    new_keys: VecDeque<K>,
+    // An item that has been pulled from the stream but whose group buffer
+    // was full at the time.
+    pending: Option<(K, St::Item)>,
+    root_waker: Option<Waker>,
+    root_detached: bool,
+    done: bool,
+}
+
+struct Group<T> {
+    buffer: VecDeque<T>,
+    waker: Option<Waker>,
+    detached: bool,
+}
+
+impl<T> Group<T> {
+    fn new() -> Self {
+        Group {
+            buffer: VecDeque::new(),
+            waker: None,
+            detached: false,
+        }
+    }
+}
+
+impl<St, F, K> GroupBy<St, F, K>
+where
+    St: Stream,
+    K: Eq + Hash,
+{
+    pub(super) fn new(stream: St, f: F, capacity: usize) -> Self {
+        assert!(capacity > 0, "`capacity` must be non-zero.");
+
+        GroupBy {
+            inner: Arc::new(Mutex::new(Inner {
+                stream,
+                f,
+                capacity,
+                groups: HashMap::new(),
+                new_keys: VecDeque::new(),
+                pending: None,
+                root_waker: None,
+                root_detached: false,
+                done: false,
+            })),
+        }
+    }
+}
+
+impl<St, F, K> Inner<St, F, K>
+where
+    St: Stream + Unpin,
+    F: FnMut(&St::Item) -> K,
+    K: Clone + Eq + Hash,
+{
+    /// Pulls from the underlying stream and routes items to their group
+    /// buffers until the stream is pending, exhausted, or a destination
+    /// buffer is full.
+    fn drive(&mut self, cx: &mut Context<'_>) {
+        loop {
+            // Place a previously routed item before pulling another one.
+            if let Some((key, item)) = self.pending.take() {
+                match self.groups.get_mut(&key) {
+                    Some(group) if group.detached => {}
+                    Some(group) if group.buffer.len() < self.capacity => {
+                        group.buffer.push_back(item);
+                        if let Some(waker) = group.waker.take() {
+                            waker.wake();
+                        }
+                    }
+                    Some(_) => {
+                        // The buffer is full; the group's consumer re-drives
+                        // once it frees a slot.
+                        self.pending = Some((key, item));
+                        return;
+                    }
+                    None => {}
+                }
+            }
+
+            if self.done {
+                return;
+            }
+
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let key = (self.f)(&item);
+                    if !self.groups.contains_key(&key) {
+                        if self.root_detached {
+                            // Nobody can receive this group anymore.
+                            continue;
+                        }
+                        self.groups.insert(key.clone(), Group::new());
+                        self.new_keys.push_back(key.clone());
+                        if let Some(waker) = self.root_waker.take() {
+                            waker.wake();
+                        }
+                    }
+                    self.pending = Some((key, item));
+                }
+                Poll::Ready(None) => {
+                    self.done = true;
+                    self.wake_all();
+                    return;
+                }
+                Poll::Pending => return,
+            }
+        }
+    }
+
+    fn wake_all(&mut self) {
+        if let Some(waker) = self.root_waker.take() {
+            waker.wake();
+        }
+        for group in self.groups.values_mut() {
+            if let Some(waker) = group.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<St, F, K> Stream for GroupBy<St, F, K>
+where
+    St: Stream + Unpin,
+    F: FnMut(&St::Item) -> K,
+    K: Clone + Eq + Hash,
+{
+    type Item = (K, GroupStream<St, F, K>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.drive(cx);
+
+        if let Some(key) = inner.new_keys.pop_front() {
+            let group = GroupStream {
+                inner: self.inner.clone(),
+                key: key.clone(),
+            };
+            return Poll::Ready(Some((key, group)));
+        }
+
+        if inner.done {
+            Poll::Ready(None)
+        } else {
+            inner.root_waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<St, F, K> Drop for GroupBy<St, F, K>
+where
+    St: Stream,
+    K: Eq + Hash,
+{
+    fn drop(&mut self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.root_detached = true;
+
+        // Groups that were never handed out can no longer be consumed.
+        while let Some(key) = inner.new_keys.pop_front() {
+            inner.groups.remove(&key);
+        }
+        let orphaned = matches!(&inner.pending, Some((key, _)) if !inner.groups.contains_key(key));
+        if orphaned {
+            inner.pending = None;
+        }
+
+        // Wake every consumer so one of them resumes driving the stream.
+        let wakers: Vec<_> = inner
+            .groups
+            .values_mut()
+            .filter_map(|group| group.waker.take())
+            .collect();
+        drop(inner);
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<St, F, K> Stream for GroupStream<St, F, K>
+where
+    St: Stream + Unpin,
+    F: FnMut(&St::Item) -> K,
+    K: Clone + Eq + Hash,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.drive(cx);
+
+        let group = inner
+            .groups
+            .get_mut(&self.key)
+            .expect("group state missing");
+        if let Some(item) = group.buffer.pop_front() {
+            // A slot was freed; a blocked routing attempt can proceed now.
+            inner.drive(cx);
+            return Poll::Ready(Some(item));
+        }
+
+        if inner.done {
+            Poll::Ready(None)
+        } else {
+            let group = inner
+                .groups
+                .get_mut(&self.key)
+                .expect("group state missing");
+            group.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<St, F, K> Drop for GroupStream<St, F, K>
+where
+    St: Stream,
+    K: Eq + Hash,
+{
+    fn drop(&mut self) {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some(group) = inner.groups.get_mut(&self.key) {
+            group.detached = true;
+            group.buffer.clear();
+        }
+
+        // Discarding the buffer may unblock a pending routing attempt; wake
+        // the other consumers so one of them resumes driving the stream.
+        let mut wakers: Vec<_> = inner
+            .groups
+            .values_mut()
+            .filter_map(|group| group.waker.take())
+            .collect();
+        wakers.extend(inner.root_waker.take());
+        drop(inner);
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<St, F, K> fmt::Debug for GroupBy<St, F, K>
+where
+    St: Stream,
+    K: Eq + Hash,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupBy").finish()
+    }
+}
+
+impl<St, F, K> fmt::Debug for GroupStream<St, F, K>
+where
+    St: Stream,
+    K: Eq + Hash + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupStream")
+            .field("key", &self.key)
+            .finish()
+    }
+}
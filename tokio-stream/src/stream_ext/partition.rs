@@ -0,0 +1,138 @@
+use crate::Stream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Stream for the [`partition`](super::StreamExt::partition) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct Partition<St, F>
+where
+    St: Stream,
+{
+    inner: Arc<Mutex<Inner<St, F>>>,
+    // Index of the side this half yields: `0` for items matching the
+    // predicate, `1` for the rest.
+    side: usize,
+}
+
+struct Inner<St, F>
+where
+    St: Stream,
+{
+    stream: St,
+    f: F,
+    // Items routed to a side that was not the one polling at the time.
+    buffers: [VecDeque<St::Item>; 2],
+    wakers: [Option<Waker>; 2],
+    dropped: [bool; 2],
+    done: bool,
+}
+
+pub(super) fn new<St, F>(stream: St, f: F) -> (Partition<St, F>, Partition<St, F>)
+where
+    St: Stream,
+{
+    let inner = Arc::new(Mutex::new(Inner {
+        stream,
+        f,
+        buffers: [VecDeque::new(), VecDeque::new()],
+        wakers: [None, None],
+        dropped: [false, false],
+        done: false,
+    }));
+
+    let matches = Partition {
+        inner: inner.clone(),
+        side: 0,
+    };
+    let rest = Partition { inner, side: 1 };
+
+    (matches, rest)
+}
+
+impl<St, F> Stream for Partition<St, F>
+where
+    St: Stream + Unpin,
+    F: FnMut(&St::Item) -> bool,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let side = self.side;
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some(item) = inner.buffers[side].pop_front() {
+            return Poll::Ready(Some(item));
+        }
+
+        if inner.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match Pin::new(&mut inner.stream).poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let dest = if (inner.f)(&item) { 0 } else { 1 };
+                    if dest == side {
+                        return Poll::Ready(Some(item));
+                    }
+                    // Hold the item for the other half, unless it is gone.
+                    if !inner.dropped[dest] {
+                        inner.buffers[dest].push_back(item);
+                        if let Some(waker) = inner.wakers[dest].take() {
+                            waker.wake();
+                        }
+                    }
+                }
+                Poll::Ready(None) => {
+                    inner.done = true;
+                    if let Some(waker) = inner.wakers[1 - side].take() {
+                        waker.wake();
+                    }
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {
+                    inner.wakers[side] = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let inner = self.inner.lock().unwrap();
+        let buffered = inner.buffers[self.side].len();
+        let upper = inner.stream.size_hint().1;
+
+        // Every remaining item could be routed to the other half.
+        (
+            buffered,
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+impl<St, F> Drop for Partition<St, F>
+where
+    St: Stream,
+{
+    fn drop(&mut self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.dropped[self.side] = true;
+        inner.buffers[self.side].clear();
+    }
+}
+
+impl<St, F> fmt::Debug for Partition<St, F>
+where
+    St: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Partition")
+            .field("side", &self.side)
+            .finish()
+    }
+}
@@ -7,6 +7,11 @@ use pin_project_lite::pin_project;
 use crate::stream_ext::Fuse;
 use crate::StreamExt;
 
+#[cfg(feature = "time")]
+use crate::Elapsed;
+#[cfg(feature = "time")]
+use std::time::Duration;
+
 pin_project! {
     /// Stream returned by the [`peekable`](super::StreamExt::peekable) method.
     pub struct Peekable<T: Stream> {
@@ -34,6 +39,112 @@ impl<T: Stream> Peekable<T> {
             self.peek.as_ref()
         }
     }
+
+    /// Peek at the next item in the stream, returning a mutable reference.
+    ///
+    /// The peeked item can be modified in place before it is consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let mut stream = stream::iter(vec![1, 2, 3]).peekable();
+    ///
+    /// if let Some(item) = stream.peek_mut().await {
+    ///     *item = 10;
+    /// }
+    /// assert_eq!(stream.next().await, Some(10));
+    /// assert_eq!(stream.next().await, Some(2));
+    /// # }
+    /// ```
+    pub async fn peek_mut(&mut self) -> Option<&mut T::Item>
+    where
+        T: Unpin,
+    {
+        if self.peek.is_none() {
+            self.peek = self.next().await;
+        }
+        self.peek.as_mut()
+    }
+
+    /// Consume and return the next item in the stream if it satisfies the
+    /// given predicate.
+    ///
+    /// If the predicate returns `false`, the item stays buffered and is
+    /// returned by the next call to [`next`], [`peek`] or `next_if`, matching
+    /// the behavior of [`std::iter::Peekable::next_if`].
+    ///
+    /// [`next`]: crate::StreamExt::next
+    /// [`peek`]: Self::peek
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use tokio_stream::{self as stream, StreamExt};
+    ///
+    /// let mut stream = stream::iter(vec![1, 2, 3]).peekable();
+    ///
+    /// assert_eq!(stream.next_if(|&x| x == 1).await, Some(1));
+    /// // The predicate fails, so the item is not consumed.
+    /// assert_eq!(stream.next_if(|&x| x == 1).await, None);
+    /// assert_eq!(stream.next().await, Some(2));
+    /// # }
+    /// ```
+    pub async fn next_if(&mut self, func: impl FnOnce(&T::Item) -> bool) -> Option<T::Item>
+    where
+        T: Unpin,
+    {
+        if let Some(item) = self.peek().await {
+            if func(item) {
+                return self.peek.take();
+            }
+        }
+
+        None
+    }
+
+    /// Peek at the next item in the stream, waiting at most `duration` for it
+    /// to arrive.
+    ///
+    /// Returns `Err(Elapsed)` if no item arrives within the duration. The
+    /// timeout does not consume anything: an item that arrives later is
+    /// returned by a subsequent call to [`peek`] or [`next`].
+    ///
+    /// [`peek`]: Self::peek
+    /// [`next`]: crate::StreamExt::next
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    /// use tokio_stream::{StreamExt, wrappers::IntervalStream};
+    ///
+    /// let ticks = IntervalStream::new(tokio::time::interval(Duration::from_secs(10)));
+    /// let mut ticks = ticks.skip(1).peekable();
+    ///
+    /// // The next tick is 10s away, so a 1s peek times out.
+    /// assert!(ticks.peek_timeout(Duration::from_secs(1)).await.is_err());
+    /// // With a longer timeout the tick is observed.
+    /// assert!(ticks.peek_timeout(Duration::from_secs(15)).await.is_ok());
+    /// # }
+    /// ```
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub async fn peek_timeout(&mut self, duration: Duration) -> Result<Option<&T::Item>, Elapsed>
+    where
+        T: Unpin,
+    {
+        tokio::time::timeout(duration, self.peek())
+            .await
+            .map_err(|_| Elapsed::new())
+    }
 }
 
 impl<T: Stream> Stream for Peekable<T> {
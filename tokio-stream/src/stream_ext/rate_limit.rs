@@ -0,0 +1,92 @@
+use crate::Stream;
+use tokio::sync::RateLimiter;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+use std::sync::Arc;
+
+type Acquire = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+
+pin_project! {
+    /// Stream for the [`rate_limit`](super::StreamExt::rate_limit) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RateLimit<St>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: St,
+        limiter: Arc<RateLimiter>,
+        // An item that is held back until a permit is acquired for it.
+        pending_item: Option<St::Item>,
+        acquire: Option<Acquire>,
+    }
+}
+
+impl<St> fmt::Debug for RateLimit<St>
+where
+    St: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimit")
+            .field("stream", &self.stream)
+            .field("limiter", &self.limiter)
+            .finish()
+    }
+}
+
+impl<St> RateLimit<St>
+where
+    St: Stream,
+{
+    pub(super) fn new(stream: St, limiter: Arc<RateLimiter>) -> Self {
+        RateLimit {
+            stream,
+            limiter,
+            pending_item: None,
+            acquire: None,
+        }
+    }
+}
+
+impl<St> Stream for RateLimit<St>
+where
+    St: Stream,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if let Some(acquire) = me.acquire.as_mut() {
+                ready!(acquire.as_mut().poll(cx));
+                *me.acquire = None;
+                return Poll::Ready(Some(me.pending_item.take().unwrap()));
+            }
+
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    // A permit is acquired only once an item is ready, so an
+                    // idle stream does not consume quota.
+                    *me.pending_item = Some(item);
+                    *me.acquire = Some(Box::pin(me.limiter.clone().acquire_owned()));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending_item.is_some());
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = lower.saturating_add(pending);
+        let upper = upper.and_then(|upper| upper.checked_add(pending));
+
+        (lower, upper)
+    }
+}
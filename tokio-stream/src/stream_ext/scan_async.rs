@@ -0,0 +1,103 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`scan_async`](super::StreamExt::scan_async) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ScanAsync<St, S, Fut, F> {
+        #[pin]
+        stream: St,
+        state: Option<S>,
+        f: F,
+        #[pin]
+        future: Option<Fut>,
+        done: bool,
+    }
+}
+
+impl<St, S, Fut, F> fmt::Debug for ScanAsync<St, S, Fut, F>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScanAsync")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St, S, Fut, F> ScanAsync<St, S, Fut, F> {
+    pub(super) fn new(stream: St, initial_state: S, f: F) -> Self {
+        Self {
+            stream,
+            state: Some(initial_state),
+            f,
+            future: None,
+            done: false,
+        }
+    }
+}
+
+impl<St, S, B, Fut, F> Stream for ScanAsync<St, S, Fut, F>
+where
+    St: Stream,
+    F: FnMut(S, St::Item) -> Fut,
+    Fut: Future<Output = Option<(S, B)>>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<B>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            if let Some(fut) = me.future.as_mut().as_pin_mut() {
+                let result = ready!(fut.poll(cx));
+                me.future.set(None);
+
+                return match result {
+                    Some((state, item)) => {
+                        *me.state = Some(state);
+                        Poll::Ready(Some(item))
+                    }
+                    None => {
+                        *me.done = true;
+                        Poll::Ready(None)
+                    }
+                };
+            }
+
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(v) => {
+                    let state = me.state.take().unwrap();
+                    me.future.set(Some((me.f)(state, v)));
+                }
+                None => {
+                    *me.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        // The scan may end early, so there is no lower bound. The in-flight
+        // future may still produce an item.
+        let upper = self.stream.size_hint().1;
+        let in_flight = usize::from(self.future.is_some());
+
+        (0, upper.and_then(|upper| upper.checked_add(in_flight)))
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Stream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+use std::collections::VecDeque;
+
+pin_project! {
+    /// Stream for the [`sliding_window`](super::StreamExt::sliding_window) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SlidingWindow<St>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: St,
+        window: VecDeque<St::Item>,
+        size: usize,
+    }
+}
+
+impl<St> fmt::Debug for SlidingWindow<St>
+where
+    St: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlidingWindow")
+            .field("stream", &self.stream)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<St> SlidingWindow<St>
+where
+    St: Stream,
+{
+    pub(super) fn new(stream: St, size: usize) -> Self {
+        assert!(size > 0, "`size` must be non-zero.");
+
+        SlidingWindow {
+            stream,
+            window: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+}
+
+impl<St> Stream for SlidingWindow<St>
+where
+    St: Stream,
+    St::Item: Clone,
+{
+    type Item = Vec<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Vec<St::Item>>> {
+        let mut me = self.project();
+
+        loop {
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    me.window.push_back(item);
+                    if me.window.len() == *me.size {
+                        let window: Vec<_> = me.window.iter().cloned().collect();
+                        me.window.pop_front();
+                        return Poll::Ready(Some(window));
+                    }
+                }
+                // A stream shorter than the window yields nothing.
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A full window is produced for each item from the `size`-th onward.
+        let buffered = self.window.len();
+        let shortfall = self.size - 1;
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = lower.saturating_add(buffered).saturating_sub(shortfall);
+        let upper = upper
+            .and_then(|upper| upper.checked_add(buffered))
+            .map(|upper| upper.saturating_sub(shortfall));
+
+        (lower, upper)
+    }
+}
@@ -0,0 +1,209 @@
+use crate::Stream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Arc, Mutex};
+
+/// Prefix stream returned by the [`split_when`](super::StreamExt::split_when)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct SplitPrefix<St, F>
+where
+    St: Stream,
+{
+    inner: Arc<Mutex<Inner<St, F>>>,
+}
+
+/// Remainder stream returned by the
+/// [`split_when`](super::StreamExt::split_when) method.
+///
+/// Pending until the prefix stream has ended, either by the predicate matching
+/// or by being dropped; it then yields the remaining items, starting with the
+/// one that matched.
+#[must_use = "streams do nothing unless polled"]
+pub struct SplitRemainder<St, F>
+where
+    St: Stream,
+{
+    inner: Arc<Mutex<Inner<St, F>>>,
+}
+
+struct Inner<St, F>
+where
+    St: Stream,
+{
+    stream: St,
+    f: F,
+    // The item that matched the predicate, held for the remainder.
+    buffered: Option<St::Item>,
+    // Set once the prefix has ended, whether by a match, the end of the
+    // underlying stream, or the prefix being dropped.
+    split: bool,
+    done: bool,
+    remainder_waker: Option<Waker>,
+}
+
+pub(super) fn new<St, F>(stream: St, f: F) -> (SplitPrefix<St, F>, SplitRemainder<St, F>)
+where
+    St: Stream,
+{
+    let inner = Arc::new(Mutex::new(Inner {
+        stream,
+        f,
+        buffered: None,
+        split: false,
+        done: false,
+        remainder_waker: None,
+    }));
+
+    let prefix = SplitPrefix {
+        inner: inner.clone(),
+    };
+    let remainder = SplitRemainder { inner };
+
+    (prefix, remainder)
+}
+
+impl<St, F> Stream for SplitPrefix<St, F>
+where
+    St: Stream + Unpin,
+    F: FnMut(&St::Item) -> bool,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.split {
+            return Poll::Ready(None);
+        }
+
+        match Pin::new(&mut inner.stream).poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                if (inner.f)(&item) {
+                    // The matching item starts the remainder.
+                    inner.buffered = Some(item);
+                    inner.split = true;
+                    if let Some(waker) = inner.remainder_waker.take() {
+                        waker.wake();
+                    }
+                    Poll::Ready(None)
+                } else {
+                    Poll::Ready(Some(item))
+                }
+            }
+            Poll::Ready(None) => {
+                inner.split = true;
+                inner.done = true;
+                if let Some(waker) = inner.remainder_waker.take() {
+                    waker.wake();
+                }
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let inner = self.inner.lock().unwrap();
+
+        if inner.split {
+            return (0, Some(0));
+        }
+
+        // The predicate may end the prefix at any point.
+        (0, inner.stream.size_hint().1)
+    }
+}
+
+impl<St, F> Stream for SplitRemainder<St, F>
+where
+    St: Stream + Unpin,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if !inner.split {
+            // The prefix is still running; wait for it to finish.
+            inner.remainder_waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        if let Some(item) = inner.buffered.take() {
+            return Poll::Ready(Some(item));
+        }
+
+        if inner.done {
+            return Poll::Ready(None);
+        }
+
+        match Pin::new(&mut inner.stream).poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
+            Poll::Ready(None) => {
+                inner.done = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let inner = self.inner.lock().unwrap();
+        let buffered = usize::from(inner.buffered.is_some());
+
+        if inner.done {
+            return (buffered, Some(buffered));
+        }
+
+        let (lower, upper) = inner.stream.size_hint();
+
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+impl<St, F> Drop for SplitPrefix<St, F>
+where
+    St: Stream,
+{
+    fn drop(&mut self) {
+        let waker = {
+            let mut inner = self.inner.lock().unwrap();
+            if inner.split {
+                None
+            } else {
+                // Dropping the prefix hands the rest of the stream to the
+                // remainder.
+                inner.split = true;
+                inner.remainder_waker.take()
+            }
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<St, F> fmt::Debug for SplitPrefix<St, F>
+where
+    St: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitPrefix").finish()
+    }
+}
+
+impl<St, F> fmt::Debug for SplitRemainder<St, F>
+where
+    St: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitRemainder").finish()
+    }
+}
@@ -0,0 +1,85 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`switch_map`](super::StreamExt::switch_map) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SwitchMap<St, Fut, F> {
+        #[pin]
+        stream: St,
+        #[pin]
+        future: Option<Fut>,
+        f: F,
+        done: bool,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for SwitchMap<St, Fut, F>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SwitchMap")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> SwitchMap<St, Fut, F> {
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self {
+            stream,
+            future: None,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<St, Fut, F> Stream for SwitchMap<St, Fut, F>
+where
+    St: Stream,
+    Fut: Future,
+    F: FnMut(St::Item) -> Fut,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Fut::Output>> {
+        let mut me = self.project();
+
+        // Drain every value the stream has ready, so only the most recent
+        // one is mapped; replacing the future drops the superseded one.
+        while !*me.done {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => me.future.set(Some((me.f)(item))),
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if let Some(future) = me.future.as_mut().as_pin_mut() {
+            if let Poll::Ready(output) = future.poll(cx) {
+                me.future.set(None);
+                return Poll::Ready(Some(output));
+            }
+        } else if *me.done {
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Superseded values never produce an output, so only an upper bound
+        // is known.
+        let in_flight = usize::from(self.future.is_some());
+        let upper = self.stream.size_hint().1;
+
+        (0, upper.and_then(|upper| upper.checked_add(in_flight)))
+    }
+}
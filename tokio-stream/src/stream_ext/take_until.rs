@@ -0,0 +1,80 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`take_until`](super::StreamExt::take_until) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TakeUntil<St, Fut> {
+        #[pin]
+        stream: St,
+        #[pin]
+        future: Option<Fut>,
+        done: bool,
+    }
+}
+
+impl<St, Fut> fmt::Debug for TakeUntil<St, Fut>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeUntil")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St, Fut> TakeUntil<St, Fut> {
+    pub(super) fn new(stream: St, future: Fut) -> Self {
+        TakeUntil {
+            stream,
+            future: Some(future),
+            done: false,
+        }
+    }
+}
+
+impl<St, Fut> Stream for TakeUntil<St, Fut>
+where
+    St: Stream,
+    Fut: Future,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        if let Some(fut) = me.future.as_mut().as_pin_mut() {
+            if fut.poll(cx).is_ready() {
+                *me.done = true;
+                me.future.set(None);
+                return Poll::Ready(None);
+            }
+        }
+
+        let next = me.stream.poll_next(cx);
+        if let Poll::Ready(None) = next {
+            *me.done = true;
+            me.future.set(None);
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        // The future may end the stream at any point.
+        (0, self.stream.size_hint().1)
+    }
+}
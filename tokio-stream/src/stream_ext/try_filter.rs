@@ -0,0 +1,62 @@
+use crate::Stream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`try_filter`](super::StreamExt::try_filter) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryFilter<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+    }
+}
+
+impl<St, F> fmt::Debug for TryFilter<St, F>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFilter")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St, F> TryFilter<St, F> {
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f }
+    }
+}
+
+impl<St, F, T, E> Stream for TryFilter<St, F>
+where
+    St: Stream<Item = Result<T, E>>,
+    F: FnMut(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<T, E>>> {
+        let mut me = self.project();
+
+        loop {
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(Ok(value)) => {
+                    if (me.f)(&value) {
+                        return Poll::Ready(Some(Ok(value)));
+                    }
+                }
+                // Errors are passed through without running the predicate.
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1) // can't know a lower bound, due to the predicate
+    }
+}
@@ -0,0 +1,95 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future returned by the
+    /// [`try_for_each_concurrent`](super::StreamExt::try_for_each_concurrent) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryForEachConcurrent<St, Fut, F> {
+        #[pin]
+        stream: St,
+        f: F,
+        in_flight: Vec<Pin<Box<Fut>>>,
+        max: usize,
+        done: bool,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for TryForEachConcurrent<St, Fut, F>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryForEachConcurrent")
+            .field("stream", &self.stream)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> TryForEachConcurrent<St, Fut, F> {
+    pub(super) fn new(stream: St, f: F, max: usize) -> Self {
+        assert!(max > 0, "`max` must be non-zero.");
+
+        TryForEachConcurrent {
+            stream,
+            f,
+            in_flight: Vec::with_capacity(max),
+            max,
+            done: false,
+        }
+    }
+}
+
+impl<St, Fut, F, T, E> Future for TryForEachConcurrent<St, Fut, F>
+where
+    St: Stream<Item = Result<T, E>>,
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+{
+    type Output = Result<(), E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), E>> {
+        let mut me = self.project();
+
+        loop {
+            // Fill the set of in-flight futures up to the concurrency limit.
+            // An error short-circuits: in-flight futures are dropped.
+            while !*me.done && me.in_flight.len() < *me.max {
+                match me.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(Ok(item))) => me.in_flight.push(Box::pin((me.f)(item))),
+                    Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(e)),
+                    Poll::Ready(None) => *me.done = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            let mut progress = false;
+            let mut i = 0;
+            while i < me.in_flight.len() {
+                match me.in_flight[i].as_mut().poll(cx) {
+                    Poll::Ready(Ok(())) => {
+                        drop(me.in_flight.swap_remove(i));
+                        progress = true;
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => i += 1,
+                }
+            }
+
+            if me.in_flight.is_empty() && *me.done {
+                return Poll::Ready(Ok(()));
+            }
+
+            // A completed future freed a slot, so try pulling again.
+            if !progress {
+                return Poll::Pending;
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::Stream;
 
 use std::borrow::Borrow;
+use std::fmt;
 use std::future::poll_fn;
 use std::hash::Hash;
 use std::pin::Pin;
@@ -205,8 +206,53 @@ use std::task::{ready, Context, Poll};
 pub struct StreamMap<K, V> {
     /// Streams stored in the map
     entries: Vec<(K, V)>,
+
+    /// Polling priority of each entry, parallel to `entries`. All zero unless
+    /// `insert_with_priority` has been used.
+    priorities: Vec<u32>,
+
+    /// Indices into `entries` in descending priority order, maintained on
+    /// insertion and removal so prioritized polling does not have to sort.
+    by_priority: Vec<usize>,
+
+    /// Maximum number of entries, enforced by the insert methods.
+    limit: Option<usize>,
+
+    /// Round-robin polling cursor; only used when `round_robin` is set.
+    cursor: usize,
+
+    /// Whether to poll entries in round-robin order instead of starting from
+    /// a random index.
+    round_robin: bool,
+}
+
+/// Error returned by [`StreamMap::try_insert`] and
+/// [`StreamMap::try_insert_with_priority`] when the map is at the limit set
+/// with [`StreamMap::with_limit`].
+///
+/// Contains the key and stream that could not be inserted; they can be taken
+/// back out with [`into_inner`](TryInsertError::into_inner).
+#[derive(Debug)]
+pub struct TryInsertError<K, V> {
+    key: K,
+    stream: V,
+}
+
+impl<K, V> TryInsertError<K, V> {
+    /// Returns the key and stream that could not be inserted.
+    pub fn into_inner(self) -> (K, V) {
+        (self.key, self.stream)
+    }
 }
 
+impl<K, V> fmt::Display for TryInsertError<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "stream map is at its entry limit".fmt(f)
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> std::error::Error for TryInsertError<K, V> {}
+
 impl<K, V> StreamMap<K, V> {
     /// An iterator visiting all key-value pairs in arbitrary order.
     ///
@@ -267,7 +313,14 @@ impl<K, V> StreamMap<K, V> {
     /// let map: StreamMap<&str, Pending<()>> = StreamMap::new();
     /// ```
     pub fn new() -> StreamMap<K, V> {
-        StreamMap { entries: vec![] }
+        StreamMap {
+            entries: vec![],
+            priorities: vec![],
+            by_priority: vec![],
+            limit: None,
+            cursor: 0,
+            round_robin: false,
+        }
     }
 
     /// Creates an empty `StreamMap` with the specified capacity.
@@ -285,9 +338,76 @@ impl<K, V> StreamMap<K, V> {
     pub fn with_capacity(capacity: usize) -> StreamMap<K, V> {
         StreamMap {
             entries: Vec::with_capacity(capacity),
+            priorities: Vec::with_capacity(capacity),
+            by_priority: Vec::with_capacity(capacity),
+            limit: None,
+            cursor: 0,
+            round_robin: false,
         }
     }
 
+    /// Creates an empty `StreamMap` that holds at most `limit` entries.
+    ///
+    /// The limit is enforced by [`insert`], [`insert_with_priority`] and their
+    /// fallible counterparts [`try_insert`] and [`try_insert_with_priority`];
+    /// [`extend`](Extend::extend) does not check it.
+    ///
+    /// [`insert`]: StreamMap::insert
+    /// [`insert_with_priority`]: StreamMap::insert_with_priority
+    /// [`try_insert`]: StreamMap::try_insert
+    /// [`try_insert_with_priority`]: StreamMap::try_insert_with_priority
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio_stream::{StreamMap, pending};
+    ///
+    /// let mut map = StreamMap::with_limit(1);
+    ///
+    /// map.insert("a", pending::<i32>());
+    /// assert!(map.try_insert("b", pending()).is_err());
+    /// ```
+    pub fn with_limit(limit: usize) -> StreamMap<K, V> {
+        assert!(limit > 0, "`limit` must be non-zero.");
+
+        StreamMap {
+            entries: vec![],
+            priorities: vec![],
+            by_priority: vec![],
+            limit: Some(limit),
+            cursor: 0,
+            round_robin: false,
+        }
+    }
+
+    /// Returns the maximum number of entries the map may hold, or `None` if
+    /// the map was not created with [`with_limit`].
+    ///
+    /// [`with_limit`]: StreamMap::with_limit
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets whether entries are polled in round-robin order.
+    ///
+    /// By default, each poll starts from a randomly chosen entry so that no
+    /// stream can starve the others. With round-robin polling enabled, polling
+    /// instead resumes from the entry after the one that last yielded a value,
+    /// making the rotation deterministic. The cursor is positional, so it
+    /// keeps rotating through the map as entries are inserted and removed.
+    ///
+    /// This only affects one-at-a-time polling (`next` and `poll_next_entry`);
+    /// it has no effect when at least one entry was inserted with a non-zero
+    /// priority, as prioritized polling always checks entries in descending
+    /// priority order.
+    pub fn set_round_robin(&mut self, enabled: bool) {
+        self.round_robin = enabled;
+    }
+
     /// Returns an iterator visiting all keys in arbitrary order.
     ///
     /// The iterator element type is `&'a K`.
@@ -421,6 +541,8 @@ impl<K, V> StreamMap<K, V> {
     /// ```
     pub fn clear(&mut self) {
         self.entries.clear();
+        self.priorities.clear();
+        self.by_priority.clear();
     }
 
     /// Insert a key-stream pair into the map.
@@ -443,14 +565,159 @@ impl<K, V> StreamMap<K, V> {
     /// map.insert(37, pending());
     /// assert!(map.insert(37, pending()).is_some());
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map was created with [`with_limit`] and inserting a new
+    /// key would exceed the limit. Use [`try_insert`] to handle that case
+    /// without panicking.
+    ///
+    /// [`with_limit`]: StreamMap::with_limit
+    /// [`try_insert`]: StreamMap::try_insert
     pub fn insert(&mut self, k: K, stream: V) -> Option<V>
     where
         K: Hash + Eq,
     {
+        self.insert_with_priority(k, stream, 0)
+    }
+
+    /// Attempts to insert a key-stream pair into the map.
+    ///
+    /// This is the fallible variant of [`insert`]: if the map was created with
+    /// [`with_limit`] and inserting a new key would exceed the limit, the key
+    /// and stream are handed back in a [`TryInsertError`]. Replacing the
+    /// stream of a key already present never fails, as the map does not grow.
+    ///
+    /// [`insert`]: StreamMap::insert
+    /// [`with_limit`]: StreamMap::with_limit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio_stream::{StreamMap, pending};
+    ///
+    /// let mut map = StreamMap::with_limit(1);
+    ///
+    /// assert!(map.try_insert(37, pending::<i32>()).is_ok());
+    /// // Replacing an existing key is fine...
+    /// assert!(map.try_insert(37, pending()).is_ok());
+    /// // ...but a new key would exceed the limit.
+    /// let err = map.try_insert(38, pending()).unwrap_err();
+    /// let (key, _stream) = err.into_inner();
+    /// assert_eq!(key, 38);
+    /// ```
+    pub fn try_insert(&mut self, k: K, stream: V) -> Result<Option<V>, TryInsertError<K, V>>
+    where
+        K: Hash + Eq,
+    {
+        self.try_insert_with_priority(k, stream, 0)
+    }
+
+    /// Insert a key-stream pair into the map with a polling priority.
+    ///
+    /// When at least one entry has a non-zero priority, entries are polled in
+    /// descending priority order instead of the randomized fair order, so
+    /// values from higher-priority streams are yielded first when several
+    /// streams are ready. Entries inserted with [`insert`] have priority `0`.
+    ///
+    /// The priority only affects one-at-a-time polling (`next` and
+    /// `poll_next_entry`); batch receives with [`next_many`] drain every
+    /// ready stream regardless.
+    ///
+    /// If the map did have this key present, the new `stream` replaces the
+    /// old one and the old stream is returned.
+    ///
+    /// [`insert`]: StreamMap::insert
+    /// [`next_many`]: StreamMap::next_many
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio_stream::{StreamMap, pending};
+    ///
+    /// let mut map = StreamMap::new();
+    ///
+    /// map.insert("best effort", pending::<i32>());
+    /// map.insert_with_priority("control", pending(), 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map was created with [`with_limit`] and inserting a new
+    /// key would exceed the limit. Use [`try_insert_with_priority`] to handle
+    /// that case without panicking.
+    ///
+    /// [`with_limit`]: StreamMap::with_limit
+    /// [`try_insert_with_priority`]: StreamMap::try_insert_with_priority
+    pub fn insert_with_priority(&mut self, k: K, stream: V, priority: u32) -> Option<V>
+    where
+        K: Hash + Eq,
+    {
+        match self.try_insert_with_priority(k, stream, priority) {
+            Ok(ret) => ret,
+            Err(_) => panic!("`StreamMap` is at its entry limit."),
+        }
+    }
+
+    /// Attempts to insert a key-stream pair into the map with a polling
+    /// priority.
+    ///
+    /// This is the fallible variant of [`insert_with_priority`]: if the map
+    /// was created with [`with_limit`] and inserting a new key would exceed
+    /// the limit, the key and stream are handed back in a [`TryInsertError`].
+    ///
+    /// [`insert_with_priority`]: StreamMap::insert_with_priority
+    /// [`with_limit`]: StreamMap::with_limit
+    pub fn try_insert_with_priority(
+        &mut self,
+        k: K,
+        stream: V,
+        priority: u32,
+    ) -> Result<Option<V>, TryInsertError<K, V>>
+    where
+        K: Hash + Eq,
+    {
+        if let Some(limit) = self.limit {
+            if self.entries.len() >= limit && !self.contains_key(&k) {
+                return Err(TryInsertError { key: k, stream });
+            }
+        }
+
         let ret = self.remove(&k);
+        let idx = self.entries.len();
         self.entries.push((k, stream));
+        self.priorities.push(priority);
+        // Entries of equal priority keep their insertion order.
+        let pos = self
+            .by_priority
+            .partition_point(|&i| self.priorities[i] >= priority);
+        self.by_priority.insert(pos, idx);
+
+        Ok(ret)
+    }
 
-        ret
+    /// Removes the entry at `idx` through `swap_remove`, keeping the parallel
+    /// vectors and the cached priority order consistent.
+    fn swap_remove_index(&mut self, idx: usize) -> (K, V) {
+        self.priorities.swap_remove(idx);
+        let entry = self.entries.swap_remove(idx);
+
+        // The entry previously at this index is gone from the cached order,
+        // and the one that `swap_remove` moved into its place is reachable
+        // under `idx` now. The moved entry's priority is unchanged, so its
+        // position in the order stays valid.
+        let moved = self.entries.len();
+        let mut removed_pos = 0;
+        for (pos, i) in self.by_priority.iter_mut().enumerate() {
+            if *i == idx {
+                removed_pos = pos;
+            } else if *i == moved {
+                *i = idx;
+            }
+        }
+        self.by_priority.remove(removed_pos);
+
+        entry
     }
 
     /// Removes a key from the map, returning the stream at the key if the key was previously in the map.
@@ -475,7 +742,7 @@ impl<K, V> StreamMap<K, V> {
     {
         for i in 0..self.entries.len() {
             if self.entries[i].0.borrow() == k {
-                return Some(self.entries.swap_remove(i).1);
+                return Some(self.swap_remove_index(i).1);
             }
         }
 
@@ -519,17 +786,33 @@ where
 {
     /// Polls the next value, includes the vec entry index
     fn poll_next_entry(&mut self, cx: &mut Context<'_>) -> Poll<Option<(usize, V::Item)>> {
-        let start = self::rand::thread_rng_n(self.entries.len() as u32) as usize;
+        if self.priorities.iter().any(|priority| *priority != 0) {
+            return self.poll_next_entry_prioritized(cx);
+        }
+
+        let start = if self.round_robin {
+            if self.entries.is_empty() {
+                0
+            } else {
+                self.cursor % self.entries.len()
+            }
+        } else {
+            self::rand::thread_rng_n(self.entries.len() as u32) as usize
+        };
         let mut idx = start;
 
         for _ in 0..self.entries.len() {
             let (_, stream) = &mut self.entries[idx];
 
             match Pin::new(stream).poll_next(cx) {
-                Poll::Ready(Some(val)) => return Poll::Ready(Some((idx, val))),
+                Poll::Ready(Some(val)) => {
+                    // Resume after the entry that yielded on the next poll.
+                    self.cursor = idx + 1;
+                    return Poll::Ready(Some((idx, val)));
+                }
                 Poll::Ready(None) => {
                     // Remove the entry
-                    self.entries.swap_remove(idx);
+                    self.swap_remove_index(idx);
 
                     // Check if this was the last entry, if so the cursor needs
                     // to wrap
@@ -554,6 +837,38 @@ where
             Poll::Pending
         }
     }
+
+    /// Polls the entries in descending priority order rather than the
+    /// randomized fair order.
+    fn poll_next_entry_prioritized(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(usize, V::Item)>> {
+        let mut pos = 0;
+        while pos < self.by_priority.len() {
+            let idx = self.by_priority[pos];
+            let (_, stream) = &mut self.entries[idx];
+
+            match Pin::new(stream).poll_next(cx) {
+                Poll::Ready(Some(val)) => return Poll::Ready(Some((idx, val))),
+                Poll::Ready(None) => {
+                    // The removal shifts the positions after `pos` down by
+                    // one, so the next candidate is found at `pos` itself.
+                    // Positions before `pos` are unaffected; the entry that
+                    // was swapped into `idx` keeps its place in the order.
+                    self.swap_remove_index(idx);
+                }
+                Poll::Pending => pos += 1,
+            }
+        }
+
+        // If the map is empty, then the stream is complete.
+        if self.entries.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
 }
 
 impl<K, V> Default for StreamMap<K, V> {
@@ -633,7 +948,7 @@ where
                     }
                     Poll::Ready(None) => {
                         // Remove the entry
-                        self.entries.swap_remove(idx);
+                        self.swap_remove_index(idx);
 
                         // Check if this was the last entry, if so the cursor needs
                         // to wrap
@@ -724,6 +1039,11 @@ impl<K, V> Extend<(K, V)> for StreamMap<K, V> {
         T: IntoIterator<Item = (K, V)>,
     {
         self.entries.extend(iter);
+        // Keep the parallel priority vector in sync; extended entries get the
+        // default priority, which sorts after every existing entry.
+        let start = self.priorities.len();
+        self.priorities.resize(self.entries.len(), 0);
+        self.by_priority.extend(start..self.entries.len());
     }
 }
 
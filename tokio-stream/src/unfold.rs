@@ -0,0 +1,98 @@
+use crate::Stream;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`unfold`](fn@unfold) function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Unfold<T, F, Fut> {
+        state: Option<T>,
+        f: F,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+/// Creates a stream from a seed value and an async closure, like a
+/// generator.
+///
+/// Each call of the closure receives the current state and resolves to
+/// `Some((item, next_state))` to yield `item` and continue, or `None` to end
+/// the stream.
+///
+/// # Examples
+///
+/// ```
+/// use tokio_stream::{self as stream, StreamExt};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let stream = stream::unfold(0, |n| async move {
+///         if n < 3 {
+///             Some((n * 2, n + 1))
+///         } else {
+///             None
+///         }
+///     });
+///
+///     let values: Vec<i32> = stream.collect().await;
+///     assert_eq!(values, vec![0, 2, 4]);
+/// }
+/// ```
+pub fn unfold<T, F, Fut, Item>(init: T, f: F) -> Unfold<T, F, Fut>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Option<(Item, T)>>,
+{
+    Unfold {
+        state: Some(init),
+        f,
+        future: None,
+    }
+}
+
+impl<T, F, Fut, Item> Stream for Unfold<T, F, Fut>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Option<(Item, T)>>,
+{
+    type Item = Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Item>> {
+        let mut me = self.project();
+
+        if me.future.is_none() {
+            let state = match me.state.take() {
+                Some(state) => state,
+                None => return Poll::Ready(None),
+            };
+            me.future.set(Some((me.f)(state)));
+        }
+
+        let step = ready!(me.future.as_mut().as_pin_mut().unwrap().poll(cx));
+        me.future.set(None);
+
+        match step {
+            Some((item, next_state)) => {
+                *me.state = Some(next_state);
+                Poll::Ready(Some(item))
+            }
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl<T, F, Fut> fmt::Debug for Unfold<T, F, Fut>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Unfold")
+            .field("state", &self.state)
+            .finish()
+    }
+}
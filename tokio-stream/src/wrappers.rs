@@ -15,7 +15,7 @@ pub use mpsc_unbounded::UnboundedReceiverStream;
 
 cfg_sync! {
     mod broadcast;
-    pub use broadcast::BroadcastStream;
+    pub use broadcast::{BroadcastLagPolicy, BroadcastStream};
 
     mod watch;
     pub use watch::WatchStream;
@@ -30,12 +30,19 @@ cfg_signal! {
     #[cfg(any(windows, docsrs))]
     mod signal_windows;
     #[cfg(any(windows, docsrs))]
-    pub use signal_windows::{CtrlCStream, CtrlBreakStream};
+    pub use signal_windows::{
+        CtrlBreakStream, CtrlCStream, CtrlCloseStream, CtrlLogoffStream, CtrlShutdownStream,
+    };
 }
 
 cfg_time! {
     mod interval;
     pub use interval::IntervalStream;
+
+    #[cfg(feature = "sync")]
+    mod delay_queue;
+    #[cfg(feature = "sync")]
+    pub use delay_queue::{DelayQueueHandle, DelayQueueStream};
 }
 
 cfg_net! {
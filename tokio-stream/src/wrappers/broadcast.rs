@@ -38,6 +38,29 @@ use std::task::{ready, Context, Poll};
 #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
 pub struct BroadcastStream<T> {
     inner: ReusableBoxFuture<'static, (Result<T, RecvError>, Receiver<T>)>,
+    policy: BroadcastLagPolicy,
+    lagged: u64,
+    closed: bool,
+}
+
+/// What a [`BroadcastStream`] does when its receiver lags too far behind.
+///
+/// Configured with [`BroadcastStream::with_lag_policy`]. Regardless of the
+/// policy, the number of skipped messages is accumulated and available through
+/// [`BroadcastStream::lagged_count`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+pub enum BroadcastLagPolicy {
+    /// Yield [`BroadcastStreamRecvError::Lagged`] as an item, leaving the
+    /// caller to decide how to proceed. This is the default, and the behavior
+    /// of [`BroadcastStream::new`].
+    #[default]
+    Yield,
+    /// Silently skip the lost messages and continue with the oldest message
+    /// still retained by the channel.
+    Skip,
+    /// Terminate the stream, returning `None` on this and every later poll.
+    Close,
 }
 
 /// An error returned from the inner stream of a [`BroadcastStream`].
@@ -68,22 +91,85 @@ async fn make_future<T: Clone>(mut rx: Receiver<T>) -> (Result<T, RecvError>, Re
 impl<T: 'static + Clone + Send> BroadcastStream<T> {
     /// Create a new `BroadcastStream`.
     pub fn new(rx: Receiver<T>) -> Self {
+        Self::with_lag_policy(rx, BroadcastLagPolicy::Yield)
+    }
+
+    /// Create a new `BroadcastStream` with the given [`BroadcastLagPolicy`].
+    ///
+    /// With [`BroadcastLagPolicy::Skip`], the stream never yields an error and
+    /// the `Result` wrapper only exists for parity with [`new`]; lost messages
+    /// are still counted by [`lagged_count`].
+    ///
+    /// [`new`]: Self::new
+    /// [`lagged_count`]: Self::lagged_count
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tokio::sync::broadcast;
+    /// use tokio_stream::wrappers::{BroadcastLagPolicy, BroadcastStream};
+    /// use tokio_stream::StreamExt;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let (tx, rx) = broadcast::channel(2);
+    /// for i in 0..5 {
+    ///     tx.send(i).unwrap();
+    /// }
+    /// drop(tx);
+    ///
+    /// let mut stream = BroadcastStream::with_lag_policy(rx, BroadcastLagPolicy::Skip);
+    /// assert_eq!(stream.next().await, Some(Ok(3)));
+    /// assert_eq!(stream.next().await, Some(Ok(4)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert_eq!(stream.lagged_count(), 3);
+    /// # }
+    /// ```
+    pub fn with_lag_policy(rx: Receiver<T>, policy: BroadcastLagPolicy) -> Self {
         Self {
             inner: ReusableBoxFuture::new(make_future(rx)),
+            policy,
+            lagged: 0,
+            closed: false,
         }
     }
 }
 
+impl<T> BroadcastStream<T> {
+    /// Returns the total number of messages this stream has skipped because
+    /// the receiver lagged behind, regardless of the configured
+    /// [`BroadcastLagPolicy`].
+    pub fn lagged_count(&self) -> u64 {
+        self.lagged
+    }
+}
+
 impl<T: 'static + Clone + Send> Stream for BroadcastStream<T> {
     type Item = Result<T, BroadcastStreamRecvError>;
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let (result, rx) = ready!(self.inner.poll(cx));
-        self.inner.set(make_future(rx));
-        match result {
-            Ok(item) => Poll::Ready(Some(Ok(item))),
-            Err(RecvError::Closed) => Poll::Ready(None),
-            Err(RecvError::Lagged(n)) => {
-                Poll::Ready(Some(Err(BroadcastStreamRecvError::Lagged(n))))
+        if self.closed {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let (result, rx) = ready!(self.inner.poll(cx));
+            self.inner.set(make_future(rx));
+            match result {
+                Ok(item) => return Poll::Ready(Some(Ok(item))),
+                Err(RecvError::Closed) => return Poll::Ready(None),
+                Err(RecvError::Lagged(n)) => {
+                    self.lagged += n;
+                    match self.policy {
+                        BroadcastLagPolicy::Yield => {
+                            return Poll::Ready(Some(Err(BroadcastStreamRecvError::Lagged(n))))
+                        }
+                        BroadcastLagPolicy::Skip => {}
+                        BroadcastLagPolicy::Close => {
+                            self.closed = true;
+                            return Poll::Ready(None);
+                        }
+                    }
+                }
             }
         }
     }
@@ -91,7 +177,10 @@ impl<T: 'static + Clone + Send> Stream for BroadcastStream<T> {
 
 impl<T> fmt::Debug for BroadcastStream<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("BroadcastStream").finish()
+        f.debug_struct("BroadcastStream")
+            .field("policy", &self.policy)
+            .field("lagged", &self.lagged)
+            .finish()
     }
 }
 
@@ -0,0 +1,224 @@
+use crate::Stream;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use tokio::time::Instant;
+use tokio_util::time::delay_queue::{DelayQueue, Expired, Key};
+
+/// A wrapper around [`DelayQueue`] that implements [`Stream`].
+///
+/// Driving a [`DelayQueue`] by calling [`poll_expired`] inside a hand-written
+/// `poll_next` is easy to get wrong: entries inserted while the queue is empty
+/// must wake the task that last polled it, and the queue yields `None` whenever
+/// it is momentarily empty rather than when it is finished. This wrapper takes
+/// care of both. The stream yields entries as they expire and stays pending
+/// while the queue is empty; it ends once the queue is empty *and* every
+/// [`DelayQueueHandle`] has been dropped, after which it keeps returning
+/// `None`.
+///
+/// Entries are inserted and reset through the [`DelayQueueHandle`] returned by
+/// [`new`], which can be cloned and used from other tasks.
+///
+/// [`DelayQueue`]: struct@tokio_util::time::DelayQueue
+/// [`poll_expired`]: method@tokio_util::time::DelayQueue::poll_expired
+/// [`Stream`]: trait@crate::Stream
+/// [`new`]: method@Self::new
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_stream::{StreamExt, wrappers::DelayQueueStream};
+/// use tokio_util::time::DelayQueue;
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let (mut stream, handle) = DelayQueueStream::new(DelayQueue::new());
+///
+/// handle.insert("slow", Duration::from_secs(2));
+/// handle.insert("fast", Duration::from_secs(1));
+/// drop(handle);
+///
+/// let first = stream.next().await.unwrap();
+/// assert_eq!(first.into_inner(), "fast");
+/// let second = stream.next().await.unwrap();
+/// assert_eq!(second.into_inner(), "slow");
+/// assert!(stream.next().await.is_none());
+/// # }
+/// ```
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "time", feature = "sync"))))]
+pub struct DelayQueueStream<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+/// A cloneable handle that inserts entries into a [`DelayQueueStream`].
+///
+/// Created by [`DelayQueueStream::new`]. Every mutation wakes the task polling
+/// the stream so that a newly inserted or reset entry with an earlier deadline
+/// is observed immediately. Once all handles are dropped the stream ends after
+/// draining the remaining entries.
+///
+/// [`DelayQueueStream`]: struct@DelayQueueStream
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "time", feature = "sync"))))]
+pub struct DelayQueueHandle<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    queue: DelayQueue<T>,
+    waker: Option<Waker>,
+    handles: usize,
+}
+
+impl<T> DelayQueueStream<T> {
+    /// Create a new `DelayQueueStream` wrapping the given queue, along with a
+    /// handle for inserting entries into it.
+    pub fn new(queue: DelayQueue<T>) -> (Self, DelayQueueHandle<T>) {
+        let shared = Arc::new(Mutex::new(Shared {
+            queue,
+            waker: None,
+            handles: 1,
+        }));
+
+        (
+            DelayQueueStream {
+                shared: shared.clone(),
+            },
+            DelayQueueHandle { shared },
+        )
+    }
+}
+
+impl<T> Stream for DelayQueueStream<T> {
+    type Item = Expired<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Expired<T>>> {
+        let mut shared = self.shared.lock().unwrap();
+
+        match shared.queue.poll_expired(cx) {
+            Poll::Ready(Some(expired)) => Poll::Ready(Some(expired)),
+            Poll::Ready(None) if shared.handles == 0 => Poll::Ready(None),
+            // The queue is empty, but a handle may still insert an entry;
+            // stash the waker so the insert can wake us.
+            _ => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let shared = self.shared.lock().unwrap();
+        let len = shared.queue.len();
+
+        if shared.handles == 0 {
+            (len, Some(len))
+        } else {
+            (len, None)
+        }
+    }
+}
+
+impl<T> DelayQueueHandle<T> {
+    /// Insert `value` into the queue, to expire after `timeout`.
+    ///
+    /// Returns the [`Key`] for the entry, which can be passed to
+    /// [`reset`](Self::reset) or [`remove`](Self::remove).
+    pub fn insert(&self, value: T, timeout: Duration) -> Key {
+        self.mutate(|queue| queue.insert(value, timeout))
+    }
+
+    /// Insert `value` into the queue, to expire at `when`.
+    pub fn insert_at(&self, value: T, when: Instant) -> Key {
+        self.mutate(|queue| queue.insert_at(value, when))
+    }
+
+    /// Change the expiration of the entry associated with `key` to `timeout`
+    /// from now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not present in the queue.
+    pub fn reset(&self, key: &Key, timeout: Duration) {
+        self.mutate(|queue| queue.reset(key, timeout));
+    }
+
+    /// Change the expiration of the entry associated with `key` to `when`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not present in the queue.
+    pub fn reset_at(&self, key: &Key, when: Instant) {
+        self.mutate(|queue| queue.reset_at(key, when));
+    }
+
+    /// Remove the entry associated with `key` from the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not present in the queue.
+    pub fn remove(&self, key: &Key) -> Expired<T> {
+        self.mutate(|queue| queue.remove(key))
+    }
+
+    /// Returns the number of entries currently in the queue.
+    pub fn len(&self) -> usize {
+        self.shared.lock().unwrap().queue.len()
+    }
+
+    /// Returns `true` if the queue has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.shared.lock().unwrap().queue.is_empty()
+    }
+
+    /// Apply `f` to the queue and wake the stream, as the earliest deadline
+    /// may have changed.
+    fn mutate<R>(&self, f: impl FnOnce(&mut DelayQueue<T>) -> R) -> R {
+        let (ret, waker) = {
+            let mut shared = self.shared.lock().unwrap();
+            let ret = f(&mut shared.queue);
+            (ret, shared.waker.take())
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+
+        ret
+    }
+}
+
+impl<T> Clone for DelayQueueHandle<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().unwrap().handles += 1;
+
+        DelayQueueHandle {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for DelayQueueHandle<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap();
+            shared.handles -= 1;
+
+            // The stream ends once the queue drains with no handles left, so
+            // the last handle wakes it up to observe that.
+            if shared.handles == 0 {
+                shared.waker.take()
+            } else {
+                None
+            }
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
@@ -28,6 +28,16 @@ use tokio::sync::mpsc::Receiver;
 /// # }
 /// ```
 ///
+/// # The channel closes when the stream is dropped
+///
+/// Dropping a `ReceiverStream` drops the wrapped [`Receiver`], which closes
+/// the channel: further sends fail with a `SendError`. To wrap a receiver in a
+/// stream temporarily, call [`into_inner`] to take the receiver back out
+/// instead of dropping the stream.
+///
+/// [`Receiver`]: struct@tokio::sync::mpsc::Receiver
+/// [`into_inner`]: method@Self::into_inner
+///
 /// [`tokio::sync::mpsc::Receiver`]: struct@tokio::sync::mpsc::Receiver
 /// [`Stream`]: trait@crate::Stream
 #[derive(Debug)]
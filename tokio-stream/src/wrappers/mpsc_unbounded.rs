@@ -28,6 +28,17 @@ use tokio::sync::mpsc::UnboundedReceiver;
 /// # }
 /// ```
 ///
+/// # The channel closes when the stream is dropped
+///
+/// Dropping an `UnboundedReceiverStream` drops the wrapped
+/// [`UnboundedReceiver`], which closes the channel: further sends fail with a
+/// `SendError`. To wrap a receiver in a stream temporarily, call
+/// [`into_inner`] to take the receiver back out instead of dropping the
+/// stream.
+///
+/// [`UnboundedReceiver`]: struct@tokio::sync::mpsc::UnboundedReceiver
+/// [`into_inner`]: method@Self::into_inner
+///
 /// [`tokio::sync::mpsc::UnboundedReceiver`]: struct@tokio::sync::mpsc::UnboundedReceiver
 /// [`Stream`]: trait@crate::Stream
 #[derive(Debug)]
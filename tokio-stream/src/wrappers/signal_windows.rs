@@ -1,7 +1,7 @@
 use crate::Stream;
 use std::pin::Pin;
 use std::task::{Context, Poll};
-use tokio::signal::windows::{CtrlBreak, CtrlC};
+use tokio::signal::windows::{CtrlBreak, CtrlC, CtrlClose, CtrlLogoff, CtrlShutdown};
 
 /// A wrapper around [`CtrlC`] that implements [`Stream`].
 ///
@@ -120,3 +120,180 @@ impl AsMut<CtrlBreak> for CtrlBreakStream {
         &mut self.inner
     }
 }
+
+/// A wrapper around [`CtrlClose`] that implements [`Stream`].
+///
+/// # Example
+///
+/// ```no_run
+/// use tokio::signal::windows::ctrl_close;
+/// use tokio_stream::{StreamExt, wrappers::CtrlCloseStream};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> std::io::Result<()> {
+/// let signals = ctrl_close()?;
+/// let mut stream = CtrlCloseStream::new(signals);
+/// while stream.next().await.is_some() {
+///     println!("ctrl-close received");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`CtrlClose`]: struct@tokio::signal::windows::CtrlClose
+/// [`Stream`]: trait@crate::Stream
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(all(windows, feature = "signal"))))]
+pub struct CtrlCloseStream {
+    inner: CtrlClose,
+}
+
+impl CtrlCloseStream {
+    /// Create a new `CtrlCloseStream`.
+    pub fn new(signal: CtrlClose) -> Self {
+        Self { inner: signal }
+    }
+
+    /// Get back the inner `CtrlClose`.
+    pub fn into_inner(self) -> CtrlClose {
+        self.inner
+    }
+}
+
+impl Stream for CtrlCloseStream {
+    type Item = ();
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.inner.poll_recv(cx)
+    }
+}
+
+impl AsRef<CtrlClose> for CtrlCloseStream {
+    fn as_ref(&self) -> &CtrlClose {
+        &self.inner
+    }
+}
+
+impl AsMut<CtrlClose> for CtrlCloseStream {
+    fn as_mut(&mut self) -> &mut CtrlClose {
+        &mut self.inner
+    }
+}
+
+/// A wrapper around [`CtrlShutdown`] that implements [`Stream`].
+///
+/// # Example
+///
+/// ```no_run
+/// use tokio::signal::windows::ctrl_shutdown;
+/// use tokio_stream::{StreamExt, wrappers::CtrlShutdownStream};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> std::io::Result<()> {
+/// let signals = ctrl_shutdown()?;
+/// let mut stream = CtrlShutdownStream::new(signals);
+/// while stream.next().await.is_some() {
+///     println!("ctrl-shutdown received");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`CtrlShutdown`]: struct@tokio::signal::windows::CtrlShutdown
+/// [`Stream`]: trait@crate::Stream
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(all(windows, feature = "signal"))))]
+pub struct CtrlShutdownStream {
+    inner: CtrlShutdown,
+}
+
+impl CtrlShutdownStream {
+    /// Create a new `CtrlShutdownStream`.
+    pub fn new(signal: CtrlShutdown) -> Self {
+        Self { inner: signal }
+    }
+
+    /// Get back the inner `CtrlShutdown`.
+    pub fn into_inner(self) -> CtrlShutdown {
+        self.inner
+    }
+}
+
+impl Stream for CtrlShutdownStream {
+    type Item = ();
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.inner.poll_recv(cx)
+    }
+}
+
+impl AsRef<CtrlShutdown> for CtrlShutdownStream {
+    fn as_ref(&self) -> &CtrlShutdown {
+        &self.inner
+    }
+}
+
+impl AsMut<CtrlShutdown> for CtrlShutdownStream {
+    fn as_mut(&mut self) -> &mut CtrlShutdown {
+        &mut self.inner
+    }
+}
+
+/// A wrapper around [`CtrlLogoff`] that implements [`Stream`].
+///
+/// # Example
+///
+/// ```no_run
+/// use tokio::signal::windows::ctrl_logoff;
+/// use tokio_stream::{StreamExt, wrappers::CtrlLogoffStream};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> std::io::Result<()> {
+/// let signals = ctrl_logoff()?;
+/// let mut stream = CtrlLogoffStream::new(signals);
+/// while stream.next().await.is_some() {
+///     println!("ctrl-logoff received");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`CtrlLogoff`]: struct@tokio::signal::windows::CtrlLogoff
+/// [`Stream`]: trait@crate::Stream
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(all(windows, feature = "signal"))))]
+pub struct CtrlLogoffStream {
+    inner: CtrlLogoff,
+}
+
+impl CtrlLogoffStream {
+    /// Create a new `CtrlLogoffStream`.
+    pub fn new(signal: CtrlLogoff) -> Self {
+        Self { inner: signal }
+    }
+
+    /// Get back the inner `CtrlLogoff`.
+    pub fn into_inner(self) -> CtrlLogoff {
+        self.inner
+    }
+}
+
+impl Stream for CtrlLogoffStream {
+    type Item = ();
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.inner.poll_recv(cx)
+    }
+}
+
+impl AsRef<CtrlLogoff> for CtrlLogoffStream {
+    fn as_ref(&self) -> &CtrlLogoff {
+        &self.inner
+    }
+}
+
+impl AsMut<CtrlLogoff> for CtrlLogoffStream {
+    fn as_mut(&mut self) -> &mut CtrlLogoff {
+        &mut self.inner
+    }
+}
@@ -73,6 +73,10 @@ use tokio::sync::watch::error::RecvError;
 #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
 pub struct WatchStream<T> {
     inner: ReusableBoxFuture<'static, (Result<(), RecvError>, Receiver<T>)>,
+    // A clone of the receiver, kept so that `into_inner` can hand a receiver
+    // back out: the original is owned by the future stored in `inner` and
+    // cannot be recovered without polling it to completion.
+    rx: Receiver<T>,
 }
 
 async fn make_future<T: Clone + Send + Sync>(
@@ -86,6 +90,7 @@ impl<T: 'static + Clone + Send + Sync> WatchStream<T> {
     /// Create a new `WatchStream`.
     pub fn new(rx: Receiver<T>) -> Self {
         Self {
+            rx: rx.clone(),
             inner: ReusableBoxFuture::new(async move { (Ok(()), rx) }),
         }
     }
@@ -93,9 +98,42 @@ impl<T: 'static + Clone + Send + Sync> WatchStream<T> {
     /// Create a new `WatchStream` that waits for the value to be changed.
     pub fn from_changes(rx: Receiver<T>) -> Self {
         Self {
+            rx: rx.clone(),
             inner: ReusableBoxFuture::new(make_future(rx)),
         }
     }
+
+    /// Get back an inner `Receiver`.
+    ///
+    /// The returned receiver observes the same channel as the one the stream
+    /// was created from, with the current value marked as seen: a subsequent
+    /// call to [`changed`] waits for the next value sent after this call.
+    ///
+    /// [`changed`]: method@tokio::sync::watch::Receiver::changed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use tokio_stream::{StreamExt, wrappers::WatchStream};
+    /// use tokio::sync::watch;
+    ///
+    /// let (tx, rx) = watch::channel("hello");
+    /// let mut stream = WatchStream::new(rx);
+    /// assert_eq!(stream.next().await, Some("hello"));
+    ///
+    /// // Hand the receiver back out without closing the channel.
+    /// let mut rx = stream.into_inner();
+    /// tx.send("goodbye").unwrap();
+    /// rx.changed().await.unwrap();
+    /// assert_eq!(*rx.borrow(), "goodbye");
+    /// # }
+    /// ```
+    pub fn into_inner(mut self) -> Receiver<T> {
+        self.rx.mark_unchanged();
+        self.rx
+    }
 }
 
 impl<T: Clone + 'static + Send + Sync> Stream for WatchStream<T> {
@@ -0,0 +1,88 @@
+#![cfg(feature = "sync")]
+
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::{BroadcastLagPolicy, BroadcastStream};
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn yield_policy_surfaces_lag_error() {
+    let (tx, rx) = broadcast::channel(2);
+    for i in 0..5 {
+        tx.send(i).unwrap();
+    }
+    drop(tx);
+
+    let mut stream = BroadcastStream::new(rx);
+    assert_eq!(
+        stream.next().await,
+        Some(Err(BroadcastStreamRecvError::Lagged(3)))
+    );
+    assert_eq!(stream.next().await, Some(Ok(3)));
+    assert_eq!(stream.next().await, Some(Ok(4)));
+    assert_eq!(stream.next().await, None);
+    assert_eq!(stream.lagged_count(), 3);
+}
+
+#[tokio::test]
+async fn skip_policy_continues_without_errors() {
+    let (tx, rx) = broadcast::channel(2);
+    for i in 0..5 {
+        tx.send(i).unwrap();
+    }
+    drop(tx);
+
+    let mut stream = BroadcastStream::with_lag_policy(rx, BroadcastLagPolicy::Skip);
+    assert_eq!(stream.next().await, Some(Ok(3)));
+    assert_eq!(stream.next().await, Some(Ok(4)));
+    assert_eq!(stream.next().await, None);
+    assert_eq!(stream.lagged_count(), 3);
+}
+
+#[tokio::test]
+async fn close_policy_terminates_on_lag() {
+    let (tx, rx) = broadcast::channel(2);
+    for i in 0..5 {
+        tx.send(i).unwrap();
+    }
+
+    let mut stream = BroadcastStream::with_lag_policy(rx, BroadcastLagPolicy::Close);
+    assert_eq!(stream.next().await, None);
+    assert_eq!(stream.lagged_count(), 3);
+
+    // The stream stays terminated even though the channel has messages.
+    tx.send(5).unwrap();
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn counter_accumulates_across_lags() {
+    let (tx, rx) = broadcast::channel(1);
+    let mut stream = BroadcastStream::with_lag_policy(rx, BroadcastLagPolicy::Skip);
+
+    tx.send(0).unwrap();
+    tx.send(1).unwrap();
+    assert_eq!(stream.next().await, Some(Ok(1)));
+    assert_eq!(stream.lagged_count(), 1);
+
+    tx.send(2).unwrap();
+    tx.send(3).unwrap();
+    drop(tx);
+    assert_eq!(stream.next().await, Some(Ok(3)));
+    assert_eq!(stream.next().await, None);
+    assert_eq!(stream.lagged_count(), 2);
+}
+
+#[tokio::test]
+async fn no_lag_counts_nothing() {
+    let (tx, rx) = broadcast::channel(4);
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    drop(tx);
+
+    let mut stream = BroadcastStream::with_lag_policy(rx, BroadcastLagPolicy::Skip);
+    assert_eq!(stream.next().await, Some(Ok(1)));
+    assert_eq!(stream.next().await, Some(Ok(2)));
+    assert_eq!(stream.next().await, None);
+    assert_eq!(stream.lagged_count(), 0);
+}
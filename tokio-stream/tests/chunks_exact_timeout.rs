@@ -0,0 +1,59 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "time", feature = "sync", feature = "io-util"))]
+
+use tokio::time;
+use tokio_stream::{self as stream, StreamExt};
+use tokio_test::assert_pending;
+use tokio_test::task;
+
+use futures::FutureExt;
+use std::time::Duration;
+
+#[tokio::test(start_paused = true)]
+async fn pads_on_deadline() {
+    let iter = vec![1, 2, 3].into_iter();
+    let stream0 = stream::iter(iter);
+
+    let iter = vec![4].into_iter();
+    let stream1 =
+        stream::iter(iter).then(move |n| time::sleep(Duration::from_secs(3)).map(move |_| n));
+
+    let chunk_stream = stream0
+        .chain(stream1)
+        .chunks_exact_timeout(4, Duration::from_secs(2));
+
+    let mut chunk_stream = task::spawn(chunk_stream);
+
+    assert_pending!(chunk_stream.poll_next());
+    time::advance(Duration::from_secs(2)).await;
+    assert_eq!(chunk_stream.next().await, Some(vec![1, 2, 3, 0]));
+
+    assert_eq!(chunk_stream.next().await, Some(vec![4, 0, 0, 0]));
+    assert_eq!(chunk_stream.next().await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn full_chunk_is_not_padded() {
+    let chunk_stream = stream::iter(vec![1, 2, 3, 4]).chunks_exact_timeout(2, Duration::MAX);
+    let mut chunk_stream = task::spawn(chunk_stream);
+
+    assert_eq!(chunk_stream.next().await, Some(vec![1, 2]));
+    assert_eq!(chunk_stream.next().await, Some(vec![3, 4]));
+    assert_eq!(chunk_stream.next().await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn pads_final_partial_chunk() {
+    let chunk_stream = stream::iter(vec![1, 2, 3]).chunks_exact_timeout(2, Duration::MAX);
+    let mut chunk_stream = task::spawn(chunk_stream);
+
+    assert_eq!(chunk_stream.next().await, Some(vec![1, 2]));
+    assert_eq!(chunk_stream.next().await, Some(vec![3, 0]));
+    assert_eq!(chunk_stream.next().await, None);
+}
+
+#[tokio::test]
+#[should_panic = "`chunk_size` must be non-zero."]
+async fn chunk_size_zero_panics() {
+    let _ = stream::iter(vec![1]).chunks_exact_timeout(0, Duration::MAX);
+}
@@ -0,0 +1,108 @@
+use tokio::sync::oneshot;
+use tokio_stream::{self as stream, StreamExt};
+
+async fn wait(rx: oneshot::Receiver<i32>) -> i32 {
+    rx.await.unwrap()
+}
+
+#[tokio::test]
+async fn buffered_preserves_stream_order() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+
+    let stream = stream::iter(vec![wait(rx1), wait(rx2)]).buffered(2);
+    tokio::pin!(stream);
+
+    // Complete the futures in reverse; the outputs still arrive in stream
+    // order.
+    tx2.send(2).unwrap();
+    tx1.send(1).unwrap();
+
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(stream.next().await, Some(2));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn buffered_runs_futures_concurrently() {
+    let (tx, rx) = oneshot::channel::<()>();
+
+    // The first future only completes once the second has run, which
+    // deadlocks unless both are in flight at the same time.
+    let first = async move {
+        rx.await.unwrap();
+        1
+    };
+    let second = async move {
+        tx.send(()).unwrap();
+        2
+    };
+
+    let results: Vec<i32> = stream::iter(vec![
+        Box::pin(first) as std::pin::Pin<Box<dyn std::future::Future<Output = i32>>>,
+        Box::pin(second),
+    ])
+    .buffered(2)
+    .collect()
+    .await;
+
+    assert_eq!(results, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn buffer_unordered_yields_completion_order() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+
+    let stream = stream::iter(vec![wait(rx1), wait(rx2)]).buffer_unordered(2);
+    tokio::pin!(stream);
+
+    tx2.send(2).unwrap();
+    assert_eq!(stream.next().await, Some(2));
+
+    tx1.send(1).unwrap();
+    assert_eq!(stream.next().await, Some(1));
+
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn buffer_limits_concurrency() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let in_flight = Rc::new(Cell::new(0));
+    let peak = Rc::new(Cell::new(0));
+
+    let futures: Vec<_> = (0..10)
+        .map(|i| {
+            let in_flight = in_flight.clone();
+            let peak = peak.clone();
+            async move {
+                in_flight.set(in_flight.get() + 1);
+                peak.set(peak.get().max(in_flight.get()));
+                tokio::task::yield_now().await;
+                in_flight.set(in_flight.get() - 1);
+                i
+            }
+        })
+        .collect();
+
+    let mut results: Vec<i32> = stream::iter(futures).buffer_unordered(3).collect().await;
+    results.sort_unstable();
+
+    assert_eq!(results, (0..10).collect::<Vec<_>>());
+    assert!(peak.get() <= 3, "ran {} futures at once", peak.get());
+}
+
+#[test]
+#[should_panic(expected = "`max` must be non-zero")]
+fn buffered_zero_panics() {
+    let _ = stream::iter(vec![async {}]).buffered(0);
+}
+
+#[test]
+#[should_panic(expected = "`max` must be non-zero")]
+fn buffer_unordered_zero_panics() {
+    let _ = stream::iter(vec![async {}]).buffer_unordered(0);
+}
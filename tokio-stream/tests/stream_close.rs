@@ -9,3 +9,13 @@ async fn basic_usage() {
     assert_eq!(stream.next().await, Some(None));
     assert_eq!(stream.next().await, None);
 }
+
+#[tokio::test]
+async fn notify_close_method() {
+    let mut stream = tokio_stream::iter(vec![0, 1]).notify_close();
+
+    assert_eq!(stream.next().await, Some(Some(0)));
+    assert_eq!(stream.next().await, Some(Some(1)));
+    assert_eq!(stream.next().await, Some(None));
+    assert_eq!(stream.next().await, None);
+}
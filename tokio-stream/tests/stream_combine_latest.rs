@@ -0,0 +1,75 @@
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{self as stream, StreamExt};
+use tokio_test::{assert_pending, assert_ready, task};
+
+#[tokio::test]
+async fn combine_latest_pairs_most_recent_values() {
+    let (tx_a, rx_a) = mpsc::channel(4);
+    let (tx_b, rx_b) = mpsc::channel(4);
+
+    let stream = ReceiverStream::new(rx_a).combine_latest(ReceiverStream::new(rx_b));
+    let mut stream = task::spawn(stream);
+
+    // Nothing is yielded until both sides have a value.
+    tx_a.send(1).await.unwrap();
+    assert_pending!(stream.poll_next());
+
+    tx_b.send("a").await.unwrap();
+    assert!(stream.is_woken());
+    assert_eq!(assert_ready!(stream.poll_next()), Some((1, "a")));
+
+    // A faster left side sees the latest right value repeated.
+    tx_a.send(2).await.unwrap();
+    assert_eq!(assert_ready!(stream.poll_next()), Some((2, "a")));
+    tx_a.send(3).await.unwrap();
+    assert_eq!(assert_ready!(stream.poll_next()), Some((3, "a")));
+
+    // An update on the right side also emits.
+    tx_b.send("b").await.unwrap();
+    assert_eq!(assert_ready!(stream.poll_next()), Some((3, "b")));
+
+    drop(tx_a);
+    drop(tx_b);
+    assert!(assert_ready!(stream.poll_next()).is_none());
+}
+
+#[tokio::test]
+async fn combine_latest_no_items_lost_at_different_rates() {
+    let fast = stream::iter(vec![1, 2, 3, 4]);
+    let slow = stream::once("only");
+
+    let combined: Vec<(i32, &str)> = fast.combine_latest(slow).collect().await;
+
+    // Every value of the fast stream after both sides yielded shows up.
+    let seen: Vec<i32> = combined.iter().map(|&(x, _)| x).collect();
+    assert_eq!(seen.last(), Some(&4));
+    assert!(combined.iter().all(|&(_, s)| s == "only"));
+}
+
+#[tokio::test]
+async fn combine_latest_ends_if_one_side_is_empty() {
+    let items = stream::iter(vec![1, 2, 3]);
+    let empty = stream::empty::<&str>();
+
+    let combined: Vec<(i32, &str)> = items.combine_latest(empty).collect().await;
+    assert!(combined.is_empty());
+}
+
+#[tokio::test]
+async fn combine_latest_continues_after_one_side_ends() {
+    let (tx_b, rx_b) = mpsc::channel(4);
+
+    let stream = stream::iter(vec![1]).combine_latest(ReceiverStream::new(rx_b));
+    let mut stream = task::spawn(stream);
+
+    tx_b.send("a").await.unwrap();
+    assert_eq!(assert_ready!(stream.poll_next()), Some((1, "a")));
+
+    // The left side is done, but its last value keeps pairing.
+    tx_b.send("b").await.unwrap();
+    assert_eq!(assert_ready!(stream.poll_next()), Some((1, "b")));
+
+    drop(tx_b);
+    assert!(assert_ready!(stream.poll_next()).is_none());
+}
@@ -0,0 +1,118 @@
+#![cfg(all(feature = "time", feature = "sync", feature = "io-util"))]
+
+use tokio::time::{self, sleep, Duration, Instant};
+use tokio_stream::StreamExt;
+use tokio_test::{assert_pending, assert_ready, task};
+
+use futures::stream;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+async fn maybe_sleep(idx: i32) -> i32 {
+    if idx % 2 == 0 {
+        sleep(ms(200)).await;
+    }
+    idx
+}
+
+#[tokio::test]
+async fn deadline_extracted_from_previous_item() {
+    time::pause();
+
+    // Each item promises the next within 100ms; items 2 and 4 take 200ms, so
+    // an error is inserted before each of them.
+    let stream = stream::iter(1..=4)
+        .then(maybe_sleep)
+        .deadline_by(|_| Instant::now() + ms(100));
+    let mut stream = task::spawn(stream);
+
+    assert_ready!(stream.poll_next()); // Ok(1)
+
+    assert_pending!(stream.poll_next());
+    time::advance(ms(150)).await;
+    let v = assert_ready!(stream.poll_next());
+    assert!(matches!(v, Some(Err(_))));
+
+    time::advance(ms(100)).await;
+    let v = assert_ready!(stream.poll_next());
+    assert_eq!(v.unwrap().unwrap(), 2);
+}
+
+#[tokio::test]
+async fn no_deadline_before_first_item() {
+    time::pause();
+
+    let stream =
+        stream::once(async { sleep(ms(500)).await }).deadline_by(|_| Instant::now() + ms(10));
+    let mut stream = task::spawn(stream);
+
+    // No matter how long the first item takes, no error is produced.
+    assert_pending!(stream.poll_next());
+    time::advance(ms(400)).await;
+    assert_pending!(stream.poll_next());
+    time::advance(ms(150)).await;
+    let v = assert_ready!(stream.poll_next());
+    assert!(matches!(v, Some(Ok(()))));
+
+    let v = assert_ready!(stream.poll_next());
+    assert!(v.is_none());
+}
+
+#[tokio::test]
+async fn deadline_varies_per_item() {
+    time::pause();
+
+    // The deadline comes from the item itself: item `n` allows `n * 100` ms
+    // for its successor.
+    let stream = stream::iter(1..=2)
+        .then(|n| async move {
+            sleep(ms(150)).await;
+            n
+        })
+        .deadline_by(|&n| Instant::now() + ms(n as u64 * 100));
+    let mut stream = task::spawn(stream);
+
+    assert_pending!(stream.poll_next());
+    time::advance(ms(200)).await;
+    let v = assert_ready!(stream.poll_next());
+    assert_eq!(v.unwrap().unwrap(), 1);
+
+    // Item 1 allowed only 100ms, which elapses before item 2 arrives.
+    assert_pending!(stream.poll_next());
+    time::advance(ms(120)).await;
+    let v = assert_ready!(stream.poll_next());
+    assert!(matches!(v, Some(Err(_))));
+
+    // Item 2 arrives afterwards; its own 200ms deadline never fires since
+    // the stream ends.
+    time::advance(ms(50)).await;
+    let v = assert_ready!(stream.poll_next());
+    assert_eq!(v.unwrap().unwrap(), 2);
+
+    let v = assert_ready!(stream.poll_next());
+    assert!(v.is_none());
+}
+
+#[tokio::test]
+async fn only_one_error_per_deadline() {
+    time::pause();
+
+    let stream = stream::iter(std::iter::once(1))
+        .chain(stream::pending())
+        .deadline_by(|_| Instant::now() + ms(50));
+    let mut stream = task::spawn(stream);
+
+    assert_ready!(stream.poll_next()); // Ok(1)
+
+    assert_pending!(stream.poll_next());
+    time::advance(ms(70)).await;
+    let v = assert_ready!(stream.poll_next());
+    assert!(matches!(v, Some(Err(_))));
+
+    // The deadline is disarmed until the next item; no repeated errors.
+    assert_pending!(stream.poll_next());
+    time::advance(ms(1000)).await;
+    assert_pending!(stream.poll_next());
+}
@@ -0,0 +1,63 @@
+use tokio_stream::{self as stream, Stream, StreamExt};
+
+#[tokio::test]
+async fn distinct_until_changed_suppresses_repeats() {
+    let values: Vec<i32> = stream::iter(vec![1, 1, 2, 2, 2, 1, 3, 3])
+        .distinct_until_changed()
+        .collect()
+        .await;
+    assert_eq!(values, vec![1, 2, 1, 3]);
+}
+
+#[tokio::test]
+async fn distinct_until_changed_empty_stream() {
+    let values: Vec<i32> = stream::empty().distinct_until_changed().collect().await;
+    assert!(values.is_empty());
+}
+
+#[tokio::test]
+async fn distinct_until_changed_no_repeats() {
+    let values: Vec<i32> = stream::iter(vec![1, 2, 3])
+        .distinct_until_changed()
+        .collect()
+        .await;
+    assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn distinct_until_changed_size_hint() {
+    let stream = stream::iter(vec![1, 1, 2]).distinct_until_changed();
+    assert_eq!(stream.size_hint(), (0, Some(3)));
+}
+
+#[tokio::test]
+async fn dedup_by_key_suppresses_same_key_runs() {
+    let values: Vec<&str> =
+        stream::iter(vec!["apple", "avocado", "banana", "cherry", "clementine"])
+            .dedup_by_key(|s| s.chars().next())
+            .collect()
+            .await;
+    assert_eq!(values, vec!["apple", "banana", "cherry"]);
+}
+
+#[tokio::test]
+async fn dedup_by_key_does_not_require_clone() {
+    // The values themselves implement neither `Clone` nor `PartialEq`.
+    struct Event {
+        id: u64,
+    }
+
+    let events = vec![Event { id: 1 }, Event { id: 1 }, Event { id: 2 }];
+    let ids: Vec<u64> = stream::iter(events)
+        .dedup_by_key(|event| event.id)
+        .map(|event| event.id)
+        .collect()
+        .await;
+    assert_eq!(ids, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn dedup_by_key_empty_stream() {
+    let values: Vec<i32> = stream::empty().dedup_by_key(|&x: &i32| x).collect().await;
+    assert!(values.is_empty());
+}
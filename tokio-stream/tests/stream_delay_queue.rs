@@ -0,0 +1,103 @@
+#![cfg(all(feature = "time", feature = "sync"))]
+
+use std::time::Duration;
+
+use tokio::time::{self, Instant};
+use tokio_stream::wrappers::DelayQueueStream;
+use tokio_stream::StreamExt;
+use tokio_test::{assert_pending, assert_ready, task};
+use tokio_util::time::DelayQueue;
+
+#[tokio::test(start_paused = true)]
+async fn entries_expire_in_deadline_order() {
+    let (mut stream, handle) = DelayQueueStream::new(DelayQueue::new());
+
+    handle.insert("slow", Duration::from_millis(20));
+    handle.insert("fast", Duration::from_millis(10));
+    drop(handle);
+
+    assert_eq!(stream.next().await.unwrap().into_inner(), "fast");
+    assert_eq!(stream.next().await.unwrap().into_inner(), "slow");
+    assert!(stream.next().await.is_none());
+
+    // The stream is fused.
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn insert_wakes_pending_stream() {
+    let (mut stream, handle) = DelayQueueStream::new(DelayQueue::new());
+
+    let mut next = task::spawn(stream.next());
+    assert_pending!(next.poll());
+
+    handle.insert("hello", Duration::from_millis(5));
+    assert!(next.is_woken());
+    assert_pending!(next.poll());
+
+    time::advance(Duration::from_millis(5)).await;
+    let expired = assert_ready!(next.poll()).unwrap();
+    assert_eq!(expired.into_inner(), "hello");
+}
+
+#[tokio::test(start_paused = true)]
+async fn reset_changes_deadline() {
+    let (mut stream, handle) = DelayQueueStream::new(DelayQueue::new());
+
+    let key = handle.insert("reset me", Duration::from_millis(10));
+    handle.insert("other", Duration::from_millis(20));
+    handle.reset(&key, Duration::from_millis(30));
+    drop(handle);
+
+    assert_eq!(stream.next().await.unwrap().into_inner(), "other");
+
+    let expired = stream.next().await.unwrap();
+    assert_eq!(expired.key(), key);
+    assert_eq!(expired.into_inner(), "reset me");
+}
+
+#[tokio::test(start_paused = true)]
+async fn remove_takes_entry_back_out() {
+    let (mut stream, handle) = DelayQueueStream::new(DelayQueue::new());
+
+    let key = handle.insert("removed", Duration::from_millis(10));
+    handle.insert("kept", Duration::from_millis(20));
+    assert_eq!(handle.len(), 2);
+
+    let removed = handle.remove(&key);
+    assert_eq!(removed.into_inner(), "removed");
+    assert_eq!(handle.len(), 1);
+    drop(handle);
+
+    assert_eq!(stream.next().await.unwrap().into_inner(), "kept");
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn stream_ends_when_last_handle_drops() {
+    let (mut stream, handle) = DelayQueueStream::new(DelayQueue::<&str>::new());
+    let cloned = handle.clone();
+
+    let mut next = task::spawn(stream.next());
+    assert_pending!(next.poll());
+
+    drop(handle);
+    assert_pending!(next.poll());
+
+    drop(cloned);
+    assert!(next.is_woken());
+    assert!(assert_ready!(next.poll()).is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn insert_at_uses_absolute_deadline() {
+    let (mut stream, handle) = DelayQueueStream::new(DelayQueue::new());
+
+    let start = Instant::now();
+    handle.insert_at("later", start + Duration::from_millis(50));
+    drop(handle);
+
+    let expired = stream.next().await.unwrap();
+    assert_eq!(expired.into_inner(), "later");
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
@@ -0,0 +1,113 @@
+use tokio::sync::oneshot;
+use tokio_stream::{self as stream, Stream, StreamExt};
+
+#[tokio::test]
+async fn filter_async_some_pass() {
+    let result: Vec<i32> = stream::iter(vec![1, -2, 3, -4, 5])
+        .filter_async(|&x| async move { x > 0 })
+        .collect()
+        .await;
+    assert_eq!(result, vec![1, 3, 5]);
+}
+
+#[tokio::test]
+async fn filter_async_none_pass() {
+    let result: Vec<i32> = stream::iter(vec![-1, -2, -3])
+        .filter_async(|&x| async move { x > 0 })
+        .collect()
+        .await;
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn filter_async_awaits_predicate() {
+    let (tx, rx) = oneshot::channel::<()>();
+
+    // The predicate for the first item does not resolve until the oneshot
+    // fires, so the stream must stay pending rather than skip ahead.
+    let mut rx = Some(rx);
+    let stream = stream::iter(vec![1, 2]).filter_async(move |&x| {
+        let rx = rx.take();
+        async move {
+            if let Some(rx) = rx {
+                rx.await.unwrap();
+            }
+            x % 2 == 0
+        }
+    });
+    tokio::pin!(stream);
+
+    let next = tokio::time::timeout(std::time::Duration::from_millis(10), stream.next());
+    assert!(next.await.is_err());
+
+    tx.send(()).unwrap();
+    assert_eq!(stream.next().await, Some(2));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn filter_async_size_hint() {
+    let stream = stream::iter(vec![1, 2, 3]).filter_async(|&x| async move { x > 0 });
+    assert_eq!(stream.size_hint(), (0, Some(3)));
+}
+
+#[tokio::test]
+async fn then_concurrent_preserves_stream_order() {
+    async fn wait(rx: oneshot::Receiver<i32>) -> i32 {
+        rx.await.unwrap()
+    }
+
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+
+    let mut rxs = vec![rx1, rx2].into_iter();
+    let stream = stream::iter(vec![1, 2]).then_concurrent(move |_| wait(rxs.next().unwrap()), 2);
+    tokio::pin!(stream);
+
+    // Complete the futures in reverse; the outputs still arrive in stream
+    // order.
+    tx2.send(2).unwrap();
+    tx1.send(1).unwrap();
+
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(stream.next().await, Some(2));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn then_concurrent_runs_futures_concurrently() {
+    let (tx, rx) = oneshot::channel::<()>();
+
+    let mut tx = Some(tx);
+    let mut rx = Some(rx);
+
+    // The future for the first item only completes once the second has run,
+    // which deadlocks unless both are in flight at the same time.
+    let results: Vec<i32> = stream::iter(vec![1, 2])
+        .then_concurrent(
+            move |x| {
+                let tx = if x == 2 { tx.take() } else { None };
+                let rx = if x == 1 { rx.take() } else { None };
+                async move {
+                    if let Some(tx) = tx {
+                        tx.send(()).unwrap();
+                    }
+                    if let Some(rx) = rx {
+                        rx.await.unwrap();
+                    }
+                    x * 10
+                }
+            },
+            2,
+        )
+        .collect()
+        .await;
+
+    assert_eq!(results, vec![10, 20]);
+}
+
+#[tokio::test]
+#[should_panic = "`max` must be non-zero."]
+async fn then_concurrent_zero_panics() {
+    let _ = stream::iter(vec![1]).then_concurrent(|x| async move { x }, 0);
+}
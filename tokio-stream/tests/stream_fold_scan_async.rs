@@ -0,0 +1,98 @@
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::{self as stream, Stream, StreamExt};
+
+#[tokio::test]
+async fn fold_async_accumulates() {
+    let sum = stream::iter(vec![1u8, 2, 3])
+        .fold_async(0, |acc, x| async move { acc + x })
+        .await;
+    assert_eq!(sum, 6);
+}
+
+#[tokio::test]
+async fn fold_async_empty_stream_returns_init() {
+    let value = stream::empty::<i32>()
+        .fold_async(42, |acc, _| async move { acc })
+        .await;
+    assert_eq!(value, 42);
+}
+
+#[tokio::test]
+async fn fold_async_awaits_between_items() {
+    // The accumulator sends each item through a channel and waits for an
+    // acknowledgement, proving the await completes before the next item.
+    let (tx, mut rx) = mpsc::channel::<i32>(1);
+
+    let fold = tokio::spawn(stream::iter(vec![1, 2, 3]).fold_async(0, move |acc, x| {
+        let tx = tx.clone();
+        async move {
+            tx.send(x).await.unwrap();
+            acc + x
+        }
+    }));
+
+    assert_eq!(rx.recv().await, Some(1));
+    assert_eq!(rx.recv().await, Some(2));
+    assert_eq!(rx.recv().await, Some(3));
+    assert_eq!(fold.await.unwrap(), 6);
+}
+
+#[tokio::test]
+async fn scan_async_threads_state() {
+    let sums: Vec<i32> = stream::iter(vec![1, 2, 3, 4])
+        .scan_async(0, |acc, x| async move { Some((acc + x, acc + x)) })
+        .collect()
+        .await;
+    assert_eq!(sums, vec![1, 3, 6, 10]);
+}
+
+#[tokio::test]
+async fn scan_async_ends_early_on_none() {
+    let sums: Vec<i32> = stream::iter(1..100)
+        .scan_async(0, |acc, x| async move {
+            let acc = acc + x;
+            if acc > 5 {
+                None
+            } else {
+                Some((acc, acc))
+            }
+        })
+        .collect()
+        .await;
+    assert_eq!(sums, vec![1, 3]);
+}
+
+#[tokio::test]
+async fn scan_async_stays_done_after_none() {
+    let stream =
+        stream::iter(vec![1, 2, 3]).scan_async((), |_, _| async move { None::<((), i32)> });
+    tokio::pin!(stream);
+
+    assert!(stream.next().await.is_none());
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn scan_async_future_completes_before_next_item() {
+    let (tx, rx) = oneshot::channel::<()>();
+
+    let stream = stream::iter(vec![1, 2]).scan_async(Some(rx), |rx, x| async move {
+        if let Some(rx) = rx {
+            // The first state update waits for the signal.
+            rx.await.unwrap();
+        }
+        Some((None, x))
+    });
+    tokio::pin!(stream);
+
+    tx.send(()).unwrap();
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(stream.next().await, Some(2));
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn scan_async_size_hint() {
+    let stream = stream::iter(vec![1, 2, 3]).scan_async(0, |acc, x| async move { Some((acc, x)) });
+    assert_eq!(stream.size_hint(), (0, Some(3)));
+}
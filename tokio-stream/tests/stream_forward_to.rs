@@ -0,0 +1,55 @@
+#![cfg(feature = "sync")]
+
+use tokio::sync::mpsc;
+use tokio_stream::{self as stream, StreamExt};
+use tokio_test::{assert_pending, assert_ready_ok, task};
+
+#[tokio::test]
+async fn forwards_all_values() {
+    let (tx, mut rx) = mpsc::channel(4);
+
+    stream::iter(1..=3).forward_to(tx).await.unwrap();
+
+    assert_eq!(Some(1), rx.recv().await);
+    assert_eq!(Some(2), rx.recv().await);
+    assert_eq!(Some(3), rx.recv().await);
+    assert_eq!(None, rx.recv().await);
+}
+
+#[tokio::test]
+async fn applies_backpressure() {
+    let (tx, mut rx) = mpsc::channel(1);
+
+    let mut forward = task::spawn(stream::iter(1..=3).forward_to(tx));
+
+    // Only one value fits in the channel; the forward stalls on the second.
+    assert_pending!(forward.poll());
+    assert_eq!(Some(1), rx.recv().await);
+
+    assert!(forward.is_woken());
+    assert_pending!(forward.poll());
+    assert_eq!(Some(2), rx.recv().await);
+
+    assert!(forward.is_woken());
+    assert_ready_ok!(forward.poll());
+    drop(forward);
+    assert_eq!(Some(3), rx.recv().await);
+    assert_eq!(None, rx.recv().await);
+}
+
+#[tokio::test]
+async fn errors_when_receiver_dropped() {
+    let (tx, rx) = mpsc::channel::<i32>(1);
+    drop(rx);
+
+    let err = stream::iter(1..=3).forward_to(tx).await.unwrap_err();
+    assert!(err.into_inner().is_none());
+}
+
+#[tokio::test]
+async fn empty_stream_completes_immediately() {
+    let (tx, mut rx) = mpsc::channel::<i32>(1);
+
+    stream::empty().forward_to(tx).await.unwrap();
+    assert_eq!(None, rx.recv().await);
+}
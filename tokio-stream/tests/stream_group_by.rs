@@ -0,0 +1,65 @@
+use tokio::join;
+use tokio_stream::{self as stream, StreamExt};
+
+#[tokio::test]
+async fn group_by_demultiplexes_by_key() {
+    let stream = stream::iter(vec![("a", 1), ("b", 2), ("a", 3), ("c", 4), ("b", 5)]);
+    let mut groups = stream.group_by(|&(key, _)| key, 16);
+
+    let (key_a, group_a) = groups.next().await.unwrap();
+    let (key_b, group_b) = groups.next().await.unwrap();
+    let (key_c, group_c) = groups.next().await.unwrap();
+    assert_eq!((key_a, key_b, key_c), ("a", "b", "c"));
+    assert!(groups.next().await.is_none());
+
+    assert_eq!(vec![("a", 1), ("a", 3)], group_a.collect::<Vec<_>>().await);
+    assert_eq!(vec![("b", 2), ("b", 5)], group_b.collect::<Vec<_>>().await);
+    assert_eq!(vec![("c", 4)], group_c.collect::<Vec<_>>().await);
+}
+
+#[tokio::test]
+async fn group_by_empty_stream() {
+    let mut groups = stream::empty::<i32>().group_by(|&x| x, 16);
+    assert!(groups.next().await.is_none());
+}
+
+#[tokio::test]
+async fn group_by_applies_backpressure() {
+    // With a capacity of one the groups can only make progress if both
+    // consumers run concurrently.
+    let stream = stream::iter(vec![0, 1, 2, 3, 4, 5]);
+    let mut groups = stream.group_by(|&x| x % 2, 1);
+
+    let (_, evens) = groups.next().await.unwrap();
+    let (_, odds) = groups.next().await.unwrap();
+
+    let (evens, odds, end) = join!(
+        evens.collect::<Vec<_>>(),
+        odds.collect::<Vec<_>>(),
+        groups.next(),
+    );
+    assert_eq!(evens, vec![0, 2, 4]);
+    assert_eq!(odds, vec![1, 3, 5]);
+    assert!(end.is_none());
+}
+
+#[tokio::test]
+async fn group_by_dropped_group_discards_its_values() {
+    let stream = stream::iter(vec![0, 1, 2, 3, 4, 5]);
+    let mut groups = stream.group_by(|&x| x % 2, 1);
+
+    let (_, evens) = groups.next().await.unwrap();
+    let (_, odds) = groups.next().await.unwrap();
+    drop(evens);
+
+    // The even values no longer exert backpressure and are thrown away.
+    let (odds, end) = join!(odds.collect::<Vec<_>>(), groups.next());
+    assert_eq!(odds, vec![1, 3, 5]);
+    assert!(end.is_none());
+}
+
+#[tokio::test]
+#[should_panic = "`capacity` must be non-zero."]
+async fn group_by_zero_capacity_panics() {
+    let _ = stream::iter(vec![1]).group_by(|&x| x, 0);
+}
@@ -0,0 +1,60 @@
+use tokio_stream::{self as stream, Stream, StreamExt};
+use tokio_test::{assert_pending, assert_ready, task};
+
+use std::pin::Pin;
+
+mod support {
+    pub(crate) mod mpsc;
+}
+
+use support::mpsc;
+
+#[tokio::test]
+async fn merge_all_yields_all_values() {
+    let streams = vec![
+        stream::iter(vec![0, 3]),
+        stream::iter(vec![1, 4]),
+        stream::iter(vec![2, 5]),
+    ];
+
+    let s = stream::merge_all(streams);
+    assert_eq!(s.size_hint(), (6, Some(6)));
+
+    let mut values: Vec<_> = s.collect().await;
+    values.sort_unstable();
+    assert_eq!(values, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn merge_all_empty_collection() {
+    let mut s = stream::merge_all(Vec::<stream::Iter<std::vec::IntoIter<i32>>>::new());
+
+    assert!(s.next().await.is_none());
+}
+
+#[test]
+fn merge_all_completes_once_all_streams_complete() {
+    let (tx1, rx1) = mpsc::unbounded_channel_stream::<i32>();
+    let (tx2, rx2) = mpsc::unbounded_channel_stream::<i32>();
+
+    let mut s = task::spawn(stream::merge_all(vec![
+        Box::pin(rx1) as Pin<Box<dyn Stream<Item = i32>>>,
+        Box::pin(rx2),
+    ]));
+
+    assert_pending!(s.poll_next());
+
+    tx1.send(1).unwrap();
+    assert_eq!(assert_ready!(s.poll_next()), Some(1));
+    assert_pending!(s.poll_next());
+
+    // Exhausting one stream does not complete the merge.
+    drop(tx1);
+    assert_pending!(s.poll_next());
+
+    tx2.send(2).unwrap();
+    assert_eq!(assert_ready!(s.poll_next()), Some(2));
+
+    drop(tx2);
+    assert_eq!(assert_ready!(s.poll_next()), None);
+}
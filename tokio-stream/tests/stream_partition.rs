@@ -0,0 +1,48 @@
+use tokio_stream::{self as stream, Stream, StreamExt};
+
+#[tokio::test]
+async fn partition_splits_by_predicate() {
+    let (evens, odds) = stream::iter(1..=6).partition(|x| x % 2 == 0);
+
+    assert_eq!(vec![2, 4, 6], evens.collect::<Vec<_>>().await);
+    assert_eq!(vec![1, 3, 5], odds.collect::<Vec<_>>().await);
+}
+
+#[tokio::test]
+async fn partition_interleaved_consumption() {
+    let (evens, odds) = stream::iter(1..=6).partition(|x| x % 2 == 0);
+    tokio::pin!(evens);
+    tokio::pin!(odds);
+
+    assert_eq!(Some(1), odds.next().await);
+    assert_eq!(Some(2), evens.next().await);
+    assert_eq!(Some(4), evens.next().await);
+    assert_eq!(Some(3), odds.next().await);
+    assert_eq!(Some(5), odds.next().await);
+    assert_eq!(None, odds.next().await);
+    assert_eq!(Some(6), evens.next().await);
+    assert_eq!(None, evens.next().await);
+}
+
+#[tokio::test]
+async fn partition_empty_stream() {
+    let (matches, rest) = stream::empty::<i32>().partition(|&x| x > 0);
+
+    assert!(matches.collect::<Vec<_>>().await.is_empty());
+    assert!(rest.collect::<Vec<_>>().await.is_empty());
+}
+
+#[tokio::test]
+async fn partition_dropped_half_discards_its_values() {
+    let (evens, odds) = stream::iter(1..=6).partition(|x| x % 2 == 0);
+    drop(evens);
+
+    assert_eq!(vec![1, 3, 5], odds.collect::<Vec<_>>().await);
+}
+
+#[tokio::test]
+async fn partition_size_hint() {
+    let (evens, odds) = stream::iter(1..=6).partition(|x| x % 2 == 0);
+    assert_eq!((0, Some(6)), evens.size_hint());
+    assert_eq!((0, Some(6)), odds.size_hint());
+}
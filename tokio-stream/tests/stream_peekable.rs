@@ -0,0 +1,85 @@
+#![cfg(all(feature = "time", feature = "sync", feature = "io-util"))]
+
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::time;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{self as stream, StreamExt};
+
+#[tokio::test]
+async fn peek_mut_modifies_in_place() {
+    let mut stream = stream::iter(vec![1, 2, 3]).peekable();
+
+    assert_eq!(stream.peek().await, Some(&1));
+    if let Some(item) = stream.peek_mut().await {
+        *item += 100;
+    }
+    assert_eq!(stream.next().await, Some(101));
+    assert_eq!(stream.next().await, Some(2));
+}
+
+#[tokio::test]
+async fn next_if_consumes_only_on_match() {
+    let mut stream = stream::iter(1..=4).peekable();
+
+    assert_eq!(stream.next_if(|&x| x < 3).await, Some(1));
+    assert_eq!(stream.next_if(|&x| x < 3).await, Some(2));
+
+    // 3 fails the predicate and stays buffered.
+    assert_eq!(stream.next_if(|&x| x < 3).await, None);
+    assert_eq!(stream.next_if(|&x| x < 3).await, None);
+    assert_eq!(stream.next().await, Some(3));
+    assert_eq!(stream.next().await, Some(4));
+
+    // The stream is exhausted.
+    assert_eq!(stream.next_if(|_| true).await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn peek_timeout_elapses_without_item() {
+    let (tx, rx) = mpsc::channel::<i32>(1);
+    let mut stream = ReceiverStream::new(rx).peekable();
+
+    assert!(stream
+        .peek_timeout(Duration::from_millis(10))
+        .await
+        .is_err());
+
+    // The timeout consumed nothing; a later item is still observed.
+    tx.send(7).await.unwrap();
+    assert_eq!(
+        stream.peek_timeout(Duration::from_millis(10)).await,
+        Ok(Some(&7))
+    );
+    assert_eq!(stream.next().await, Some(7));
+}
+
+#[tokio::test(start_paused = true)]
+async fn peek_timeout_sees_end_of_stream() {
+    let (tx, rx) = mpsc::channel::<i32>(1);
+    let mut stream = ReceiverStream::new(rx).peekable();
+
+    drop(tx);
+    assert_eq!(
+        stream.peek_timeout(Duration::from_millis(10)).await,
+        Ok(None)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn peek_timeout_returns_buffered_item_immediately() {
+    let (tx, rx) = mpsc::channel::<i32>(1);
+    let mut stream = ReceiverStream::new(rx).peekable();
+
+    tx.send(1).await.unwrap();
+    assert_eq!(stream.peek().await, Some(&1));
+
+    // The clock is paused: no time needs to pass for a buffered peek.
+    let start = time::Instant::now();
+    assert_eq!(
+        stream.peek_timeout(Duration::from_secs(60)).await,
+        Ok(Some(&1))
+    );
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
@@ -0,0 +1,34 @@
+use tokio_stream::{self as stream, Stream, StreamExt};
+
+#[tokio::test]
+async fn sliding_window_yields_overlapping_windows() {
+    let windows: Vec<Vec<i32>> = stream::iter(1..=5).sliding_window(3).collect().await;
+    assert_eq!(windows, vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+}
+
+#[tokio::test]
+async fn sliding_window_of_one() {
+    let windows: Vec<Vec<i32>> = stream::iter(1..=3).sliding_window(1).collect().await;
+    assert_eq!(windows, vec![vec![1], vec![2], vec![3]]);
+}
+
+#[tokio::test]
+async fn sliding_window_short_stream_yields_nothing() {
+    let windows: Vec<Vec<i32>> = stream::iter(1..=2).sliding_window(3).collect().await;
+    assert!(windows.is_empty());
+}
+
+#[tokio::test]
+async fn sliding_window_size_hint() {
+    let stream = stream::iter(1..=5).sliding_window(3);
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+
+    let stream = stream::iter(1..=2).sliding_window(3);
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
+
+#[tokio::test]
+#[should_panic = "`size` must be non-zero."]
+async fn sliding_window_zero_panics() {
+    let _ = stream::iter(vec![1]).sliding_window(0);
+}
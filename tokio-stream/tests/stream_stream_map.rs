@@ -328,6 +328,137 @@ fn one_ready_many_none() {
     }
 }
 
+#[test]
+fn priority_orders_ready_streams() {
+    // Run a few times to show the order does not depend on the randomized
+    // fallback.
+    for _ in 0..100 {
+        let mut map = task::spawn(StreamMap::new());
+
+        map.insert(0, pin_box(stream::once("low")));
+        map.insert_with_priority(1, pin_box(stream::once("high")), 2);
+        map.insert_with_priority(2, pin_box(stream::once("mid")), 1);
+
+        assert_eq!(assert_ready_some!(map.poll_next()), (1, "high"));
+        assert_eq!(assert_ready_some!(map.poll_next()), (2, "mid"));
+        assert_eq!(assert_ready_some!(map.poll_next()), (0, "low"));
+        assert_ready_none!(map.poll_next());
+    }
+}
+
+#[test]
+fn priority_skips_pending_streams() {
+    let mut map = task::spawn(StreamMap::new());
+
+    map.insert_with_priority(0, pin_box(stream::pending()), 9);
+    map.insert_with_priority(1, pin_box(stream::once("ready")), 1);
+
+    // The higher priority stream is not ready, so the lower one yields.
+    assert_eq!(assert_ready_some!(map.poll_next()), (1, "ready"));
+    assert_pending!(map.poll_next());
+}
+
+#[test]
+fn priority_resumes_after_completed_streams() {
+    let mut map = task::spawn(StreamMap::new());
+
+    // Several high-priority streams end during a single poll; the poll must
+    // keep descending through the order and still find the ready stream.
+    map.insert_with_priority(0, pin_box(stream::empty()), 5);
+    map.insert_with_priority(1, pin_box(stream::empty()), 4);
+    map.insert_with_priority(2, pin_box(stream::empty()), 3);
+    map.insert_with_priority(3, pin_box(stream::once("ready")), 2);
+    map.insert_with_priority(4, pin_box(stream::pending()), 1);
+
+    assert_eq!(assert_ready_some!(map.poll_next()), (3, "ready"));
+    assert_eq!(map.len(), 2);
+    assert_pending!(map.poll_next());
+}
+
+#[test]
+fn try_insert_respects_limit() {
+    let mut map = StreamMap::with_limit(2);
+
+    assert_ok!(map.try_insert(0, pending::<i32>()));
+    assert_ok!(map.try_insert(1, pending()));
+    assert_eq!(map.limit(), Some(2));
+
+    let err = map.try_insert(2, pending()).unwrap_err();
+    let (key, _stream) = err.into_inner();
+    assert_eq!(key, 2);
+
+    // Replacing an existing key does not grow the map.
+    assert_ok!(map.try_insert(1, pending()));
+
+    // Removal frees a slot.
+    assert!(map.remove(&0).is_some());
+    assert_ok!(map.try_insert(2, pending()));
+}
+
+#[test]
+#[should_panic = "`StreamMap` is at its entry limit."]
+fn insert_past_limit_panics() {
+    let mut map = StreamMap::with_limit(1);
+
+    map.insert(0, pending::<i32>());
+    map.insert(1, pending());
+}
+
+#[test]
+#[should_panic = "`limit` must be non-zero."]
+fn zero_limit_panics() {
+    let _ = StreamMap::<usize, stream::Pending<()>>::with_limit(0);
+}
+
+#[test]
+fn round_robin_rotates_deterministically() {
+    // Run a few times to show the order does not depend on the randomized
+    // fallback.
+    for _ in 0..100 {
+        let mut map = task::spawn(StreamMap::new());
+        map.set_round_robin(true);
+
+        map.insert(0, pin_box(iter(vec!["a1", "a2"])));
+        map.insert(1, pin_box(iter(vec!["b1", "b2"])));
+
+        assert_eq!(assert_ready_some!(map.poll_next()), (0, "a1"));
+        assert_eq!(assert_ready_some!(map.poll_next()), (1, "b1"));
+        assert_eq!(assert_ready_some!(map.poll_next()), (0, "a2"));
+        assert_eq!(assert_ready_some!(map.poll_next()), (1, "b2"));
+        assert_ready_none!(map.poll_next());
+    }
+}
+
+#[test]
+fn round_robin_survives_insert_and_remove() {
+    let mut map = task::spawn(StreamMap::new());
+    map.set_round_robin(true);
+
+    map.insert(0, pin_box(iter(vec!["a1", "a2"])));
+    map.insert(1, pin_box(iter(vec!["b1", "b2"])));
+
+    assert_eq!(assert_ready_some!(map.poll_next()), (0, "a1"));
+    assert!(map.remove(&0).is_some());
+
+    // The cursor keeps rotating over the remaining entries.
+    assert_eq!(assert_ready_some!(map.poll_next()), (1, "b1"));
+
+    map.insert(2, pin_box(iter(vec!["c1"])));
+    assert_eq!(assert_ready_some!(map.poll_next()), (2, "c1"));
+    assert_eq!(assert_ready_some!(map.poll_next()), (1, "b2"));
+}
+
+#[test]
+fn extend_keeps_priorities_in_sync() {
+    let mut map = task::spawn(StreamMap::new());
+
+    map.insert_with_priority(0, pin_box(stream::pending::<&str>()), 1);
+    map.extend(vec![(1, pin_box(stream::once("ext")))]);
+
+    // Extended entries get the default priority, and polling does not panic.
+    assert_eq!(assert_ready_some!(map.poll_next()), (1, "ext"));
+}
+
 fn pin_box<T: Stream<Item = U> + 'static, U>(s: T) -> Pin<Box<dyn Stream<Item = U>>> {
     Box::pin(s)
 }
@@ -0,0 +1,63 @@
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::{self as stream, StreamExt};
+use tokio_test::{assert_pending, assert_ready, task};
+
+#[tokio::test]
+async fn switch_map_yields_completed_futures() {
+    let results: Vec<i32> = stream::iter(vec![1])
+        .switch_map(|x| async move { x * 10 })
+        .collect()
+        .await;
+    assert_eq!(results, vec![10]);
+}
+
+#[tokio::test]
+async fn switch_map_supersedes_stale_values() {
+    // Both values are ready on the first poll, so only the most recent one
+    // is mapped.
+    let results: Vec<i32> = stream::iter(vec![1, 2])
+        .switch_map(|x| async move { x * 10 })
+        .collect()
+        .await;
+    assert_eq!(results, vec![20]);
+}
+
+#[tokio::test]
+async fn switch_map_cancels_in_flight_future() {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let (done_tx, done_rx) = oneshot::channel::<i32>();
+
+    let mut done_rx = Some(done_rx);
+    let stream = UnboundedReceiverStream::new(rx).switch_map(move |x: i32| {
+        let done_rx = done_rx.take();
+        async move {
+            match done_rx {
+                // The first future never completes on its own.
+                Some(done_rx) => done_rx.await.unwrap(),
+                None => x * 10,
+            }
+        }
+    });
+    let mut stream = task::spawn(stream);
+
+    tx.send(1).unwrap();
+    assert_pending!(stream.poll_next());
+
+    // The second value drops the first future, closing its oneshot.
+    tx.send(2).unwrap();
+    assert_eq!(assert_ready!(stream.poll_next()), Some(20));
+    assert!(done_tx.is_closed());
+
+    drop(tx);
+    assert_eq!(assert_ready!(stream.poll_next()), None);
+}
+
+#[tokio::test]
+async fn switch_map_empty_stream() {
+    let results: Vec<i32> = stream::empty::<i32>()
+        .switch_map(|x| async move { x })
+        .collect()
+        .await;
+    assert!(results.is_empty());
+}
@@ -0,0 +1,103 @@
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{self as stream, StreamExt};
+use tokio_test::{assert_pending, assert_ready, task};
+
+#[tokio::test]
+async fn take_until_passes_items_through() {
+    let (_tx, rx) = oneshot::channel::<()>();
+
+    let items: Vec<i32> = stream::iter(1..=3).take_until(rx).collect().await;
+    assert_eq!(items, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn take_until_stops_on_future() {
+    let (item_tx, item_rx) = mpsc::channel(4);
+    let (stop_tx, stop_rx) = oneshot::channel::<()>();
+
+    let stream = ReceiverStream::new(item_rx).take_until(stop_rx);
+    let mut stream = task::spawn(stream);
+
+    item_tx.send(1).await.unwrap();
+    assert_eq!(assert_ready!(stream.poll_next()), Some(1));
+    assert_pending!(stream.poll_next());
+
+    stop_tx.send(()).unwrap();
+    assert!(stream.is_woken());
+    assert!(assert_ready!(stream.poll_next()).is_none());
+
+    // Items sent after shutdown are never yielded.
+    item_tx.send(2).await.unwrap();
+    assert!(assert_ready!(stream.poll_next()).is_none());
+}
+
+#[tokio::test]
+async fn take_until_stays_done_after_stream_ends() {
+    // The future never resolving must not keep the stream alive.
+    let (_tx, rx) = oneshot::channel::<()>();
+
+    let stream = stream::iter(vec![1]).take_until(rx);
+    let mut stream = task::spawn(stream);
+
+    assert_eq!(assert_ready!(stream.poll_next()), Some(1));
+    assert!(assert_ready!(stream.poll_next()).is_none());
+    assert!(assert_ready!(stream.poll_next()).is_none());
+}
+
+#[tokio::test]
+async fn split_when_yields_prefix_then_remainder() {
+    let (prefix, rest) = stream::iter(1..=6).split_when(|&x| x == 4);
+
+    let prefix: Vec<i32> = prefix.collect().await;
+    assert_eq!(prefix, vec![1, 2, 3]);
+
+    // The remainder starts with the matching item.
+    let rest: Vec<i32> = rest.collect().await;
+    assert_eq!(rest, vec![4, 5, 6]);
+}
+
+#[tokio::test]
+async fn split_when_remainder_pending_until_split() {
+    let (prefix, rest) = stream::iter(1..=4).split_when(|&x| x == 3);
+    let mut prefix = task::spawn(prefix);
+    let mut rest = task::spawn(rest);
+
+    assert_pending!(rest.poll_next());
+
+    assert_eq!(assert_ready!(prefix.poll_next()), Some(1));
+    assert_eq!(assert_ready!(prefix.poll_next()), Some(2));
+    assert_pending!(rest.poll_next());
+
+    // The match ends the prefix and wakes the remainder.
+    assert!(assert_ready!(prefix.poll_next()).is_none());
+    assert!(rest.is_woken());
+    assert_eq!(assert_ready!(rest.poll_next()), Some(3));
+    assert_eq!(assert_ready!(rest.poll_next()), Some(4));
+    assert!(assert_ready!(rest.poll_next()).is_none());
+}
+
+#[tokio::test]
+async fn split_when_no_match_ends_both() {
+    let (prefix, rest) = stream::iter(1..=3).split_when(|&x| x == 99);
+
+    let prefix: Vec<i32> = prefix.collect().await;
+    assert_eq!(prefix, vec![1, 2, 3]);
+
+    let rest: Vec<i32> = rest.collect().await;
+    assert!(rest.is_empty());
+}
+
+#[tokio::test]
+async fn split_when_dropping_prefix_resumes_remainder() {
+    let (prefix, rest) = stream::iter(1..=4).split_when(|&x| x == 99);
+    let mut rest = task::spawn(rest);
+
+    assert_pending!(rest.poll_next());
+
+    // Dropping the undrained prefix hands the whole stream to the remainder.
+    drop(prefix);
+    assert!(rest.is_woken());
+    assert_eq!(assert_ready!(rest.poll_next()), Some(1));
+    assert_eq!(assert_ready!(rest.poll_next()), Some(2));
+}
@@ -0,0 +1,143 @@
+use tokio::sync::oneshot;
+use tokio_stream::{self as stream, StreamExt};
+
+#[tokio::test]
+async fn try_filter_keeps_matching_ok_values() {
+    let results: Vec<Result<i32, &str>> = stream::iter(vec![Ok(1), Ok(2), Err("nope"), Ok(4)])
+        .try_filter(|&x| x % 2 == 0)
+        .collect()
+        .await;
+    assert_eq!(results, vec![Ok(2), Err("nope"), Ok(4)]);
+}
+
+#[tokio::test]
+async fn try_filter_empty_stream() {
+    let results: Vec<Result<i32, &str>> =
+        stream::empty().try_filter(|&x: &i32| x > 0).collect().await;
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn try_collect_ok() {
+    let values: Result<Vec<i32>, &str> =
+        stream::iter(vec![Ok(1), Ok(2), Ok(3)]).try_collect().await;
+    assert_eq!(values, Ok(vec![1, 2, 3]));
+}
+
+#[tokio::test]
+async fn try_collect_short_circuits_on_error() {
+    let values: Result<Vec<i32>, &str> = stream::iter(vec![Ok(1), Err("no"), Ok(3), Err("nein")])
+        .try_collect()
+        .await;
+    assert_eq!(values, Err("no"));
+}
+
+#[tokio::test]
+async fn try_for_each_concurrent_runs_all() {
+    let (tx, rx) = oneshot::channel::<()>();
+
+    let mut tx = Some(tx);
+    let mut rx = Some(rx);
+
+    // The future for the first item only completes once the second has run,
+    // which deadlocks unless both are in flight at the same time.
+    let result: Result<(), &str> = stream::iter(vec![Ok(1), Ok(2)])
+        .try_for_each_concurrent(
+            move |x| {
+                let tx = if x == 2 { tx.take() } else { None };
+                let rx = if x == 1 { rx.take() } else { None };
+                async move {
+                    if let Some(tx) = tx {
+                        tx.send(()).unwrap();
+                    }
+                    if let Some(rx) = rx {
+                        rx.await.unwrap();
+                    }
+                    Ok(())
+                }
+            },
+            2,
+        )
+        .await;
+    assert_eq!(result, Ok(()));
+}
+
+#[tokio::test]
+async fn try_for_each_concurrent_stops_on_stream_error() {
+    let mut seen = Vec::new();
+    let result = stream::iter(vec![Ok(1), Err("bad"), Ok(3)])
+        .try_for_each_concurrent(
+            |x| {
+                seen.push(x);
+                async { Ok(()) }
+            },
+            1,
+        )
+        .await;
+    assert_eq!(result, Err("bad"));
+    assert_eq!(seen, vec![1]);
+}
+
+#[tokio::test]
+async fn try_for_each_concurrent_stops_on_future_error() {
+    let result: Result<(), &str> = stream::iter(vec![Ok(1), Ok(2), Ok(3)])
+        .try_for_each_concurrent(
+            |x| async move {
+                if x == 2 {
+                    Err("two")
+                } else {
+                    Ok(())
+                }
+            },
+            1,
+        )
+        .await;
+    assert_eq!(result, Err("two"));
+}
+
+#[tokio::test]
+async fn try_buffer_unordered_yields_completion_order() {
+    async fn wait(rx: oneshot::Receiver<i32>) -> Result<i32, &'static str> {
+        rx.await.map_err(|_| "cancelled")
+    }
+
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+
+    let stream = stream::iter(vec![Ok(wait(rx1)), Ok(wait(rx2))]).try_buffer_unordered(2);
+    tokio::pin!(stream);
+
+    tx2.send(2).unwrap();
+    assert_eq!(stream.next().await, Some(Ok(2)));
+
+    tx1.send(1).unwrap();
+    assert_eq!(stream.next().await, Some(Ok(1)));
+
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn try_buffer_unordered_yields_stream_errors() {
+    async fn fetch(x: i32) -> Result<i32, &'static str> {
+        Ok(x * 10)
+    }
+
+    let results: Vec<Result<i32, &str>> = stream::iter(vec![Ok(fetch(1)), Err("bad")])
+        .try_buffer_unordered(2)
+        .collect()
+        .await;
+    assert!(results.contains(&Ok(10)));
+    assert!(results.contains(&Err("bad")));
+}
+
+#[tokio::test]
+#[should_panic = "`max` must be non-zero."]
+async fn try_for_each_concurrent_zero_panics() {
+    drop(stream::iter(vec![Ok::<i32, ()>(1)]).try_for_each_concurrent(|_| async { Ok(()) }, 0));
+}
+
+#[tokio::test]
+#[should_panic = "`max` must be non-zero."]
+async fn try_buffer_unordered_zero_panics() {
+    let _ = stream::iter(vec![Ok::<_, ()>(async { Ok::<i32, ()>(1) })]).try_buffer_unordered(0);
+}
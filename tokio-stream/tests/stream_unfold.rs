@@ -0,0 +1,77 @@
+use tokio_stream::{self as stream, Stream, StreamExt};
+
+#[tokio::test]
+async fn once_future_basic_usage() {
+    let one = stream::once_future(async { 1 });
+    tokio::pin!(one);
+
+    assert_eq!(one.size_hint(), (1, Some(1)));
+    assert_eq!(Some(1), one.next().await);
+
+    assert_eq!(one.size_hint(), (0, Some(0)));
+    assert_eq!(None, one.next().await);
+}
+
+#[tokio::test]
+async fn once_future_awaits_the_future() {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
+    let mut stream = stream::once_future(rx);
+
+    tx.send("hello").unwrap();
+    assert_eq!(Some(Ok("hello")), stream.next().await);
+    assert_eq!(None, stream.next().await);
+}
+
+#[tokio::test]
+async fn repeat_with_async_calls_closure_per_item() {
+    let mut count = 0;
+    let stream = stream::repeat_with_async(move || {
+        count += 1;
+        async move { count }
+    });
+
+    assert_eq!(stream.size_hint(), (usize::MAX, None));
+
+    let values: Vec<i32> = stream.take(4).collect().await;
+    assert_eq!(values, vec![1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn unfold_yields_until_none() {
+    let stream = stream::unfold(1, |state| async move {
+        if state <= 3 {
+            Some((state * 10, state + 1))
+        } else {
+            None
+        }
+    });
+
+    let values: Vec<i32> = stream.collect().await;
+    assert_eq!(values, vec![10, 20, 30]);
+}
+
+#[tokio::test]
+async fn unfold_stays_done() {
+    let stream = stream::unfold((), |()| async { None::<(i32, ())> });
+    tokio::pin!(stream);
+
+    assert_eq!(None, stream.next().await);
+    assert_eq!(None, stream.next().await);
+}
+
+#[tokio::test]
+async fn unfold_state_can_await() {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
+    let stream = stream::unfold(Some(rx), |rx| async move {
+        let rx = rx?;
+        let value = rx.await.unwrap();
+        Some((value, None))
+    });
+    tokio::pin!(stream);
+
+    tx.send(7).unwrap();
+    assert_eq!(Some(7), stream.next().await);
+    assert_eq!(None, stream.next().await);
+}
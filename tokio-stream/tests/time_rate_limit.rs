@@ -0,0 +1,35 @@
+#![cfg(all(feature = "time", feature = "sync"))]
+
+use tokio::sync::RateLimiter;
+use tokio::time::Instant;
+use tokio_stream::{self as stream, StreamExt};
+
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test(start_paused = true)]
+async fn rate_limit_paces_stream() {
+    let limiter = Arc::new(RateLimiter::new(1, Duration::from_secs(1)));
+
+    let start = Instant::now();
+    let values: Vec<_> = stream::iter(1..=4).rate_limit(limiter).collect().await;
+
+    assert_eq!(values, vec![1, 2, 3, 4]);
+    // The burst permit is free; the remaining three are released one per second.
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+}
+
+#[tokio::test(start_paused = true)]
+async fn rate_limit_shares_quota_between_streams() {
+    let limiter = Arc::new(RateLimiter::new(1, Duration::from_secs(1)));
+
+    let first = stream::iter(0..2).rate_limit(limiter.clone());
+    let second = stream::iter(2..4).rate_limit(limiter);
+
+    let start = Instant::now();
+    let values: Vec<i32> = first.merge(second).collect().await;
+
+    // Four items through a shared one-per-second quota, however interleaved.
+    assert_eq!(values.len(), 4);
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+}
@@ -55,3 +55,20 @@ async fn watch_stream_from_changes() {
 
     assert_eq!(stream.next().await.unwrap(), "bye");
 }
+
+#[tokio::test]
+async fn watch_stream_into_inner() {
+    let (tx, rx) = watch::channel("hello");
+
+    let mut stream = WatchStream::new(rx);
+    assert_eq!(stream.next().await.unwrap(), "hello");
+
+    // Taking the receiver back out does not close the channel, and the
+    // current value is marked as seen.
+    let mut rx = stream.into_inner();
+    assert!(!rx.has_changed().unwrap());
+
+    tx.send("bye").unwrap();
+    rx.changed().await.unwrap();
+    assert_eq!(*rx.borrow(), "bye");
+}
@@ -205,6 +205,12 @@ impl Decoder for AnyDelimiterCodec {
             }
         })
     }
+
+    fn recover(&mut self, _buf: &mut BytesMut) {
+        // `decode` resynchronizes itself: a chunk over the length limit is
+        // discarded up to the next delimiter on subsequent calls, so no
+        // bytes need to be dropped here.
+    }
 }
 
 impl<T> Encoder<T> for AnyDelimiterCodec
@@ -0,0 +1,249 @@
+use crate::codec::decoder::Decoder;
+use crate::codec::encoder::Encoder;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::{cmp, fmt, io};
+
+/// A [`Decoder`] and [`Encoder`] for COBS framing.
+///
+/// Consistent Overhead Byte Stuffing encodes frames so that they contain no
+/// zero bytes, allowing `0x00` to be used as an unambiguous frame
+/// delimiter. The encoding adds at most one byte of overhead per 254 bytes
+/// of data, which makes it popular on serial and embedded transports.
+///
+/// A stuffed frame that does not decode cleanly, for example because a code
+/// byte points past the end of the frame, produces a
+/// [`CobsCodecError::MalformedFrame`] error. The offending frame has
+/// already been consumed up to its delimiter, so decoding resynchronizes on
+/// the next frame automatically.
+///
+/// [`Decoder`]: crate::codec::Decoder
+/// [`Encoder`]: crate::codec::Encoder
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CobsCodec {
+    // Stored index of the next index to examine for a zero byte. This is
+    // used to optimize searching, like in `LinesCodec`.
+    next_index: usize,
+
+    /// The maximum length for a given stuffed frame on the wire. If
+    /// `usize::MAX`, frames will be read until a zero byte is reached.
+    max_length: usize,
+
+    /// Are we currently discarding the remainder of a frame which was over
+    /// the length limit?
+    is_discarding: bool,
+}
+
+impl CobsCodec {
+    /// Returns a `CobsCodec` for splitting up data into COBS frames.
+    ///
+    /// # Note
+    ///
+    /// The returned `CobsCodec` will not have an upper bound on the length
+    /// of a buffered frame. See the documentation for
+    /// [`new_with_max_length`] for information on why this could be a
+    /// potential security risk.
+    ///
+    /// [`new_with_max_length`]: crate::codec::CobsCodec::new_with_max_length()
+    pub fn new() -> CobsCodec {
+        CobsCodec {
+            next_index: 0,
+            max_length: usize::MAX,
+            is_discarding: false,
+        }
+    }
+
+    /// Returns a `CobsCodec` with a maximum frame length limit.
+    ///
+    /// If this is set, calls to `CobsCodec::decode` will return a
+    /// [`CobsCodecError::MaxFrameLengthExceeded`] error when the stuffed
+    /// frame exceeds the length limit. Subsequent calls will discard up to
+    /// `limit` bytes from that frame until a zero byte is reached,
+    /// returning `None` until the frame over the limit has been fully
+    /// discarded. After that point, calls to `decode` will function as
+    /// normal.
+    ///
+    /// # Note
+    ///
+    /// Setting a length limit is highly recommended for any `CobsCodec`
+    /// which will be exposed to untrusted input. Otherwise, the size of the
+    /// buffer that holds the frame currently being read is unbounded. An
+    /// attacker could exploit this unbounded buffer by sending an unbounded
+    /// amount of input without any zero bytes, causing unbounded memory
+    /// consumption.
+    pub fn new_with_max_length(max_length: usize) -> Self {
+        CobsCodec {
+            max_length,
+            ..CobsCodec::new()
+        }
+    }
+
+    /// Returns the maximum frame length when decoding.
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+}
+
+fn unstuff(frame: &[u8]) -> Result<BytesMut, CobsCodecError> {
+    let mut dst = BytesMut::with_capacity(frame.len());
+    let mut index = 0;
+
+    while index < frame.len() {
+        let code = usize::from(frame[index]);
+
+        // A zero code byte cannot occur inside a stuffed frame, and a code
+        // byte may not point past the end of the frame.
+        if code == 0 || index + code > frame.len() {
+            return Err(CobsCodecError::MalformedFrame);
+        }
+
+        dst.extend_from_slice(&frame[index + 1..index + code]);
+        index += code;
+
+        // A code byte below 0xFF represents an encoded zero, unless it is
+        // the last block of the frame.
+        if code < 0xFF && index < frame.len() {
+            dst.put_u8(0);
+        }
+    }
+
+    Ok(dst)
+}
+
+impl Decoder for CobsCodec {
+    type Item = BytesMut;
+    type Error = CobsCodecError;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<BytesMut>, CobsCodecError> {
+        loop {
+            // Determine how far into the buffer we'll search for a zero
+            // byte. If there's no max_length set, we'll read to the end of
+            // the buffer.
+            let read_to = cmp::min(self.max_length.saturating_add(1), buf.len());
+
+            let zero_offset = buf[self.next_index..read_to].iter().position(|b| *b == 0);
+
+            match (self.is_discarding, zero_offset) {
+                (true, Some(offset)) => {
+                    // If we found a zero byte, discard up to that offset
+                    // and then stop discarding. On the next iteration,
+                    // we'll try to read a frame normally.
+                    buf.advance(offset + self.next_index + 1);
+                    self.is_discarding = false;
+                    self.next_index = 0;
+                }
+                (true, None) => {
+                    // Otherwise, we didn't find a zero byte, so we'll
+                    // discard everything we read. On the next iteration,
+                    // we'll continue discarding up to max_len bytes unless
+                    // we find a zero byte.
+                    buf.advance(read_to);
+                    self.next_index = 0;
+                    if buf.is_empty() {
+                        return Ok(None);
+                    }
+                }
+                (false, Some(offset)) => {
+                    // Found a frame!
+                    let zero_index = offset + self.next_index;
+                    self.next_index = 0;
+                    let frame = buf.split_to(zero_index + 1);
+                    return unstuff(&frame[..frame.len() - 1]).map(Some);
+                }
+                (false, None) if buf.len() > self.max_length => {
+                    // Reached the maximum length without finding a zero
+                    // byte, return an error and start discarding on the
+                    // next call.
+                    self.is_discarding = true;
+                    return Err(CobsCodecError::MaxFrameLengthExceeded);
+                }
+                (false, None) => {
+                    // We didn't find a frame or reach the length limit, so
+                    // the next call will resume searching at the current
+                    // offset.
+                    self.next_index = read_to;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    fn recover(&mut self, _buf: &mut BytesMut) {
+        // `decode` resynchronizes itself: a malformed frame has already
+        // been consumed up to its delimiter and an oversized frame is
+        // discarded on subsequent calls, so no bytes need to be dropped
+        // here.
+    }
+}
+
+impl Encoder<Bytes> for CobsCodec {
+    type Error = CobsCodecError;
+
+    fn encode(&mut self, data: Bytes, buf: &mut BytesMut) -> Result<(), CobsCodecError> {
+        // Worst case overhead is one code byte per 254 data bytes, plus the
+        // leading code byte and the trailing delimiter.
+        buf.reserve(data.len() + data.len() / 254 + 2);
+
+        let mut code_index = buf.len();
+        buf.put_u8(0);
+        let mut code = 1u8;
+
+        for &byte in &data[..] {
+            if byte == 0 {
+                buf[code_index] = code;
+                code_index = buf.len();
+                buf.put_u8(0);
+                code = 1;
+            } else {
+                buf.put_u8(byte);
+                code += 1;
+
+                if code == 0xFF {
+                    buf[code_index] = code;
+                    code_index = buf.len();
+                    buf.put_u8(0);
+                    code = 1;
+                }
+            }
+        }
+
+        buf[code_index] = code;
+        buf.put_u8(0);
+        Ok(())
+    }
+}
+
+impl Default for CobsCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An error occurred while encoding or decoding a COBS frame.
+#[derive(Debug)]
+pub enum CobsCodecError {
+    /// The maximum frame length was exceeded.
+    MaxFrameLengthExceeded,
+    /// A frame did not decode as valid COBS data.
+    MalformedFrame,
+    /// An IO error occurred.
+    Io(io::Error),
+}
+
+impl fmt::Display for CobsCodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CobsCodecError::MaxFrameLengthExceeded => write!(f, "max frame length exceeded"),
+            CobsCodecError::MalformedFrame => write!(f, "malformed COBS frame"),
+            CobsCodecError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for CobsCodecError {
+    fn from(e: io::Error) -> CobsCodecError {
+        CobsCodecError::Io(e)
+    }
+}
+
+impl std::error::Error for CobsCodecError {}
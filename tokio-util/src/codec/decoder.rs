@@ -154,6 +154,23 @@ pub trait Decoder {
         }
     }
 
+    /// Discards buffered input until a new frame could plausibly start.
+    ///
+    /// This method is called by [`FramedRead::skip_to_next_frame`] after
+    /// `decode` has returned an error, to resynchronize the buffer before
+    /// decoding resumes. The default implementation clears the buffer,
+    /// which is the only safe choice for an unknown framing.
+    ///
+    /// Decoders for delimiter-based protocols should override this to
+    /// advance `src` just past the next frame delimiter, and decoders that
+    /// already consume the malformed frame before returning an error can
+    /// override it to do nothing.
+    ///
+    /// [`FramedRead::skip_to_next_frame`]: crate::codec::FramedRead::skip_to_next_frame
+    fn recover(&mut self, src: &mut BytesMut) {
+        src.clear();
+    }
+
     /// Provides a [`Stream`] and [`Sink`] interface for reading and writing to this
     /// `Io` object, using `Decode` and `Encode` to read and write the raw data.
     ///
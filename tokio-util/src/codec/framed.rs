@@ -277,6 +277,27 @@ impl<T, U> Framed<T, U> {
         self.inner.state.write.backpressure_boundary = boundary;
     }
 
+    /// Attempts to write out buffered data until the write buffer is below
+    /// the backpressure boundary.
+    ///
+    /// Returns `Poll::Pending` while the buffer holds at least
+    /// [`backpressure_boundary`] bytes and the underlying I/O is not ready
+    /// to accept more data, registering the current task for wakeup. Unlike
+    /// `poll_flush`, this resolves as soon as the buffer is back below the
+    /// boundary instead of draining it completely, so servers can use it to
+    /// cap per-connection memory while a peer is slow to read.
+    ///
+    /// [`backpressure_boundary`]: Framed::backpressure_boundary
+    pub fn poll_backpressure(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), io::Error>>
+    where
+        T: AsyncWrite,
+    {
+        self.project().inner.poll_backpressure(cx)
+    }
+
     /// Consumes the `Framed`, returning its underlying I/O stream.
     ///
     /// Note that care should be taken to not tamper with the underlying stream
@@ -249,6 +249,46 @@ where
     }
 }
 
+impl<T, U, W> FramedImpl<T, U, W>
+where
+    T: AsyncWrite,
+    W: BorrowMut<WriteFrame>,
+{
+    pub(crate) fn poll_backpressure(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        use crate::util::poll_write_buf;
+        let mut pinned = self.project();
+
+        // Write out buffered data until the buffer is back below the
+        // backpressure boundary. Unlike `poll_flush`, this stops as soon as
+        // the high watermark is cleared instead of draining the buffer
+        // completely.
+        loop {
+            let state: &WriteFrame = (*pinned.state).borrow();
+            if state.buffer.is_empty() || state.buffer.len() < state.backpressure_boundary {
+                break;
+            }
+
+            let WriteFrame { buffer, .. } = pinned.state.borrow_mut();
+            trace!(remaining = buffer.len(), "writing;");
+
+            let n = ready!(poll_write_buf(pinned.inner.as_mut(), cx, buffer))?;
+
+            if n == 0 {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "failed to \
+                     write frame to transport",
+                )));
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
 impl<T, I, U, W> Sink<I> for FramedImpl<T, U, W>
 where
     T: AsyncWrite,
@@ -153,6 +153,30 @@ impl<T, D> FramedRead<T, D> {
     pub fn read_buffer_mut(&mut self) -> &mut BytesMut {
         &mut self.inner.state.buffer
     }
+
+    /// Resynchronizes the stream after a decode error.
+    ///
+    /// When `decode` returns an error, the stream is considered corrupt and
+    /// subsequent polls return `None`. Calling this method instead asks the
+    /// decoder to discard buffered input up to the next frame boundary via
+    /// [`Decoder::recover`] and resumes decoding from there, so a single
+    /// malformed frame does not permanently poison the stream.
+    ///
+    /// This has no effect before the first decode error.
+    ///
+    /// [`Decoder::recover`]: crate::codec::Decoder::recover
+    pub fn skip_to_next_frame(&mut self)
+    where
+        D: Decoder,
+    {
+        let state = &mut self.inner.state;
+
+        if state.has_errored {
+            self.inner.codec.recover(&mut state.buffer);
+            state.has_errored = false;
+            state.is_readable = true;
+        }
+    }
 }
 
 // This impl just defers to the underlying FramedImpl
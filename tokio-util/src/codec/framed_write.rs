@@ -159,6 +159,27 @@ impl<T, E> FramedWrite<T, E> {
     pub fn set_backpressure_boundary(&mut self, boundary: usize) {
         self.inner.state.backpressure_boundary = boundary;
     }
+
+    /// Attempts to write out buffered data until the write buffer is below
+    /// the backpressure boundary.
+    ///
+    /// Returns `Poll::Pending` while the buffer holds at least
+    /// [`backpressure_boundary`] bytes and the underlying I/O is not ready
+    /// to accept more data, registering the current task for wakeup. Unlike
+    /// `poll_flush`, this resolves as soon as the buffer is back below the
+    /// boundary instead of draining it completely, so servers can use it to
+    /// cap per-connection memory while a peer is slow to read.
+    ///
+    /// [`backpressure_boundary`]: FramedWrite::backpressure_boundary
+    pub fn poll_backpressure(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), io::Error>>
+    where
+        T: AsyncWrite,
+    {
+        self.project().inner.poll_backpressure(cx)
+    }
 }
 
 // This impl just defers to the underlying FramedImpl
@@ -0,0 +1,190 @@
+use crate::codec::decoder::Decoder;
+use crate::codec::encoder::Encoder;
+use crate::codec::lines_codec::{LinesCodec, LinesCodecError};
+
+use bytes::{BufMut, BytesMut};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::{fmt, io};
+
+/// A [`Decoder`] and [`Encoder`] for newline-delimited JSON.
+///
+/// Each frame is a single JSON value followed by a `\n` character, as in the
+/// [JSON Lines] format. Decoded frames are deserialized into `T` and encoded
+/// values are serialized from `T`, so a [`Framed`] transport built with this
+/// codec works directly with typed values.
+///
+/// [JSON Lines]: https://jsonlines.org
+/// [`Decoder`]: crate::codec::Decoder
+/// [`Encoder`]: crate::codec::Encoder
+/// [`Framed`]: crate::codec::Framed
+///
+/// # Example
+///
+/// ```
+/// use futures::SinkExt;
+/// use serde::{Deserialize, Serialize};
+/// use tokio_util::codec::{FramedWrite, JsonLinesCodec};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     id: u32,
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut writer = FramedWrite::new(Vec::new(), JsonLinesCodec::<Message>::new());
+///
+///     writer.send(Message { id: 1 }).await.unwrap();
+///
+///     assert_eq!(writer.get_ref().as_slice(), b"{\"id\":1}\n");
+/// }
+/// ```
+pub struct JsonLinesCodec<T> {
+    lines: LinesCodec,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> JsonLinesCodec<T> {
+    /// Returns a `JsonLinesCodec` without an upper bound on the length of a
+    /// buffered line.
+    ///
+    /// See the documentation for [`LinesCodec::new_with_max_length`] for
+    /// why an unbounded line length can be a security risk when reading
+    /// untrusted input.
+    pub fn new() -> Self {
+        Self {
+            lines: LinesCodec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a `JsonLinesCodec` with a maximum line length limit.
+    ///
+    /// Lines exceeding the limit produce a
+    /// [`JsonLinesCodecError::MaxLineLengthExceeded`] error and are
+    /// discarded, exactly as described for
+    /// [`LinesCodec::new_with_max_length`].
+    pub fn new_with_max_length(max_length: usize) -> Self {
+        Self {
+            lines: LinesCodec::new_with_max_length(max_length),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the maximum line length when decoding.
+    pub fn max_length(&self) -> usize {
+        self.lines.max_length()
+    }
+}
+
+impl<T> Decoder for JsonLinesCodec<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = T;
+    type Error = JsonLinesCodecError;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<T>, JsonLinesCodecError> {
+        match self.lines.decode(buf)? {
+            Some(line) => Ok(Some(serde_json::from_str(&line)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<T>, JsonLinesCodecError> {
+        match self.lines.decode_eof(buf)? {
+            Some(line) => Ok(Some(serde_json::from_str(&line)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn recover(&mut self, buf: &mut BytesMut) {
+        // The underlying `LinesCodec` resynchronizes itself, and a line
+        // with invalid JSON has already been consumed.
+        self.lines.recover(buf);
+    }
+}
+
+impl<T> Encoder<T> for JsonLinesCodec<T>
+where
+    T: Serialize,
+{
+    type Error = JsonLinesCodecError;
+
+    fn encode(&mut self, item: T, buf: &mut BytesMut) -> Result<(), JsonLinesCodecError> {
+        let json = serde_json::to_vec(&item)?;
+        buf.reserve(json.len() + 1);
+        buf.put(json.as_slice());
+        buf.put_u8(b'\n');
+        Ok(())
+    }
+}
+
+impl<T> Clone for JsonLinesCodec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            lines: self.lines.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for JsonLinesCodec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsonLinesCodec")
+            .field("lines", &self.lines)
+            .finish()
+    }
+}
+
+impl<T> Default for JsonLinesCodec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An error occurred while encoding or decoding a JSON line.
+#[derive(Debug)]
+pub enum JsonLinesCodecError {
+    /// The maximum line length was exceeded.
+    MaxLineLengthExceeded,
+    /// A value could not be serialized or deserialized.
+    Json(serde_json::Error),
+    /// An IO error occurred.
+    Io(io::Error),
+}
+
+impl fmt::Display for JsonLinesCodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonLinesCodecError::MaxLineLengthExceeded => write!(f, "max line length exceeded"),
+            JsonLinesCodecError::Json(e) => write!(f, "{e}"),
+            JsonLinesCodecError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<LinesCodecError> for JsonLinesCodecError {
+    fn from(e: LinesCodecError) -> JsonLinesCodecError {
+        match e {
+            LinesCodecError::MaxLineLengthExceeded => JsonLinesCodecError::MaxLineLengthExceeded,
+            LinesCodecError::Io(e) => JsonLinesCodecError::Io(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for JsonLinesCodecError {
+    fn from(e: serde_json::Error) -> JsonLinesCodecError {
+        JsonLinesCodecError::Json(e)
+    }
+}
+
+impl From<io::Error> for JsonLinesCodecError {
+    fn from(e: io::Error) -> JsonLinesCodecError {
+        JsonLinesCodecError::Io(e)
+    }
+}
+
+impl std::error::Error for JsonLinesCodecError {}
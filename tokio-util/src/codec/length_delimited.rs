@@ -355,6 +355,36 @@
 //! A simple example where there are unused bytes between the length field
 //! and the payload.
 //!
+//! ## Example 8
+//!
+//! The following will parse a protobuf-style varint length field, omitting
+//! the frame head in the yielded `BytesMut`. This is the framing used by
+//! protobuf and gRPC-like streams, where the header width varies with the
+//! payload length.
+//!
+//! ```
+//! # use tokio_stream::StreamExt;
+//! # use tokio_util::codec::LengthDelimitedCodec;
+//! # #[tokio::main]
+//! # async fn main() {
+//! # let io: &[u8] = b"\x0BHello world";
+//! let mut reader = LengthDelimitedCodec::builder()
+//!     .length_field_varint()
+//!     .new_read(io);
+//! # let res = reader.next().await.unwrap().unwrap().to_vec();
+//! # assert_eq!(res, b"Hello world");
+//! # }
+//! ```
+//!
+//! The following frame will be decoded as such:
+//!
+//! ```text
+//!        INPUT                       DECODED
+//! +- len -+--- Payload ---+     +--- Payload ---+
+//! | \x0B  |  Hello world  | --> |  Hello world  |
+//! +-------+---------------+     +---------------+
+//! ```
+//!
 //! # Encoding
 //!
 //! [`FramedWrite`] adapts an [`AsyncWrite`] into a `Sink` of [`BytesMut`],
@@ -432,6 +462,14 @@ pub struct Builder {
 
     // Length field byte order (little or big endian)
     length_field_is_big_endian: bool,
+
+    // Parse the length field as a protobuf-style varint instead of a
+    // fixed-width integer
+    length_field_is_varint: bool,
+
+    // Append a CRC32 trailer to each encoded frame and verify it when
+    // decoding
+    crc32_checksum: bool,
 }
 
 /// An error when the number of bytes read is more than max frame length.
@@ -439,6 +477,48 @@ pub struct LengthDelimitedCodecError {
     _priv: (),
 }
 
+/// An error when a frame's CRC32 trailer does not match its contents.
+///
+/// This error is returned by [`LengthDelimitedCodec`] when the
+/// [`Builder::crc32_checksum`] option is enabled and a decoded frame fails
+/// verification. It is carried as the custom value of an [`io::Error`] with
+/// kind [`InvalidData`], and can be recovered with
+/// [`io::Error::downcast`].
+///
+/// The corrupt frame is discarded; decoding can continue with the next frame.
+///
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+/// [`io::Error::downcast`]: std::io::Error::downcast
+#[derive(Debug)]
+pub struct ChecksumMismatchError {
+    expected: u32,
+    computed: u32,
+}
+
+impl ChecksumMismatchError {
+    /// Returns the checksum carried in the frame's trailer.
+    pub fn expected(&self) -> u32 {
+        self.expected
+    }
+
+    /// Returns the checksum computed over the frame's contents.
+    pub fn computed(&self) -> u32 {
+        self.computed
+    }
+}
+
+impl fmt::Display for ChecksumMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frame checksum mismatch: expected {:#010x}, computed {:#010x}",
+            self.expected, self.computed
+        )
+    }
+}
+
+impl StdError for ChecksumMismatchError {}
+
 /// A codec for frames delimited by a frame head specifying their lengths.
 ///
 /// This allows the consumer to work with entire frames without having to worry
@@ -462,6 +542,28 @@ enum DecodeState {
     Data(usize),
 }
 
+// A `u64` varint is at most 10 bytes long.
+const MAX_VARINT_LEN: usize = 10;
+
+// Number of bytes in the CRC32 checksum trailer.
+const CHECKSUM_LEN: usize = 4;
+
+/// Computes the CRC-32/ISO-HDLC checksum (the variant used by zlib, PNG and
+/// Ethernet) of `data`.
+fn crc32(data: &[u8]) -> u32 {
+    const POLY: u32 = 0xEDB8_8320;
+
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (POLY & mask);
+        }
+    }
+    !crc
+}
+
 // ===== impl LengthDelimitedCodec ======
 
 impl LengthDelimitedCodec {
@@ -497,7 +599,76 @@ impl LengthDelimitedCodec {
         self.builder.max_frame_length(val);
     }
 
+    fn decode_varint_head(&mut self, src: &mut BytesMut) -> io::Result<Option<usize>> {
+        let mut value = 0u64;
+        let mut head_len = None;
+
+        for idx in 0..cmp::min(src.len(), MAX_VARINT_LEN) {
+            let byte = src[idx];
+
+            // The tenth byte only has room for the top bit of a `u64`; a
+            // larger value or a continuation bit there cannot be a valid
+            // length.
+            if idx == MAX_VARINT_LEN - 1 && byte > 1 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "malformed length varint",
+                ));
+            }
+
+            value |= u64::from(byte & 0x7F) << (7 * idx);
+
+            if byte & 0x80 == 0 {
+                head_len = Some(idx + 1);
+                break;
+            }
+        }
+
+        let head_len = match head_len {
+            Some(head_len) => head_len,
+            // The varint continues past the buffered bytes; wait for more
+            // data.
+            None => return Ok(None),
+        };
+
+        if value > self.builder.max_frame_len as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                LengthDelimitedCodecError { _priv: () },
+            ));
+        }
+
+        // The check above ensures there is no overflow
+        let n = value as usize;
+
+        // Adjust `n` with bounds checking
+        let n = if self.builder.length_adjustment < 0 {
+            n.checked_sub(-self.builder.length_adjustment as usize)
+        } else {
+            n.checked_add(self.builder.length_adjustment as usize)
+        };
+
+        let n = n.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "provided length would overflow after adjustment",
+            )
+        })?;
+
+        src.advance(head_len);
+
+        // Ensure that the buffer has enough space to read the incoming
+        // payload
+        src.reserve(n.saturating_sub(src.len()));
+
+        Ok(Some(n))
+    }
+
     fn decode_head(&mut self, src: &mut BytesMut) -> io::Result<Option<usize>> {
+        if self.builder.length_field_is_varint {
+            return self.decode_varint_head(src);
+        }
+
         let head_len = self.builder.num_head_bytes();
         let field_len = self.builder.length_field_len;
 
@@ -566,6 +737,49 @@ impl LengthDelimitedCodec {
 
         Some(src.split_to(n))
     }
+
+    // Writes the CRC32 trailer for `data` to `dst`, using the same byte order
+    // as the length field.
+    fn put_checksum(&self, data: &[u8], dst: &mut BytesMut) {
+        let crc = crc32(data);
+
+        if self.builder.length_field_is_big_endian {
+            dst.put_u32(crc);
+        } else {
+            dst.put_u32_le(crc);
+        }
+    }
+
+    // Splits the CRC32 trailer off `data` and verifies it against the
+    // remaining frame contents.
+    fn verify_checksum(&self, mut data: BytesMut) -> io::Result<BytesMut> {
+        if data.len() < CHECKSUM_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "frame too short to carry a checksum trailer",
+            ));
+        }
+
+        let trailer = data.split_off(data.len() - CHECKSUM_LEN);
+        let trailer: [u8; CHECKSUM_LEN] = trailer[..].try_into().unwrap();
+
+        let expected = if self.builder.length_field_is_big_endian {
+            u32::from_be_bytes(trailer)
+        } else {
+            u32::from_le_bytes(trailer)
+        };
+
+        let computed = crc32(&data);
+
+        if computed != expected {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ChecksumMismatchError { expected, computed },
+            ));
+        }
+
+        Ok(data)
+    }
 }
 
 impl Decoder for LengthDelimitedCodec {
@@ -585,13 +799,17 @@ impl Decoder for LengthDelimitedCodec {
         };
 
         match self.decode_data(n, src) {
-            Some(data) => {
+            Some(mut data) => {
                 // Update the decode state
                 self.state = DecodeState::Head;
 
                 // Make sure the buffer has enough space to read the next head
                 src.reserve(self.builder.num_head_bytes().saturating_sub(src.len()));
 
+                if self.builder.crc32_checksum {
+                    data = self.verify_checksum(data)?;
+                }
+
                 Ok(Some(data))
             }
             None => Ok(None),
@@ -603,7 +821,15 @@ impl Encoder<Bytes> for LengthDelimitedCodec {
     type Error = io::Error;
 
     fn encode(&mut self, data: Bytes, dst: &mut BytesMut) -> Result<(), io::Error> {
-        let n = data.len();
+        let checksum_len = if self.builder.crc32_checksum {
+            CHECKSUM_LEN
+        } else {
+            0
+        };
+
+        // The checksum trailer is part of the frame, so it counts towards the
+        // length field and the max frame length.
+        let n = data.len() + checksum_len;
 
         if n > self.builder.max_frame_len {
             return Err(io::Error::new(
@@ -626,6 +852,33 @@ impl Encoder<Bytes> for LengthDelimitedCodec {
             )
         })?;
 
+        if self.builder.length_field_is_varint {
+            // Reserve capacity in the destination buffer to fit the frame
+            // and the worst-case varint header.
+            dst.reserve(MAX_VARINT_LEN + data.len() + checksum_len);
+
+            let mut value = n as u64;
+            loop {
+                let byte = (value & 0x7F) as u8;
+                value >>= 7;
+
+                if value == 0 {
+                    dst.put_u8(byte);
+                    break;
+                }
+
+                dst.put_u8(byte | 0x80);
+            }
+
+            dst.extend_from_slice(&data[..]);
+
+            if self.builder.crc32_checksum {
+                self.put_checksum(&data, dst);
+            }
+
+            return Ok(());
+        }
+
         // Reserve capacity in the destination buffer to fit the frame and
         // length field (plus adjustment).
         dst.reserve(self.builder.length_field_len + n);
@@ -639,6 +892,10 @@ impl Encoder<Bytes> for LengthDelimitedCodec {
         // Write the frame to the buffer
         dst.extend_from_slice(&data[..]);
 
+        if self.builder.crc32_checksum {
+            self.put_checksum(&data, dst);
+        }
+
         Ok(())
     }
 }
@@ -707,6 +964,12 @@ impl Builder {
 
             // Default to reading the length field in network (big) endian.
             length_field_is_big_endian: true,
+
+            // Default to a fixed-width length field.
+            length_field_is_varint: false,
+
+            // Default to no checksum trailer.
+            crc32_checksum: false,
         }
     }
 
@@ -785,6 +1048,75 @@ impl Builder {
         }
     }
 
+    /// Read and write the length field as a protobuf-style varint
+    ///
+    /// The frame head is a base 128 varint as used by protobuf and
+    /// gRPC-like stream framing: each header byte contributes seven bits of
+    /// the length, least significant group first, and the high bit marks a
+    /// continuation. The header is therefore between 1 and 10 bytes long.
+    /// A header that does not terminate within 10 bytes is rejected as
+    /// corrupt, and partially received headers simply wait for more data.
+    ///
+    /// In this mode, the header has no fixed width, so
+    /// [`Builder::length_field_length`], [`Builder::length_field_offset`],
+    /// [`Builder::num_skip`], and the endianness settings do not apply. The
+    /// [`Builder::max_frame_length`] and [`Builder::length_adjustment`]
+    /// settings are honored as usual: the decoded length is checked against
+    /// the maximum before any adjustment is applied.
+    ///
+    /// This configuration option applies to both encoding and decoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tokio::io::AsyncRead;
+    /// use tokio_util::codec::LengthDelimitedCodec;
+    ///
+    /// # fn bind_read<T: AsyncRead>(io: T) {
+    /// LengthDelimitedCodec::builder()
+    ///     .length_field_varint()
+    ///     .new_read(io);
+    /// # }
+    /// # pub fn main() {}
+    /// ```
+    pub fn length_field_varint(&mut self) -> &mut Self {
+        self.length_field_is_varint = true;
+        self
+    }
+
+    /// Append and verify a CRC32 checksum trailer on each frame
+    ///
+    /// When encoding, the CRC-32/ISO-HDLC checksum of the payload is appended
+    /// after it, using the same byte order as the length field. When decoding,
+    /// the trailer is split off the frame, the checksum is recomputed over the
+    /// remaining contents and compared to it, and the verified payload is
+    /// returned without the trailer. A mismatch produces an `io::Error` with
+    /// the custom value of the [`ChecksumMismatchError`] type; the corrupt
+    /// frame is discarded and decoding can continue with the next frame.
+    ///
+    /// The four trailer bytes are part of the frame: they are included in the
+    /// length field and count towards [`Builder::max_frame_length`].
+    ///
+    /// This configuration option applies to both encoding and decoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tokio::io::AsyncRead;
+    /// use tokio_util::codec::LengthDelimitedCodec;
+    ///
+    /// # fn bind_read<T: AsyncRead>(io: T) {
+    /// LengthDelimitedCodec::builder()
+    ///     .crc32_checksum()
+    ///     .new_read(io);
+    /// # }
+    /// # pub fn main() {}
+    /// ```
+    pub fn crc32_checksum(&mut self) -> &mut Self {
+        self.crc32_checksum = true;
+        self
+    }
+
     /// Sets the max frame length in bytes
     ///
     /// This configuration option applies to both encoding and decoding. The
@@ -1039,6 +1371,10 @@ impl Builder {
     }
 
     fn num_head_bytes(&self) -> usize {
+        if self.length_field_is_varint {
+            return MAX_VARINT_LEN;
+        }
+
         let num = self.length_field_offset + self.length_field_len;
         cmp::max(num, self.num_skip.unwrap_or(0))
     }
@@ -1049,6 +1385,11 @@ impl Builder {
     }
 
     fn adjust_max_frame_len(&mut self) {
+        // A varint header can represent any `u64`, so no clipping is needed.
+        if self.length_field_is_varint {
+            return;
+        }
+
         // Calculate the maximum number that can be represented using `length_field_len` bytes.
         let max_number = match 1u64.checked_shl((8 * self.length_field_len) as u32) {
             Some(shl) => shl - 1,
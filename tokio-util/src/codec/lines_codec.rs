@@ -182,6 +182,13 @@ impl Decoder for LinesCodec {
             }
         })
     }
+
+    fn recover(&mut self, _buf: &mut BytesMut) {
+        // `decode` resynchronizes itself: a line over the length limit is
+        // discarded up to the next newline on subsequent calls, and an
+        // invalid line has already been consumed, so no bytes need to be
+        // dropped here.
+    }
 }
 
 impl<T> Encoder<T> for LinesCodec
@@ -347,10 +347,33 @@ mod framed_write;
 pub use self::framed_write::FramedWrite;
 
 pub mod length_delimited;
-pub use self::length_delimited::{LengthDelimitedCodec, LengthDelimitedCodecError};
+pub use self::length_delimited::{
+    ChecksumMismatchError, LengthDelimitedCodec, LengthDelimitedCodecError,
+};
 
 mod lines_codec;
 pub use self::lines_codec::{LinesCodec, LinesCodecError};
 
+#[cfg(feature = "json-codec")]
+mod json_lines_codec;
+#[cfg(feature = "json-codec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json-codec")))]
+pub use self::json_lines_codec::{JsonLinesCodec, JsonLinesCodecError};
+
+#[cfg(feature = "serde-codec")]
+mod serde_codec;
+#[cfg(feature = "json-codec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json-codec")))]
+pub use self::serde_codec::Json;
+#[cfg(feature = "serde-codec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-codec")))]
+pub use self::serde_codec::{SerdeCodec, SerdeFormat};
+
 mod any_delimiter_codec;
 pub use self::any_delimiter_codec::{AnyDelimiterCodec, AnyDelimiterCodecError};
+
+mod cobs_codec;
+pub use self::cobs_codec::{CobsCodec, CobsCodecError};
+
+mod slip_codec;
+pub use self::slip_codec::{SlipCodec, SlipCodecError};
@@ -0,0 +1,128 @@
+use crate::codec::decoder::Decoder;
+use crate::codec::encoder::Encoder;
+use crate::codec::length_delimited::LengthDelimitedCodec;
+
+use bytes::{Bytes, BytesMut};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io;
+use std::marker::PhantomData;
+
+/// A serialization format usable with [`SerdeCodec`].
+///
+/// Implementations turn typed values into bytes and back. The codec takes
+/// care of framing, so a format only has to handle complete messages.
+/// Format errors are reported as [`io::Error`]s with the
+/// [`io::ErrorKind::InvalidData`] kind.
+///
+/// With the `json-codec` feature enabled, the [`Json`] format implements
+/// this trait using `serde_json`.
+pub trait SerdeFormat {
+    /// Serializes `item` into `dst`.
+    fn serialize<T: Serialize>(&mut self, item: &T, dst: &mut BytesMut) -> io::Result<()>;
+
+    /// Deserializes a value from the bytes of a complete frame.
+    fn deserialize<T: DeserializeOwned>(&mut self, src: &[u8]) -> io::Result<T>;
+}
+
+/// A [`Decoder`] and [`Encoder`] for typed values with a pluggable
+/// serialization format.
+///
+/// Each value is serialized with the configured [`SerdeFormat`] and framed
+/// with a [`LengthDelimitedCodec`], so any self-describing or binary format
+/// can be transported over a byte stream. For newline-delimited JSON, see
+/// [`JsonLinesCodec`] instead.
+///
+/// [`Decoder`]: crate::codec::Decoder
+/// [`Encoder`]: crate::codec::Encoder
+/// [`JsonLinesCodec`]: crate::codec::JsonLinesCodec
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "json-codec")] {
+/// use tokio_util::codec::{Json, SerdeCodec};
+///
+/// let codec: SerdeCodec<Vec<u32>, Json> = SerdeCodec::new(Json);
+/// # drop(codec);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SerdeCodec<T, F> {
+    inner: LengthDelimitedCodec,
+    format: F,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, F> SerdeCodec<T, F> {
+    /// Creates a new `SerdeCodec` with the given format and default
+    /// length-delimited framing.
+    pub fn new(format: F) -> Self {
+        Self::with_framing(format, LengthDelimitedCodec::new())
+    }
+
+    /// Creates a new `SerdeCodec` with the given format and a configured
+    /// [`LengthDelimitedCodec`] for framing.
+    ///
+    /// This allows adjusting the frame header or the maximum frame length
+    /// via [`LengthDelimitedCodec::builder`].
+    pub fn with_framing(format: F, framing: LengthDelimitedCodec) -> Self {
+        Self {
+            inner: framing,
+            format,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Decoder for SerdeCodec<T, F>
+where
+    T: DeserializeOwned,
+    F: SerdeFormat,
+{
+    type Item = T;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<T>> {
+        let frame = match self.inner.decode(src)? {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+
+        self.format.deserialize(&frame).map(Some)
+    }
+}
+
+impl<T, F> Encoder<T> for SerdeCodec<T, F>
+where
+    T: Serialize,
+    F: SerdeFormat,
+{
+    type Error = io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> io::Result<()> {
+        let mut buf = BytesMut::new();
+        self.format.serialize(&item, &mut buf)?;
+        self.inner.encode(Bytes::from(buf), dst)
+    }
+}
+
+/// A [`SerdeFormat`] backed by `serde_json`.
+#[cfg(feature = "json-codec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json-codec")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json;
+
+#[cfg(feature = "json-codec")]
+impl SerdeFormat for Json {
+    fn serialize<T: Serialize>(&mut self, item: &T, dst: &mut BytesMut) -> io::Result<()> {
+        let json =
+            serde_json::to_vec(item).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        dst.extend_from_slice(&json);
+        Ok(())
+    }
+
+    fn deserialize<T: DeserializeOwned>(&mut self, src: &[u8]) -> io::Result<T> {
+        serde_json::from_slice(src).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
@@ -0,0 +1,239 @@
+use crate::codec::decoder::Decoder;
+use crate::codec::encoder::Encoder;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::{cmp, fmt, io};
+
+const END: u8 = 0xC0;
+const ESC: u8 = 0xDB;
+const ESC_END: u8 = 0xDC;
+const ESC_ESC: u8 = 0xDD;
+
+/// A [`Decoder`] and [`Encoder`] for SLIP (RFC 1055) framing.
+///
+/// SLIP delimits frames with the `END` byte (`0xC0`). Occurrences of `END`
+/// and `ESC` (`0xDB`) within a frame are escaped as `ESC ESC_END` and
+/// `ESC ESC_ESC` respectively, so the delimiter never appears inside frame
+/// data. Empty frames, such as the extra `END` some senders emit before a
+/// frame to flush line noise, are skipped.
+///
+/// A malformed escape sequence produces a
+/// [`SlipCodecError::MalformedFrame`] error. The offending frame has
+/// already been consumed up to its delimiter, so decoding resynchronizes on
+/// the next frame automatically.
+///
+/// [`Decoder`]: crate::codec::Decoder
+/// [`Encoder`]: crate::codec::Encoder
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SlipCodec {
+    // Stored index of the next index to examine for an `END` byte. This is
+    // used to optimize searching, like in `LinesCodec`.
+    next_index: usize,
+
+    /// The maximum length for a given frame on the wire. If `usize::MAX`,
+    /// frames will be read until an `END` byte is reached.
+    max_length: usize,
+
+    /// Are we currently discarding the remainder of a frame which was over
+    /// the length limit?
+    is_discarding: bool,
+}
+
+impl SlipCodec {
+    /// Returns a `SlipCodec` for splitting up data into SLIP frames.
+    ///
+    /// # Note
+    ///
+    /// The returned `SlipCodec` will not have an upper bound on the length
+    /// of a buffered frame. See the documentation for
+    /// [`new_with_max_length`] for information on why this could be a
+    /// potential security risk.
+    ///
+    /// [`new_with_max_length`]: crate::codec::SlipCodec::new_with_max_length()
+    pub fn new() -> SlipCodec {
+        SlipCodec {
+            next_index: 0,
+            max_length: usize::MAX,
+            is_discarding: false,
+        }
+    }
+
+    /// Returns a `SlipCodec` with a maximum frame length limit.
+    ///
+    /// If this is set, calls to `SlipCodec::decode` will return a
+    /// [`SlipCodecError::MaxFrameLengthExceeded`] error when the escaped
+    /// frame exceeds the length limit. Subsequent calls will discard up to
+    /// `limit` bytes from that frame until an `END` byte is reached,
+    /// returning `None` until the frame over the limit has been fully
+    /// discarded. After that point, calls to `decode` will function as
+    /// normal.
+    ///
+    /// # Note
+    ///
+    /// Setting a length limit is highly recommended for any `SlipCodec`
+    /// which will be exposed to untrusted input. Otherwise, the size of the
+    /// buffer that holds the frame currently being read is unbounded. An
+    /// attacker could exploit this unbounded buffer by sending an unbounded
+    /// amount of input without any `END` bytes, causing unbounded memory
+    /// consumption.
+    pub fn new_with_max_length(max_length: usize) -> Self {
+        SlipCodec {
+            max_length,
+            ..SlipCodec::new()
+        }
+    }
+
+    /// Returns the maximum frame length when decoding.
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+}
+
+fn unescape(frame: &[u8]) -> Result<BytesMut, SlipCodecError> {
+    let mut dst = BytesMut::with_capacity(frame.len());
+    let mut iter = frame.iter();
+
+    while let Some(&byte) = iter.next() {
+        match byte {
+            ESC => match iter.next() {
+                Some(&ESC_END) => dst.put_u8(END),
+                Some(&ESC_ESC) => dst.put_u8(ESC),
+                _ => return Err(SlipCodecError::MalformedFrame),
+            },
+            byte => dst.put_u8(byte),
+        }
+    }
+
+    Ok(dst)
+}
+
+impl Decoder for SlipCodec {
+    type Item = BytesMut;
+    type Error = SlipCodecError;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<BytesMut>, SlipCodecError> {
+        loop {
+            // Determine how far into the buffer we'll search for an `END`
+            // byte. If there's no max_length set, we'll read to the end of
+            // the buffer.
+            let read_to = cmp::min(self.max_length.saturating_add(1), buf.len());
+
+            let end_offset = buf[self.next_index..read_to].iter().position(|b| *b == END);
+
+            match (self.is_discarding, end_offset) {
+                (true, Some(offset)) => {
+                    // If we found an `END` byte, discard up to that offset
+                    // and then stop discarding. On the next iteration,
+                    // we'll try to read a frame normally.
+                    buf.advance(offset + self.next_index + 1);
+                    self.is_discarding = false;
+                    self.next_index = 0;
+                }
+                (true, None) => {
+                    // Otherwise, we didn't find an `END` byte, so we'll
+                    // discard everything we read. On the next iteration,
+                    // we'll continue discarding up to max_len bytes unless
+                    // we find an `END` byte.
+                    buf.advance(read_to);
+                    self.next_index = 0;
+                    if buf.is_empty() {
+                        return Ok(None);
+                    }
+                }
+                (false, Some(offset)) => {
+                    // Found a frame!
+                    let end_index = offset + self.next_index;
+                    self.next_index = 0;
+                    let frame = buf.split_to(end_index + 1);
+
+                    // Skip empty frames produced by back-to-back `END`
+                    // bytes.
+                    if frame.len() > 1 {
+                        return unescape(&frame[..frame.len() - 1]).map(Some);
+                    }
+                }
+                (false, None) if buf.len() > self.max_length => {
+                    // Reached the maximum length without finding an `END`
+                    // byte, return an error and start discarding on the
+                    // next call.
+                    self.is_discarding = true;
+                    return Err(SlipCodecError::MaxFrameLengthExceeded);
+                }
+                (false, None) => {
+                    // We didn't find a frame or reach the length limit, so
+                    // the next call will resume searching at the current
+                    // offset.
+                    self.next_index = read_to;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    fn recover(&mut self, _buf: &mut BytesMut) {
+        // `decode` resynchronizes itself: a malformed frame has already
+        // been consumed up to its delimiter and an oversized frame is
+        // discarded on subsequent calls, so no bytes need to be dropped
+        // here.
+    }
+}
+
+impl Encoder<Bytes> for SlipCodec {
+    type Error = SlipCodecError;
+
+    fn encode(&mut self, data: Bytes, buf: &mut BytesMut) -> Result<(), SlipCodecError> {
+        buf.reserve(data.len() + 1);
+
+        for &byte in &data[..] {
+            match byte {
+                END => {
+                    buf.put_u8(ESC);
+                    buf.put_u8(ESC_END);
+                }
+                ESC => {
+                    buf.put_u8(ESC);
+                    buf.put_u8(ESC_ESC);
+                }
+                byte => buf.put_u8(byte),
+            }
+        }
+
+        buf.put_u8(END);
+        Ok(())
+    }
+}
+
+impl Default for SlipCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An error occurred while encoding or decoding a SLIP frame.
+#[derive(Debug)]
+pub enum SlipCodecError {
+    /// The maximum frame length was exceeded.
+    MaxFrameLengthExceeded,
+    /// A frame contained an invalid escape sequence.
+    MalformedFrame,
+    /// An IO error occurred.
+    Io(io::Error),
+}
+
+impl fmt::Display for SlipCodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlipCodecError::MaxFrameLengthExceeded => write!(f, "max frame length exceeded"),
+            SlipCodecError::MalformedFrame => write!(f, "malformed SLIP frame"),
+            SlipCodecError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for SlipCodecError {
+    fn from(e: io::Error) -> SlipCodecError {
+        SlipCodecError::Io(e)
+    }
+}
+
+impl std::error::Error for SlipCodecError {}
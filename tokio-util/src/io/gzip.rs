@@ -0,0 +1,287 @@
+use bytes::buf::Writer;
+use bytes::{Buf, BufMut, BytesMut};
+use flate2::write::{GzDecoder, GzEncoder};
+use pin_project_lite::pin_project;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+// How much compressed output we let accumulate before forcing it out to
+// the underlying transport, and how much compressed input we request per
+// read of the underlying transport.
+const BACKPRESSURE_BOUNDARY: usize = 8 * 1024;
+
+pin_project! {
+    /// An adapter that decompresses a gzip stream as it is read.
+    ///
+    /// `GzipReader` wraps an [`AsyncRead`] producing gzip-compressed data
+    /// and implements [`AsyncRead`] yielding the decompressed bytes. The
+    /// wrapped stream must contain a single gzip member; the CRC and size
+    /// trailer are verified when the wrapped stream reaches EOF, and a
+    /// truncated or corrupt stream produces an error of kind
+    /// [`io::ErrorKind::InvalidData`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tokio::io::AsyncReadExt;
+    /// use tokio_util::io::GzipReader;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> std::io::Result<()> {
+    /// // A gzip stream containing the bytes `hello world`.
+    /// let compressed: &[u8] = &[
+    ///     0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xcb,
+    ///     0x48, 0xcd, 0xc9, 0xc9, 0x57, 0x28, 0xcf, 0x2f, 0xca, 0x49, 0x01,
+    ///     0x00, 0x85, 0x11, 0x4a, 0x0d, 0x0b, 0x00, 0x00, 0x00,
+    /// ];
+    ///
+    /// let mut reader = GzipReader::new(compressed);
+    /// let mut decompressed = String::new();
+    /// reader.read_to_string(&mut decompressed).await?;
+    ///
+    /// assert_eq!(decompressed, "hello world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub struct GzipReader<R> {
+        #[pin]
+        reader: R,
+        decoder: GzDecoder<Writer<BytesMut>>,
+        input: BytesMut,
+        read_eof: bool,
+        finished: bool,
+    }
+}
+
+impl<R: AsyncRead> GzipReader<R> {
+    /// Creates a new `GzipReader`, wrapping `reader`.
+    pub fn new(reader: R) -> GzipReader<R> {
+        GzipReader {
+            reader,
+            decoder: GzDecoder::new(BytesMut::new().writer()),
+            input: BytesMut::new(),
+            read_eof: false,
+            finished: false,
+        }
+    }
+}
+
+impl<R> GzipReader<R> {
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the wrapped reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Consumes the `GzipReader`, returning the wrapped reader.
+    ///
+    /// Any compressed data buffered but not yet decompressed is discarded.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for GzipReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+
+        loop {
+            // Serve decompressed bytes before reading from the transport
+            // again.
+            let decompressed = me.decoder.get_mut().get_mut();
+            if !decompressed.is_empty() {
+                let len = decompressed.len().min(buf.remaining());
+                buf.put_slice(&decompressed[..len]);
+                decompressed.advance(len);
+                return Poll::Ready(Ok(()));
+            }
+
+            if *me.finished {
+                return Poll::Ready(Ok(()));
+            }
+
+            if *me.read_eof {
+                // The transport is exhausted; finish the decoder, which
+                // verifies the gzip trailer and flushes any remaining
+                // output.
+                me.decoder.try_finish()?;
+                *me.finished = true;
+                continue;
+            }
+
+            me.input.reserve(BACKPRESSURE_BOUNDARY);
+            let n = ready!(crate::util::poll_read_buf(me.reader.as_mut(), cx, me.input))?;
+            if n == 0 {
+                *me.read_eof = true;
+            } else {
+                me.decoder.write_all(me.input)?;
+                me.input.clear();
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// An adapter that gzip-compresses data as it is written.
+    ///
+    /// `GzipWriter` wraps an [`AsyncWrite`] and implements [`AsyncWrite`],
+    /// compressing everything written to it into a single gzip stream.
+    ///
+    /// Calling [`flush`] performs a sync flush of the compressor before
+    /// flushing the wrapped writer, so everything written so far becomes
+    /// decodable by the remote peer without ending the stream. Calling
+    /// [`shutdown`] finishes the gzip stream, writing the trailer, and then
+    /// shuts down the wrapped writer; dropping the `GzipWriter` without
+    /// shutting it down produces a truncated stream.
+    ///
+    /// [`flush`]: tokio::io::AsyncWriteExt::flush
+    /// [`shutdown`]: tokio::io::AsyncWriteExt::shutdown
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tokio::io::AsyncWriteExt;
+    /// use tokio_util::io::{GzipReader, GzipWriter};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> std::io::Result<()> {
+    /// let mut writer = GzipWriter::new(Vec::new());
+    /// writer.write_all(b"hello world").await?;
+    /// writer.shutdown().await?;
+    ///
+    /// let compressed = writer.into_inner();
+    /// let mut reader = GzipReader::new(&compressed[..]);
+    /// let mut decompressed = String::new();
+    /// tokio::io::AsyncReadExt::read_to_string(&mut reader, &mut decompressed).await?;
+    ///
+    /// assert_eq!(decompressed, "hello world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub struct GzipWriter<W> {
+        #[pin]
+        writer: W,
+        encoder: GzEncoder<Writer<BytesMut>>,
+    }
+}
+
+impl<W: AsyncWrite> GzipWriter<W> {
+    /// Creates a new `GzipWriter`, wrapping `writer` and compressing at the
+    /// default compression level.
+    pub fn new(writer: W) -> GzipWriter<W> {
+        GzipWriter {
+            writer,
+            encoder: GzEncoder::new(BytesMut::new().writer(), flate2::Compression::default()),
+        }
+    }
+
+    /// Creates a new `GzipWriter`, wrapping `writer` and compressing at the
+    /// given compression level.
+    ///
+    /// Valid levels are `0-9`, where `0` stores the data uncompressed and
+    /// `9` compresses hardest.
+    pub fn with_level(writer: W, level: u32) -> GzipWriter<W> {
+        GzipWriter {
+            writer,
+            encoder: GzEncoder::new(BytesMut::new().writer(), flate2::Compression::new(level)),
+        }
+    }
+}
+
+impl<W> GzipWriter<W> {
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the wrapped writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the `GzipWriter`, returning the wrapped writer.
+    ///
+    /// The gzip stream is not finished; call [`shutdown`] first to obtain a
+    /// complete stream.
+    ///
+    /// [`shutdown`]: tokio::io::AsyncWriteExt::shutdown
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Writes buffered compressed bytes out to `writer` until the buffer is
+/// empty.
+fn poll_drain<W: AsyncWrite>(
+    mut writer: Pin<&mut W>,
+    cx: &mut Context<'_>,
+    buffer: &mut BytesMut,
+) -> Poll<io::Result<()>> {
+    while !buffer.is_empty() {
+        let n = ready!(writer.as_mut().poll_write(cx, buffer))?;
+        if n == 0 {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "failed to write compressed data to transport",
+            )));
+        }
+        buffer.advance(n);
+    }
+    Poll::Ready(Ok(()))
+}
+
+impl<W: AsyncWrite> AsyncWrite for GzipWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let me = self.project();
+
+        // Apply backpressure by draining the compressed output to the
+        // transport once it grows past the boundary.
+        if me.encoder.get_ref().get_ref().len() >= BACKPRESSURE_BOUNDARY {
+            ready!(poll_drain(me.writer, cx, me.encoder.get_mut().get_mut()))?;
+        }
+
+        Poll::Ready(me.encoder.write(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+
+        // A sync flush makes everything written so far decodable without
+        // ending the stream.
+        me.encoder.flush()?;
+        ready!(poll_drain(
+            me.writer.as_mut(),
+            cx,
+            me.encoder.get_mut().get_mut()
+        ))?;
+        me.writer.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+
+        // Finishing is idempotent, so resuming after the transport returned
+        // `Pending` below is fine.
+        me.encoder.try_finish()?;
+        ready!(poll_drain(
+            me.writer.as_mut(),
+            cx,
+            me.encoder.get_mut().get_mut()
+        ))?;
+        me.writer.poll_shutdown(cx)
+    }
+}
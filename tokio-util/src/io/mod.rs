@@ -11,11 +11,20 @@
 //! [`AsyncRead`]: tokio::io::AsyncRead
 
 mod copy_to_bytes;
+#[cfg(feature = "gzip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gzip")))]
+mod gzip;
 mod inspect;
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "io", feature = "time"))))]
+mod rate_limited;
 mod read_buf;
 mod reader_stream;
 mod sink_writer;
 mod stream_reader;
+#[cfg(feature = "zstd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zstd")))]
+mod zstd;
 
 cfg_io_util! {
     mod read_arc;
@@ -26,9 +35,18 @@ cfg_io_util! {
 }
 
 pub use self::copy_to_bytes::CopyToBytes;
+#[cfg(feature = "gzip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gzip")))]
+pub use self::gzip::{GzipReader, GzipWriter};
 pub use self::inspect::{InspectReader, InspectWriter};
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "io", feature = "time"))))]
+pub use self::rate_limited::{RateLimitedReader, RateLimitedWriter};
 pub use self::read_buf::read_buf;
 pub use self::reader_stream::ReaderStream;
 pub use self::sink_writer::SinkWriter;
-pub use self::stream_reader::StreamReader;
+pub use self::stream_reader::{PreserveErrors, StreamReader};
+#[cfg(feature = "zstd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zstd")))]
+pub use self::zstd::{ZstdReader, ZstdWriter};
 pub use crate::util::{poll_read_buf, poll_write_buf};
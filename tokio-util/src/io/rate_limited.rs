@@ -0,0 +1,284 @@
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::RateLimiter;
+
+type Acquire = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+
+// The most bytes transferred per call to the wrapped resource. Splitting
+// large reads and writes into chunks spreads them over the quota instead
+// of alternating between a large burst and a long stall.
+const MAX_CHUNK: usize = 4096;
+
+pin_project! {
+    /// An adapter that limits the rate at which data is read.
+    ///
+    /// `RateLimitedReader` wraps an [`AsyncRead`] and caps the number of
+    /// bytes per second read from it, with one [`RateLimiter`] permit
+    /// accounting for one byte. Reads are billed after they complete, so a
+    /// read never returns less data than the wrapped reader produced;
+    /// instead the next read waits until the quota has recovered.
+    ///
+    /// The limiter can be shared: wrapping several connections around one
+    /// [`RateLimiter`] with [`with_limiter`] caps their combined rate.
+    ///
+    /// [`with_limiter`]: RateLimitedReader::with_limiter
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio::net::TcpStream;
+    /// use tokio_util::io::RateLimitedReader;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let stream = TcpStream::connect("127.0.0.1:8080").await?;
+    ///
+    /// // Download at no more than 64 KiB/s.
+    /// let reader = RateLimitedReader::new(stream, 64 * 1024);
+    /// # let _ = reader;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub struct RateLimitedReader<R> {
+        #[pin]
+        reader: R,
+        limiter: Arc<RateLimiter>,
+        // Pays for the bytes transferred by the previous read.
+        acquire: Option<Acquire>,
+    }
+}
+
+impl<R: AsyncRead> RateLimitedReader<R> {
+    /// Creates a new `RateLimitedReader` reading at most `bytes_per_sec`
+    /// bytes per second, with a burst capacity of `bytes_per_sec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_sec` is zero.
+    #[track_caller]
+    pub fn new(reader: R, bytes_per_sec: u64) -> RateLimitedReader<R> {
+        let limiter = RateLimiter::new(bytes_per_sec, Duration::from_secs(1));
+        RateLimitedReader::with_limiter(reader, Arc::new(limiter))
+    }
+
+    /// Creates a new `RateLimitedReader` reading at most `bytes_per_sec`
+    /// bytes per second and allowing bursts of up to `burst` bytes after an
+    /// idle period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_sec` or `burst` is zero.
+    #[track_caller]
+    pub fn with_burst(reader: R, bytes_per_sec: u64, burst: u64) -> RateLimitedReader<R> {
+        let limiter = RateLimiter::with_burst(bytes_per_sec, Duration::from_secs(1), burst);
+        RateLimitedReader::with_limiter(reader, Arc::new(limiter))
+    }
+
+    /// Creates a new `RateLimitedReader` drawing one permit per byte from
+    /// the given limiter.
+    pub fn with_limiter(reader: R, limiter: Arc<RateLimiter>) -> RateLimitedReader<R> {
+        RateLimitedReader {
+            reader,
+            limiter,
+            acquire: None,
+        }
+    }
+}
+
+impl<R> RateLimitedReader<R> {
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the wrapped reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the limiter this reader draws from.
+    pub fn limiter(&self) -> &Arc<RateLimiter> {
+        &self.limiter
+    }
+
+    /// Consumes the `RateLimitedReader`, returning the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for RateLimitedReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let me = self.project();
+
+        // Pay for the previous read before starting another one.
+        if let Some(acquire) = me.acquire.as_mut() {
+            ready!(acquire.as_mut().poll(cx));
+            *me.acquire = None;
+        }
+
+        let mut chunk = buf.take(MAX_CHUNK);
+        ready!(me.reader.poll_read(cx, &mut chunk))?;
+        let n = chunk.filled().len();
+
+        // The wrapped reader initialized and filled `n` bytes of the chunk,
+        // which borrowed the start of `buf`'s unfilled section.
+        unsafe {
+            buf.assume_init(n);
+        }
+        buf.advance(n);
+
+        if n > 0 {
+            *me.acquire = Some(Box::pin(me.limiter.clone().acquire_many_owned(n as u64)));
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<R> fmt::Debug for RateLimitedReader<R>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitedReader")
+            .field("reader", &self.reader)
+            .field("limiter", &self.limiter)
+            .finish()
+    }
+}
+
+pin_project! {
+    /// An adapter that limits the rate at which data is written.
+    ///
+    /// `RateLimitedWriter` wraps an [`AsyncWrite`] and caps the number of
+    /// bytes per second written to it, with one [`RateLimiter`] permit
+    /// accounting for one byte. Writes are billed after they complete, so
+    /// the wrapped writer accepts data at its own pace; the next write
+    /// waits until the quota has recovered. Flushing and shutting down are
+    /// not throttled.
+    ///
+    /// The limiter can be shared: wrapping several connections around one
+    /// [`RateLimiter`] with [`with_limiter`] caps their combined rate.
+    ///
+    /// [`with_limiter`]: RateLimitedWriter::with_limiter
+    pub struct RateLimitedWriter<W> {
+        #[pin]
+        writer: W,
+        limiter: Arc<RateLimiter>,
+        // Pays for the bytes transferred by the previous write.
+        acquire: Option<Acquire>,
+    }
+}
+
+impl<W: AsyncWrite> RateLimitedWriter<W> {
+    /// Creates a new `RateLimitedWriter` writing at most `bytes_per_sec`
+    /// bytes per second, with a burst capacity of `bytes_per_sec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_sec` is zero.
+    #[track_caller]
+    pub fn new(writer: W, bytes_per_sec: u64) -> RateLimitedWriter<W> {
+        let limiter = RateLimiter::new(bytes_per_sec, Duration::from_secs(1));
+        RateLimitedWriter::with_limiter(writer, Arc::new(limiter))
+    }
+
+    /// Creates a new `RateLimitedWriter` writing at most `bytes_per_sec`
+    /// bytes per second and allowing bursts of up to `burst` bytes after an
+    /// idle period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_sec` or `burst` is zero.
+    #[track_caller]
+    pub fn with_burst(writer: W, bytes_per_sec: u64, burst: u64) -> RateLimitedWriter<W> {
+        let limiter = RateLimiter::with_burst(bytes_per_sec, Duration::from_secs(1), burst);
+        RateLimitedWriter::with_limiter(writer, Arc::new(limiter))
+    }
+
+    /// Creates a new `RateLimitedWriter` drawing one permit per byte from
+    /// the given limiter.
+    pub fn with_limiter(writer: W, limiter: Arc<RateLimiter>) -> RateLimitedWriter<W> {
+        RateLimitedWriter {
+            writer,
+            limiter,
+            acquire: None,
+        }
+    }
+}
+
+impl<W> RateLimitedWriter<W> {
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the wrapped writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Returns the limiter this writer draws from.
+    pub fn limiter(&self) -> &Arc<RateLimiter> {
+        &self.limiter
+    }
+
+    /// Consumes the `RateLimitedWriter`, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: AsyncWrite> AsyncWrite for RateLimitedWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let me = self.project();
+
+        // Pay for the previous write before starting another one.
+        if let Some(acquire) = me.acquire.as_mut() {
+            ready!(acquire.as_mut().poll(cx));
+            *me.acquire = None;
+        }
+
+        let chunk = buf.len().min(MAX_CHUNK);
+        let n = ready!(me.writer.poll_write(cx, &buf[..chunk]))?;
+
+        if n > 0 {
+            *me.acquire = Some(Box::pin(me.limiter.clone().acquire_many_owned(n as u64)));
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().writer.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().writer.poll_shutdown(cx)
+    }
+}
+
+impl<W> fmt::Debug for RateLimitedWriter<W>
+where
+    W: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitedWriter")
+            .field("writer", &self.writer)
+            .field("limiter", &self.limiter)
+            .finish()
+    }
+}
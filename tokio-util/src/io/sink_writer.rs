@@ -64,13 +64,40 @@ pin_project! {
     pub struct SinkWriter<S> {
         #[pin]
         inner: S,
+        // Bytes waiting to be coalesced into one item; unused when
+        // `threshold` is zero.
+        buffer: Vec<u8>,
+        threshold: usize,
     }
 }
 
 impl<S> SinkWriter<S> {
     /// Creates a new [`SinkWriter`].
     pub fn new(sink: S) -> Self {
-        Self { inner: sink }
+        Self {
+            inner: sink,
+            buffer: Vec::new(),
+            threshold: 0,
+        }
+    }
+
+    /// Creates a new [`SinkWriter`] that coalesces small writes.
+    ///
+    /// Written bytes accumulate in an internal buffer and are sent to the
+    /// sink as a single item once at least `threshold` bytes are buffered,
+    /// instead of one item per `write` call. Buffered bytes are also sent
+    /// when the writer is flushed or shut down, so no data is held back
+    /// longer than the caller requests.
+    ///
+    /// This is useful when each item has a fixed cost, for example a channel
+    /// send or an HTTP body frame, and the data arrives in many small
+    /// writes.
+    pub fn with_flush_threshold(sink: S, threshold: usize) -> Self {
+        Self {
+            inner: sink,
+            buffer: Vec::with_capacity(threshold),
+            threshold,
+        }
     }
 
     /// Gets a reference to the underlying sink.
@@ -84,6 +111,11 @@ impl<S> SinkWriter<S> {
     }
 
     /// Consumes this [`SinkWriter`], returning the underlying sink.
+    ///
+    /// On a writer created with [`with_flush_threshold`], any bytes still
+    /// in the internal buffer are lost; flush first to preserve them.
+    ///
+    /// [`with_flush_threshold`]: SinkWriter::with_flush_threshold
     pub fn into_inner(self) -> S {
         self.inner
     }
@@ -100,19 +132,59 @@ where
     ) -> Poll<Result<usize, io::Error>> {
         let mut this = self.project();
 
-        ready!(this.inner.as_mut().poll_ready(cx).map_err(Into::into))?;
-        match this.inner.as_mut().start_send(buf) {
-            Ok(()) => Poll::Ready(Ok(buf.len())),
-            Err(e) => Poll::Ready(Err(e.into())),
+        if *this.threshold == 0 {
+            ready!(this.inner.as_mut().poll_ready(cx).map_err(Into::into))?;
+            return match this.inner.as_mut().start_send(buf) {
+                Ok(()) => Poll::Ready(Ok(buf.len())),
+                Err(e) => Poll::Ready(Err(e.into())),
+            };
         }
+
+        if this.buffer.len() + buf.len() >= *this.threshold {
+            ready!(this.inner.as_mut().poll_ready(cx).map_err(Into::into))?;
+            if this.buffer.is_empty() {
+                // A large write with nothing buffered is sent as-is.
+                this.inner.as_mut().start_send(buf).map_err(Into::into)?;
+            } else {
+                this.buffer.extend_from_slice(buf);
+                this.inner
+                    .as_mut()
+                    .start_send(this.buffer)
+                    .map_err(Into::into)?;
+                this.buffer.clear();
+            }
+        } else {
+            this.buffer.extend_from_slice(buf);
+        }
+        Poll::Ready(Ok(buf.len()))
     }
 
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
-        self.project().inner.poll_flush(cx).map_err(Into::into)
+        let mut this = self.project();
+
+        if !this.buffer.is_empty() {
+            ready!(this.inner.as_mut().poll_ready(cx).map_err(Into::into))?;
+            this.inner
+                .as_mut()
+                .start_send(this.buffer)
+                .map_err(Into::into)?;
+            this.buffer.clear();
+        }
+        this.inner.poll_flush(cx).map_err(Into::into)
     }
 
     fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
-        self.project().inner.poll_close(cx).map_err(Into::into)
+        let mut this = self.project();
+
+        if !this.buffer.is_empty() {
+            ready!(this.inner.as_mut().poll_ready(cx).map_err(Into::into))?;
+            this.inner
+                .as_mut()
+                .start_send(this.buffer)
+                .map_err(Into::into)?;
+            this.buffer.clear();
+        }
+        this.inner.poll_close(cx).map_err(Into::into)
     }
 }
 
@@ -1,6 +1,7 @@
 use bytes::Buf;
 use futures_core::stream::Stream;
 use futures_sink::Sink;
+use pin_project_lite::pin_project;
 use std::io;
 use std::pin::Pin;
 use std::task::{Context, Poll};
@@ -203,6 +204,65 @@ where
     }
 }
 
+impl<S, B, E> StreamReader<PreserveErrors<S>, B>
+where
+    S: Stream<Item = Result<B, E>>,
+    B: Buf,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    /// Convert a stream of byte chunks whose error type is not an
+    /// [`io::Error`] into an [`AsyncRead`], preserving the original error
+    /// value.
+    ///
+    /// Each error produced by the stream is wrapped in an [`io::Error`] of
+    /// kind [`Other`] instead of being flattened to a message, so the
+    /// original value can still be inspected with [`io::Error::get_ref`] or
+    /// recovered by downcasting.
+    ///
+    /// [`Other`]: std::io::ErrorKind::Other
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use tokio::io::AsyncReadExt;
+    /// use tokio_util::io::StreamReader;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> std::io::Result<()> {
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ProtocolError(u32);
+    ///
+    /// impl std::fmt::Display for ProtocolError {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         write!(f, "protocol error {}", self.0)
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for ProtocolError {}
+    ///
+    /// let stream = tokio_stream::iter(vec![
+    ///     Ok(Bytes::from_static(&[0, 1, 2, 3])),
+    ///     Err(ProtocolError(42)),
+    /// ]);
+    ///
+    /// let mut read = StreamReader::new_preserving_errors(stream);
+    ///
+    /// let mut buf = [0; 4];
+    /// read.read_exact(&mut buf).await?;
+    ///
+    /// // The error can be downcast back to the stream's error type.
+    /// let error = read.read(&mut buf).await.unwrap_err();
+    /// let original = error.get_ref().unwrap().downcast_ref::<ProtocolError>();
+    /// assert_eq!(original, Some(&ProtocolError(42)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_preserving_errors(stream: S) -> Self {
+        StreamReader::new(PreserveErrors { inner: stream })
+    }
+}
+
 impl<S, B> StreamReader<S, B> {
     /// Gets a reference to the underlying stream.
     ///
@@ -252,16 +312,29 @@ where
             return Poll::Ready(Ok(()));
         }
 
-        let inner_buf = match self.as_mut().poll_fill_buf(cx) {
-            Poll::Ready(Ok(buf)) => buf,
+        match self.as_mut().poll_fill_buf(cx) {
+            Poll::Ready(Ok([])) => return Poll::Ready(Ok(())),
+            Poll::Ready(Ok(_)) => {}
             Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
             Poll::Pending => return Poll::Pending,
-        };
-        let len = std::cmp::min(inner_buf.len(), buf.remaining());
-        buf.put_slice(&inner_buf[..len]);
+        }
+
+        // The current chunk may be segmented; copy from all of its segments
+        // instead of returning one segment per read. The stream itself is
+        // not polled again, so no error can be lost after a partial copy.
+        loop {
+            let len = {
+                let segment = self.as_mut().project().chunk.as_ref().unwrap().chunk();
+                let len = std::cmp::min(segment.len(), buf.remaining());
+                buf.put_slice(&segment[..len]);
+                len
+            };
+            self.as_mut().consume(len);
 
-        self.consume(len);
-        Poll::Ready(Ok(()))
+            if buf.remaining() == 0 || !self.has_chunk() {
+                return Poll::Ready(Ok(()));
+            }
+        }
     }
 }
 
@@ -326,6 +399,46 @@ impl<S, B> StreamReader<S, B> {
     }
 }
 
+pin_project! {
+    /// Stream wrapped by [`StreamReader::new_preserving_errors`].
+    ///
+    /// Wraps each error produced by the inner stream in an [`io::Error`] of
+    /// kind [`Other`] without losing the original value, which stays
+    /// accessible through [`io::Error::get_ref`] or by downcasting.
+    ///
+    /// [`Other`]: std::io::ErrorKind::Other
+    #[derive(Debug)]
+    pub struct PreserveErrors<S> {
+        #[pin]
+        inner: S,
+    }
+}
+
+impl<S> PreserveErrors<S> {
+    /// Consumes this `PreserveErrors`, returning the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, B, E> Stream for PreserveErrors<S>
+where
+    S: Stream<Item = Result<B, E>>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    type Item = Result<B, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx).map(|item| {
+            item.map(|result| result.map_err(|err| io::Error::new(io::ErrorKind::Other, err)))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
 impl<S: Sink<T, Error = E>, B, E, T> Sink<T> for StreamReader<S, B> {
     type Error = E;
     fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
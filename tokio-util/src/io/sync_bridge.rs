@@ -207,6 +207,50 @@ use tokio::io::{
 /// }
 /// ```
 ///
+/// ## Combined Usage and Buffering
+///
+/// All trait implementations are available on the same bridge, so a resource
+/// that is `AsyncRead + AsyncWrite + AsyncSeek` yields a bridge that is
+/// `Read + Write + Seek` at once, as archive and compression libraries often
+/// require.
+///
+/// Every call on an unbuffered bridge blocks on the async source, even a
+/// one-byte read. When the synchronous consumer issues many small calls,
+/// construct the bridge with [`with_capacity`] to amortize them over an
+/// internal buffer.
+///
+/// ```rust
+/// use std::io::{Read, Seek, SeekFrom, Write};
+/// use tokio_util::io::SyncIoBridge;
+///
+/// #[tokio::main]
+/// async fn main() -> std::io::Result<()> {
+///     let file = std::io::Cursor::new(Vec::new());
+///     let mut bridge = SyncIoBridge::with_capacity(file, 8 * 1024);
+///
+///     tokio::task::spawn_blocking(move || {
+///         bridge.write_all(b"hello world")?;
+///         bridge.flush()?;
+///         bridge.seek(SeekFrom::Start(0))?;
+///
+///         let mut contents = String::new();
+///         bridge.read_to_string(&mut contents)?;
+///         assert_eq!(contents, "hello world");
+///         Ok(())
+///     })
+///     .await?
+/// }
+/// ```
+///
+/// ## Bridging a Borrowed Resource
+///
+/// A bridge does not have to own the resource: `SyncIoBridge::new(&mut src)`
+/// works because [`AsyncRead`], [`AsyncWrite`] and [`AsyncSeek`] are
+/// implemented for `&mut T`, so `src` can be used again once the blocking
+/// section completes.
+///
+/// [`with_capacity`]: SyncIoBridge::with_capacity
+///
 /// ## Correct Usage of `SyncIoBridge` inside `spawn_blocking`
 ///
 /// `SyncIoBridge` is mainly useful when you need to interface with synchronous
@@ -261,78 +305,245 @@ use tokio::io::{
 pub struct SyncIoBridge<T> {
     src: T,
     rt: tokio::runtime::Handle,
+    /// Size of the internal buffers; zero disables buffering.
+    capacity: usize,
+    read_buf: Vec<u8>,
+    read_pos: usize,
+    write_buf: Vec<u8>,
 }
 
 impl<T: AsyncBufRead + Unpin> BufRead for SyncIoBridge<T> {
     fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
-        let src = &mut self.src;
-        self.rt.block_on(AsyncBufReadExt::fill_buf(src))
+        if self.capacity == 0 {
+            let src = &mut self.src;
+            return self.rt.block_on(AsyncBufReadExt::fill_buf(src));
+        }
+        if self.read_pos == self.read_buf.len() {
+            self.refill()?;
+        }
+        Ok(&self.read_buf[self.read_pos..])
     }
 
     fn consume(&mut self, amt: usize) {
-        let src = &mut self.src;
-        AsyncBufReadExt::consume(src, amt)
+        if self.capacity == 0 {
+            let src = &mut self.src;
+            AsyncBufReadExt::consume(src, amt)
+        } else {
+            self.read_pos = std::cmp::min(self.read_pos + amt, self.read_buf.len());
+        }
     }
 
     fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> std::io::Result<usize> {
-        let src = &mut self.src;
-        self.rt
-            .block_on(AsyncBufReadExt::read_until(src, byte, buf))
+        if self.capacity == 0 {
+            let src = &mut self.src;
+            return self
+                .rt
+                .block_on(AsyncBufReadExt::read_until(src, byte, buf));
+        }
+        let mut read = 0;
+        loop {
+            let (done, used) = {
+                let available = self.fill_buf()?;
+                match available.iter().position(|b| *b == byte) {
+                    Some(i) => {
+                        buf.extend_from_slice(&available[..=i]);
+                        (true, i + 1)
+                    }
+                    None => {
+                        buf.extend_from_slice(available);
+                        (available.is_empty(), available.len())
+                    }
+                }
+            };
+            self.consume(used);
+            read += used;
+            if done {
+                return Ok(read);
+            }
+        }
     }
     fn read_line(&mut self, buf: &mut String) -> std::io::Result<usize> {
-        let src = &mut self.src;
-        self.rt.block_on(AsyncBufReadExt::read_line(src, buf))
+        if self.capacity == 0 {
+            let src = &mut self.src;
+            return self.rt.block_on(AsyncBufReadExt::read_line(src, buf));
+        }
+        let mut bytes = Vec::new();
+        let read = self.read_until(b'\n', &mut bytes)?;
+        buf.push_str(utf8(&bytes)?);
+        Ok(read)
+    }
+}
+
+impl<T: AsyncRead + Unpin> SyncIoBridge<T> {
+    /// Refills the internal read buffer from the async source.
+    fn refill(&mut self) -> std::io::Result<()> {
+        self.read_buf.clear();
+        self.read_pos = 0;
+        self.read_buf.resize(self.capacity, 0);
+        let Self {
+            src, rt, read_buf, ..
+        } = self;
+        let n = rt.block_on(AsyncReadExt::read(src, read_buf))?;
+        self.read_buf.truncate(n);
+        Ok(())
+    }
+
+    /// Appends the unread part of the internal read buffer to `buf`,
+    /// returning how many bytes were appended.
+    fn drain_buffered(&mut self, buf: &mut Vec<u8>) -> usize {
+        let available = &self.read_buf[self.read_pos..];
+        buf.extend_from_slice(available);
+        let n = available.len();
+        self.read_pos = self.read_buf.len();
+        n
     }
 }
 
 impl<T: AsyncRead + Unpin> Read for SyncIoBridge<T> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let src = &mut self.src;
-        self.rt.block_on(AsyncReadExt::read(src, buf))
+        if self.read_pos == self.read_buf.len() {
+            // Reads at least as large as the internal buffer bypass it.
+            if buf.len() >= self.capacity {
+                let src = &mut self.src;
+                return self.rt.block_on(AsyncReadExt::read(src, buf));
+            }
+            self.refill()?;
+        }
+        let available = &self.read_buf[self.read_pos..];
+        let n = std::cmp::min(buf.len(), available.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.read_pos += n;
+        Ok(n)
     }
 
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+        let buffered = self.drain_buffered(buf);
         let src = &mut self.src;
-        self.rt.block_on(src.read_to_end(buf))
+        Ok(buffered + self.rt.block_on(src.read_to_end(buf))?)
     }
 
     fn read_to_string(&mut self, buf: &mut String) -> std::io::Result<usize> {
-        let src = &mut self.src;
-        self.rt.block_on(src.read_to_string(buf))
+        if self.capacity == 0 {
+            let src = &mut self.src;
+            return self.rt.block_on(src.read_to_string(buf));
+        }
+        let mut bytes = Vec::new();
+        let n = self.read_to_end(&mut bytes)?;
+        buf.push_str(utf8(&bytes)?);
+        Ok(n)
     }
 
     fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
-        let src = &mut self.src;
-        // The AsyncRead trait returns the count, synchronous doesn't.
-        let _n = self.rt.block_on(src.read_exact(buf))?;
+        if self.capacity == 0 {
+            let src = &mut self.src;
+            // The AsyncRead trait returns the count, synchronous doesn't.
+            let _n = self.rt.block_on(src.read_exact(buf))?;
+            return Ok(());
+        }
+        let mut buf = buf;
+        while !buf.is_empty() {
+            let n = self.read(buf)?;
+            if n == 0 {
+                return Err(std::io::ErrorKind::UnexpectedEof.into());
+            }
+            buf = &mut buf[n..];
+        }
+        Ok(())
+    }
+}
+
+fn utf8(bytes: &[u8]) -> std::io::Result<&str> {
+    std::str::from_utf8(bytes).map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "stream did not contain valid UTF-8",
+        )
+    })
+}
+
+impl<T: AsyncWrite + Unpin> SyncIoBridge<T> {
+    /// Writes the internal write buffer out to the async destination.
+    fn flush_write_buf(&mut self) -> std::io::Result<()> {
+        if !self.write_buf.is_empty() {
+            let Self {
+                src, rt, write_buf, ..
+            } = self;
+            rt.block_on(src.write_all(write_buf))?;
+            write_buf.clear();
+        }
         Ok(())
     }
 }
 
 impl<T: AsyncWrite + Unpin> Write for SyncIoBridge<T> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        let src = &mut self.src;
-        self.rt.block_on(src.write(buf))
+        if self.write_buf.len() + buf.len() > self.capacity {
+            self.flush_write_buf()?;
+        }
+        // Writes at least as large as the internal buffer bypass it. This
+        // is also the unbuffered path, since the capacity is zero then.
+        if buf.len() >= self.capacity {
+            let src = &mut self.src;
+            return self.rt.block_on(src.write(buf));
+        }
+        self.write_buf.extend_from_slice(buf);
+        Ok(buf.len())
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
+        self.flush_write_buf()?;
         let src = &mut self.src;
         self.rt.block_on(src.flush())
     }
 
     fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
-        let src = &mut self.src;
-        self.rt.block_on(src.write_all(buf))
+        if self.write_buf.len() + buf.len() > self.capacity {
+            self.flush_write_buf()?;
+        }
+        if buf.len() >= self.capacity {
+            let src = &mut self.src;
+            return self.rt.block_on(src.write_all(buf));
+        }
+        self.write_buf.extend_from_slice(buf);
+        Ok(())
     }
 
     fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
-        let src = &mut self.src;
-        self.rt.block_on(src.write_vectored(bufs))
+        if self.capacity == 0 {
+            let src = &mut self.src;
+            return self.rt.block_on(src.write_vectored(bufs));
+        }
+        // Write the first non-empty buffer through the internal buffer.
+        match bufs.iter().find(|b| !b.is_empty()) {
+            Some(buf) => self.write(buf),
+            None => Ok(0),
+        }
     }
 }
 
 impl<T: AsyncSeek + Unpin> Seek for SyncIoBridge<T> {
     fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let mut pos = pos;
+        if self.capacity != 0 {
+            // Buffered writes cannot be flushed here: this impl only has
+            // `T: AsyncSeek`, so there is no way to write them out.
+            // Seeking would emit them at the new position later, corrupting
+            // the destination, so refuse instead.
+            if !self.write_buf.is_empty() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "cannot seek with buffered writes pending; call flush first",
+                ));
+            }
+            // The source is ahead of the caller's logical position by the
+            // bytes that were read ahead into the internal buffer.
+            let unread = (self.read_buf.len() - self.read_pos) as i64;
+            if let std::io::SeekFrom::Current(n) = &mut pos {
+                *n -= unread;
+            }
+            self.read_buf.clear();
+            self.read_pos = 0;
+        }
         let src = &mut self.src;
         self.rt.block_on(AsyncSeekExt::seek(src, pos))
     }
@@ -359,6 +570,7 @@ impl<T: AsyncWrite + Unpin> SyncIoBridge<T> {
     ///
     /// [`AsyncWriteExt::shutdown`]: tokio::io::AsyncWriteExt::shutdown
     pub fn shutdown(&mut self) -> std::io::Result<()> {
+        self.flush_write_buf()?;
         let src = &mut self.src;
         self.rt.block_on(src.shutdown())
     }
@@ -391,16 +603,65 @@ impl<T: Unpin> SyncIoBridge<T> {
         Self::new_with_handle(src, tokio::runtime::Handle::current())
     }
 
+    /// Use a [`tokio::io::AsyncRead`] synchronously as a [`std::io::Read`] or
+    /// a [`tokio::io::AsyncWrite`] as a [`std::io::Write`], buffering both
+    /// directions with internal buffers of `capacity` bytes.
+    ///
+    /// Reads are served from the internal buffer, which is refilled with a
+    /// single blocking read once it runs dry; writes accumulate in a second
+    /// internal buffer which is written out once it fills up, on
+    /// [`Write::flush`], and on [`shutdown`]. Reads and writes at least
+    /// `capacity` bytes large bypass the buffers. This greatly reduces the
+    /// number of blocking round trips to the async resource when the
+    /// synchronous consumer issues many small calls.
+    ///
+    /// Note that buffered data is discarded by [`into_inner`], and that
+    /// [`Seek::seek`] returns an error while buffered writes are pending,
+    /// so call [`Write::flush`] before seeking when both are in use. A
+    /// `capacity` of zero is equivalent to [`new`].
+    ///
+    /// [`shutdown`]: SyncIoBridge::shutdown
+    /// [`into_inner`]: SyncIoBridge::into_inner
+    /// [`new`]: SyncIoBridge::new
+    ///
+    /// # Panics
+    ///
+    /// This will panic if called outside the context of a Tokio runtime.
+    #[track_caller]
+    pub fn with_capacity(src: T, capacity: usize) -> Self {
+        Self::with_capacity_and_handle(src, capacity, tokio::runtime::Handle::current())
+    }
+
+    /// This is the same as [`SyncIoBridge::with_capacity`], but allows
+    /// passing an arbitrary handle and hence may be initially invoked
+    /// outside of an asynchronous context.
+    pub fn with_capacity_and_handle(src: T, capacity: usize, rt: tokio::runtime::Handle) -> Self {
+        Self {
+            src,
+            rt,
+            capacity,
+            read_buf: Vec::new(),
+            read_pos: 0,
+            write_buf: Vec::with_capacity(capacity),
+        }
+    }
+
     /// Use a [`tokio::io::AsyncRead`] synchronously as a [`std::io::Read`] or
     /// a [`tokio::io::AsyncWrite`] as a [`std::io::Write`].
     ///
     /// This is the same as [`SyncIoBridge::new`], but allows passing an arbitrary handle and hence may
     /// be initially invoked outside of an asynchronous context.
     pub fn new_with_handle(src: T, rt: tokio::runtime::Handle) -> Self {
-        Self { src, rt }
+        Self::with_capacity_and_handle(src, 0, rt)
     }
 
     /// Consume this bridge, returning the underlying stream.
+    ///
+    /// Any internally buffered data is discarded; on a bridge created with
+    /// [`with_capacity`], call [`Write::flush`] first to preserve buffered
+    /// writes.
+    ///
+    /// [`with_capacity`]: SyncIoBridge::with_capacity
     pub fn into_inner(self) -> T {
         self.src
     }
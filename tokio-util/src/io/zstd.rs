@@ -0,0 +1,257 @@
+use bytes::buf::Writer;
+use bytes::{Buf, BufMut, BytesMut};
+use pin_project_lite::pin_project;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use zstd::stream::write::{Decoder, Encoder};
+
+// How much compressed output we let accumulate before forcing it out to
+// the underlying transport, and how much compressed input we request per
+// read of the underlying transport.
+const BACKPRESSURE_BOUNDARY: usize = 8 * 1024;
+
+pin_project! {
+    /// An adapter that decompresses a zstd stream as it is read.
+    ///
+    /// `ZstdReader` wraps an [`AsyncRead`] producing zstd-compressed data
+    /// and implements [`AsyncRead`] yielding the decompressed bytes. A
+    /// corrupt stream produces an error from `poll_read`; a stream
+    /// truncated at a frame boundary is not detected and simply ends the
+    /// output early.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tokio::io::AsyncReadExt;
+    /// use tokio_util::io::{ZstdReader, ZstdWriter};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> std::io::Result<()> {
+    /// let mut writer = ZstdWriter::new(Vec::new())?;
+    /// tokio::io::AsyncWriteExt::write_all(&mut writer, b"hello world").await?;
+    /// tokio::io::AsyncWriteExt::shutdown(&mut writer).await?;
+    /// let compressed = writer.into_inner();
+    ///
+    /// let mut reader = ZstdReader::new(&compressed[..])?;
+    /// let mut decompressed = String::new();
+    /// reader.read_to_string(&mut decompressed).await?;
+    ///
+    /// assert_eq!(decompressed, "hello world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub struct ZstdReader<R> {
+        #[pin]
+        reader: R,
+        decoder: Decoder<'static, Writer<BytesMut>>,
+        input: BytesMut,
+        read_eof: bool,
+        finished: bool,
+    }
+}
+
+impl<R: AsyncRead> ZstdReader<R> {
+    /// Creates a new `ZstdReader`, wrapping `reader`.
+    pub fn new(reader: R) -> io::Result<ZstdReader<R>> {
+        Ok(ZstdReader {
+            reader,
+            decoder: Decoder::new(BytesMut::new().writer())?,
+            input: BytesMut::new(),
+            read_eof: false,
+            finished: false,
+        })
+    }
+}
+
+impl<R> ZstdReader<R> {
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the wrapped reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Consumes the `ZstdReader`, returning the wrapped reader.
+    ///
+    /// Any compressed data buffered but not yet decompressed is discarded.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for ZstdReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+
+        loop {
+            // Serve decompressed bytes before reading from the transport
+            // again.
+            let decompressed = me.decoder.get_mut().get_mut();
+            if !decompressed.is_empty() {
+                let len = decompressed.len().min(buf.remaining());
+                buf.put_slice(&decompressed[..len]);
+                decompressed.advance(len);
+                return Poll::Ready(Ok(()));
+            }
+
+            if *me.finished {
+                return Poll::Ready(Ok(()));
+            }
+
+            if *me.read_eof {
+                // The transport is exhausted; flush whatever complete
+                // output the decoder still buffers.
+                me.decoder.flush()?;
+                *me.finished = true;
+                continue;
+            }
+
+            me.input.reserve(BACKPRESSURE_BOUNDARY);
+            let n = ready!(crate::util::poll_read_buf(me.reader.as_mut(), cx, me.input))?;
+            if n == 0 {
+                *me.read_eof = true;
+            } else {
+                me.decoder.write_all(me.input)?;
+                me.input.clear();
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// An adapter that zstd-compresses data as it is written.
+    ///
+    /// `ZstdWriter` wraps an [`AsyncWrite`] and implements [`AsyncWrite`],
+    /// compressing everything written to it into a single zstd frame.
+    ///
+    /// Calling [`flush`] flushes the compressor before flushing the wrapped
+    /// writer, so everything written so far becomes decodable by the remote
+    /// peer without ending the frame. Calling [`shutdown`] finishes the
+    /// frame and then shuts down the wrapped writer; dropping the
+    /// `ZstdWriter` without shutting it down produces a truncated stream.
+    ///
+    /// [`flush`]: tokio::io::AsyncWriteExt::flush
+    /// [`shutdown`]: tokio::io::AsyncWriteExt::shutdown
+    pub struct ZstdWriter<W> {
+        #[pin]
+        writer: W,
+        encoder: Encoder<'static, Writer<BytesMut>>,
+    }
+}
+
+impl<W: AsyncWrite> ZstdWriter<W> {
+    /// Creates a new `ZstdWriter`, wrapping `writer` and compressing at the
+    /// default compression level.
+    pub fn new(writer: W) -> io::Result<ZstdWriter<W>> {
+        ZstdWriter::with_level(writer, 0)
+    }
+
+    /// Creates a new `ZstdWriter`, wrapping `writer` and compressing at the
+    /// given compression level.
+    ///
+    /// Valid levels are `1-22`, with `0` meaning the zstd library's default
+    /// level.
+    pub fn with_level(writer: W, level: i32) -> io::Result<ZstdWriter<W>> {
+        Ok(ZstdWriter {
+            writer,
+            encoder: Encoder::new(BytesMut::new().writer(), level)?,
+        })
+    }
+}
+
+impl<W> ZstdWriter<W> {
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the wrapped writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the `ZstdWriter`, returning the wrapped writer.
+    ///
+    /// The zstd frame is not finished; call [`shutdown`] first to obtain a
+    /// complete stream.
+    ///
+    /// [`shutdown`]: tokio::io::AsyncWriteExt::shutdown
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Writes buffered compressed bytes out to `writer` until the buffer is
+/// empty.
+fn poll_drain<W: AsyncWrite>(
+    mut writer: Pin<&mut W>,
+    cx: &mut Context<'_>,
+    buffer: &mut BytesMut,
+) -> Poll<io::Result<()>> {
+    while !buffer.is_empty() {
+        let n = ready!(writer.as_mut().poll_write(cx, buffer))?;
+        if n == 0 {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "failed to write compressed data to transport",
+            )));
+        }
+        buffer.advance(n);
+    }
+    Poll::Ready(Ok(()))
+}
+
+impl<W: AsyncWrite> AsyncWrite for ZstdWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let me = self.project();
+
+        // Apply backpressure by draining the compressed output to the
+        // transport once it grows past the boundary.
+        if me.encoder.get_ref().get_ref().len() >= BACKPRESSURE_BOUNDARY {
+            ready!(poll_drain(me.writer, cx, me.encoder.get_mut().get_mut()))?;
+        }
+
+        Poll::Ready(me.encoder.write(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+
+        // Flushing the encoder makes everything written so far decodable
+        // without ending the frame.
+        me.encoder.flush()?;
+        ready!(poll_drain(
+            me.writer.as_mut(),
+            cx,
+            me.encoder.get_mut().get_mut()
+        ))?;
+        me.writer.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+
+        // Finishing is idempotent, so resuming after the transport returned
+        // `Pending` below is fine.
+        me.encoder.do_finish()?;
+        ready!(poll_drain(
+            me.writer.as_mut(),
+            cx,
+            me.encoder.get_mut().get_mut()
+        ))?;
+        me.writer.poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,65 @@
+use std::task::{ready, Context, Poll};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::broadcast::Receiver;
+
+use super::ReusableBoxFuture;
+
+async fn make_future<T: Clone>(mut rx: Receiver<T>) -> (Receiver<T>, Result<T, RecvError>) {
+    let result = rx.recv().await;
+    (rx, result)
+}
+
+/// A wrapper around [`broadcast::Receiver`] that provides a `poll_recv` method.
+///
+/// This is useful when implementing a [`Future`] or [`Stream`] by hand, as it
+/// avoids having to store a boxed [`recv`] future alongside the receiver. If
+/// you only need a [`Stream`] of received values, consider using
+/// [`tokio_stream::wrappers::BroadcastStream`] instead.
+///
+/// [`broadcast::Receiver`]: tokio::sync::broadcast::Receiver
+/// [`recv`]: tokio::sync::broadcast::Receiver::recv
+/// [`Future`]: std::future::Future
+/// [`Stream`]: futures_core::Stream
+/// [`tokio_stream::wrappers::BroadcastStream`]: https://docs.rs/tokio-stream/latest/tokio_stream/wrappers/struct.BroadcastStream.html
+pub struct PollBroadcastReceiver<T> {
+    inner: ReusableBoxFuture<'static, (Receiver<T>, Result<T, RecvError>)>,
+}
+
+impl<T: 'static + Clone + Send> PollBroadcastReceiver<T> {
+    /// Create a new `PollBroadcastReceiver`.
+    pub fn new(receiver: Receiver<T>) -> Self {
+        Self {
+            inner: ReusableBoxFuture::new(make_future(receiver)),
+        }
+    }
+
+    /// Poll to receive the next value sent on the channel.
+    ///
+    /// This can return the following values:
+    ///
+    ///  - `Poll::Pending` if no value is currently available.
+    ///  - `Poll::Ready(Ok(value))` if a value was received.
+    ///  - `Poll::Ready(Err(RecvError::Lagged(n)))` if the receiver lagged too
+    ///    far behind and `n` values were skipped. The next call picks up at
+    ///    the oldest value still retained by the channel.
+    ///  - `Poll::Ready(Err(RecvError::Closed))` if the sender has been dropped
+    ///    and all buffered values have been received.
+    ///
+    /// When this method returns `Poll::Pending`, the current task is scheduled
+    /// to receive a wakeup when a value is sent, or when the sender is
+    /// dropped. Note that on multiple calls to `poll_recv`, only the `Waker`
+    /// from the `Context` passed to the most recent call is scheduled to
+    /// receive a wakeup.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
+        let (rx, result) = ready!(self.inner.poll(cx));
+        self.inner.set(make_future(rx));
+        Poll::Ready(result)
+    }
+}
+
+impl<T> std::fmt::Debug for PollBroadcastReceiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollBroadcastReceiver")
+            .finish_non_exhaustive()
+    }
+}
@@ -198,7 +198,39 @@ impl CancellationToken {
     /// `false` from `is_cancelled` on another child node. However, once the
     /// call to `cancel` returns, all child nodes have been fully cancelled.
     pub fn cancel(&self) {
-        tree_node::cancel(&self.inner);
+        tree_node::cancel(&self.inner, None);
+    }
+
+    /// Cancel the [`CancellationToken`] like [`cancel`] does, additionally
+    /// recording `reason` as the explanation for the cancellation.
+    ///
+    /// The reason is stored on this token and all child tokens cancelled by
+    /// this call, and can be retrieved through [`cancellation_reason`] once
+    /// [`cancelled`] resolves. Only the first cancellation takes effect: if
+    /// the token is already cancelled, the reason is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio_util::sync::CancellationToken;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let token = CancellationToken::new();
+    /// let child_token = token.child_token();
+    ///
+    /// token.cancel_with("config reloaded");
+    ///
+    /// child_token.cancelled().await;
+    /// assert_eq!(child_token.cancellation_reason().as_deref(), Some("config reloaded"));
+    /// # }
+    /// ```
+    ///
+    /// [`cancel`]: CancellationToken::cancel
+    /// [`cancelled`]: CancellationToken::cancelled
+    /// [`cancellation_reason`]: CancellationToken::cancellation_reason
+    pub fn cancel_with(&self, reason: impl Into<std::sync::Arc<str>>) {
+        tree_node::cancel(&self.inner, Some(reason.into()));
     }
 
     /// Returns `true` if the `CancellationToken` is cancelled.
@@ -206,6 +238,80 @@ impl CancellationToken {
         tree_node::is_cancelled(&self.inner)
     }
 
+    /// Returns the reason the `CancellationToken` was cancelled with, if any.
+    ///
+    /// This returns `None` if the token has not been cancelled yet, or if it
+    /// was cancelled through [`cancel`] rather than [`cancel_with`].
+    ///
+    /// [`cancel`]: CancellationToken::cancel
+    /// [`cancel_with`]: CancellationToken::cancel_with
+    pub fn cancellation_reason(&self) -> Option<std::sync::Arc<str>> {
+        tree_node::cancellation_reason(&self.inner)
+    }
+
+    /// Cancel the [`CancellationToken`] once `timeout` has elapsed.
+    ///
+    /// This spawns a background task that calls [`cancel`] when the timeout
+    /// expires. The task holds a clone of this token, so the token stays
+    /// alive until the deadline is reached or the token is cancelled by other
+    /// means, whichever happens first. Arming several timers is allowed; the
+    /// earliest deadline wins.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside of a Tokio runtime context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio_util::sync::CancellationToken;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let token = CancellationToken::new();
+    /// token.cancel_after(Duration::from_secs(1));
+    ///
+    /// // Resolves once the second has elapsed.
+    /// token.cancelled().await;
+    /// # }
+    /// ```
+    ///
+    /// [`cancel`]: CancellationToken::cancel
+    #[cfg(all(feature = "rt", feature = "time"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "rt", feature = "time"))))]
+    pub fn cancel_after(&self, timeout: std::time::Duration) {
+        self.cancel_at(tokio::time::Instant::now() + timeout);
+    }
+
+    /// Cancel the [`CancellationToken`] at the given deadline.
+    ///
+    /// This spawns a background task that calls [`cancel`] once `deadline` is
+    /// reached. The task holds a clone of this token, so the token stays
+    /// alive until the deadline is reached or the token is cancelled by other
+    /// means, whichever happens first. Arming several timers is allowed; the
+    /// earliest deadline wins.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside of a Tokio runtime context.
+    ///
+    /// [`cancel`]: CancellationToken::cancel
+    #[cfg(all(feature = "rt", feature = "time"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "rt", feature = "time"))))]
+    pub fn cancel_at(&self, deadline: tokio::time::Instant) {
+        let token = self.clone();
+        tokio::spawn(async move {
+            if token
+                .run_until_cancelled(tokio::time::sleep_until(deadline))
+                .await
+                .is_some()
+            {
+                token.cancel();
+            }
+        });
+    }
+
     /// Returns a [`Future`] that gets fulfilled when cancellation is requested.
     ///
     /// Equivalent to:
@@ -55,6 +55,7 @@ impl TreeNode {
                 parent_idx: 0,
                 children: vec![],
                 is_cancelled: false,
+                cancel_reason: None,
                 num_handles: 1,
             }),
             waker: tokio::sync::Notify::new(),
@@ -75,6 +76,9 @@ struct Inner {
     parent_idx: usize,
     children: Vec<Arc<TreeNode>>,
     is_cancelled: bool,
+    // The reason passed to the cancellation call, if any. Set at most once,
+    // under the same lock that sets `is_cancelled`.
+    cancel_reason: Option<std::sync::Arc<str>>,
     num_handles: usize,
 }
 
@@ -83,6 +87,11 @@ pub(crate) fn is_cancelled(node: &Arc<TreeNode>) -> bool {
     node.inner.lock().unwrap().is_cancelled
 }
 
+/// Returns the reason the node was cancelled with, if any
+pub(crate) fn cancellation_reason(node: &Arc<TreeNode>) -> Option<std::sync::Arc<str>> {
+    node.inner.lock().unwrap().cancel_reason.clone()
+}
+
 /// Creates a child node
 pub(crate) fn child_node(parent: &Arc<TreeNode>) -> Arc<TreeNode> {
     let mut locked_parent = parent.inner.lock().unwrap();
@@ -97,6 +106,7 @@ pub(crate) fn child_node(parent: &Arc<TreeNode>) -> Arc<TreeNode> {
                 parent_idx: 0,
                 children: vec![],
                 is_cancelled: true,
+                cancel_reason: locked_parent.cancel_reason.clone(),
                 num_handles: 1,
             }),
             waker: tokio::sync::Notify::new(),
@@ -109,6 +119,7 @@ pub(crate) fn child_node(parent: &Arc<TreeNode>) -> Arc<TreeNode> {
             parent_idx: locked_parent.children.len(),
             children: vec![],
             is_cancelled: false,
+            cancel_reason: None,
             num_handles: 1,
         }),
         waker: tokio::sync::Notify::new(),
@@ -294,7 +305,11 @@ pub(crate) fn decrease_handle_refcount(node: &Arc<TreeNode>) {
 }
 
 /// Cancels a node and its children.
-pub(crate) fn cancel(node: &Arc<TreeNode>) {
+///
+/// The `reason`, if any, is stored on every node that this call transitions
+/// into the cancelled state. Nodes that were already cancelled keep the
+/// reason of their first cancellation.
+pub(crate) fn cancel(node: &Arc<TreeNode>, reason: Option<std::sync::Arc<str>>) {
     let mut locked_node = node.inner.lock().unwrap();
 
     if locked_node.is_cancelled {
@@ -337,6 +352,7 @@ pub(crate) fn cancel(node: &Arc<TreeNode>) {
             if locked_grandchild.children.is_empty() {
                 // Cancel the grandchild
                 locked_grandchild.is_cancelled = true;
+                locked_grandchild.cancel_reason = reason.clone();
                 locked_grandchild.children = Vec::new();
                 drop(locked_grandchild);
                 grandchild.waker.notify_waiters();
@@ -351,6 +367,7 @@ pub(crate) fn cancel(node: &Arc<TreeNode>) {
 
         // Cancel the child
         locked_child.is_cancelled = true;
+        locked_child.cancel_reason = reason.clone();
         locked_child.children = Vec::new();
         drop(locked_child);
         child.waker.notify_waiters();
@@ -361,6 +378,7 @@ pub(crate) fn cancel(node: &Arc<TreeNode>) {
 
     // Cancel the node itself.
     locked_node.is_cancelled = true;
+    locked_node.cancel_reason = reason;
     locked_node.children = Vec::new();
     drop(locked_node);
     node.waker.notify_waiters();
@@ -1,5 +1,8 @@
 //! Synchronization primitives
 
+mod broadcast;
+pub use broadcast::PollBroadcastReceiver;
+
 mod cancellation_token;
 pub use cancellation_token::{
     guard::DropGuard, guard_ref::DropGuardRef, CancellationToken, WaitForCancellationFuture,
@@ -15,3 +18,6 @@ pub use poll_semaphore::PollSemaphore;
 
 mod reusable_box;
 pub use reusable_box::ReusableBoxFuture;
+
+mod watch;
+pub use watch::PollWatchReceiver;
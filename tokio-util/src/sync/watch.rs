@@ -0,0 +1,70 @@
+use std::task::{ready, Context, Poll};
+use tokio::sync::watch::error::RecvError;
+use tokio::sync::watch::Receiver;
+
+use super::ReusableBoxFuture;
+
+async fn make_future<T>(mut rx: Receiver<T>) -> (Receiver<T>, Result<(), RecvError>) {
+    let result = rx.changed().await;
+    (rx, result)
+}
+
+/// A wrapper around [`watch::Receiver`] that provides a `poll_changed` method.
+///
+/// This is useful when implementing a [`Future`] or [`Stream`] by hand, as it
+/// avoids having to store a boxed [`changed`] future alongside the receiver.
+/// If you only need a [`Stream`] of changed values, consider using
+/// [`tokio_stream::wrappers::WatchStream`] instead.
+///
+/// [`watch::Receiver`]: tokio::sync::watch::Receiver
+/// [`changed`]: tokio::sync::watch::Receiver::changed
+/// [`Future`]: std::future::Future
+/// [`Stream`]: futures_core::Stream
+/// [`tokio_stream::wrappers::WatchStream`]: https://docs.rs/tokio-stream/latest/tokio_stream/wrappers/struct.WatchStream.html
+pub struct PollWatchReceiver<T> {
+    inner: ReusableBoxFuture<'static, (Receiver<T>, Result<(), RecvError>)>,
+}
+
+impl<T: 'static + Clone + Send + Sync> PollWatchReceiver<T> {
+    /// Create a new `PollWatchReceiver`.
+    ///
+    /// If the value currently held by the channel has not yet been marked as
+    /// seen by the receiver, the first call to `poll_changed` resolves
+    /// immediately with it.
+    pub fn new(receiver: Receiver<T>) -> Self {
+        Self {
+            inner: ReusableBoxFuture::new(make_future(receiver)),
+        }
+    }
+
+    /// Poll for a changed value.
+    ///
+    /// This can return the following values:
+    ///
+    ///  - `Poll::Pending` if no new value has been sent yet.
+    ///  - `Poll::Ready(Some(value))` with a clone of the most recent value if
+    ///    it has changed since the last call that returned `Poll::Ready`. The
+    ///    value is marked as seen.
+    ///  - `Poll::Ready(None)` if the sender has been dropped.
+    ///
+    /// When this method returns `Poll::Pending`, the current task is scheduled
+    /// to receive a wakeup when a new value is sent, or when the sender is
+    /// dropped. Note that on multiple calls to `poll_changed`, only the
+    /// `Waker` from the `Context` passed to the most recent call is scheduled
+    /// to receive a wakeup.
+    pub fn poll_changed(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let (mut rx, result) = ready!(self.inner.poll(cx));
+        let output = match result {
+            Ok(()) => Some(rx.borrow_and_update().clone()),
+            Err(_closed) => None,
+        };
+        self.inner.set(make_future(rx));
+        Poll::Ready(output)
+    }
+}
+
+impl<T> std::fmt::Debug for PollWatchReceiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollWatchReceiver").finish_non_exhaustive()
+    }
+}
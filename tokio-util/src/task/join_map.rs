@@ -2,6 +2,7 @@ use hashbrown::hash_table::Entry;
 use hashbrown::{HashMap, HashTable};
 use std::borrow::Borrow;
 use std::collections::hash_map::RandomState;
+use std::collections::VecDeque;
 use std::fmt;
 use std::future::Future;
 use std::hash::{BuildHasher, Hash, Hasher};
@@ -112,6 +113,16 @@ pub struct JoinMap<K, V, S = RandomState> {
     /// The [`JoinSet`] that awaits the completion of tasks spawned on this
     /// `JoinMap`.
     tasks: JoinSet<V>,
+
+    /// Outputs of tasks that completed while [`join_key`] was waiting for a
+    /// different task.
+    ///
+    /// These are returned by [`join_next`] before polling the [`JoinSet`]
+    /// again, so that no output is lost.
+    ///
+    /// [`join_key`]: JoinMap::join_key
+    /// [`join_next`]: JoinMap::join_next
+    completed: VecDeque<(K, Result<V, JoinError>)>,
 }
 
 impl<K, V> JoinMap<K, V> {
@@ -203,21 +214,28 @@ impl<K, V, S> JoinMap<K, V, S> {
             tasks_by_key: HashTable::with_capacity(capacity),
             hashes_by_task: HashMap::with_capacity_and_hasher(capacity, hash_builder),
             tasks: JoinSet::new(),
+            completed: VecDeque::new(),
         }
     }
 
     /// Returns the number of tasks currently in the `JoinMap`.
+    ///
+    /// This includes tasks whose output has been buffered by a call to
+    /// [`join_key`] but not yet returned by [`join_next`].
+    ///
+    /// [`join_key`]: Self::join_key
+    /// [`join_next`]: Self::join_next
     pub fn len(&self) -> usize {
         let len = self.tasks_by_key.len();
         debug_assert_eq!(len, self.hashes_by_task.len());
-        len
+        len + self.completed.len()
     }
 
     /// Returns whether the `JoinMap` is empty.
     pub fn is_empty(&self) -> bool {
         let empty = self.tasks_by_key.is_empty();
         debug_assert_eq!(empty, self.hashes_by_task.is_empty());
-        empty
+        empty && self.completed.is_empty()
     }
 
     /// Returns the number of tasks the map can hold without reallocating.
@@ -389,6 +407,34 @@ where
         self.insert(key, task)
     }
 
+    /// Returns an entry view for the given key, which can be used to spawn a
+    /// task only if the key does not already have a running task.
+    ///
+    /// This is useful for maintaining one singleton background task per key,
+    /// such as one refresher task per cache entry, without tracking the set of
+    /// running keys separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio_util::task::JoinMap;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut map = JoinMap::new();
+    ///
+    /// // The first call spawns the task...
+    /// assert!(map.entry("cache-key").or_spawn(std::future::pending::<()>()));
+    ///
+    /// // ...and subsequent calls are no-ops while it is still running.
+    /// assert!(!map.entry("cache-key").or_spawn(std::future::pending::<()>()));
+    /// assert_eq!(map.len(), 1);
+    /// # }
+    /// ```
+    pub fn entry(&mut self, key: K) -> JoinMapEntry<'_, K, V, S> {
+        JoinMapEntry { map: self, key }
+    }
+
     fn insert(&mut self, mut key: K, mut abort: AbortHandle) {
         let hash_builder = self.hashes_by_task.hasher();
         let hash = hash_one(hash_builder, &key);
@@ -452,6 +498,11 @@ where
     ///
     /// [`tokio::select!`]: tokio::select
     pub async fn join_next(&mut self) -> Option<(K, Result<V, JoinError>)> {
+        // Outputs buffered by `join_key` are returned before polling the
+        // `JoinSet` again.
+        if let Some(completed) = self.completed.pop_front() {
+            return Some(completed);
+        }
         loop {
             let (res, id) = match self.tasks.join_next_with_id().await {
                 Some(Ok((id, output))) => (Ok(output), id),
@@ -467,6 +518,72 @@ where
         }
     }
 
+    /// Waits until the task corresponding to the provided `key` completes and
+    /// returns its output.
+    ///
+    /// Returns `None` if no task exists for `key`.
+    ///
+    /// Outputs of other tasks that complete while waiting are not lost: they
+    /// are buffered inside the `JoinMap` and returned by subsequent calls to
+    /// [`join_next`].
+    ///
+    /// # Cancel Safety
+    ///
+    /// This method is cancel safe. If `join_key` is used as the event in a
+    /// [`tokio::select!`] statement and some other branch completes first, the
+    /// task corresponding to `key` remains in this `JoinMap`, and any outputs
+    /// buffered in the meantime will be returned by [`join_next`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio_util::task::JoinMap;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut map = JoinMap::new();
+    ///
+    /// map.spawn("hello world", std::future::ready(1));
+    /// map.spawn("goodbye world", std::future::ready(2));
+    ///
+    /// assert_eq!(map.join_key("goodbye world").await.unwrap().unwrap(), 2);
+    /// assert_eq!(map.join_key("hello world").await.unwrap().unwrap(), 1);
+    /// assert!(map.join_key("no such key").await.is_none());
+    /// # }
+    /// ```
+    ///
+    /// [`join_next`]: Self::join_next
+    /// [`tokio::select!`]: tokio::select
+    pub async fn join_key<Q>(&mut self, key: &Q) -> Option<Result<V, JoinError>>
+    where
+        Q: ?Sized + Hash + Eq,
+        K: Borrow<Q>,
+    {
+        // The task may already have completed and had its output buffered by
+        // a previous call to `join_key`.
+        if let Some(pos) = self.completed.iter().position(|(k, _)| k.borrow() == key) {
+            return self.completed.remove(pos).map(|(_, res)| res);
+        }
+
+        let id = self.get_by_key(key)?.1.id();
+        loop {
+            let (res, completed_id) = match self.tasks.join_next_with_id().await {
+                Some(Ok((id, output))) => (Ok(output), id),
+                Some(Err(e)) => {
+                    let id = e.id();
+                    (Err(e), id)
+                }
+                None => return None,
+            };
+            if let Some(completed_key) = self.remove_by_id(completed_id) {
+                if completed_id == id {
+                    return Some(res);
+                }
+                self.completed.push_back((completed_key, res));
+            }
+        }
+    }
+
     /// Aborts all tasks and waits for them to finish shutting down.
     ///
     /// Calling this method is equivalent to calling [`abort_all`] and then calling [`join_next`] in
@@ -626,6 +743,21 @@ where
         }
     }
 
+    /// Returns an iterator visiting the keys of all tasks in this `JoinMap`
+    /// that are still running, in arbitrary order.
+    ///
+    /// Unlike [`keys`], this skips tasks that have completed but whose output
+    /// hasn't yet been consumed by a call to [`join_next`].
+    ///
+    /// [`keys`]: fn@Self::keys
+    /// [`join_next`]: fn@Self::join_next
+    pub fn running_keys(&self) -> JoinMapRunningKeys<'_, K, V> {
+        JoinMapRunningKeys {
+            iter: self.tasks_by_key.iter(),
+            _value: PhantomData,
+        }
+    }
+
     /// Returns `true` if this `JoinMap` contains a task for the provided key.
     ///
     /// If the task has completed, but its output hasn't yet been consumed by a
@@ -798,6 +930,67 @@ where
         self.tasks.detach_all();
         self.tasks_by_key.clear();
         self.hashes_by_task.clear();
+        self.completed.clear();
+    }
+}
+
+/// An entry view into a single key of a [`JoinMap`], created by
+/// [`JoinMap::entry`].
+pub struct JoinMapEntry<'map, K, V, S = RandomState> {
+    map: &'map mut JoinMap<K, V, S>,
+    key: K,
+}
+
+impl<'map, K, V, S> JoinMapEntry<'map, K, V, S>
+where
+    K: Hash + Eq,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Spawn the provided task and store it in the `JoinMap` with this entry's
+    /// key, unless a still-running task already exists for the key.
+    ///
+    /// Returns `true` if the task was spawned, or `false` if a task for the
+    /// key is still running and `task` was dropped without being spawned.
+    ///
+    /// If the key's previous task has completed but its output hasn't yet been
+    /// consumed by a call to [`join_next`], the task is replaced as in
+    /// [`spawn`], and the output of the previous task will *not* be returned
+    /// by [`join_next`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside of a Tokio runtime.
+    ///
+    /// [`join_next`]: JoinMap::join_next
+    /// [`spawn`]: JoinMap::spawn
+    #[track_caller]
+    pub fn or_spawn<F>(self, task: F) -> bool
+    where
+        F: Future<Output = V>,
+        F: Send + 'static,
+        V: Send,
+    {
+        match self.map.get_by_key(&self.key) {
+            Some((_, abort)) if !abort.is_finished() => false,
+            _ => {
+                self.map.spawn(self.key, task);
+                true
+            }
+        }
+    }
+}
+
+impl<'map, K: fmt::Debug, V, S> fmt::Debug for JoinMapEntry<'map, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinMapEntry")
+            .field("key", &self.key)
+            .finish()
     }
 }
 
@@ -862,3 +1055,31 @@ impl<'a, K, V> ExactSizeIterator for JoinMapKeys<'a, K, V> {
 }
 
 impl<'a, K, V> std::iter::FusedIterator for JoinMapKeys<'a, K, V> {}
+
+/// An iterator over the keys of the still-running tasks in a [`JoinMap`].
+#[derive(Debug, Clone)]
+pub struct JoinMapRunningKeys<'a, K, V> {
+    iter: hashbrown::hash_table::Iter<'a, (K, AbortHandle)>,
+    /// To make it easier to change `JoinMap` in the future, keep V as a generic
+    /// parameter.
+    _value: PhantomData<&'a V>,
+}
+
+impl<'a, K, V> Iterator for JoinMapRunningKeys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        loop {
+            let (key, abort) = self.iter.next()?;
+            if !abort.is_finished() {
+                return Some(key);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, K, V> std::iter::FusedIterator for JoinMapRunningKeys<'a, K, V> {}
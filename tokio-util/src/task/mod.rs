@@ -19,4 +19,4 @@ cfg_rt! {
 mod join_map;
 #[cfg(feature = "join-map")]
 #[cfg_attr(docsrs, doc(cfg(feature = "join-map")))]
-pub use join_map::{JoinMap, JoinMapKeys};
+pub use join_map::{JoinMap, JoinMapEntry, JoinMapKeys, JoinMapRunningKeys};
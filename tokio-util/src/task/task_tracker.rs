@@ -3,18 +3,19 @@
 //! See the documentation of [`TaskTracker`] for more information.
 
 use pin_project_lite::pin_project;
+use std::collections::HashMap;
 use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 use tokio::sync::{futures::Notified, Notify};
 
 #[cfg(feature = "rt")]
 use tokio::{
     runtime::Handle,
-    task::{JoinHandle, LocalSet},
+    task::{AbortHandle, JoinHandle, LocalSet},
 };
 
 /// A task tracker used for waiting until tasks exit.
@@ -161,6 +162,9 @@ pub struct TaskTracker {
 #[derive(Debug)]
 pub struct TaskTrackerToken {
     task_tracker: TaskTracker,
+    /// The id of the task's entry in the registry, if the task was spawned
+    /// through [`TaskTracker::spawn_named`].
+    registration: Option<u64>,
 }
 
 struct TaskTrackerInner {
@@ -172,6 +176,26 @@ struct TaskTrackerInner {
     state: AtomicUsize,
     /// Used to notify when the last task exits.
     on_last_exit: Notify,
+    /// Names and abort handles for tasks spawned through
+    /// [`TaskTracker::spawn_named`].
+    ///
+    /// Registration is opt-in so that the unnamed spawn methods stay on the
+    /// lock-free counter above; only named spawns pay for the lock.
+    registry: Mutex<TaskRegistry>,
+}
+
+/// The registry of named tasks spawned through a [`TaskTracker`].
+#[derive(Default)]
+struct TaskRegistry {
+    next_id: u64,
+    tasks: HashMap<u64, TaskEntry>,
+}
+
+struct TaskEntry {
+    name: Arc<str>,
+    /// Set once the task has been spawned. The entry is removed when the
+    /// spawned task is dropped, so a short window without a handle exists.
+    abort_handle: Option<AbortHandle>,
 }
 
 pin_project! {
@@ -200,12 +224,56 @@ pin_project! {
     }
 }
 
+/// Error returned by [`TaskTracker::wait_timeout`] if the timeout elapsed before the
+/// `TaskTracker` became closed and empty.
+#[cfg(all(feature = "rt", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "rt", feature = "time"))))]
+#[derive(Debug)]
+pub struct WaitTimeoutError {
+    unfinished: usize,
+    names: Vec<Arc<str>>,
+}
+
+#[cfg(all(feature = "rt", feature = "time"))]
+impl WaitTimeoutError {
+    /// Returns the number of tasks that were still tracked when the timeout elapsed.
+    #[must_use]
+    pub fn unfinished_tasks(&self) -> usize {
+        self.unfinished
+    }
+
+    /// Returns the names of the named tasks that were still running when the timeout elapsed.
+    ///
+    /// Unnamed tasks are included in [`unfinished_tasks`] but do not show up here.
+    ///
+    /// [`unfinished_tasks`]: Self::unfinished_tasks
+    #[must_use]
+    pub fn unfinished_names(&self) -> &[Arc<str>] {
+        &self.names
+    }
+}
+
+#[cfg(all(feature = "rt", feature = "time"))]
+impl fmt::Display for WaitTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} tasks did not finish before the timeout",
+            self.unfinished
+        )
+    }
+}
+
+#[cfg(all(feature = "rt", feature = "time"))]
+impl std::error::Error for WaitTimeoutError {}
+
 impl TaskTrackerInner {
     #[inline]
     fn new() -> Self {
         Self {
             state: AtomicUsize::new(0),
             on_last_exit: Notify::new(),
+            registry: Mutex::new(TaskRegistry::default()),
         }
     }
 
@@ -267,6 +335,35 @@ impl TaskTrackerInner {
         }
     }
 
+    /// Inserts a registry entry for a task about to be spawned, returning its id.
+    fn register(&self, name: &str) -> u64 {
+        let mut registry = self.registry.lock().unwrap();
+        let id = registry.next_id;
+        registry.next_id += 1;
+        registry.tasks.insert(
+            id,
+            TaskEntry {
+                name: Arc::from(name),
+                abort_handle: None,
+            },
+        );
+        id
+    }
+
+    /// Records the abort handle of a freshly spawned task.
+    ///
+    /// This is a no-op if the task already exited and removed its entry.
+    fn set_abort_handle(&self, id: u64, abort_handle: AbortHandle) {
+        if let Some(entry) = self.registry.lock().unwrap().tasks.get_mut(&id) {
+            entry.abort_handle = Some(abort_handle);
+        }
+    }
+
+    /// Removes the registry entry of an exited task.
+    fn remove_registration(&self, id: u64) {
+        self.registry.lock().unwrap().tasks.remove(&id);
+    }
+
     #[cold]
     fn notify_now(&self) {
         // Insert an acquire fence. This matters for `drop_task` but doesn't matter for
@@ -370,7 +467,11 @@ impl TaskTracker {
 
     /// Spawn the provided future on the current Tokio runtime, and track it in this `TaskTracker`.
     ///
-    /// This is equivalent to `tokio::spawn(tracker.track_future(task))`.
+    /// This is equivalent to `tokio::spawn(tracker.track_future(task))`. Use [`spawn_named`] if
+    /// the task should additionally be abortable through [`abort_all`].
+    ///
+    /// [`spawn_named`]: Self::spawn_named
+    /// [`abort_all`]: Self::abort_all
     #[inline]
     #[track_caller]
     #[cfg(feature = "rt")]
@@ -383,6 +484,51 @@ impl TaskTracker {
         tokio::task::spawn(self.track_future(task))
     }
 
+    /// Spawn the provided future like [`spawn`] does, additionally recording `name` for it.
+    ///
+    /// The name shows up in the snapshot returned by [`count_by_name`] and in the error returned
+    /// by [`wait_timeout`], which makes it possible to tell which kind of task is holding up a
+    /// shutdown. Multiple tasks may share the same name. Named tasks are also the only ones that
+    /// can be aborted through [`abort_all`].
+    ///
+    /// Recording the name registers the task in a shared registry guarded by a lock, a cost per
+    /// spawn and exit that the unnamed spawn methods do not pay.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio_util::task::TaskTracker;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let tracker = TaskTracker::new();
+    ///
+    /// tracker.spawn_named("connection", std::future::pending::<()>());
+    /// tracker.spawn_named("connection", std::future::pending::<()>());
+    ///
+    /// assert_eq!(tracker.count_by_name().get("connection"), Some(&2));
+    /// # }
+    /// ```
+    ///
+    /// [`spawn`]: Self::spawn
+    /// [`count_by_name`]: Self::count_by_name
+    /// [`wait_timeout`]: Self::wait_timeout
+    /// [`abort_all`]: Self::abort_all
+    #[inline]
+    #[track_caller]
+    #[cfg(feature = "rt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub fn spawn_named<F>(&self, name: &str, task: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (id, future) = self.track_registered(name, task);
+        let handle = tokio::task::spawn(future);
+        self.inner.set_abort_handle(id, handle.abort_handle());
+        handle
+    }
+
     /// Spawn the provided future on the provided Tokio runtime, and track it in this `TaskTracker`.
     ///
     /// This is equivalent to `handle.spawn(tracker.track_future(task))`.
@@ -432,6 +578,94 @@ impl TaskTracker {
         local_set.spawn_local(self.track_future(task))
     }
 
+    /// Aborts all tasks that were spawned through [`spawn_named`].
+    ///
+    /// Only named tasks are registered with the tracker as abortable. Tasks spawned through the
+    /// unnamed `spawn*` methods, futures tracked through [`track_future`], and tasks represented
+    /// by a [`token`] are unaffected; the same applies to blocking tasks, which cannot be aborted
+    /// once they have started running.
+    ///
+    /// Aborted tasks still count as tracked until their futures are dropped, so a call to
+    /// [`wait`] is still needed to wait for them to exit.
+    ///
+    /// [`spawn_named`]: Self::spawn_named
+    /// [`track_future`]: Self::track_future
+    /// [`token`]: Self::token
+    /// [`wait`]: Self::wait
+    #[cfg(feature = "rt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub fn abort_all(&self) {
+        let registry = self.inner.registry.lock().unwrap();
+        for entry in registry.tasks.values() {
+            if let Some(abort_handle) = &entry.abort_handle {
+                abort_handle.abort();
+            }
+        }
+    }
+
+    /// Returns a snapshot of how many currently tracked tasks carry each name.
+    ///
+    /// Only tasks spawned with [`spawn_named`] are counted; unnamed tasks are not part of the
+    /// snapshot. The snapshot is immediately stale, as tasks may be spawned or may exit
+    /// concurrently.
+    ///
+    /// [`spawn_named`]: Self::spawn_named
+    #[must_use]
+    #[cfg(feature = "rt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub fn count_by_name(&self) -> HashMap<Arc<str>, usize> {
+        let registry = self.inner.registry.lock().unwrap();
+        let mut counts = HashMap::new();
+        for entry in registry.tasks.values() {
+            *counts.entry(entry.name.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Waits like [`wait`] does, but aborts all remaining tasks once `timeout` has elapsed.
+    ///
+    /// If the `TaskTracker` becomes both closed and empty within the timeout, this returns
+    /// `Ok(())` and is equivalent to [`wait`]. Otherwise, all tasks spawned through
+    /// [`spawn_named`] are aborted as through [`abort_all`], the method waits for the tracked
+    /// tasks to exit, and a [`WaitTimeoutError`] is returned describing the tasks that were
+    /// still running when the timeout elapsed.
+    ///
+    /// Note that only named tasks can be aborted; this method keeps waiting for all other
+    /// tracked tasks even after the timeout.
+    ///
+    /// Like [`wait`], this does not return until the tracker has been [closed](Self::close).
+    ///
+    /// [`wait`]: Self::wait
+    /// [`spawn_named`]: Self::spawn_named
+    /// [`abort_all`]: Self::abort_all
+    #[cfg(all(feature = "rt", feature = "time"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "rt", feature = "time"))))]
+    pub async fn wait_timeout(&self, timeout: std::time::Duration) -> Result<(), WaitTimeoutError> {
+        if tokio::time::timeout(timeout, self.wait()).await.is_ok() {
+            return Ok(());
+        }
+
+        let unfinished = self.len();
+        let names = {
+            let registry = self.inner.registry.lock().unwrap();
+            for entry in registry.tasks.values() {
+                if let Some(abort_handle) = &entry.abort_handle {
+                    abort_handle.abort();
+                }
+            }
+            registry
+                .tasks
+                .values()
+                .map(|entry| entry.name.clone())
+                .collect()
+        };
+
+        // Aborted tasks still count as tracked until they are dropped.
+        self.wait().await;
+
+        Err(WaitTimeoutError { unfinished, names })
+    }
+
     /// Spawn the provided blocking task on the current Tokio runtime, and track it in this `TaskTracker`.
     ///
     /// This is equivalent to `tokio::task::spawn_blocking(tracker.track_future(task))`.
@@ -527,6 +761,21 @@ impl TaskTracker {
         }
     }
 
+    /// Track the provided future and insert a registry entry for it.
+    ///
+    /// The caller is expected to spawn the returned future and record the abort handle of the
+    /// spawned task under the returned id.
+    #[cfg(feature = "rt")]
+    fn track_registered<F: Future>(&self, name: &str, future: F) -> (u64, TrackedFuture<F>) {
+        let id = self.inner.register(name);
+        self.inner.add_task();
+        let token = TaskTrackerToken {
+            task_tracker: self.clone(),
+            registration: Some(id),
+        };
+        (id, TrackedFuture { future, token })
+    }
+
     /// Creates a [`TaskTrackerToken`] representing a task tracked by this `TaskTracker`.
     ///
     /// This token is a lower-level utility than the spawn methods. Each token is considered to
@@ -541,6 +790,7 @@ impl TaskTracker {
         self.inner.add_task();
         TaskTrackerToken {
             task_tracker: self.clone(),
+            registration: None,
         }
     }
 
@@ -659,6 +909,12 @@ impl Drop for TaskTrackerToken {
     /// Dropping the token indicates to the [`TaskTracker`] that the task has exited.
     #[inline]
     fn drop(&mut self) {
+        // Remove the registry entry before decrementing the task count, so
+        // that a waiter that observes an empty tracker also observes an empty
+        // registry.
+        if let Some(id) = self.registration {
+            self.task_tracker.inner.remove_registration(id);
+        }
         self.task_tracker.inner.drop_task();
     }
 }
@@ -0,0 +1,87 @@
+//! A pluggable source of time for timer utilities.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// A source of time for the timer utilities in this module.
+///
+/// The utilities in this module normally read time from the tokio runtime,
+/// which can be controlled in tests with [`start_paused`] or
+/// [`tokio::time::pause`]. When a utility is embedded in another runtime, or
+/// when a test cannot pause the whole runtime, a custom `Clock` can be
+/// injected instead — typically a [`ManualClock`] that is advanced explicitly
+/// by the test.
+///
+/// Note that a custom clock only controls how the current time is *read*: a
+/// [`DelayQueue`] still registers its wakeups with the tokio timer, so after
+/// advancing a manual clock the queue must be polled (or drained with
+/// [`drain_expired`]) for the new time to be observed.
+///
+/// [`start_paused`]: https://docs.rs/tokio/latest/tokio/attr.test.html
+/// [`tokio::time::pause`]: https://docs.rs/tokio/latest/tokio/time/fn.pause.html
+/// [`DelayQueue`]: crate::time::DelayQueue
+/// [`drain_expired`]: crate::time::DelayQueue::drain_expired
+pub trait Clock {
+    /// Returns the current instant, according to this clock.
+    fn now(&self) -> Instant;
+}
+
+/// The default [`Clock`], reading time with [`tokio::time::Instant::now`].
+///
+/// This clock respects the runtime's paused time, so utilities using it can be
+/// tested with `#[tokio::test(start_paused = true)]`.
+#[derive(Debug, Clone, Default)]
+pub struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [`Clock`] that only advances when explicitly told to.
+///
+/// Cloning a `ManualClock` returns a handle to the same underlying time, so a
+/// test can keep one handle for itself and hand the other to the utility under
+/// test.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl ManualClock {
+    /// Creates a clock whose current time is `now` until advanced.
+    pub fn new(now: Instant) -> ManualClock {
+        ManualClock {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Returns the current time of the clock.
+    pub fn now(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+
+    /// Advances the clock by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+
+    /// Sets the current time of the clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `now` is earlier than the clock's current time.
+    pub fn set_now(&self, now: Instant) {
+        let mut lock = self.now.lock().unwrap();
+        assert!(*lock <= now, "time cannot go backwards");
+        *lock = now;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        ManualClock::now(self)
+    }
+}
@@ -5,6 +5,7 @@
 //! [`DelayQueue`]: struct@DelayQueue
 
 use crate::time::wheel::{self, Wheel};
+use crate::time::{Clock, TokioClock};
 
 use tokio::time::{sleep_until, Duration, Instant, Sleep};
 
@@ -127,7 +128,7 @@ use std::task::{self, ready, Poll, Waker};
 /// [`capacity`]: method@Self::capacity
 /// [`reserve`]: method@Self::reserve
 #[derive(Debug)]
-pub struct DelayQueue<T> {
+pub struct DelayQueue<T, C = TokioClock> {
     /// Stores data associated with entries
     slab: SlabStorage<T>,
 
@@ -151,6 +152,9 @@ pub struct DelayQueue<T> {
     /// Because we lazily create the timer when the first entry is created, we
     /// need to awaken any poller that polled us before that point.
     waker: Option<Waker>,
+
+    /// Source of the current time.
+    clock: C,
 }
 
 #[derive(Default)]
@@ -470,14 +474,74 @@ impl<T> DelayQueue<T> {
     /// # }
     /// ```
     pub fn with_capacity(capacity: usize) -> DelayQueue<T> {
+        DelayQueue::with_capacity_and_clock(capacity, TokioClock)
+    }
+}
+
+impl<T, C: Clock> DelayQueue<T, C> {
+    /// Creates a new, empty, `DelayQueue` reading time from the given
+    /// [`Clock`].
+    ///
+    /// The queue behaves exactly like one created with [`new`], except that
+    /// every read of the current time goes through `clock` instead of
+    /// [`tokio::time::Instant::now`]. This allows timer-heavy utilities built
+    /// on `DelayQueue` to be tested deterministically with a [`ManualClock`],
+    /// even outside a paused tokio runtime.
+    ///
+    /// Note that [`poll_expired`] still registers its wakeups with the tokio
+    /// timer, so after advancing a manual clock the queue must be polled (or
+    /// drained with [`drain_expired`]) for the new time to be observed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tokio::time::{Duration, Instant};
+    /// use tokio_util::time::{DelayQueue, ManualClock};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let clock = ManualClock::new(Instant::now());
+    /// let mut delay_queue = DelayQueue::with_clock(clock.clone());
+    ///
+    /// delay_queue.insert("foo", Duration::from_secs(5));
+    ///
+    /// assert_eq!(delay_queue.drain_expired().count(), 0);
+    ///
+    /// clock.advance(Duration::from_secs(5));
+    ///
+    /// let expired: Vec<_> = delay_queue
+    ///     .drain_expired()
+    ///     .map(|entry| entry.into_inner())
+    ///     .collect();
+    /// assert_eq!(expired, ["foo"]);
+    /// # }
+    /// ```
+    ///
+    /// [`new`]: DelayQueue::new
+    /// [`ManualClock`]: crate::time::ManualClock
+    /// [`poll_expired`]: method@Self::poll_expired
+    /// [`drain_expired`]: method@Self::drain_expired
+    pub fn with_clock(clock: C) -> DelayQueue<T, C> {
+        DelayQueue::with_capacity_and_clock(0, clock)
+    }
+
+    /// Creates a new, empty, `DelayQueue` with the specified capacity, reading
+    /// time from the given [`Clock`].
+    ///
+    /// See [`with_clock`] and [`with_capacity`] for more details.
+    ///
+    /// [`with_clock`]: DelayQueue::with_clock
+    /// [`with_capacity`]: DelayQueue::with_capacity
+    pub fn with_capacity_and_clock(capacity: usize, clock: C) -> DelayQueue<T, C> {
         DelayQueue {
             wheel: Wheel::new(),
             slab: SlabStorage::with_capacity(capacity),
             expired: Stack::default(),
             delay: None,
             wheel_now: 0,
-            start: Instant::now(),
+            start: clock.now(),
             waker: None,
+            clock,
         }
     }
 
@@ -650,7 +714,7 @@ impl<T> DelayQueue<T> {
     /// [type]: #
     #[track_caller]
     pub fn insert(&mut self, value: T, timeout: Duration) -> Key {
-        self.insert_at(value, Instant::now() + timeout)
+        self.insert_at(value, self.clock.now() + timeout)
     }
 
     #[track_caller]
@@ -702,6 +766,67 @@ impl<T> DelayQueue<T> {
         self.start + Duration::from_millis(self.slab[*key].when)
     }
 
+    /// Returns a reference to the value associated with `key`.
+    ///
+    /// Returns `None` if `key` is not contained by the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage
+    ///
+    /// ```rust
+    /// use tokio_util::time::DelayQueue;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut delay_queue = DelayQueue::new();
+    /// let key = delay_queue.insert("foo", Duration::from_secs(5));
+    ///
+    /// assert_eq!(delay_queue.get_ref(&key), Some(&"foo"));
+    ///
+    /// delay_queue.remove(&key);
+    /// assert_eq!(delay_queue.get_ref(&key), None);
+    /// # }
+    /// ```
+    pub fn get_ref(&self, key: &Key) -> Option<&T> {
+        if self.slab.contains(key) {
+            Some(&self.slab[*key].inner)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the value associated with `key`.
+    ///
+    /// Returns `None` if `key` is not contained by the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage
+    ///
+    /// ```rust
+    /// use tokio_util::time::DelayQueue;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut delay_queue = DelayQueue::new();
+    /// let key = delay_queue.insert("foo".to_string(), Duration::from_secs(5));
+    ///
+    /// delay_queue.get_mut(&key).unwrap().push_str("bar");
+    ///
+    /// assert_eq!(delay_queue.remove(&key).into_inner(), "foobar");
+    /// # }
+    /// ```
+    pub fn get_mut(&mut self, key: &Key) -> Option<&mut T> {
+        if self.slab.contains(key) {
+            Some(&mut self.slab[*key].inner)
+        } else {
+            None
+        }
+    }
+
     /// Removes the key from the expired queue or the timer wheel
     /// depending on its expiration status.
     ///
@@ -946,6 +1071,91 @@ impl<T> DelayQueue<T> {
         self.expired.peek().or_else(|| self.wheel.peek())
     }
 
+    /// Returns the deadline of the item that [`peek`] returns, that is, the
+    /// deadline of the item that [`poll_expired`] will pull out of the queue
+    /// next.
+    ///
+    /// Since the queue operates at millisecond granularity, the returned
+    /// deadline may not exactly match the value that was given when initially
+    /// inserting the item into the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage
+    ///
+    /// ```rust
+    /// use tokio_util::time::DelayQueue;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut delay_queue = DelayQueue::new();
+    /// assert!(delay_queue.peek_deadline().is_none());
+    ///
+    /// let key1 = delay_queue.insert("foo", Duration::from_secs(10));
+    /// let key2 = delay_queue.insert("bar", Duration::from_secs(5));
+    ///
+    /// assert_eq!(delay_queue.peek_deadline(), Some(delay_queue.deadline(&key2)));
+    /// # }
+    /// ```
+    ///
+    /// [`peek`]: method@Self::peek
+    /// [`poll_expired`]: method@Self::poll_expired
+    pub fn peek_deadline(&self) -> Option<Instant> {
+        self.peek().map(|key| self.deadline(&key))
+    }
+
+    /// Removes all items that have already expired and returns them in a
+    /// draining iterator.
+    ///
+    /// Unlike [`poll_expired`], this does not require a task context, and it
+    /// does not wait: only items whose deadline has already passed are
+    /// returned, in the same order in which [`poll_expired`] would have
+    /// returned them. Items that have not yet expired remain in the queue,
+    /// even if the iterator is dropped before being fully consumed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage
+    ///
+    /// ```rust
+    /// use tokio_util::time::DelayQueue;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let mut delay_queue = DelayQueue::new();
+    ///
+    /// delay_queue.insert("foo", Duration::ZERO);
+    /// delay_queue.insert("bar", Duration::from_secs(10));
+    ///
+    /// // Give the zero-duration deadline a chance to elapse.
+    /// tokio::time::sleep(Duration::from_millis(1)).await;
+    ///
+    /// let expired: Vec<_> = delay_queue
+    ///     .drain_expired()
+    ///     .map(|entry| entry.into_inner())
+    ///     .collect();
+    ///
+    /// assert_eq!(expired, ["foo"]);
+    /// assert_eq!(delay_queue.len(), 1);
+    /// # }
+    /// ```
+    ///
+    /// [`poll_expired`]: method@Self::poll_expired
+    pub fn drain_expired(&mut self) -> DrainExpired<'_, T, C> {
+        let now = if self.clock.now() < self.start {
+            0
+        } else {
+            crate::time::ms(self.clock.now() - self.start, crate::time::Round::Down)
+        };
+
+        // The wheel cannot be polled backwards.
+        let now = cmp::max(now, self.wheel.elapsed());
+
+        DrainExpired { queue: self, now }
+    }
+
     /// Returns the next time to poll as determined by the wheel.
     ///
     /// Note that this does not include deadlines in the `expired` queue.
@@ -992,7 +1202,7 @@ impl<T> DelayQueue<T> {
     /// ```
     #[track_caller]
     pub fn reset(&mut self, key: &Key, timeout: Duration) {
-        self.reset_at(key, Instant::now() + timeout);
+        self.reset_at(key, self.clock.now() + timeout);
     }
 
     /// Clears the queue, removing all items.
@@ -1179,8 +1389,69 @@ impl<T> DelayQueue<T> {
     }
 }
 
+/// A draining iterator over the expired entries of a [`DelayQueue`].
+///
+/// This struct is created by [`DelayQueue::drain_expired`].
+#[derive(Debug)]
+pub struct DrainExpired<'a, T, C: Clock = TokioClock> {
+    queue: &'a mut DelayQueue<T, C>,
+
+    /// The queue-relative timestamp used as "now" for the entire drain.
+    now: u64,
+}
+
+impl<T, C: Clock> Iterator for DrainExpired<'_, T, C> {
+    type Item = Expired<T>;
+
+    fn next(&mut self) -> Option<Expired<T>> {
+        use self::wheel::Stack;
+
+        let key = self
+            .queue
+            .expired
+            .pop(&mut self.queue.slab)
+            .or_else(|| self.queue.wheel.poll(self.now, &mut self.queue.slab))?;
+
+        let data = self.queue.slab.remove(&key);
+        debug_assert!(data.next.is_none());
+        debug_assert!(data.prev.is_none());
+
+        Some(Expired {
+            key,
+            data: data.inner,
+            deadline: self.queue.start + Duration::from_millis(data.when),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.queue.slab.len()))
+    }
+}
+
+impl<T, C: Clock> std::iter::FusedIterator for DrainExpired<'_, T, C> {}
+
+impl<T, C: Clock> Drop for DrainExpired<'_, T, C> {
+    fn drop(&mut self) {
+        // The timer may still be set for an entry that was just drained, so
+        // bring it back in sync with the remaining entries.
+        match (self.queue.next_deadline(), &mut self.queue.delay) {
+            (Some(deadline), Some(delay)) => delay.as_mut().reset(deadline),
+            // No task is parked on the timer, so it can be created lazily by
+            // the next call to `poll_expired`.
+            (Some(_), None) => {}
+            (None, delay) => *delay = None,
+        }
+
+        if self.queue.slab.is_empty() {
+            if let Some(waker) = self.queue.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
 // We never put `T` in a `Pin`...
-impl<T> Unpin for DelayQueue<T> {}
+impl<T, C> Unpin for DelayQueue<T, C> {}
 
 impl<T> Default for DelayQueue<T> {
     fn default() -> DelayQueue<T> {
@@ -1188,7 +1459,7 @@ impl<T> Default for DelayQueue<T> {
     }
 }
 
-impl<T> futures_core::Stream for DelayQueue<T> {
+impl<T, C: Clock> futures_core::Stream for DelayQueue<T, C> {
     // DelayQueue seems much more specific, where a user may care that it
     // has reached capacity, so return those errors instead of panicking.
     type Item = Expired<T>;
@@ -12,6 +12,9 @@ use std::time::Duration;
 
 mod wheel;
 
+mod clock;
+pub use clock::{Clock, ManualClock, TokioClock};
+
 pub mod delay_queue;
 
 // re-export `FutureExt` to avoid breaking change
@@ -19,7 +22,7 @@ pub mod delay_queue;
 pub use crate::future::FutureExt;
 
 #[doc(inline)]
-pub use delay_queue::DelayQueue;
+pub use delay_queue::{DelayQueue, DrainExpired};
 
 // ===== Internal utils =====
 
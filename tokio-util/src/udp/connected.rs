@@ -0,0 +1,235 @@
+use crate::codec::{Decoder, Encoder};
+use crate::udp::frame::{INITIAL_RD_CAPACITY, INITIAL_WR_CAPACITY};
+
+use futures_core::Stream;
+use tokio::{io::ReadBuf, net::UdpSocket};
+
+use bytes::{BufMut, BytesMut};
+use futures_sink::Sink;
+use std::borrow::Borrow;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+/// A [`UdpFramed`] variant for sockets that have been [`connect`]ed to a
+/// single remote address.
+///
+/// Because the peer is fixed, the [`Stream`] side yields bare frames and the
+/// [`Sink`] side accepts bare frames, with no per-datagram address handling.
+/// Datagrams are sent and received with [`poll_send`] and [`poll_recv`], which
+/// lets the operating system skip the address lookup performed by their
+/// `_to`/`_from` counterparts.
+///
+/// The socket must already be connected when it is passed to [`new`];
+/// otherwise every send fails with an error equivalent to
+/// [`ErrorKind::NotConnected`].
+///
+/// [`UdpFramed`]: crate::udp::UdpFramed
+/// [`connect`]: tokio::net::UdpSocket::connect
+/// [`Stream`]: futures_core::Stream
+/// [`Sink`]: futures_sink::Sink
+/// [`poll_send`]: tokio::net::UdpSocket::poll_send
+/// [`poll_recv`]: tokio::net::UdpSocket::poll_recv
+/// [`new`]: UdpFramedConnected::new
+/// [`ErrorKind::NotConnected`]: std::io::ErrorKind::NotConnected
+#[must_use = "sinks do nothing unless polled"]
+#[derive(Debug)]
+pub struct UdpFramedConnected<C, T = UdpSocket> {
+    socket: T,
+    codec: C,
+    rd: BytesMut,
+    wr: BytesMut,
+    flushed: bool,
+    is_readable: bool,
+}
+
+impl<C, T> Unpin for UdpFramedConnected<C, T> {}
+
+impl<C, T> Stream for UdpFramedConnected<C, T>
+where
+    T: Borrow<UdpSocket>,
+    C: Decoder,
+{
+    type Item = Result<C::Item, C::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let pin = self.get_mut();
+
+        pin.rd.reserve(INITIAL_RD_CAPACITY);
+
+        loop {
+            // Are there still bytes left in the read buffer to decode?
+            if pin.is_readable {
+                if let Some(frame) = pin.codec.decode_eof(&mut pin.rd)? {
+                    return Poll::Ready(Some(Ok(frame)));
+                }
+
+                // if this line has been reached then decode has returned `None`.
+                pin.is_readable = false;
+                pin.rd.clear();
+            }
+
+            // We're out of data. Try and fetch more data to decode
+            {
+                // Safety: `chunk_mut()` returns a `&mut UninitSlice`, and `UninitSlice` is a
+                // transparent wrapper around `[MaybeUninit<u8>]`.
+                let buf = unsafe { pin.rd.chunk_mut().as_uninit_slice_mut() };
+                let mut read = ReadBuf::uninit(buf);
+                let ptr = read.filled().as_ptr();
+                ready!(pin.socket.borrow().poll_recv(cx, &mut read))?;
+
+                assert_eq!(ptr, read.filled().as_ptr());
+
+                let filled = read.filled().len();
+                // Safety: This is guaranteed to be the number of initialized (and read) bytes due
+                // to the invariants provided by `ReadBuf::filled`.
+                unsafe { pin.rd.advance_mut(filled) };
+            }
+
+            pin.is_readable = true;
+        }
+    }
+}
+
+impl<I, C, T> Sink<I> for UdpFramedConnected<C, T>
+where
+    T: Borrow<UdpSocket>,
+    C: Encoder<I>,
+{
+    type Error = C::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if !self.flushed {
+            match self.poll_flush(cx)? {
+                Poll::Ready(()) => {}
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: I) -> Result<(), Self::Error> {
+        let pin = self.get_mut();
+
+        pin.codec.encode(item, &mut pin.wr)?;
+        pin.flushed = false;
+
+        Ok(())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.flushed {
+            return Poll::Ready(Ok(()));
+        }
+
+        let Self {
+            ref socket,
+            ref mut wr,
+            ..
+        } = *self;
+
+        let n = ready!(socket.borrow().poll_send(cx, wr))?;
+
+        let wrote_all = n == self.wr.len();
+        self.wr.clear();
+        self.flushed = true;
+
+        let res = if wrote_all {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                "failed to write entire datagram to socket",
+            )
+            .into())
+        };
+
+        Poll::Ready(res)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        ready!(self.poll_flush(cx))?;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<C, T> UdpFramedConnected<C, T>
+where
+    T: Borrow<UdpSocket>,
+{
+    /// Create a new `UdpFramedConnected` backed by the given socket and codec.
+    ///
+    /// The socket should already be connected with [`UdpSocket::connect`].
+    ///
+    /// See struct level documentation for more details.
+    ///
+    /// [`UdpSocket::connect`]: tokio::net::UdpSocket::connect
+    pub fn new(socket: T, codec: C) -> UdpFramedConnected<C, T> {
+        Self {
+            socket,
+            codec,
+            rd: BytesMut::with_capacity(INITIAL_RD_CAPACITY),
+            wr: BytesMut::with_capacity(INITIAL_WR_CAPACITY),
+            flushed: true,
+            is_readable: false,
+        }
+    }
+
+    /// Returns a reference to the underlying I/O stream wrapped by
+    /// `UdpFramedConnected`.
+    ///
+    /// # Note
+    ///
+    /// Care should be taken to not tamper with the underlying stream of data
+    /// coming in as it may corrupt the stream of frames otherwise being worked
+    /// with.
+    pub fn get_ref(&self) -> &T {
+        &self.socket
+    }
+
+    /// Returns a mutable reference to the underlying I/O stream wrapped by
+    /// `UdpFramedConnected`.
+    ///
+    /// # Note
+    ///
+    /// Care should be taken to not tamper with the underlying stream of data
+    /// coming in as it may corrupt the stream of frames otherwise being worked
+    /// with.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.socket
+    }
+
+    /// Returns a reference to the underlying codec wrapped by
+    /// `UdpFramedConnected`.
+    ///
+    /// Note that care should be taken to not tamper with the underlying codec
+    /// as it may corrupt the stream of frames otherwise being worked with.
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Returns a mutable reference to the underlying codec wrapped by
+    /// `UdpFramedConnected`.
+    ///
+    /// Note that care should be taken to not tamper with the underlying codec
+    /// as it may corrupt the stream of frames otherwise being worked with.
+    pub fn codec_mut(&mut self) -> &mut C {
+        &mut self.codec
+    }
+
+    /// Returns a reference to the read buffer.
+    pub fn read_buffer(&self) -> &BytesMut {
+        &self.rd
+    }
+
+    /// Returns a mutable reference to the read buffer.
+    pub fn read_buffer_mut(&mut self) -> &mut BytesMut {
+        &mut self.rd
+    }
+
+    /// Consumes the `UdpFramedConnected`, returning its underlying I/O stream.
+    pub fn into_inner(self) -> T {
+        self.socket
+    }
+}
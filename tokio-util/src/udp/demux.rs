@@ -0,0 +1,180 @@
+use crate::codec::Decoder;
+use crate::udp::UdpFramed;
+
+use futures_core::Stream;
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc;
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+/// Routes frames decoded from a shared [`UdpFramed`] to per-peer channels.
+///
+/// A single UDP socket often serves many peers at once, with each peer handled
+/// by its own task — the shape of a QUIC or DTLS server. `UdpFramedDemux`
+/// drives the [`Stream`] side of a [`UdpFramed`] and forwards each decoded
+/// frame to the channel registered for its source address with
+/// [`register_peer`]. Frames from addresses without a registered channel are
+/// yielded from [`poll_unclaimed`] (or the [`Stream`] implementation), which
+/// is where new connections are first observed.
+///
+/// The demux must be polled for any peer to make progress, since all frames
+/// arrive through the shared socket. If a peer channel is full, frames for
+/// that peer are dropped, just as the operating system drops datagrams when a
+/// socket's receive buffer overflows. If a peer channel is closed, the peer is
+/// deregistered and its frames become unclaimed again.
+///
+/// [`Stream`]: futures_core::Stream
+/// [`register_peer`]: UdpFramedDemux::register_peer
+/// [`poll_unclaimed`]: UdpFramedDemux::poll_unclaimed
+#[must_use = "streams do nothing unless polled"]
+pub struct UdpFramedDemux<C: Decoder, T = UdpSocket> {
+    framed: UdpFramed<C, T>,
+    peers: HashMap<SocketAddr, mpsc::Sender<C::Item>>,
+    capacity: usize,
+}
+
+impl<C: Decoder, T> Unpin for UdpFramedDemux<C, T> {}
+
+impl<C, T> UdpFramedDemux<C, T>
+where
+    T: Borrow<UdpSocket>,
+    C: Decoder,
+{
+    /// Create a new `UdpFramedDemux` wrapping the given [`UdpFramed`].
+    ///
+    /// Each registered peer gets a channel buffering up to `capacity` frames;
+    /// frames beyond that are dropped until the peer catches up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(framed: UdpFramed<C, T>, capacity: usize) -> UdpFramedDemux<C, T> {
+        assert!(capacity > 0, "capacity must be a positive number");
+        Self {
+            framed,
+            peers: HashMap::new(),
+            capacity,
+        }
+    }
+
+    /// Register a peer address, returning the receiving half of its channel.
+    ///
+    /// Subsequent frames decoded from datagrams sent by `addr` are forwarded
+    /// to the returned receiver instead of being yielded as unclaimed. If the
+    /// address was already registered, the previous channel is replaced and
+    /// its receiver stops receiving frames.
+    pub fn register_peer(&mut self, addr: SocketAddr) -> mpsc::Receiver<C::Item> {
+        let (tx, rx) = mpsc::channel(self.capacity);
+        self.peers.insert(addr, tx);
+        rx
+    }
+
+    /// Deregister a peer address, returning whether it was registered.
+    ///
+    /// Subsequent frames from `addr` are yielded as unclaimed again.
+    pub fn deregister_peer(&mut self, addr: &SocketAddr) -> bool {
+        self.peers.remove(addr).is_some()
+    }
+
+    /// Returns the number of currently registered peers.
+    pub fn peer_count(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Poll for the next frame from an address with no registered channel.
+    ///
+    /// Frames from registered peers are forwarded to their channels as a side
+    /// effect of this call, so a server accepting new connections can drive
+    /// the entire demux by polling this method alone.
+    ///
+    /// This can return the following values:
+    ///
+    ///  - `Poll::Pending` if no datagram is currently available.
+    ///  - `Poll::Ready(Some(Ok((frame, addr))))` for a frame from an
+    ///    unregistered address.
+    ///  - `Poll::Ready(Some(Err(e)))` if the socket or the decoder failed.
+    ///
+    /// When this method returns `Poll::Pending`, the current task is scheduled
+    /// to receive a wakeup when a datagram arrives.
+    #[allow(clippy::type_complexity)]
+    pub fn poll_unclaimed(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<(C::Item, SocketAddr), C::Error>>> {
+        loop {
+            let (frame, addr) = match ready!(Pin::new(&mut self.framed).poll_next(cx)) {
+                Some(Ok(pair)) => pair,
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            };
+
+            match self.peers.get(&addr) {
+                Some(tx) => match tx.try_send(frame) {
+                    Ok(()) => {}
+                    // The peer is not keeping up; drop the frame like the
+                    // operating system drops datagrams on a full receive
+                    // buffer.
+                    Err(mpsc::error::TrySendError::Full(_)) => {}
+                    Err(mpsc::error::TrySendError::Closed(frame)) => {
+                        self.peers.remove(&addr);
+                        return Poll::Ready(Some(Ok((frame, addr))));
+                    }
+                },
+                None => return Poll::Ready(Some(Ok((frame, addr)))),
+            }
+        }
+    }
+
+    /// Returns a reference to the underlying `UdpFramed`.
+    pub fn get_ref(&self) -> &UdpFramed<C, T> {
+        &self.framed
+    }
+
+    /// Returns a mutable reference to the underlying `UdpFramed`.
+    ///
+    /// # Note
+    ///
+    /// Care should be taken to not tamper with the underlying stream of data
+    /// coming in as it may corrupt the stream of frames otherwise being worked
+    /// with.
+    pub fn get_mut(&mut self) -> &mut UdpFramed<C, T> {
+        &mut self.framed
+    }
+
+    /// Consumes the `UdpFramedDemux`, returning its underlying `UdpFramed`.
+    ///
+    /// Registered peer channels are closed.
+    pub fn into_inner(self) -> UdpFramed<C, T> {
+        self.framed
+    }
+}
+
+impl<C, T> Stream for UdpFramedDemux<C, T>
+where
+    T: Borrow<UdpSocket>,
+    C: Decoder,
+{
+    type Item = Result<(C::Item, SocketAddr), C::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_unclaimed(cx)
+    }
+}
+
+impl<C, T> fmt::Debug for UdpFramedDemux<C, T>
+where
+    C: Decoder + fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UdpFramedDemux")
+            .field("framed", &self.framed)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
@@ -46,8 +46,8 @@ pub struct UdpFramed<C, T = UdpSocket> {
     current_addr: Option<SocketAddr>,
 }
 
-const INITIAL_RD_CAPACITY: usize = 64 * 1024;
-const INITIAL_WR_CAPACITY: usize = 8 * 1024;
+pub(super) const INITIAL_RD_CAPACITY: usize = 64 * 1024;
+pub(super) const INITIAL_WR_CAPACITY: usize = 8 * 1024;
 
 impl<C, T> Unpin for UdpFramed<C, T> {}
 
@@ -1,4 +1,10 @@
 //! UDP framing
 
+mod connected;
+pub use connected::UdpFramedConnected;
+
+mod demux;
+pub use demux::UdpFramedDemux;
+
 mod frame;
 pub use frame::UdpFramed;
@@ -0,0 +1,134 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "json-codec")]
+
+use futures::SinkExt;
+use serde::{Deserialize, Serialize};
+use tokio_stream::StreamExt;
+use tokio_util::codec::{
+    Decoder, Encoder, FramedRead, FramedWrite, Json, JsonLinesCodec, JsonLinesCodecError,
+    SerdeCodec,
+};
+
+use bytes::BytesMut;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Message {
+    id: u32,
+    body: String,
+}
+
+#[tokio::test]
+async fn json_lines_read() {
+    let io: &[u8] = b"{\"id\":1,\"body\":\"a\"}\n{\"id\":2,\"body\":\"b\"}\n";
+    let mut reader = FramedRead::new(io, JsonLinesCodec::<Message>::new());
+
+    assert_eq!(
+        reader.next().await.unwrap().unwrap(),
+        Message {
+            id: 1,
+            body: "a".to_string()
+        }
+    );
+    assert_eq!(
+        reader.next().await.unwrap().unwrap(),
+        Message {
+            id: 2,
+            body: "b".to_string()
+        }
+    );
+    assert!(reader.next().await.is_none());
+}
+
+#[tokio::test]
+async fn json_lines_read_last_line_without_newline() {
+    let io: &[u8] = b"{\"id\":1,\"body\":\"a\"}";
+    let mut reader = FramedRead::new(io, JsonLinesCodec::<Message>::new());
+
+    assert_eq!(reader.next().await.unwrap().unwrap().id, 1);
+    assert!(reader.next().await.is_none());
+}
+
+#[tokio::test]
+async fn json_lines_write() {
+    let mut writer = FramedWrite::new(Vec::new(), JsonLinesCodec::<Message>::new());
+
+    writer
+        .send(Message {
+            id: 1,
+            body: "a".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(writer.get_ref().as_slice(), b"{\"id\":1,\"body\":\"a\"}\n");
+}
+
+#[tokio::test]
+async fn json_lines_invalid_json_is_an_error() {
+    let io: &[u8] = b"not json\n";
+    let mut reader = FramedRead::new(io, JsonLinesCodec::<Message>::new());
+
+    assert!(matches!(
+        reader.next().await.unwrap(),
+        Err(JsonLinesCodecError::Json(_))
+    ));
+}
+
+#[tokio::test]
+async fn json_lines_max_length() {
+    let io: &[u8] = b"{\"id\":1,\"body\":\"aaaaaaaaaaaaaaaa\"}\n";
+    let mut reader = FramedRead::new(io, JsonLinesCodec::<Message>::new_with_max_length(8));
+
+    assert!(matches!(
+        reader.next().await.unwrap(),
+        Err(JsonLinesCodecError::MaxLineLengthExceeded)
+    ));
+}
+
+#[test]
+fn serde_codec_round_trip() {
+    let mut codec: SerdeCodec<Message, Json> = SerdeCodec::new(Json);
+
+    let message = Message {
+        id: 7,
+        body: "hello".to_string(),
+    };
+
+    let mut buf = BytesMut::new();
+    codec.encode(message.clone(), &mut buf).unwrap();
+
+    assert_eq!(codec.decode(&mut buf).unwrap(), Some(message));
+    assert_eq!(codec.decode(&mut buf).unwrap(), None);
+}
+
+#[test]
+fn serde_codec_partial_frame() {
+    let mut codec: SerdeCodec<Message, Json> = SerdeCodec::new(Json);
+
+    let mut buf = BytesMut::new();
+    codec
+        .encode(
+            Message {
+                id: 7,
+                body: "hello".to_string(),
+            },
+            &mut buf,
+        )
+        .unwrap();
+
+    // Only feed part of the frame; the codec must wait for the rest.
+    let rest = buf.split_off(5);
+    assert_eq!(codec.decode(&mut buf).unwrap(), None);
+
+    buf.unsplit(rest);
+    assert_eq!(codec.decode(&mut buf).unwrap().unwrap().id, 7);
+}
+
+#[test]
+fn serde_codec_invalid_payload_is_an_error() {
+    let mut codec: SerdeCodec<Message, Json> = SerdeCodec::new(Json);
+
+    // A length-delimited frame whose payload is not valid JSON.
+    let mut buf = BytesMut::from(&b"\x00\x00\x00\x03abc"[..]);
+    assert!(codec.decode(&mut buf).is_err());
+}
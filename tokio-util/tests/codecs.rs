@@ -1,6 +1,8 @@
 #![warn(rust_2018_idioms)]
 
-use tokio_util::codec::{AnyDelimiterCodec, BytesCodec, Decoder, Encoder, LinesCodec};
+use tokio_util::codec::{
+    AnyDelimiterCodec, BytesCodec, CobsCodec, Decoder, Encoder, LinesCodec, SlipCodec,
+};
 
 use bytes::{BufMut, Bytes, BytesMut};
 
@@ -441,3 +443,128 @@ fn any_delimiter_encoder() {
     codec.encode("chunk 2", &mut buf).unwrap();
     assert_eq!("chunk 1;--;chunk 2;--;", buf);
 }
+
+#[test]
+fn slip_round_trip() {
+    let mut codec = SlipCodec::new();
+    let mut buf = BytesMut::new();
+
+    // Payload containing both bytes that need escaping.
+    let payload = Bytes::from_static(&[0x01, 0xC0, 0x02, 0xDB, 0x03]);
+    codec.encode(payload.clone(), &mut buf).unwrap();
+    assert_eq!(&buf[..], &[0x01, 0xDB, 0xDC, 0x02, 0xDB, 0xDD, 0x03, 0xC0]);
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, payload);
+    assert_eq!(None, codec.decode(&mut buf).unwrap());
+}
+
+#[test]
+fn slip_skips_empty_frames() {
+    let mut codec = SlipCodec::new();
+    let buf = &mut BytesMut::new();
+
+    // A leading END to flush line noise, then a frame, then two more ENDs.
+    buf.put_slice(&[0xC0, b'h', b'i', 0xC0, 0xC0, 0xC0]);
+    assert_eq!("hi", codec.decode(buf).unwrap().unwrap());
+    assert_eq!(None, codec.decode(buf).unwrap());
+}
+
+#[test]
+fn slip_partial_frame() {
+    let mut codec = SlipCodec::new();
+    let buf = &mut BytesMut::new();
+
+    buf.put_slice(b"abc");
+    assert_eq!(None, codec.decode(buf).unwrap());
+    buf.put_slice(&[b'd', 0xC0]);
+    assert_eq!("abcd", codec.decode(buf).unwrap().unwrap());
+}
+
+#[test]
+fn slip_malformed_escape_resyncs() {
+    let mut codec = SlipCodec::new();
+    let buf = &mut BytesMut::new();
+
+    // ESC followed by a byte that is neither ESC_END nor ESC_ESC.
+    buf.put_slice(&[0xDB, 0x01, 0xC0, b'o', b'k', 0xC0]);
+    assert!(codec.decode(buf).is_err());
+    assert_eq!("ok", codec.decode(buf).unwrap().unwrap());
+}
+
+#[test]
+fn slip_max_frame_length() {
+    let mut codec = SlipCodec::new_with_max_length(4);
+    let buf = &mut BytesMut::new();
+
+    buf.put_slice(b"toolong");
+    assert!(codec.decode(buf).is_err());
+
+    // The rest of the oversized frame is discarded up to the delimiter.
+    buf.put_slice(&[b'x', 0xC0, b'o', b'k', 0xC0]);
+    assert_eq!("ok", codec.decode(buf).unwrap().unwrap());
+}
+
+#[test]
+fn cobs_round_trip() {
+    let mut codec = CobsCodec::new();
+    let mut buf = BytesMut::new();
+
+    // Payload containing zero bytes.
+    let payload = Bytes::from_static(&[0x01, 0x00, 0x02, 0x03, 0x00]);
+    codec.encode(payload.clone(), &mut buf).unwrap();
+    assert_eq!(&buf[..], &[0x02, 0x01, 0x03, 0x02, 0x03, 0x01, 0x00]);
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, payload);
+    assert_eq!(None, codec.decode(&mut buf).unwrap());
+}
+
+#[test]
+fn cobs_round_trip_long_block() {
+    let mut codec = CobsCodec::new();
+    let mut buf = BytesMut::new();
+
+    // More than 254 bytes without a zero exercises the 0xFF code blocks.
+    let payload = Bytes::from(vec![0xAA; 300]);
+    codec.encode(payload.clone(), &mut buf).unwrap();
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, payload);
+}
+
+#[test]
+fn cobs_partial_frame() {
+    let mut codec = CobsCodec::new();
+    let buf = &mut BytesMut::new();
+
+    buf.put_slice(&[0x03, b'h', b'i']);
+    assert_eq!(None, codec.decode(buf).unwrap());
+    buf.put_slice(&[0x00]);
+    assert_eq!("hi", codec.decode(buf).unwrap().unwrap());
+}
+
+#[test]
+fn cobs_malformed_frame_resyncs() {
+    let mut codec = CobsCodec::new();
+    let buf = &mut BytesMut::new();
+
+    // The code byte points past the end of the frame.
+    buf.put_slice(&[0x05, b'h', b'i', 0x00]);
+    buf.put_slice(&[0x03, b'o', b'k', 0x00]);
+    assert!(codec.decode(buf).is_err());
+    assert_eq!("ok", codec.decode(buf).unwrap().unwrap());
+}
+
+#[test]
+fn cobs_max_frame_length() {
+    let mut codec = CobsCodec::new_with_max_length(4);
+    let buf = &mut BytesMut::new();
+
+    buf.put_slice(&[0x08, b't', b'o', b'o', b'l', b'o', b'n', b'g']);
+    assert!(codec.decode(buf).is_err());
+
+    // The rest of the oversized frame is discarded up to the delimiter.
+    buf.put_slice(&[0x00, 0x03, b'o', b'k', 0x00]);
+    assert_eq!("ok", codec.decode(buf).unwrap().unwrap());
+}
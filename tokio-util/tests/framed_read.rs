@@ -311,6 +311,126 @@ fn read_eof_then_resume() {
     });
 }
 
+// A `U32Decoder` that rejects the value `0xDEAD_BEEF` without consuming it
+// and recovers by dropping the malformed frame.
+struct RecoveringU32Decoder;
+
+impl Decoder for RecoveringU32Decoder {
+    type Item = u32;
+    type Error = io::Error;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> io::Result<Option<u32>> {
+        if buf.len() < 4 {
+            return Ok(None);
+        }
+
+        let n = u32::from_be_bytes(buf[..4].try_into().unwrap());
+        if n == 0xDEAD_BEEF {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "bad frame"));
+        }
+
+        buf.advance(4);
+        Ok(Some(n))
+    }
+
+    fn recover(&mut self, buf: &mut BytesMut) {
+        // Drop the malformed frame and resume at the next one.
+        let n = std::cmp::min(4, buf.len());
+        buf.advance(n);
+    }
+}
+
+#[test]
+fn skip_to_next_frame_resumes_after_error() {
+    let mut task = task::spawn(());
+    let mock = mock! {
+        Ok(b"\x00\x00\x00\x01\xDE\xAD\xBE\xEF\x00\x00\x00\x02".to_vec()),
+    };
+    let mut framed = FramedRead::new(mock, RecoveringU32Decoder);
+
+    task.enter(|cx, _| {
+        assert_read!(pin!(framed).poll_next(cx), 1);
+        assert!(assert_ready!(pin!(framed).poll_next(cx)).unwrap().is_err());
+
+        // Resynchronize and keep decoding the rest of the stream.
+        framed.skip_to_next_frame();
+        assert_read!(pin!(framed).poll_next(cx), 2);
+        assert!(assert_ready!(pin!(framed).poll_next(cx)).is_none());
+    });
+}
+
+#[test]
+fn error_without_skip_still_terminates() {
+    let mut task = task::spawn(());
+    let mock = mock! {
+        Ok(b"\xDE\xAD\xBE\xEF\x00\x00\x00\x02".to_vec()),
+    };
+    let mut framed = FramedRead::new(mock, RecoveringU32Decoder);
+
+    task.enter(|cx, _| {
+        assert!(assert_ready!(pin!(framed).poll_next(cx)).unwrap().is_err());
+        assert!(assert_ready!(pin!(framed).poll_next(cx)).is_none());
+    });
+}
+
+#[test]
+fn skip_to_next_frame_before_error_is_noop() {
+    let mut task = task::spawn(());
+    let mock = mock! {
+        Ok(b"\x00\x00\x00\x01".to_vec()),
+    };
+    let mut framed = FramedRead::new(mock, RecoveringU32Decoder);
+
+    framed.skip_to_next_frame();
+
+    task.enter(|cx, _| {
+        assert_read!(pin!(framed).poll_next(cx), 1);
+        assert!(assert_ready!(pin!(framed).poll_next(cx)).is_none());
+    });
+}
+
+#[test]
+fn default_recover_clears_the_buffer() {
+    let mut task = task::spawn(());
+    struct StrictU32Decoder;
+
+    impl Decoder for StrictU32Decoder {
+        type Item = u32;
+        type Error = io::Error;
+
+        fn decode(&mut self, buf: &mut BytesMut) -> io::Result<Option<u32>> {
+            if buf.len() < 4 {
+                return Ok(None);
+            }
+
+            let n = u32::from_be_bytes(buf[..4].try_into().unwrap());
+            if n == 0xDEAD_BEEF {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "bad frame"));
+            }
+
+            buf.advance(4);
+            Ok(Some(n))
+        }
+    }
+
+    let mock = mock! {
+        Ok(b"\xDE\xAD\xBE\xEF\x00\x00\x00\x02".to_vec()),
+        Ok(b"\x00\x00\x00\x03".to_vec()),
+    };
+    let mut framed = FramedRead::new(mock, StrictU32Decoder);
+
+    task.enter(|cx, _| {
+        assert!(assert_ready!(pin!(framed).poll_next(cx)).unwrap().is_err());
+
+        // Without a `recover` override, everything buffered is dropped,
+        // including the frame behind the malformed one.
+        framed.skip_to_next_frame();
+        assert!(framed.read_buffer().is_empty());
+        assert_read!(pin!(framed).poll_next(cx), 3);
+        assert!(assert_ready!(pin!(framed).poll_next(cx)).is_none());
+    });
+}
+
 // ===== Mock ======
 
 struct Mock {
@@ -165,6 +165,72 @@ fn write_hits_backpressure() {
     })
 }
 
+#[test]
+fn poll_backpressure_stops_at_boundary() {
+    let mut task = task::spawn(());
+    let mock = mock! {
+        Ok(b"\x00\x00\x00\x00\x00\x00\x00\x01".to_vec()),
+    };
+    let mut framed = FramedWrite::new(mock, U32Encoder);
+
+    task.enter(|cx, _| {
+        for i in 0..4 {
+            assert!(assert_ready!(pin!(framed).poll_ready(cx)).is_ok());
+            assert!(pin!(framed).start_send(i as u32).is_ok());
+        }
+
+        framed.set_backpressure_boundary(9);
+
+        // The buffer holds 16 bytes; writing the first 8 brings it below
+        // the boundary, so the remaining data stays buffered.
+        assert!(assert_ready!(pin!(framed).poll_backpressure(cx)).is_ok());
+        assert_eq!(framed.write_buffer().len(), 8);
+        assert_eq!(0, framed.get_ref().calls.len());
+    });
+}
+
+#[test]
+fn poll_backpressure_ready_below_boundary() {
+    let mut task = task::spawn(());
+    let mock = mock!();
+    let mut framed = FramedWrite::new(mock, U32Encoder);
+
+    task.enter(|cx, _| {
+        assert!(assert_ready!(pin!(framed).poll_ready(cx)).is_ok());
+        assert!(pin!(framed).start_send(1).is_ok());
+
+        // The buffer is below the default boundary, so nothing is written.
+        assert!(assert_ready!(pin!(framed).poll_backpressure(cx)).is_ok());
+        assert_eq!(framed.write_buffer().len(), 4);
+    });
+}
+
+#[test]
+fn poll_backpressure_pending_until_writable() {
+    let mut task = task::spawn(());
+    let mock = mock! {
+        Err(io::Error::new(io::ErrorKind::WouldBlock, "not ready")),
+        Ok(b"\x00\x00\x00\x00\x00\x00\x00\x01".to_vec()),
+    };
+    let mut framed = FramedWrite::new(mock, U32Encoder);
+
+    task.enter(|cx, _| {
+        for i in 0..4 {
+            assert!(assert_ready!(pin!(framed).poll_ready(cx)).is_ok());
+            assert!(pin!(framed).start_send(i as u32).is_ok());
+        }
+
+        framed.set_backpressure_boundary(9);
+
+        // The transport blocks while the buffer is over the boundary.
+        assert!(pin!(framed).poll_backpressure(cx).is_pending());
+
+        // Once it accepts data again, the buffer drains below the boundary.
+        assert!(assert_ready!(pin!(framed).poll_backpressure(cx)).is_ok());
+        assert_eq!(framed.write_buffer().len(), 8);
+    });
+}
+
 // // ===== Mock ======
 
 struct Mock {
@@ -0,0 +1,139 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "gzip", feature = "zstd"))]
+
+use std::io::Write;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_util::io::{GzipReader, GzipWriter, ZstdReader, ZstdWriter};
+
+#[tokio::test]
+async fn gzip_round_trip() {
+    let mut writer = GzipWriter::new(Vec::new());
+    writer.write_all(b"hello world").await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    let compressed = writer.into_inner();
+    let mut reader = GzipReader::new(&compressed[..]);
+    let mut decompressed = Vec::new();
+    reader.read_to_end(&mut decompressed).await.unwrap();
+
+    assert_eq!(decompressed, b"hello world");
+}
+
+#[tokio::test]
+async fn gzip_large_round_trip() {
+    let data: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+
+    let mut writer = GzipWriter::with_level(Vec::new(), 9);
+    writer.write_all(&data).await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    let compressed = writer.into_inner();
+    assert!(compressed.len() < data.len());
+
+    let mut reader = GzipReader::new(&compressed[..]);
+    let mut decompressed = Vec::new();
+    reader.read_to_end(&mut decompressed).await.unwrap();
+
+    assert_eq!(decompressed, data);
+}
+
+#[tokio::test]
+async fn gzip_flush_makes_data_decodable() {
+    let mut writer = GzipWriter::new(Vec::new());
+    writer.write_all(b"hello").await.unwrap();
+    writer.flush().await.unwrap();
+
+    // The stream has no trailer yet, but everything written before the
+    // flush must already decode.
+    let flushed = writer.get_ref().clone();
+    let mut decoder = flate2::write::GzDecoder::new(Vec::new());
+    decoder.write_all(&flushed).unwrap();
+    decoder.flush().unwrap();
+    assert_eq!(decoder.get_ref(), b"hello");
+
+    // The stream can still be finished normally afterwards.
+    writer.write_all(b" world").await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    let compressed = writer.into_inner();
+    let mut reader = GzipReader::new(&compressed[..]);
+    let mut decompressed = Vec::new();
+    reader.read_to_end(&mut decompressed).await.unwrap();
+    assert_eq!(decompressed, b"hello world");
+}
+
+#[tokio::test]
+async fn gzip_truncated_stream_errors() {
+    let mut writer = GzipWriter::new(Vec::new());
+    writer.write_all(b"hello world").await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    let mut compressed = writer.into_inner();
+    compressed.truncate(compressed.len() - 4);
+
+    let mut reader = GzipReader::new(&compressed[..]);
+    let mut decompressed = Vec::new();
+    assert!(reader.read_to_end(&mut decompressed).await.is_err());
+}
+
+#[tokio::test]
+async fn zstd_round_trip() {
+    let mut writer = ZstdWriter::new(Vec::new()).unwrap();
+    writer.write_all(b"hello world").await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    let compressed = writer.into_inner();
+    let mut reader = ZstdReader::new(&compressed[..]).unwrap();
+    let mut decompressed = Vec::new();
+    reader.read_to_end(&mut decompressed).await.unwrap();
+
+    assert_eq!(decompressed, b"hello world");
+}
+
+#[tokio::test]
+async fn zstd_large_round_trip() {
+    let data: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+
+    let mut writer = ZstdWriter::with_level(Vec::new(), 3).unwrap();
+    writer.write_all(&data).await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    let compressed = writer.into_inner();
+    assert!(compressed.len() < data.len());
+
+    let mut reader = ZstdReader::new(&compressed[..]).unwrap();
+    let mut decompressed = Vec::new();
+    reader.read_to_end(&mut decompressed).await.unwrap();
+
+    assert_eq!(decompressed, data);
+}
+
+#[tokio::test]
+async fn zstd_flush_makes_data_decodable() {
+    let mut writer = ZstdWriter::new(Vec::new()).unwrap();
+    writer.write_all(b"hello").await.unwrap();
+    writer.flush().await.unwrap();
+
+    // The frame is unfinished, but everything written before the flush
+    // must already decode.
+    let flushed = writer.get_ref().clone();
+    let mut reader = ZstdReader::new(&flushed[..]).unwrap();
+    let mut decompressed = Vec::new();
+    reader.read_to_end(&mut decompressed).await.unwrap();
+    assert_eq!(decompressed, b"hello");
+}
+
+#[tokio::test]
+async fn gzip_corrupt_stream_errors() {
+    let mut writer = GzipWriter::new(Vec::new());
+    writer.write_all(b"hello world").await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    let mut compressed = writer.into_inner();
+    let mid = compressed.len() / 2;
+    compressed[mid] ^= 0xFF;
+
+    let mut reader = GzipReader::new(&compressed[..]);
+    let mut decompressed = Vec::new();
+    assert!(reader.read_to_end(&mut decompressed).await.is_err());
+}
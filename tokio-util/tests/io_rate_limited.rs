@@ -0,0 +1,85 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "io", feature = "time"))]
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::RateLimiter;
+use tokio::time::Instant;
+use tokio_util::io::{RateLimitedReader, RateLimitedWriter};
+
+#[tokio::test(start_paused = true)]
+async fn reader_limits_rate() {
+    let data = vec![7u8; 8192];
+
+    // 1 KiB/s with a burst of 1 KiB: 8 KiB takes seven seconds beyond the
+    // initial burst.
+    let mut reader = RateLimitedReader::with_burst(&data[..], 1024, 1024);
+    let start = Instant::now();
+    let mut out = Vec::new();
+    reader.read_to_end(&mut out).await.unwrap();
+
+    assert_eq!(out, data);
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_secs(7), "elapsed: {elapsed:?}");
+    assert!(elapsed < Duration::from_secs(8), "elapsed: {elapsed:?}");
+}
+
+#[tokio::test(start_paused = true)]
+async fn reader_burst_is_free() {
+    let data = vec![7u8; 1024];
+
+    let mut reader = RateLimitedReader::with_burst(&data[..], 1024, 2048);
+    let start = Instant::now();
+    let mut out = Vec::new();
+    reader.read_to_end(&mut out).await.unwrap();
+
+    assert_eq!(out, data);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn writer_limits_rate() {
+    let data = vec![7u8; 8192];
+
+    // Writes are split into 4 KiB chunks and billed afterwards, so the
+    // second chunk waits for the first to be paid off.
+    let mut writer = RateLimitedWriter::with_burst(Vec::new(), 1024, 1024);
+    let start = Instant::now();
+    writer.write_all(&data).await.unwrap();
+
+    assert_eq!(writer.get_ref(), &data);
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_secs(3), "elapsed: {elapsed:?}");
+    assert!(elapsed < Duration::from_secs(8), "elapsed: {elapsed:?}");
+}
+
+#[tokio::test(start_paused = true)]
+async fn shared_limiter_caps_combined_rate() {
+    let limiter = Arc::new(RateLimiter::with_burst(1024, Duration::from_secs(1), 1024));
+
+    let first = vec![1u8; 1024];
+    let second = vec![2u8; 1024];
+    let mut first = RateLimitedReader::with_limiter(&first[..], limiter.clone());
+    let mut second = RateLimitedReader::with_limiter(&second[..], limiter);
+
+    let start = Instant::now();
+    let mut out = Vec::new();
+    first.read_to_end(&mut out).await.unwrap();
+    second.read_to_end(&mut out).await.unwrap();
+
+    // The first reader consumed the shared burst, so the second had to
+    // wait for the quota to recover.
+    assert_eq!(out.len(), 2048);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn writer_passes_data_through() {
+    let mut writer = RateLimitedWriter::new(Vec::new(), 1024 * 1024);
+    writer.write_all(b"hello world").await.unwrap();
+    writer.flush().await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    assert_eq!(writer.into_inner(), b"hello world");
+}
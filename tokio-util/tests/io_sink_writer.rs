@@ -51,6 +51,38 @@ impl<'a> Encoder<&'a [u8]> for SliceEncoder {
     }
 }
 
+#[tokio::test]
+async fn test_sink_writer_flush_threshold() -> Result<(), Error> {
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<Bytes>(8);
+    let sink = CopyToBytes::new(
+        PollSender::new(tx).sink_map_err(|_| io::Error::from(ErrorKind::BrokenPipe)),
+    );
+    let mut writer = SinkWriter::with_flush_threshold(sink, 4);
+
+    // Writes below the threshold are coalesced into one item.
+    writer.write_all(&[1]).await?;
+    writer.write_all(&[2]).await?;
+    writer.write_all(&[3, 4]).await?;
+    assert_eq!(rx.recv().await.unwrap().to_vec(), vec![1, 2, 3, 4]);
+
+    // A write at least as large as the threshold is sent as-is.
+    writer.write_all(&[5, 6, 7, 8, 9]).await?;
+    assert_eq!(rx.recv().await.unwrap().to_vec(), vec![5, 6, 7, 8, 9]);
+
+    // Flushing sends out whatever is buffered.
+    writer.write_all(&[10]).await?;
+    writer.flush().await?;
+    assert_eq!(rx.recv().await.unwrap().to_vec(), vec![10]);
+
+    // So does shutting down.
+    writer.write_all(&[11]).await?;
+    writer.shutdown().await?;
+    assert_eq!(rx.recv().await.unwrap().to_vec(), vec![11]);
+    assert!(rx.recv().await.is_none());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_direct_sink_writer() -> Result<(), Error> {
     // We define a framed writer which accepts byte slices
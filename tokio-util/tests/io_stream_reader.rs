@@ -1,6 +1,7 @@
 #![warn(rust_2018_idioms)]
 
-use bytes::Bytes;
+use bytes::{Buf, Bytes};
+use std::fmt;
 use tokio::io::AsyncReadExt;
 use tokio_stream::iter;
 use tokio_util::io::StreamReader;
@@ -33,3 +34,63 @@ async fn test_stream_reader() -> std::io::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_stream_reader_segmented_chunks() -> std::io::Result<()> {
+    // A chain of two `Bytes` is a single `Buf` with two segments.
+    let stream = iter(vec![
+        std::io::Result::Ok(Bytes::from_static(&[0, 1]).chain(Bytes::from_static(&[2, 3]))),
+        Ok(Bytes::from_static(&[4, 5]).chain(Bytes::from_static(&[6, 7]))),
+    ]);
+
+    let mut read = StreamReader::new(stream);
+
+    // A single read crosses the segment boundary within a chunk.
+    let mut buf = [0; 3];
+    assert_eq!(read.read(&mut buf).await?, 3);
+    assert_eq!(buf, [0, 1, 2]);
+
+    // It does not cross into the next chunk.
+    let mut buf = [0; 5];
+    assert_eq!(read.read(&mut buf).await?, 1);
+    assert_eq!(buf[0], 3);
+
+    assert_eq!(read.read(&mut buf).await?, 4);
+    assert_eq!(&buf[..4], [4, 5, 6, 7]);
+
+    assert_eq!(read.read(&mut buf).await?, 0);
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct TestError(&'static str);
+
+impl fmt::Display for TestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for TestError {}
+
+#[tokio::test]
+async fn test_stream_reader_preserving_errors() -> std::io::Result<()> {
+    let stream = iter(vec![
+        Ok(Bytes::from_static(&[0, 1, 2, 3])),
+        Err(TestError("something bad happened")),
+    ]);
+
+    let mut read = StreamReader::new_preserving_errors(stream);
+
+    let mut buf = [0; 4];
+    read.read_exact(&mut buf).await?;
+    assert_eq!(buf, [0, 1, 2, 3]);
+
+    let error = read.read(&mut buf).await.unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::Other);
+    let original = error.get_ref().unwrap().downcast_ref::<TestError>();
+    assert_eq!(original, Some(&TestError("something bad happened")));
+
+    Ok(())
+}
@@ -2,9 +2,11 @@
 #![cfg(not(target_os = "wasi"))] // Wasi doesn't support threads
 
 use std::error::Error;
-use std::io::{Cursor, Read, Result as IoResult, Write};
+use std::io::{BufRead, Cursor, Read, Result as IoResult, Seek, SeekFrom, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use tokio::io::{AsyncRead, AsyncReadExt};
-use tokio_util::io::SyncIoBridge;
+use tokio_util::io::{InspectReader, InspectWriter, SyncIoBridge};
 
 async fn test_reader_len(
     r: impl AsyncRead + Unpin + Send + 'static,
@@ -55,6 +57,145 @@ async fn test_into_inner() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_buffered_read() -> Result<(), Box<dyn Error>> {
+    let data: Vec<u8> = (0..4096).map(|i| (i % 251) as u8).collect();
+    let reads = Arc::new(AtomicUsize::new(0));
+
+    let counter = reads.clone();
+    let reader = InspectReader::new(Cursor::new(data.clone()), move |_| {
+        counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let mut bridge = SyncIoBridge::with_capacity(reader, 1024);
+    let out = tokio::task::spawn_blocking(move || -> IoResult<_> {
+        // Many one-byte reads are served from the internal buffer.
+        let mut out = Vec::new();
+        let mut byte = [0u8; 1];
+        while bridge.read(&mut byte)? != 0 {
+            out.push(byte[0]);
+        }
+        Ok(out)
+    })
+    .await??;
+
+    assert_eq!(out, data);
+    // 4096 bytes in 1024-byte refills, plus the read reporting EOF.
+    assert_eq!(reads.load(Ordering::Relaxed), 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buffered_write() -> Result<(), Box<dyn Error>> {
+    let writes = Arc::new(AtomicUsize::new(0));
+
+    let counter = writes.clone();
+    let writer = InspectWriter::new(Vec::new(), move |_| {
+        counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let mut bridge = SyncIoBridge::with_capacity(writer, 1024);
+    let bridge = tokio::task::spawn_blocking(move || -> IoResult<_> {
+        // Many one-byte writes accumulate in the internal buffer.
+        for i in 0..4096u32 {
+            bridge.write_all(&[(i % 251) as u8])?;
+        }
+        bridge.flush()?;
+        Ok(bridge)
+    })
+    .await??;
+
+    let expected: Vec<u8> = (0..4096).map(|i| (i % 251) as u8).collect();
+    assert_eq!(bridge.into_inner().into_inner(), expected);
+    assert_eq!(writes.load(Ordering::Relaxed), 4);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buffered_read_write_seek() -> Result<(), Box<dyn Error>> {
+    let mut bridge = SyncIoBridge::with_capacity(Cursor::new(Vec::new()), 64);
+    tokio::task::spawn_blocking(move || -> IoResult<_> {
+        bridge.write_all(b"hello world")?;
+        bridge.flush()?;
+
+        bridge.seek(SeekFrom::Start(6))?;
+        let mut word = String::new();
+        bridge.read_to_string(&mut word)?;
+        assert_eq!(word, "world");
+
+        // A relative seek accounts for read-ahead into the buffer.
+        bridge.seek(SeekFrom::Start(0))?;
+        let mut byte = [0u8; 1];
+        bridge.read_exact(&mut byte)?;
+        bridge.seek(SeekFrom::Current(5))?;
+        let mut rest = String::new();
+        bridge.read_to_string(&mut rest)?;
+        assert_eq!(rest, "world");
+        Ok(())
+    })
+    .await??;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_seek_rejects_pending_writes() -> Result<(), Box<dyn Error>> {
+    let mut bridge = SyncIoBridge::with_capacity(Cursor::new(Vec::new()), 64);
+    tokio::task::spawn_blocking(move || -> IoResult<_> {
+        bridge.write_all(b"buffered")?;
+        // Seeking would replay the buffered bytes at the new position.
+        let err = bridge.seek(SeekFrom::Start(0)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+
+        // After a flush the seek goes through.
+        bridge.flush()?;
+        bridge.seek(SeekFrom::Start(0))?;
+        let mut contents = String::new();
+        bridge.read_to_string(&mut contents)?;
+        assert_eq!(contents, "buffered");
+        Ok(())
+    })
+    .await??;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_buffered_read_line() -> Result<(), Box<dyn Error>> {
+    let data = b"first\nsecond\nthird".to_vec();
+    let mut bridge = SyncIoBridge::with_capacity(Cursor::new(data), 4);
+    tokio::task::spawn_blocking(move || -> IoResult<_> {
+        let mut line = String::new();
+        bridge.read_line(&mut line)?;
+        assert_eq!(line, "first\n");
+
+        line.clear();
+        bridge.read_line(&mut line)?;
+        assert_eq!(line, "second\n");
+
+        line.clear();
+        bridge.read_line(&mut line)?;
+        assert_eq!(line, "third");
+        Ok(())
+    })
+    .await??;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_borrowed_source() -> Result<(), Box<dyn Error>> {
+    let mut src = Cursor::new(b"hello world".to_vec());
+    let read = tokio::task::block_in_place(|| -> IoResult<_> {
+        let mut bridge = SyncIoBridge::new(&mut src);
+        let mut read = String::new();
+        bridge.read_to_string(&mut read)?;
+        Ok(read)
+    })?;
+
+    assert_eq!(read, "hello world");
+    // The source is usable again once the bridge is gone.
+    assert_eq!(src.position(), 11);
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_shutdown() -> Result<(), Box<dyn Error>> {
     let (s1, mut s2) = tokio::io::duplex(1024);
@@ -749,6 +749,253 @@ fn max_allowed_length_field() {
     assert_eq!(codec.max_frame_length(), usize::MAX);
 }
 
+#[test]
+fn read_single_frame_varint() {
+    let io = length_delimited::Builder::new()
+        .length_field_varint()
+        .new_read(mock! {
+            data(b"\x09abcdefghi"),
+        });
+    pin_mut!(io);
+
+    assert_next_eq!(io, b"abcdefghi");
+    assert_done!(io);
+}
+
+#[test]
+fn read_multi_frame_varint_multi_byte_header() {
+    let mut d: Vec<u8> = vec![];
+    d.extend_from_slice(b"\x03123");
+    // 300 = 0b10_0101100, encoded as \xAC\x02
+    d.extend_from_slice(b"\xAC\x02");
+    d.extend_from_slice(&[b'x'; 300]);
+
+    let io = length_delimited::Builder::new()
+        .length_field_varint()
+        .new_read(mock! {
+            data(&d),
+        });
+    pin_mut!(io);
+
+    assert_next_eq!(io, b"123");
+    assert_next_eq!(io, [b'x'; 300]);
+    assert_done!(io);
+}
+
+#[test]
+fn read_varint_split_header() {
+    let io = length_delimited::Builder::new()
+        .length_field_varint()
+        .new_read(mock! {
+            data(b"\xAC"),
+            Poll::Pending,
+            data(b"\x02"),
+            Poll::Pending,
+            data(&[b'x'; 300]),
+        });
+    pin_mut!(io);
+
+    assert_next_pending!(io);
+    assert_next_pending!(io);
+    assert_next_eq!(io, [b'x'; 300]);
+    assert_done!(io);
+}
+
+#[test]
+fn read_varint_max_frame_len() {
+    let io = length_delimited::Builder::new()
+        .length_field_varint()
+        .max_frame_length(5)
+        .new_read(mock! {
+            data(b"\x09abcdefghi"),
+        });
+    pin_mut!(io);
+
+    assert_next_err!(io);
+}
+
+#[test]
+fn read_varint_malformed_header() {
+    // Eleven continuation bytes can never terminate into a `u64`.
+    let io = length_delimited::Builder::new()
+        .length_field_varint()
+        .new_read(mock! {
+            data(b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"),
+        });
+    pin_mut!(io);
+
+    assert_next_err!(io);
+}
+
+#[test]
+fn write_single_frame_varint() {
+    let io = length_delimited::Builder::new()
+        .length_field_varint()
+        .new_write(mock! {
+            data(b"\x09"),
+            data(b"abcdefghi"),
+            flush(),
+        });
+    pin_mut!(io);
+
+    task::spawn(()).enter(|cx, _| {
+        assert_ready_ok!(io.as_mut().poll_ready(cx));
+        assert_ok!(io.as_mut().start_send(Bytes::from("abcdefghi")));
+        assert_ready_ok!(io.as_mut().poll_flush(cx));
+        assert!(io.get_ref().calls.is_empty());
+    });
+}
+
+#[test]
+fn write_single_frame_varint_multi_byte_header() {
+    let io = length_delimited::Builder::new()
+        .length_field_varint()
+        .new_write(mock! {
+            data(b"\xAC\x02"),
+            data(&[b'x'; 300]),
+            flush(),
+        });
+    pin_mut!(io);
+
+    task::spawn(()).enter(|cx, _| {
+        assert_ready_ok!(io.as_mut().poll_ready(cx));
+        assert_ok!(io.as_mut().start_send(Bytes::from(vec![b'x'; 300])));
+        assert_ready_ok!(io.as_mut().poll_flush(cx));
+        assert!(io.get_ref().calls.is_empty());
+    });
+}
+
+#[test]
+fn varint_roundtrip_codec() {
+    let mut codec = length_delimited::Builder::new()
+        .length_field_varint()
+        .new_codec();
+
+    let mut buf = BytesMut::new();
+    codec
+        .encode(Bytes::from(vec![b'x'; 300]), &mut buf)
+        .unwrap();
+    codec.encode(Bytes::from("abc"), &mut buf).unwrap();
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, &[b'x'; 300][..]);
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, &b"abc"[..]);
+
+    assert!(codec.decode(&mut buf).unwrap().is_none());
+}
+
+#[test]
+fn varint_does_not_clip_max_frame_len() {
+    let codec = LengthDelimitedCodec::builder()
+        .length_field_varint()
+        .max_frame_length(usize::MAX)
+        .new_codec();
+
+    assert_eq!(codec.max_frame_length(), usize::MAX);
+}
+
+#[test]
+fn read_single_frame_crc32() {
+    // 0xCBF43926 is the CRC-32/ISO-HDLC check value for "123456789".
+    let io = length_delimited::Builder::new()
+        .crc32_checksum()
+        .new_read(mock! {
+            data(b"\x00\x00\x00\x0D123456789\xCB\xF4\x39\x26"),
+        });
+    pin_mut!(io);
+
+    assert_next_eq!(io, b"123456789");
+    assert_done!(io);
+}
+
+#[test]
+fn write_single_frame_crc32() {
+    let io = length_delimited::Builder::new()
+        .crc32_checksum()
+        .new_write(mock! {
+            data(b"\x00\x00\x00\x0D"),
+            data(b"123456789\xCB\xF4\x39\x26"),
+            flush(),
+        });
+    pin_mut!(io);
+
+    task::spawn(()).enter(|cx, _| {
+        assert_ready_ok!(io.as_mut().poll_ready(cx));
+        assert_ok!(io.as_mut().start_send(Bytes::from("123456789")));
+        assert_ready_ok!(io.as_mut().poll_flush(cx));
+        assert!(io.get_ref().calls.is_empty());
+    });
+}
+
+#[test]
+fn crc32_roundtrip_codec() {
+    let mut codec = length_delimited::Builder::new()
+        .crc32_checksum()
+        .new_codec();
+
+    let mut buf = BytesMut::new();
+    codec
+        .encode(Bytes::from(vec![b'x'; 300]), &mut buf)
+        .unwrap();
+    codec.encode(Bytes::from("abc"), &mut buf).unwrap();
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, &[b'x'; 300][..]);
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, &b"abc"[..]);
+
+    assert!(codec.decode(&mut buf).unwrap().is_none());
+}
+
+#[test]
+fn crc32_varint_roundtrip_codec() {
+    let mut codec = length_delimited::Builder::new()
+        .length_field_varint()
+        .crc32_checksum()
+        .new_codec();
+
+    let mut buf = BytesMut::new();
+    codec
+        .encode(Bytes::from(vec![b'x'; 300]), &mut buf)
+        .unwrap();
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, &[b'x'; 300][..]);
+
+    assert!(codec.decode(&mut buf).unwrap().is_none());
+}
+
+#[test]
+fn crc32_mismatch_returns_distinct_error() {
+    let mut codec = length_delimited::Builder::new()
+        .crc32_checksum()
+        .new_codec();
+
+    let mut buf = BytesMut::new();
+    codec.encode(Bytes::from("123456789"), &mut buf).unwrap();
+    codec.encode(Bytes::from("abc"), &mut buf).unwrap();
+
+    // Corrupt a payload byte of the first frame.
+    buf[7] ^= 0x01;
+
+    let err = assert_err!(codec.decode(&mut buf));
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let err = err
+        .into_inner()
+        .unwrap()
+        .downcast::<ChecksumMismatchError>()
+        .unwrap();
+    assert_ne!(err.expected(), err.computed());
+
+    // The corrupt frame is discarded; the next frame decodes fine.
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(frame, &b"abc"[..]);
+}
+
 // ===== Test utils =====
 
 struct Mock {
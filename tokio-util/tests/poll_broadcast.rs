@@ -0,0 +1,77 @@
+use std::future::Future;
+use std::task::Poll;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::PollBroadcastReceiver;
+
+fn broadcast_poll<T: 'static + Clone + Send>(
+    rx: &mut PollBroadcastReceiver<T>,
+) -> tokio_test::task::Spawn<impl Future<Output = Result<T, RecvError>> + '_> {
+    let fut = std::future::poll_fn(move |cx| rx.poll_recv(cx));
+    tokio_test::task::spawn(fut)
+}
+
+#[tokio::test]
+async fn it_works() {
+    let (tx, rx) = broadcast::channel(4);
+    let mut poll_rx = PollBroadcastReceiver::new(rx);
+
+    let mut poll = broadcast_poll(&mut poll_rx);
+    assert!(poll.poll().is_pending());
+
+    tx.send(1u32).unwrap();
+    assert!(poll.is_woken());
+    assert_eq!(poll.poll(), Poll::Ready(Ok(1)));
+    drop(poll);
+
+    tx.send(2).unwrap();
+    tx.send(3).unwrap();
+
+    assert_eq!(broadcast_poll(&mut poll_rx).poll(), Poll::Ready(Ok(2)));
+    assert_eq!(broadcast_poll(&mut poll_rx).poll(), Poll::Ready(Ok(3)));
+
+    drop(tx);
+
+    assert_eq!(broadcast_poll(&mut poll_rx).await, Err(RecvError::Closed));
+
+    // Check that it is fused.
+    assert_eq!(broadcast_poll(&mut poll_rx).await, Err(RecvError::Closed));
+    assert_eq!(broadcast_poll(&mut poll_rx).await, Err(RecvError::Closed));
+}
+
+#[tokio::test]
+async fn buffered_values_are_delivered_after_sender_drop() {
+    let (tx, rx) = broadcast::channel(4);
+    let mut poll_rx = PollBroadcastReceiver::new(rx);
+
+    tx.send("a").unwrap();
+    tx.send("b").unwrap();
+    drop(tx);
+
+    assert_eq!(broadcast_poll(&mut poll_rx).poll(), Poll::Ready(Ok("a")));
+    assert_eq!(broadcast_poll(&mut poll_rx).poll(), Poll::Ready(Ok("b")));
+    assert_eq!(
+        broadcast_poll(&mut poll_rx).poll(),
+        Poll::Ready(Err(RecvError::Closed))
+    );
+}
+
+#[tokio::test]
+async fn lagged_receiver_reports_missed_count() {
+    let (tx, rx) = broadcast::channel(2);
+    let mut poll_rx = PollBroadcastReceiver::new(rx);
+
+    for i in 0..5u32 {
+        tx.send(i).unwrap();
+    }
+
+    assert_eq!(
+        broadcast_poll(&mut poll_rx).poll(),
+        Poll::Ready(Err(RecvError::Lagged(3)))
+    );
+
+    // The receiver picks up at the oldest value still in the channel.
+    assert_eq!(broadcast_poll(&mut poll_rx).poll(), Poll::Ready(Ok(3)));
+    assert_eq!(broadcast_poll(&mut poll_rx).poll(), Poll::Ready(Ok(4)));
+    assert!(broadcast_poll(&mut poll_rx).poll().is_pending());
+}
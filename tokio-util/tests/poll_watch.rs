@@ -0,0 +1,68 @@
+use std::future::Future;
+use std::task::Poll;
+use tokio::sync::watch;
+use tokio_util::sync::PollWatchReceiver;
+
+fn watch_poll<T: 'static + Clone + Send + Sync>(
+    rx: &mut PollWatchReceiver<T>,
+) -> tokio_test::task::Spawn<impl Future<Output = Option<T>> + '_> {
+    let fut = std::future::poll_fn(move |cx| rx.poll_changed(cx));
+    tokio_test::task::spawn(fut)
+}
+
+#[tokio::test]
+async fn it_works() {
+    let (tx, rx) = watch::channel(0u32);
+    let mut poll_rx = PollWatchReceiver::new(rx);
+
+    let mut poll = watch_poll(&mut poll_rx);
+    assert!(poll.poll().is_pending());
+
+    tx.send(1).unwrap();
+    assert!(poll.is_woken());
+    assert_eq!(poll.poll(), Poll::Ready(Some(1)));
+    drop(poll);
+
+    assert!(watch_poll(&mut poll_rx).poll().is_pending());
+
+    drop(tx);
+
+    assert!(watch_poll(&mut poll_rx).await.is_none());
+
+    // Check that it is fused.
+    assert!(watch_poll(&mut poll_rx).await.is_none());
+    assert!(watch_poll(&mut poll_rx).await.is_none());
+}
+
+#[tokio::test]
+async fn only_latest_value_is_seen() {
+    let (tx, rx) = watch::channel("a");
+    let mut poll_rx = PollWatchReceiver::new(rx);
+
+    tx.send("b").unwrap();
+    tx.send("c").unwrap();
+
+    assert_eq!(watch_poll(&mut poll_rx).poll(), Poll::Ready(Some("c")));
+    assert!(watch_poll(&mut poll_rx).poll().is_pending());
+}
+
+#[tokio::test]
+async fn unseen_value_resolves_immediately() {
+    let (tx, rx) = watch::channel(0u32);
+    tx.send(1).unwrap();
+
+    let mut poll_rx = PollWatchReceiver::new(rx);
+    assert_eq!(watch_poll(&mut poll_rx).poll(), Poll::Ready(Some(1)));
+}
+
+#[tokio::test]
+async fn last_value_is_delivered_after_sender_drop() {
+    let (tx, rx) = watch::channel(0u32);
+    let mut poll_rx = PollWatchReceiver::new(rx);
+
+    tx.send(1).unwrap();
+    drop(tx);
+
+    assert_eq!(watch_poll(&mut poll_rx).poll(), Poll::Ready(Some(1)));
+    assert!(watch_poll(&mut poll_rx).await.is_none());
+}
@@ -563,3 +563,69 @@ fn run_until_cancelled_owned_test() {
         );
     }
 }
+
+#[test]
+fn cancel_with_reason() {
+    let token = CancellationToken::new();
+    let child_token = token.child_token();
+    assert_eq!(token.cancellation_reason(), None);
+
+    token.cancel_with("shutting down");
+    assert!(token.is_cancelled());
+    assert_eq!(
+        token.cancellation_reason().as_deref(),
+        Some("shutting down")
+    );
+    assert_eq!(
+        child_token.cancellation_reason().as_deref(),
+        Some("shutting down")
+    );
+
+    // A child created after the cancellation inherits the reason.
+    assert_eq!(
+        token.child_token().cancellation_reason().as_deref(),
+        Some("shutting down")
+    );
+
+    // Only the first cancellation records a reason.
+    token.cancel_with("second reason");
+    assert_eq!(
+        token.cancellation_reason().as_deref(),
+        Some("shutting down")
+    );
+}
+
+#[test]
+fn cancel_without_reason() {
+    let token = CancellationToken::new();
+    token.cancel();
+    assert!(token.is_cancelled());
+    assert_eq!(token.cancellation_reason(), None);
+
+    // The reason cannot be set retroactively.
+    token.cancel_with("too late");
+    assert_eq!(token.cancellation_reason(), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancel_after_cancels_token() {
+    let token = CancellationToken::new();
+    token.cancel_after(std::time::Duration::from_secs(1));
+    assert!(!token.is_cancelled());
+
+    let start = tokio::time::Instant::now();
+    token.cancelled().await;
+    assert!(token.is_cancelled());
+    assert_eq!(start.elapsed(), std::time::Duration::from_secs(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancel_at_earliest_deadline_wins() {
+    let token = CancellationToken::new();
+    token.cancel_at(tokio::time::Instant::now() + std::time::Duration::from_secs(5));
+    token.cancel_at(tokio::time::Instant::now() + std::time::Duration::from_secs(2));
+
+    let start = tokio::time::Instant::now();
+    token.cancelled().await;
+    assert_eq!(start.elapsed(), std::time::Duration::from_secs(2));
+}
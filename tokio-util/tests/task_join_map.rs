@@ -376,3 +376,87 @@ async fn duplicate_keys_drop() {
 
     assert!(map.join_next().await.is_none());
 }
+
+#[tokio::test(start_paused = true)]
+async fn entry_or_spawn_singleton() {
+    let mut map = JoinMap::new();
+
+    // The first `or_spawn` for a key spawns the task.
+    assert!(map.entry("refresh").or_spawn(async {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        1
+    }));
+
+    // While it is running, further `or_spawn` calls are no-ops.
+    assert!(!map.entry("refresh").or_spawn(async { 2 }));
+    assert_eq!(map.len(), 1);
+
+    let (key, res) = map.join_next().await.unwrap();
+    assert_eq!(key, "refresh");
+    assert_eq!(res.unwrap(), 1);
+
+    // Once the task has been consumed, the key is free again.
+    assert!(map.entry("refresh").or_spawn(async { 3 }));
+    let (_, res) = map.join_next().await.unwrap();
+    assert_eq!(res.unwrap(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn join_key_buffers_other_outputs() {
+    let mut map = JoinMap::new();
+
+    for i in 1..=3 {
+        map.spawn(i, async move {
+            tokio::time::sleep(Duration::from_secs(i)).await;
+            i * 10
+        });
+    }
+
+    // Waiting for the slowest task buffers the outputs of the others.
+    assert_eq!(map.join_key(&3).await.unwrap().unwrap(), 30);
+    assert_eq!(map.len(), 2);
+
+    // The buffered outputs are returned by `join_next`/`join_key`.
+    assert_eq!(map.join_key(&2).await.unwrap().unwrap(), 20);
+    let (key, res) = map.join_next().await.unwrap();
+    assert_eq!(key, 1);
+    assert_eq!(res.unwrap(), 10);
+
+    assert!(map.is_empty());
+    assert!(map.join_key(&1).await.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn join_key_aborted_task() {
+    let mut map = JoinMap::new();
+
+    map.spawn("stuck", std::future::pending::<()>());
+    assert!(map.abort("stuck"));
+
+    let res = map.join_key("stuck").await.unwrap();
+    assert!(res.unwrap_err().is_cancelled());
+    assert!(map.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn running_keys_skips_finished_tasks() {
+    use std::collections::HashSet;
+
+    let mut map = JoinMap::new();
+
+    map.spawn("done", async {});
+    map.spawn("running", std::future::pending());
+
+    // Let the `done` task finish without consuming its output.
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let running = map.running_keys().collect::<HashSet<&&str>>();
+    assert_eq!(running.len(), 1);
+    assert!(running.contains(&"running"));
+
+    // `keys` still returns both.
+    assert_eq!(map.keys().count(), 2);
+
+    map.shutdown().await;
+    assert_eq!(map.running_keys().count(), 0);
+}
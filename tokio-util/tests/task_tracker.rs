@@ -176,3 +176,80 @@ fn notify_many() {
         assert_ready!(wait.poll());
     }
 }
+
+#[tokio::test]
+async fn count_by_name_snapshot() {
+    let tracker = TaskTracker::new();
+
+    tracker.spawn_named("connection", std::future::pending::<()>());
+    tracker.spawn_named("connection", std::future::pending::<()>());
+    tracker.spawn_named("listener", std::future::pending::<()>());
+    tracker.spawn(std::future::ready(()));
+
+    assert_eq!(tracker.len(), 4);
+    let counts = tracker.count_by_name();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts.get("connection"), Some(&2));
+    assert_eq!(counts.get("listener"), Some(&1));
+
+    tracker.close();
+    tracker.abort_all();
+    tracker.wait().await;
+
+    assert!(tracker.count_by_name().is_empty());
+}
+
+#[tokio::test]
+async fn abort_all_aborts_only_named_tasks() {
+    let tracker = TaskTracker::new();
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let plain = tracker.spawn(async move { rx.await.unwrap() });
+    let named = tracker.spawn_named("stuck", std::future::pending::<()>());
+    tracker.close();
+
+    tracker.abort_all();
+    assert!(named.await.unwrap_err().is_cancelled());
+
+    // Unnamed tasks are not registered, so they keep running.
+    tx.send(()).unwrap();
+    plain.await.unwrap();
+    tracker.wait().await;
+
+    assert!(tracker.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn wait_timeout_in_time() {
+    let tracker = TaskTracker::new();
+
+    tracker.spawn(tokio::time::sleep(std::time::Duration::from_secs(1)));
+    tracker.close();
+
+    assert!(tracker
+        .wait_timeout(std::time::Duration::from_secs(2))
+        .await
+        .is_ok());
+}
+
+#[tokio::test(start_paused = true)]
+async fn wait_timeout_aborts_stragglers() {
+    let tracker = TaskTracker::new();
+
+    tracker.spawn(tokio::time::sleep(std::time::Duration::from_secs(1)));
+    tracker.spawn_named("stuck", std::future::pending::<()>());
+    tracker.spawn_named("worker", std::future::pending::<()>());
+    tracker.close();
+
+    let err = tracker
+        .wait_timeout(std::time::Duration::from_secs(2))
+        .await
+        .unwrap_err();
+
+    // The sleeping task finished in time; the two named tasks were aborted.
+    assert_eq!(err.unfinished_tasks(), 2);
+    let mut names = err.unfinished_names().to_vec();
+    names.sort();
+    assert_eq!(names, ["stuck".into(), "worker".into()]);
+    assert!(tracker.is_empty());
+}
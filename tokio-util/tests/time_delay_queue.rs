@@ -5,7 +5,7 @@
 use futures::StreamExt;
 use tokio::time::{self, sleep, sleep_until, Duration, Instant};
 use tokio_test::{assert_pending, assert_ready, task};
-use tokio_util::time::DelayQueue;
+use tokio_util::time::{DelayQueue, ManualClock};
 
 macro_rules! poll {
     ($queue:ident) => {
@@ -894,6 +894,116 @@ async fn wake_after_remove_last() {
     assert!(assert_ready!(poll!(queue)).is_none());
 }
 
+#[tokio::test(start_paused = true)]
+async fn get_ref_and_get_mut() {
+    let mut queue = task::spawn(DelayQueue::new());
+    let key = queue.insert("foo".to_string(), ms(10));
+
+    assert_eq!(queue.get_ref(&key), Some(&"foo".to_string()));
+
+    queue.get_mut(&key).unwrap().push_str("bar");
+    assert_eq!(queue.get_ref(&key), Some(&"foobar".to_string()));
+
+    queue.remove(&key);
+    assert!(queue.get_ref(&key).is_none());
+    assert!(queue.get_mut(&key).is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn peek_deadline() {
+    let mut queue = task::spawn(DelayQueue::new());
+    assert!(queue.peek_deadline().is_none());
+
+    let _key1 = queue.insert("foo", ms(100));
+    let key2 = queue.insert("bar", ms(10));
+
+    assert_eq!(queue.peek_deadline(), Some(queue.deadline(&key2)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn drain_expired_returns_only_elapsed_items() {
+    let mut queue = task::spawn(DelayQueue::new());
+
+    queue.insert("foo", ms(5));
+    queue.insert("bar", ms(10));
+    queue.insert("baz", ms(100));
+
+    // Nothing has expired yet.
+    assert_eq!(queue.drain_expired().count(), 0);
+    assert_eq!(queue.len(), 3);
+
+    sleep(ms(11)).await;
+
+    let drained: Vec<_> = queue.drain_expired().map(|e| e.into_inner()).collect();
+    assert_eq!(drained, ["foo", "bar"]);
+    assert_eq!(queue.len(), 1);
+
+    // The remaining item is still delivered through `poll_expired`.
+    assert_pending!(poll!(queue));
+    sleep(ms(100)).await;
+    assert!(queue.is_woken());
+    let entry = assert_ready_some!(poll!(queue));
+    assert_eq!(entry.into_inner(), "baz");
+}
+
+#[tokio::test(start_paused = true)]
+async fn drain_expired_partially_consumed() {
+    let mut queue = task::spawn(DelayQueue::new());
+
+    queue.insert("foo", ms(1));
+    queue.insert("bar", ms(2));
+    queue.insert("baz", ms(100));
+
+    sleep(ms(5)).await;
+
+    // Dropping the iterator midway leaves the unexpired item in the queue.
+    let first = queue.drain_expired().next().map(|e| e.into_inner());
+    assert_eq!(first, Some("foo"));
+    assert_eq!(queue.len(), 2);
+
+    let drained: Vec<_> = queue.drain_expired().map(|e| e.into_inner()).collect();
+    assert_eq!(drained, ["bar"]);
+    assert_eq!(queue.len(), 1);
+}
+
+#[tokio::test]
+async fn manual_clock_drives_drain_expired() {
+    let clock = ManualClock::new(Instant::now());
+    let mut queue = DelayQueue::with_clock(clock.clone());
+
+    queue.insert("foo", ms(10));
+    queue.insert("bar", ms(50));
+
+    assert_eq!(queue.drain_expired().count(), 0);
+
+    clock.advance(ms(10));
+
+    let drained: Vec<_> = queue.drain_expired().map(|x| x.into_inner()).collect();
+    assert_eq!(drained, ["foo"]);
+
+    clock.advance(ms(40));
+
+    let drained: Vec<_> = queue.drain_expired().map(|x| x.into_inner()).collect();
+    assert_eq!(drained, ["bar"]);
+    assert!(queue.is_empty());
+}
+
+#[tokio::test]
+async fn manual_clock_insert_at() {
+    let clock = ManualClock::new(Instant::now());
+    let mut queue = DelayQueue::with_capacity_and_clock(10, clock.clone());
+
+    let key = queue.insert_at("foo", clock.now() + ms(100));
+    assert_eq!(queue.deadline(&key), clock.now() + ms(100));
+
+    clock.advance(ms(99));
+    assert_eq!(queue.drain_expired().count(), 0);
+
+    clock.advance(ms(1));
+    let drained: Vec<_> = queue.drain_expired().map(|x| x.into_inner()).collect();
+    assert_eq!(drained, ["foo"]);
+}
+
 fn ms(n: u64) -> Duration {
     Duration::from_millis(n)
 }
@@ -5,7 +5,7 @@
 use tokio::net::UdpSocket;
 use tokio_stream::StreamExt;
 use tokio_util::codec::{Decoder, Encoder, LinesCodec};
-use tokio_util::udp::UdpFramed;
+use tokio_util::udp::{UdpFramed, UdpFramedConnected, UdpFramedDemux};
 
 use bytes::{BufMut, BytesMut};
 use futures::future::try_join;
@@ -147,3 +147,88 @@ async fn framed_half() -> std::io::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn send_framed_connected() -> std::io::Result<()> {
+    let a_soc = UdpSocket::bind("127.0.0.1:0").await?;
+    let b_soc = UdpSocket::bind("127.0.0.1:0").await?;
+
+    a_soc.connect(b_soc.local_addr()?).await?;
+    b_soc.connect(a_soc.local_addr()?).await?;
+
+    let mut a = UdpFramedConnected::new(a_soc, ByteCodec);
+    let mut b = UdpFramedConnected::new(b_soc, LinesCodec::new());
+
+    let msg = b"1\r\n2\r\n3\r\n".to_vec();
+    a.send(&msg[..]).await?;
+
+    assert_eq!(b.next().await.unwrap().unwrap(), "1".to_string());
+    assert_eq!(b.next().await.unwrap().unwrap(), "2".to_string());
+    assert_eq!(b.next().await.unwrap().unwrap(), "3".to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn demux_routes_frames_to_registered_peers() -> std::io::Result<()> {
+    let server = UdpSocket::bind("127.0.0.1:0").await?;
+    let server_addr = server.local_addr()?;
+
+    let a_soc = UdpSocket::bind("127.0.0.1:0").await?;
+    let b_soc = UdpSocket::bind("127.0.0.1:0").await?;
+
+    let a_addr = a_soc.local_addr()?;
+    let b_addr = b_soc.local_addr()?;
+
+    let mut a = UdpFramed::new(a_soc, ByteCodec);
+    let mut b = UdpFramed::new(b_soc, ByteCodec);
+
+    let mut demux = UdpFramedDemux::new(UdpFramed::new(server, LinesCodec::new()), 8);
+    let mut a_frames = demux.register_peer(a_addr);
+
+    // The registered peer's frame is forwarded to its channel; the
+    // unregistered peer's frame is yielded as unclaimed.
+    a.send((&b"from a\n"[..], server_addr)).await?;
+    b.send((&b"from b\n"[..], server_addr)).await?;
+
+    let (frame, addr) = demux.next().await.unwrap().unwrap();
+    assert_eq!(frame, "from b");
+    assert_eq!(addr, b_addr);
+
+    assert_eq!(a_frames.recv().await.unwrap(), "from a");
+
+    // Deregistered peers become unclaimed again.
+    assert!(demux.deregister_peer(&a_addr));
+    assert_eq!(demux.peer_count(), 0);
+
+    a.send((&b"hello\n"[..], server_addr)).await?;
+
+    let (frame, addr) = demux.next().await.unwrap().unwrap();
+    assert_eq!(frame, "hello");
+    assert_eq!(addr, a_addr);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn demux_closed_peer_channel_is_deregistered() -> std::io::Result<()> {
+    let server = UdpSocket::bind("127.0.0.1:0").await?;
+    let server_addr = server.local_addr()?;
+
+    let a_soc = UdpSocket::bind("127.0.0.1:0").await?;
+    let a_addr = a_soc.local_addr()?;
+    let mut a = UdpFramed::new(a_soc, ByteCodec);
+
+    let mut demux = UdpFramedDemux::new(UdpFramed::new(server, LinesCodec::new()), 8);
+    let a_frames = demux.register_peer(a_addr);
+    drop(a_frames);
+
+    a.send((&b"hello\n"[..], server_addr)).await?;
+
+    let (frame, addr) = demux.next().await.unwrap().unwrap();
+    assert_eq!(frame, "hello");
+    assert_eq!(addr, a_addr);
+    assert_eq!(demux.peer_count(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::alloc::{alloc_zeroed, dealloc, Layout};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+/// A byte buffer with a guaranteed memory alignment.
+///
+/// Direct (unbuffered) file I/O requires the buffer, length, and file offset
+/// to be aligned to the storage's block size; see [`OpenOptions::direct`].
+/// `AlignedBuf` allocates with an explicit alignment so these requirements
+/// can be met without over-allocating and manually offsetting into a `Vec`.
+///
+/// The buffer derefs to `[u8]` and is zero-initialized.
+///
+/// [`OpenOptions::direct`]: super::OpenOptions::direct
+///
+/// # Examples
+///
+/// ```
+/// use tokio::fs::AlignedBuf;
+///
+/// let mut buf = AlignedBuf::zeroed(8192, 4096);
+/// assert_eq!(buf.len(), 8192);
+/// assert_eq!(buf.as_ptr() as usize % 4096, 0);
+/// buf[..5].copy_from_slice(b"hello");
+/// ```
+pub struct AlignedBuf {
+    ptr: NonNull<u8>,
+    len: usize,
+    align: usize,
+}
+
+impl AlignedBuf {
+    /// Allocates a zero-initialized buffer of `len` bytes aligned to `align`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is zero or not a power of two, or if the allocation
+    /// fails.
+    pub fn zeroed(len: usize, align: usize) -> AlignedBuf {
+        assert!(
+            align != 0 && align.is_power_of_two(),
+            "alignment must be a power of two"
+        );
+
+        let ptr = if len == 0 {
+            NonNull::dangling()
+        } else {
+            let layout = Layout::from_size_align(len, align).expect("invalid buffer layout");
+            // SAFETY: the layout has a non-zero size.
+            let ptr = unsafe { alloc_zeroed(layout) };
+            match NonNull::new(ptr) {
+                Some(ptr) => ptr,
+                None => std::alloc::handle_alloc_error(layout),
+            }
+        };
+
+        AlignedBuf { ptr, len, align }
+    }
+
+    /// Returns the alignment the buffer was allocated with.
+    pub fn alignment(&self) -> usize {
+        self.align
+    }
+}
+
+impl Deref for AlignedBuf {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: the buffer is allocated, initialized, and uniquely owned.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl DerefMut for AlignedBuf {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: the buffer is allocated, initialized, and uniquely owned.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for AlignedBuf {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            // SAFETY: allocated in `zeroed` with the identical layout.
+            unsafe {
+                dealloc(
+                    self.ptr.as_ptr(),
+                    Layout::from_size_align_unchecked(self.len, self.align),
+                );
+            }
+        }
+    }
+}
+
+// SAFETY: `AlignedBuf` owns its allocation exclusively, like `Vec<u8>`.
+unsafe impl Send for AlignedBuf {}
+unsafe impl Sync for AlignedBuf {}
+
+impl fmt::Debug for AlignedBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AlignedBuf")
+            .field("len", &self.len)
+            .field("align", &self.align)
+            .finish()
+    }
+}
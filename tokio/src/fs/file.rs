@@ -3,6 +3,8 @@
 //! [`File`]: File
 
 use crate::fs::{asyncify, OpenOptions};
+#[cfg(any(unix, windows))]
+use crate::fs::AlignedBuf;
 use crate::io::blocking::{Buf, DEFAULT_MAX_BUF_SIZE};
 use crate::io::{AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
 use crate::sync::Mutex;
@@ -426,6 +428,247 @@ impl File {
         }
     }
 
+    /// Preallocates space on disk so the file can hold at least `len` bytes.
+    ///
+    /// Unlike [`set_len`], which only changes the recorded size, this
+    /// reserves the underlying blocks with `fallocate(2)`, so later writes
+    /// within the allocated range cannot fail with `ENOSPC` and are not
+    /// slowed down by on-demand block allocation. The file size grows to
+    /// `len` if it was smaller; existing contents are unaffected.
+    ///
+    /// [`set_len`]: File::set_len
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::create("segment.log").await?;
+    /// file.allocate(64 * 1024 * 1024).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "android", target_os = "linux"))))]
+    pub async fn allocate(&self, len: u64) -> io::Result<()> {
+        let std = self.std.clone();
+        asyncify(move || sys_fallocate(&std, 0, 0, len)).await
+    }
+
+    /// Deallocates the given byte range, replacing it with a hole.
+    ///
+    /// The range is removed from the file with
+    /// `fallocate(FALLOC_FL_PUNCH_HOLE)`: the blocks are returned to the
+    /// filesystem while the file size is unchanged, and later reads of the
+    /// range see zeroes. Log-structured storage engines use this to reclaim
+    /// space from compacted regions without rewriting the file.
+    ///
+    /// Most filesystems require `offset` and `len` to be aligned to the
+    /// filesystem block size.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::create("segment.log").await?;
+    /// file.allocate(64 * 1024 * 1024).await?;
+    /// // Reclaim the first 4 MiB once it has been compacted away.
+    /// file.punch_hole(0, 4 * 1024 * 1024).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "android", target_os = "linux"))))]
+    pub async fn punch_hole(&self, offset: u64, len: u64) -> io::Result<()> {
+        let std = self.std.clone();
+        asyncify(move || {
+            sys_fallocate(
+                &std,
+                libc::FALLOC_FL_PUNCH_HOLE | libc::FALLOC_FL_KEEP_SIZE,
+                offset,
+                len,
+            )
+        })
+        .await
+    }
+
+    /// Initiates writeback of a byte range with `sync_file_range(2)`.
+    ///
+    /// With `wait` set to `false`, writeback of any dirty pages in the range
+    /// is started but not awaited, letting the caller overlap flushing with
+    /// further writes. With `wait` set to `true`, the call also waits for
+    /// the writeback to complete before and after starting it, so the range
+    /// has reached the device when the future resolves.
+    ///
+    /// This is a performance tool, not a durability guarantee: unlike
+    /// [`sync_data`], it does not flush file metadata or the device write
+    /// cache. A `len` of zero extends the range to the end of the file.
+    ///
+    /// [`sync_data`]: File::sync_data
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::create("segment.log").await?;
+    /// // Kick off writeback of the first segment without blocking on it.
+    /// file.sync_file_range(0, 4 * 1024 * 1024, false).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "android", target_os = "linux"))))]
+    pub async fn sync_file_range(&self, offset: u64, len: u64, wait: bool) -> io::Result<()> {
+        let std = self.std.clone();
+        asyncify(move || {
+            use std::os::unix::io::AsRawFd;
+
+            let flags = if wait {
+                libc::SYNC_FILE_RANGE_WAIT_BEFORE
+                    | libc::SYNC_FILE_RANGE_WRITE
+                    | libc::SYNC_FILE_RANGE_WAIT_AFTER
+            } else {
+                libc::SYNC_FILE_RANGE_WRITE
+            };
+
+            let ret = unsafe {
+                libc::sync_file_range(
+                    std.as_raw_fd(),
+                    offset as libc::off64_t,
+                    len as libc::off64_t,
+                    flags,
+                )
+            };
+            if ret != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Reads a number of bytes starting from a given offset, returning how
+    /// many bytes were read.
+    ///
+    /// The offset is relative to the start of the file and the file cursor is
+    /// not used, so positional reads from several tasks can share one `File`
+    /// without cloning the handle per task. Note that similar to
+    /// [`read`], it is not an error to return with a short read.
+    ///
+    /// Any in-flight buffered write is completed before the read is issued,
+    /// so data previously written through this `File` is observed.
+    ///
+    /// **Note**: on Windows the underlying `seek_read` operation moves the
+    /// file cursor as a side effect, so mixing `read_at` with cursor-based
+    /// reads on the same handle is not recommended there.
+    ///
+    /// If the file was opened with [`OpenOptions::direct`], the length and
+    /// offset must be multiples of the logical block size; misaligned values
+    /// are rejected with [`InvalidInput`] before the read is issued.
+    ///
+    /// [`read`]: fn@crate::io::AsyncReadExt::read
+    /// [`OpenOptions::direct`]: crate::fs::OpenOptions::direct
+    /// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::open("foo.txt").await?;
+    /// let mut buf = [0u8; 5];
+    /// let n = file.read_at(&mut buf, 7).await?;
+    ///
+    /// println!("read {} bytes", n);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(unix, windows))]
+    #[cfg_attr(docsrs, doc(cfg(any(unix, windows))))]
+    pub async fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        let mut inner = self.inner.lock().await;
+        inner.complete_inflight().await;
+
+        // The lock is not held across the operation so that positional reads
+        // can run concurrently.
+        drop(inner);
+
+        let len = cmp::min(buf.len(), self.max_buf_size);
+        let mut owned = positional_io_buf(&self.std, len, offset)?;
+        let std = self.std.clone();
+
+        let (n, owned) = asyncify(move || {
+            #[cfg(unix)]
+            let res = std::os::unix::fs::FileExt::read_at(&*std, &mut owned, offset);
+            #[cfg(windows)]
+            let res = std::os::windows::fs::FileExt::seek_read(&*std, &mut owned, offset);
+            res.map(|n| (n, owned))
+        })
+        .await?;
+
+        buf[..n].copy_from_slice(&owned[..n]);
+        Ok(n)
+    }
+
+    /// Writes a number of bytes starting from a given offset, returning how
+    /// many bytes were written.
+    ///
+    /// The offset is relative to the start of the file and the file cursor is
+    /// not used. A write beyond the end of the file extends it; the
+    /// intermediate bytes are filled in with 0s. Note that similar to
+    /// [`write`], it is not an error to return a short write.
+    ///
+    /// **Note**: on Windows the underlying `seek_write` operation moves the
+    /// file cursor as a side effect, so mixing `write_at` with cursor-based
+    /// writes on the same handle is not recommended there.
+    ///
+    /// If the file was opened with [`OpenOptions::direct`], the length and
+    /// offset must be multiples of the logical block size; misaligned values
+    /// are rejected with [`InvalidInput`] before the write is issued.
+    ///
+    /// [`write`]: fn@crate::io::AsyncWriteExt::write
+    /// [`OpenOptions::direct`]: crate::fs::OpenOptions::direct
+    /// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::create("foo.txt").await?;
+    /// file.write_at(b"world", 7).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(unix, windows))]
+    #[cfg_attr(docsrs, doc(cfg(any(unix, windows))))]
+    pub async fn write_at(&self, buf: &[u8], offset: u64) -> io::Result<usize> {
+        let mut inner = self.inner.lock().await;
+        inner.complete_inflight().await;
+        drop(inner);
+
+        let len = cmp::min(buf.len(), self.max_buf_size);
+        let mut owned = positional_io_buf(&self.std, len, offset)?;
+        owned.copy_from_slice(&buf[..len]);
+        let std = self.std.clone();
+
+        asyncify(move || {
+            #[cfg(unix)]
+            let res = std::os::unix::fs::FileExt::write_at(&*std, &owned, offset);
+            #[cfg(windows)]
+            let res = std::os::windows::fs::FileExt::seek_write(&*std, &owned, offset);
+            res
+        })
+        .await
+    }
+
     /// Queries metadata about the underlying file.
     ///
     /// # Examples
@@ -579,6 +822,290 @@ impl File {
     pub fn set_max_buf_size(&mut self, max_buf_size: usize) {
         self.max_buf_size = max_buf_size;
     }
+
+    /// Acquires an exclusive advisory lock on the file, waiting until it is
+    /// available.
+    ///
+    /// The lock is advisory: it is only observed by other lock calls, not by
+    /// reads and writes. The returned [`FileLock`] releases the lock when
+    /// dropped; waiting for the lock and releasing it both happen on the
+    /// blocking thread pool, so the executor is never blocked.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::create("work.lock").await?;
+    /// let guard = file.lock_exclusive().await?;
+    ///
+    /// // ... the critical section ...
+    ///
+    /// drop(guard); // releases the lock
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(unix, windows))]
+    #[cfg_attr(docsrs, doc(cfg(any(unix, windows))))]
+    pub async fn lock_exclusive(&self) -> io::Result<FileLock> {
+        let std = self.std.clone();
+        asyncify(move || {
+            sys_lock(&std, true, true)?;
+            Ok(FileLock { std: Some(std) })
+        })
+        .await
+    }
+
+    /// Acquires a shared advisory lock on the file, waiting until it is
+    /// available.
+    ///
+    /// Any number of shared locks can be held at the same time, but they
+    /// exclude an exclusive lock. See [`lock_exclusive`] for the locking
+    /// semantics and guard behavior.
+    ///
+    /// [`lock_exclusive`]: File::lock_exclusive
+    #[cfg(any(unix, windows))]
+    #[cfg_attr(docsrs, doc(cfg(any(unix, windows))))]
+    pub async fn lock_shared(&self) -> io::Result<FileLock> {
+        let std = self.std.clone();
+        asyncify(move || {
+            sys_lock(&std, false, true)?;
+            Ok(FileLock { std: Some(std) })
+        })
+        .await
+    }
+
+    /// Tries to acquire an exclusive advisory lock on the file without
+    /// waiting.
+    ///
+    /// If the lock is held elsewhere, an error of kind
+    /// [`ErrorKind::WouldBlock`](std::io::ErrorKind::WouldBlock) is returned.
+    #[cfg(any(unix, windows))]
+    #[cfg_attr(docsrs, doc(cfg(any(unix, windows))))]
+    pub fn try_lock_exclusive(&self) -> io::Result<FileLock> {
+        sys_lock(&self.std, true, false)?;
+        Ok(FileLock {
+            std: Some(self.std.clone()),
+        })
+    }
+
+    /// Tries to acquire a shared advisory lock on the file without waiting.
+    ///
+    /// If an exclusive lock is held elsewhere, an error of kind
+    /// [`ErrorKind::WouldBlock`](std::io::ErrorKind::WouldBlock) is returned.
+    #[cfg(any(unix, windows))]
+    #[cfg_attr(docsrs, doc(cfg(any(unix, windows))))]
+    pub fn try_lock_shared(&self) -> io::Result<FileLock> {
+        sys_lock(&self.std, false, false)?;
+        Ok(FileLock {
+            std: Some(self.std.clone()),
+        })
+    }
+}
+
+/// An advisory lock on a [`File`], released when dropped.
+///
+/// Created by [`lock_exclusive`], [`lock_shared`], and the `try_lock`
+/// variants. Dropping the guard releases the lock on the blocking thread
+/// pool when inside a runtime; call [`unlock`] to release it explicitly and
+/// observe any error.
+///
+/// The lock is held on the underlying file handle, so it stays valid if the
+/// `File` itself is dropped first.
+///
+/// [`lock_exclusive`]: File::lock_exclusive
+/// [`lock_shared`]: File::lock_shared
+/// [`unlock`]: FileLock::unlock
+#[cfg(any(unix, windows))]
+#[cfg_attr(docsrs, doc(cfg(any(unix, windows))))]
+#[derive(Debug)]
+pub struct FileLock {
+    std: Option<Arc<StdFile>>,
+}
+
+#[cfg(any(unix, windows))]
+impl FileLock {
+    /// Releases the lock.
+    pub async fn unlock(mut self) -> io::Result<()> {
+        let std = self.std.take().unwrap();
+        asyncify(move || sys_unlock(&std)).await
+    }
+}
+
+#[cfg(any(unix, windows))]
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        if let Some(std) = self.std.take() {
+            let unlock = move || {
+                let _ = sys_unlock(&std);
+            };
+
+            #[cfg(feature = "rt")]
+            if crate::runtime::Handle::try_current().is_ok() {
+                crate::task::spawn_blocking(unlock);
+                return;
+            }
+
+            unlock();
+        }
+    }
+}
+
+#[cfg(unix)]
+fn sys_lock(file: &StdFile, exclusive: bool, blocking: bool) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let mut op = if exclusive {
+        libc::LOCK_EX
+    } else {
+        libc::LOCK_SH
+    };
+    if !blocking {
+        op |= libc::LOCK_NB;
+    }
+
+    let res = unsafe { libc::flock(file.as_raw_fd(), op) };
+    if res < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn sys_unlock(file: &StdFile) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let res = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_UN) };
+    if res < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn sys_lock(file: &StdFile, exclusive: bool, blocking: bool) -> io::Result<()> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Foundation::ERROR_LOCK_VIOLATION;
+    use windows_sys::Win32::Storage::FileSystem::{
+        LockFileEx, LOCKFILE_EXCLUSIVE_LOCK, LOCKFILE_FAIL_IMMEDIATELY,
+    };
+
+    let mut flags = 0;
+    if exclusive {
+        flags |= LOCKFILE_EXCLUSIVE_LOCK;
+    }
+    if !blocking {
+        flags |= LOCKFILE_FAIL_IMMEDIATELY;
+    }
+
+    // SAFETY: the handle is valid and the overlapped structure lives for the
+    // duration of the call.
+    let res = unsafe {
+        let mut overlapped = std::mem::zeroed();
+        LockFileEx(
+            file.as_raw_handle(),
+            flags,
+            0,
+            u32::MAX,
+            u32::MAX,
+            &mut overlapped,
+        )
+    };
+    if res == 0 {
+        let err = io::Error::last_os_error();
+        if err.raw_os_error() == Some(ERROR_LOCK_VIOLATION as i32) {
+            return Err(io::Error::new(io::ErrorKind::WouldBlock, err));
+        }
+        return Err(err);
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn sys_unlock(file: &StdFile) -> io::Result<()> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::UnlockFileEx;
+
+    // SAFETY: the handle is valid and the overlapped structure lives for the
+    // duration of the call.
+    let res = unsafe {
+        let mut overlapped = std::mem::zeroed();
+        UnlockFileEx(file.as_raw_handle(), 0, u32::MAX, u32::MAX, &mut overlapped)
+    };
+    if res == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn sys_fallocate(file: &StdFile, mode: libc::c_int, offset: u64, len: u64) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let ret = unsafe {
+        libc::fallocate64(
+            file.as_raw_fd(),
+            mode,
+            offset as libc::off64_t,
+            len as libc::off64_t,
+        )
+    };
+    if ret != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Alignment of the intermediate buffer used for positional I/O on a file
+/// opened for direct I/O. 4 KiB satisfies both legacy 512-byte and modern
+/// 4 KiB logical block sizes.
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd"
+))]
+const DIRECT_IO_ALIGN: usize = 4096;
+
+/// Allocates the intermediate buffer for `read_at`/`write_at`, validating the
+/// direct I/O alignment requirements when the file is opened with `O_DIRECT`.
+#[cfg(any(unix, windows))]
+fn positional_io_buf(std: &StdFile, len: usize, offset: u64) -> io::Result<AlignedBuf> {
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd"
+    ))]
+    {
+        use std::os::unix::io::AsRawFd;
+
+        let flags = unsafe { libc::fcntl(std.as_raw_fd(), libc::F_GETFL) };
+        if flags >= 0 && flags & libc::O_DIRECT != 0 {
+            if len % 512 != 0 || offset % 512 != 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "direct I/O requires the length and offset to be multiples \
+                     of the storage block size (at least 512 bytes)",
+                ));
+            }
+            return Ok(AlignedBuf::zeroed(len, DIRECT_IO_ALIGN));
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd"
+    )))]
+    let _ = (std, offset);
+
+    Ok(AlignedBuf::zeroed(len, 1))
 }
 
 impl AsyncRead for File {
@@ -2,7 +2,7 @@ use crate::fs::asyncify;
 
 use std::fs::Metadata;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Given a path, queries the file system to get information about a file,
 /// directory, etc.
@@ -44,3 +44,41 @@ pub async fn metadata(path: impl AsRef<Path>) -> io::Result<Metadata> {
     let path = path.as_ref().to_owned();
     asyncify(|| std::fs::metadata(path)).await
 }
+
+/// Queries metadata for a batch of paths with a single blocking-pool
+/// dispatch.
+///
+/// Each path is queried as if by [`metadata`], and the results are returned
+/// in the same order as the input. A path that cannot be queried yields an
+/// `Err` in its slot without affecting the rest of the batch.
+///
+/// For tools that stat many files — backup and sync utilities, directory
+/// scanners — this amortizes the cost of handing work to the blocking pool
+/// over the whole batch instead of paying it once per file.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::fs;
+///
+/// #[tokio::main]
+/// async fn main() -> std::io::Result<()> {
+///     let results = fs::metadata_many(["a.txt", "b.txt"]).await?;
+///     for attr in results.into_iter().flatten() {
+///         println!("{} bytes", attr.len());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn metadata_many<P>(
+    paths: impl IntoIterator<Item = P>,
+) -> io::Result<Vec<io::Result<Metadata>>>
+where
+    P: AsRef<Path>,
+{
+    let paths: Vec<PathBuf> = paths
+        .into_iter()
+        .map(|path| path.as_ref().to_owned())
+        .collect();
+    asyncify(move || Ok(paths.iter().map(std::fs::metadata).collect())).await
+}
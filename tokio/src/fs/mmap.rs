@@ -0,0 +1,272 @@
+use crate::fs::{asyncify, File};
+
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::os::unix::io::AsRawFd;
+use std::ptr;
+use std::sync::Arc;
+
+/// An immutable memory map of a file.
+///
+/// The entire file is mapped into the process's address space, so its
+/// contents can be accessed as a `&[u8]` without read syscalls or buffer
+/// copies. [`into_bytes`] hands the mapping to a [`Bytes`] value without
+/// copying, which plugs directly into [`AsyncWriteExt::write_all`] and the
+/// rest of the async I/O machinery.
+///
+/// Accessing a mapping can fault the data in from disk, which blocks the
+/// thread the way an ordinary read would; mappings are best suited to files
+/// that fit comfortably in the page cache.
+///
+/// Because the mapping is backed by the file, constructing it is `unsafe`:
+/// the caller must guarantee that the file is not truncated or otherwise
+/// resized while the mapping is alive. See [`Mmap::map`].
+///
+/// [`into_bytes`]: Mmap::into_bytes
+/// [`Bytes`]: bytes::Bytes
+/// [`AsyncWriteExt::write_all`]: crate::io::AsyncWriteExt::write_all
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs::{File, Mmap};
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// let file = File::open("data.bin").await?;
+/// // SAFETY: nothing resizes the file while the mapping is alive.
+/// let map = unsafe { Mmap::map(&file).await? };
+/// println!("{} bytes mapped", map.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Mmap {
+    inner: Arc<MmapInner>,
+}
+
+impl Mmap {
+    /// Maps the entire file read-only.
+    ///
+    /// The mapping stays valid after `file` is dropped; it is tied to the
+    /// file's contents, not the handle.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the file is not truncated or resized
+    /// (e.g. via [`File::set_len`], or by another process) while the
+    /// returned mapping — or any [`Bytes`] derived from it — is alive.
+    /// Shrinking the file leaves part of the mapping without backing
+    /// storage, and touching those pages raises `SIGBUS`; the `&[u8]` view
+    /// handed out by this type would then no longer be dereferenceable,
+    /// which is undefined behavior.
+    ///
+    /// [`File::set_len`]: crate::fs::File::set_len
+    /// [`Bytes`]: bytes::Bytes
+    pub async unsafe fn map(file: &File) -> io::Result<Mmap> {
+        let len = file.metadata().await?.len();
+        let inner = MmapInner::new(file, len, false)?;
+        Ok(Mmap {
+            inner: Arc::new(inner),
+        })
+    }
+
+    /// Converts the mapping into a [`Bytes`] value without copying.
+    ///
+    /// The returned `Bytes` keeps the mapping alive and can be cheaply
+    /// cloned and sliced, so a mapped file can be fanned out to several
+    /// writers or framed into messages with no further copies.
+    ///
+    /// [`Bytes`]: bytes::Bytes
+    #[cfg(feature = "io-util")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+    pub fn into_bytes(self) -> bytes::Bytes {
+        bytes::Bytes::from_owner(self)
+    }
+}
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.inner.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for Mmap {
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_slice()
+    }
+}
+
+/// A mutable memory map of a file.
+///
+/// Like [`Mmap`], but the mapping is writable and changes are carried
+/// through to the file. Modified pages are written back by the kernel at its
+/// own pace; call [`flush_async`] to force a range out to the file before,
+/// for example, publishing it to readers.
+///
+/// As with [`Mmap`], constructing the mapping is `unsafe` because the file
+/// must not be resized while the mapping is alive. See
+/// [`map_mut`](MmapMut::map_mut).
+///
+/// [`flush_async`]: MmapMut::flush_async
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs::{File, MmapMut};
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// let file = File::options()
+///     .read(true)
+///     .write(true)
+///     .open("data.bin")
+///     .await?;
+/// // SAFETY: nothing resizes the file while the mapping is alive.
+/// let mut map = unsafe { MmapMut::map_mut(&file).await? };
+/// map[..5].copy_from_slice(b"hello");
+/// map.flush_async().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MmapMut {
+    inner: Arc<MmapInner>,
+}
+
+impl MmapMut {
+    /// Maps the entire file read-write.
+    ///
+    /// The file must be open for both reading and writing.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the file is not truncated or resized
+    /// (e.g. via [`File::set_len`], or by another process) while the
+    /// returned mapping is alive. Shrinking the file leaves part of the
+    /// mapping without backing storage, and touching those pages raises
+    /// `SIGBUS`; the `&[u8]`/`&mut [u8]` views handed out by this type
+    /// would then no longer be dereferenceable, which is undefined
+    /// behavior.
+    ///
+    /// [`File::set_len`]: crate::fs::File::set_len
+    pub async unsafe fn map_mut(file: &File) -> io::Result<MmapMut> {
+        let len = file.metadata().await?.len();
+        let inner = MmapInner::new(file, len, true)?;
+        Ok(MmapMut {
+            inner: Arc::new(inner),
+        })
+    }
+
+    /// Flushes all modified pages to the file, waiting for completion.
+    ///
+    /// The `msync` call runs on the blocking thread pool, so the async
+    /// context is not stalled while dirty pages are written back. When the
+    /// future resolves, modifications made before the call are visible to
+    /// anyone reading the file.
+    pub async fn flush_async(&self) -> io::Result<()> {
+        let inner = self.inner.clone();
+        asyncify(move || inner.sync()).await
+    }
+}
+
+impl Deref for MmapMut {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.inner.as_slice()
+    }
+}
+
+impl DerefMut for MmapMut {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: the mapping was created writable and `&mut self` gives
+        // exclusive access to it.
+        unsafe { std::slice::from_raw_parts_mut(self.inner.ptr.cast(), self.inner.len) }
+    }
+}
+
+impl AsRef<[u8]> for MmapMut {
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_slice()
+    }
+}
+
+#[derive(Debug)]
+struct MmapInner {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+// SAFETY: the mapping is owned by this value and valid until it is dropped;
+// synchronization of the contents is handled by the wrappers above.
+unsafe impl Send for MmapInner {}
+unsafe impl Sync for MmapInner {}
+
+impl MmapInner {
+    fn new(file: &File, len: u64, writable: bool) -> io::Result<MmapInner> {
+        let len = usize::try_from(len)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "file too large to map"))?;
+
+        if len == 0 {
+            // `mmap` rejects zero-length mappings; use a dangling pointer
+            // and never dereference it.
+            return Ok(MmapInner {
+                ptr: ptr::NonNull::<libc::c_void>::dangling().as_ptr(),
+                len: 0,
+            });
+        }
+
+        let prot = if writable {
+            libc::PROT_READ | libc::PROT_WRITE
+        } else {
+            libc::PROT_READ
+        };
+
+        // SAFETY: the fd is valid for the duration of the call, and the
+        // resulting pointer is checked before use.
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                prot,
+                libc::MAP_SHARED,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(MmapInner { ptr, len })
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        // SAFETY: the mapping is valid for `len` bytes until dropped.
+        unsafe { std::slice::from_raw_parts(self.ptr.cast(), self.len) }
+    }
+
+    fn sync(&self) -> io::Result<()> {
+        if self.len == 0 {
+            return Ok(());
+        }
+        // SAFETY: `ptr` and `len` describe a live mapping.
+        let ret = unsafe { libc::msync(self.ptr, self.len, libc::MS_SYNC) };
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+impl Drop for MmapInner {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            // SAFETY: this is the sole owner of the mapping.
+            unsafe {
+                libc::munmap(self.ptr, self.len);
+            }
+        }
+    }
+}
@@ -24,8 +24,10 @@ mock! {
         // which method is used.
         pub fn inner_flush(&self) -> io::Result<()>;
         pub fn inner_read(&self, dst: &mut [u8]) -> io::Result<usize>;
+        pub fn inner_read_at(&self, dst: &mut [u8], offset: u64) -> io::Result<usize>;
         pub fn inner_seek(&self, pos: SeekFrom) -> io::Result<u64>;
         pub fn inner_write(&self, src: &[u8]) -> io::Result<usize>;
+        pub fn inner_write_at(&self, src: &[u8], offset: u64) -> io::Result<usize>;
         pub fn metadata(&self) -> io::Result<Metadata>;
         pub fn open(pb: PathBuf) -> io::Result<Self>;
         pub fn set_len(&self, size: u64) -> io::Result<()>;
@@ -73,6 +75,28 @@ impl Read for &'_ MockFile {
     }
 }
 
+#[cfg(unix)]
+impl std::os::unix::fs::FileExt for MockFile {
+    fn read_at(&self, dst: &mut [u8], offset: u64) -> io::Result<usize> {
+        self.inner_read_at(dst, offset)
+    }
+
+    fn write_at(&self, src: &[u8], offset: u64) -> io::Result<usize> {
+        self.inner_write_at(src, offset)
+    }
+}
+
+#[cfg(windows)]
+impl std::os::windows::fs::FileExt for MockFile {
+    fn seek_read(&self, dst: &mut [u8], offset: u64) -> io::Result<usize> {
+        self.inner_read_at(dst, offset)
+    }
+
+    fn seek_write(&self, src: &[u8], offset: u64) -> io::Result<usize> {
+        self.inner_write_at(src, offset)
+    }
+}
+
 impl Seek for &'_ MockFile {
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
         self.inner_seek(pos)
@@ -214,6 +214,9 @@
 //! [`flush`]: crate::io::AsyncWriteExt::flush
 //! [`tokio::fs::read`]: fn@crate::fs::read
 
+mod aligned_buf;
+pub use self::aligned_buf::AlignedBuf;
+
 mod canonicalize;
 pub use self::canonicalize::canonicalize;
 
@@ -228,12 +231,26 @@ pub use self::dir_builder::DirBuilder;
 
 mod file;
 pub use self::file::File;
+#[cfg(any(unix, windows))]
+pub use self::file::FileLock;
 
 mod hard_link;
 pub use self::hard_link::hard_link;
 
 mod metadata;
-pub use self::metadata::metadata;
+pub use self::metadata::{metadata, metadata_many};
+
+#[cfg(unix)]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+mod mmap;
+#[cfg(unix)]
+pub use self::mmap::{Mmap, MmapMut};
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg_attr(docsrs, doc(cfg(any(target_os = "android", target_os = "linux"))))]
+mod statx;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use self::statx::{statx, Statx};
 
 mod open_options;
 pub use self::open_options::OpenOptions;
@@ -262,6 +279,9 @@ pub use self::remove_dir_all::remove_dir_all;
 mod remove_file;
 pub use self::remove_file::remove_file;
 
+mod reflink;
+pub use self::reflink::reflink;
+
 mod rename;
 pub use self::rename::rename;
 
@@ -271,15 +291,44 @@ pub use self::set_permissions::set_permissions;
 mod symlink_metadata;
 pub use self::symlink_metadata::symlink_metadata;
 
+// The temporary file types are not covered by the `MockFile`-based unit
+// tests; they are exercised by the integration tests instead.
+#[cfg(not(test))]
+mod temp_dir;
+#[cfg(not(test))]
+pub use self::temp_dir::TempDir;
+
+#[cfg(not(test))]
+mod temp_file;
+#[cfg(not(test))]
+pub use self::temp_file::TempFile;
+
+mod walk_dir;
+pub use self::walk_dir::{walk_dir, WalkDir, WalkEntry};
+
 mod write;
 pub use self::write::write;
 
+#[cfg(not(test))]
+mod write_atomic;
+#[cfg(not(test))]
+pub use self::write_atomic::write_atomic;
+
 mod copy;
 pub use self::copy::copy;
 
 mod try_exists;
 pub use self::try_exists::try_exists;
 
+#[cfg(all(any(target_os = "android", target_os = "linux"), feature = "net"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(any(target_os = "android", target_os = "linux"), feature = "net")))
+)]
+mod watch;
+#[cfg(all(any(target_os = "android", target_os = "linux"), feature = "net"))]
+pub use self::watch::{watch, FsEvent, FsEventKind, Watcher};
+
 #[cfg(test)]
 mod mocks;
 
@@ -299,6 +348,8 @@ cfg_windows! {
 }
 
 use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 #[cfg(not(test))]
 use crate::blocking::spawn_blocking;
@@ -318,3 +369,38 @@ where
         )),
     }
 }
+
+/// Creates a linked pair of drop guard and cancellation flag.
+///
+/// Long multi-step operations such as [`copy`] and [`remove_dir_all`] keep
+/// running on the blocking pool even after the future driving them is
+/// dropped. The async side holds the [`CancelGuard`] across its `.await`;
+/// dropping the future drops the guard, which raises the flag. The blocking
+/// closure polls [`CancelFlag::is_cancelled`] between steps so it can stop
+/// early and clean up any partial state it has created.
+pub(crate) fn cancel_signal() -> (CancelGuard, CancelFlag) {
+    let flag = Arc::new(AtomicBool::new(false));
+    (CancelGuard(flag.clone()), CancelFlag(flag))
+}
+
+/// Raises the paired [`CancelFlag`] when dropped.
+///
+/// Dropping the guard after the blocking work has completed is harmless: the
+/// flag is raised, but nothing observes it.
+pub(crate) struct CancelGuard(Arc<AtomicBool>);
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+/// The blocking-pool side of [`cancel_signal`].
+pub(crate) struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    /// Returns `true` once the paired [`CancelGuard`] has been dropped.
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
@@ -87,6 +87,24 @@ use std::os::windows::fs::OpenOptionsExt;
 #[derive(Clone, Debug)]
 pub struct OpenOptions {
     inner: Kind,
+    // Shadow copies of the custom open flags, kept so that `direct` and
+    // `custom_flags` compose instead of overwriting each other.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd"
+    ))]
+    custom_flags: i32,
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd"
+    ))]
+    direct: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -119,7 +137,25 @@ impl OpenOptions {
         #[cfg(not(all(tokio_uring, feature = "rt", feature = "fs", target_os = "linux")))]
         let inner = Kind::Std(StdOpenOptions::new());
 
-        OpenOptions { inner }
+        OpenOptions {
+            inner,
+            #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "netbsd"
+            ))]
+            custom_flags: 0,
+            #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "netbsd"
+            ))]
+            direct: false,
+        }
     }
 
     /// Sets the option for read access.
@@ -563,6 +599,24 @@ feature! {
         /// }
         /// ```
         pub fn custom_flags(&mut self, flags: i32) -> &mut OpenOptions {
+            #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "netbsd"
+            ))]
+            {
+                self.custom_flags = flags;
+                self.apply_custom_flags();
+            }
+            #[cfg(not(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "netbsd"
+            )))]
             match &mut self.inner {
                 Kind::Std(opts) => {
                     opts.custom_flags(flags);
@@ -574,6 +628,92 @@ feature! {
             }
             self
         }
+
+        /// Sets the option for direct (unbuffered) I/O.
+        ///
+        /// When enabled, the file is opened with `O_DIRECT`: reads and writes
+        /// bypass the operating system's page cache and transfer directly
+        /// between the user buffer and the storage device. This is primarily
+        /// useful for applications, such as databases, that manage their own
+        /// caching.
+        ///
+        /// Direct I/O comes with strict alignment requirements: the buffer
+        /// address, the buffer length, and the file offset must typically be
+        /// aligned to the logical block size of the underlying storage
+        /// (commonly 512 bytes or 4 KiB). [`AlignedBuf`] allocates suitably
+        /// aligned buffers, and [`File::read_at`] and [`File::write_at`]
+        /// validate the length and offset up front, returning
+        /// [`InvalidInput`] instead of the unhelpful `EINVAL` the kernel
+        /// would otherwise produce.
+        ///
+        /// Unlike [`custom_flags`], this option composes with other custom
+        /// flags instead of replacing them.
+        ///
+        /// [`AlignedBuf`]: crate::fs::AlignedBuf
+        /// [`File::read_at`]: crate::fs::File::read_at
+        /// [`File::write_at`]: crate::fs::File::write_at
+        /// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+        /// [`custom_flags`]: OpenOptions::custom_flags
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// use tokio::fs::OpenOptions;
+        /// use std::io;
+        ///
+        /// #[tokio::main]
+        /// async fn main() -> io::Result<()> {
+        ///     let file = OpenOptions::new()
+        ///         .read(true)
+        ///         .direct(true)
+        ///         .open("foo.txt")
+        ///         .await?;
+        ///
+        ///     Ok(())
+        /// }
+        /// ```
+        #[cfg(any(
+            target_os = "android",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "linux",
+            target_os = "netbsd"
+        ))]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "netbsd"
+            )))
+        )]
+        pub fn direct(&mut self, direct: bool) -> &mut OpenOptions {
+            self.direct = direct;
+            self.apply_custom_flags();
+            self
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "linux",
+            target_os = "netbsd"
+        ))]
+        fn apply_custom_flags(&mut self) {
+            let flags = self.custom_flags | if self.direct { libc::O_DIRECT } else { 0 };
+            match &mut self.inner {
+                Kind::Std(opts) => {
+                    opts.custom_flags(flags);
+                }
+                #[cfg(all(tokio_uring, feature = "rt", feature = "fs", target_os = "linux"))]
+                Kind::Uring(opts) => {
+                    opts.custom_flags(flags);
+                }
+            }
+        }
     }
 }
 
@@ -769,6 +909,22 @@ impl From<StdOpenOptions> for OpenOptions {
             // if user enables the `--cfg tokio_uring`. It is blocked by:
             // * https://github.com/rust-lang/rust/issues/74943
             // * https://github.com/rust-lang/rust/issues/76801
+            #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "netbsd"
+            ))]
+            custom_flags: 0,
+            #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "netbsd"
+            ))]
+            direct: false,
         }
     }
 }
@@ -1,7 +1,11 @@
-use crate::fs::asyncify;
+use crate::fs::{asyncify, cancel_signal};
 
+use std::io::Read;
 use std::{io, path::Path};
 
+/// How many bytes are read between cancellation checks.
+const CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
 /// Reads the entire contents of a file into a bytes vector.
 ///
 /// This is an async version of [`std::fs::read`].
@@ -30,6 +34,12 @@ use std::{io, path::Path};
 ///
 /// [`ErrorKind::Interrupted`]: std::io::ErrorKind::Interrupted
 ///
+/// # Cancel safety
+///
+/// If the returned future is dropped before completion, the read stops at the
+/// next chunk boundary and the partially filled buffer is discarded, rather
+/// than the read running to completion on the blocking pool.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -45,5 +55,20 @@ use std::{io, path::Path};
 /// ```
 pub async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
     let path = path.as_ref().to_owned();
-    asyncify(move || std::fs::read(path)).await
+    let (_guard, flag) = cancel_signal();
+    asyncify(move || {
+        let mut file = std::fs::File::open(path)?;
+        let size = file.metadata().map(|m| m.len() as usize).unwrap_or(0);
+        let mut buf = Vec::with_capacity(size.saturating_add(1));
+
+        loop {
+            if flag.is_cancelled() {
+                return Err(io::Error::new(io::ErrorKind::Interrupted, "read cancelled"));
+            }
+            if (&mut file).take(CHUNK_SIZE).read_to_end(&mut buf)? == 0 {
+                return Ok(buf);
+            }
+        }
+    })
+    .await
 }
@@ -0,0 +1,90 @@
+use crate::fs::asyncify;
+
+use std::io;
+use std::path::Path;
+
+/// Creates a copy-on-write clone of a file.
+///
+/// The clone shares its on-disk blocks with the original until either file is
+/// modified, so it completes in constant time regardless of the file size and
+/// consumes no additional space up front. This uses the `FICLONE` ioctl on
+/// Linux (supported by Btrfs, XFS, and other reflink-capable filesystems) and
+/// `clonefile` on macOS (APFS).
+///
+/// # Errors
+///
+/// If the filesystem does not support cloning, an error is returned
+/// (typically of kind [`Unsupported`] or [`InvalidInput`], depending on the
+/// platform) and no copy is made; callers wanting a fallback should use
+/// [`copy`] instead, which already clones where the operating system makes
+/// that transparent. On macOS the destination must not exist; on Linux it is
+/// created or truncated like with [`copy`].
+///
+/// [`Unsupported`]: std::io::ErrorKind::Unsupported
+/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+/// [`copy`]: super::copy
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs;
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// fs::reflink("foo.txt", "bar.txt").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn reflink(from: impl AsRef<Path>, to: impl AsRef<Path>) -> io::Result<()> {
+    let from = from.as_ref().to_owned();
+    let to = to.as_ref().to_owned();
+    asyncify(move || reflink_sync(&from, &to)).await
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn reflink_sync(from: &Path, to: &Path) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let src = std::fs::File::open(from)?;
+    let dst = std::fs::File::create(to)?;
+
+    // SAFETY: both descriptors are valid for the duration of the call.
+    let res = unsafe { libc::ioctl(dst.as_raw_fd(), libc::FICLONE, src.as_raw_fd()) };
+    if res < 0 {
+        let err = io::Error::last_os_error();
+        // Don't leave an empty destination file behind.
+        drop(dst);
+        let _ = std::fs::remove_file(to);
+        return Err(err);
+    }
+
+    let perm = src.metadata()?.permissions();
+    dst.set_permissions(perm)?;
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn reflink_sync(from: &Path, to: &Path) -> io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let from = CString::new(from.as_os_str().as_bytes())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "path contained a nul byte"))?;
+    let to = CString::new(to.as_os_str().as_bytes())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "path contained a nul byte"))?;
+
+    // SAFETY: both paths are valid C strings. `clonefile` copies permissions
+    // along with the data.
+    let res = unsafe { libc::clonefile(from.as_ptr(), to.as_ptr(), 0) };
+    if res < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux", target_os = "macos")))]
+fn reflink_sync(_from: &Path, _to: &Path) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "file cloning is not supported on this platform",
+    ))
+}
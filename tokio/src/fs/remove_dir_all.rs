@@ -1,4 +1,4 @@
-use crate::fs::asyncify;
+use crate::fs::{asyncify, cancel_signal, CancelFlag};
 
 use std::io;
 use std::path::Path;
@@ -7,8 +7,54 @@ use std::path::Path;
 ///
 /// This is an async version of [`std::fs::remove_dir_all`][std]
 ///
+/// # Cancel safety
+///
+/// If the returned future is dropped before completion, the removal stops at
+/// the next top-level directory entry instead of running to completion on the
+/// blocking pool. Entries removed up to that point stay removed.
+///
 /// [std]: fn@std::fs::remove_dir_all
 pub async fn remove_dir_all(path: impl AsRef<Path>) -> io::Result<()> {
     let path = path.as_ref().to_owned();
-    asyncify(move || std::fs::remove_dir_all(path)).await
+    let (_guard, flag) = cancel_signal();
+    asyncify(move || {
+        // Like `std::fs::remove_dir_all`, refuse to follow a symlink at the
+        // top level.
+        if std::fs::symlink_metadata(&path)?.is_symlink() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot remove a symlink as a directory",
+            ));
+        }
+        remove_dir_all_cancellable(&path, &flag)
+    })
+    .await
+}
+
+fn remove_dir_all_cancellable(path: &Path, flag: &CancelFlag) -> io::Result<()> {
+    // The cancel flag is only checked between top-level entries; each entry
+    // is removed by `std::fs::remove_dir_all`, whose traversal is hardened
+    // against an entry being swapped for a symlink mid-removal
+    // (CVE-2022-21658). Rolling our own recursive walk here would reintroduce
+    // exactly that race.
+    for entry in std::fs::read_dir(path)? {
+        if flag.is_cancelled() {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "remove_dir_all cancelled",
+            ));
+        }
+
+        let entry = entry?;
+        // `DirEntry::file_type` does not follow symlinks, so a symlink to a
+        // directory is removed as a link rather than descended into. If the
+        // entry is swapped for a symlink after this check,
+        // `std::fs::remove_dir_all` refuses to follow it.
+        if entry.file_type()?.is_dir() {
+            std::fs::remove_dir_all(entry.path())?;
+        } else {
+            std::fs::remove_file(entry.path())?;
+        }
+    }
+    std::fs::remove_dir(path)
 }
@@ -0,0 +1,143 @@
+use crate::fs::asyncify;
+
+use std::ffi::CString;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Queries extended metadata for a file using the Linux `statx(2)` system
+/// call.
+///
+/// This exposes fields that plain `stat` — and therefore
+/// [`std::fs::Metadata`] — cannot report: the file's birth (creation) time,
+/// the mount id, and the alignment requirements for direct I/O. Each field is
+/// optional because filesystems and kernels are free to omit it; a missing
+/// field is `None` rather than a garbage value.
+///
+/// This function will traverse symbolic links to query information about the
+/// destination file, matching [`metadata`].
+///
+/// [`metadata`]: super::metadata
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::fs;
+///
+/// #[tokio::main]
+/// async fn main() -> std::io::Result<()> {
+///     let statx = fs::statx("/some/file/path.txt").await?;
+///     if let Some(btime) = statx.birth_time() {
+///         println!("created at {btime:?}");
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn statx(path: impl AsRef<Path>) -> io::Result<Statx> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || statx_sync(&path)).await
+}
+
+/// Extended file metadata reported by the Linux `statx(2)` system call.
+///
+/// Returned by [`statx`]. Fields that the filesystem or kernel did not
+/// supply are reported as `None`.
+///
+/// [`statx`]: fn@self::statx
+#[derive(Debug, Clone)]
+pub struct Statx {
+    birth_time: Option<SystemTime>,
+    mount_id: Option<u64>,
+    dio_mem_align: Option<u32>,
+    dio_offset_align: Option<u32>,
+}
+
+impl Statx {
+    /// Returns the file's birth (creation) time, if the filesystem records
+    /// one.
+    pub fn birth_time(&self) -> Option<SystemTime> {
+        self.birth_time
+    }
+
+    /// Returns the id of the mount the file resides on, if the kernel
+    /// reports one (Linux 5.8 and later).
+    ///
+    /// Unlike device numbers, mount ids distinguish bind mounts of the same
+    /// filesystem.
+    pub fn mount_id(&self) -> Option<u64> {
+        self.mount_id
+    }
+
+    /// Returns the required memory buffer alignment for direct I/O on this
+    /// file, if the kernel reports one (Linux 6.1 and later).
+    ///
+    /// Buffers passed to a file opened with [`OpenOptions::direct`] must be
+    /// aligned to this value; [`AlignedBuf`] can allocate them.
+    ///
+    /// [`OpenOptions::direct`]: crate::fs::OpenOptions::direct
+    /// [`AlignedBuf`]: crate::fs::AlignedBuf
+    pub fn dio_mem_align(&self) -> Option<u32> {
+        self.dio_mem_align
+    }
+
+    /// Returns the required file offset and length alignment for direct I/O
+    /// on this file, if the kernel reports one (Linux 6.1 and later).
+    pub fn dio_offset_align(&self) -> Option<u32> {
+        self.dio_offset_align
+    }
+}
+
+fn statx_sync(path: &Path) -> io::Result<Statx> {
+    let path = CString::new(path.as_os_str().as_bytes())?;
+    let mask =
+        libc::STATX_BASIC_STATS | libc::STATX_BTIME | libc::STATX_MNT_ID | libc::STATX_DIOALIGN;
+
+    // SAFETY: the buffer is only read after `statx` reports success, and the
+    // per-field masks below gate every extended field on the kernel having
+    // filled it in.
+    let mut buf: libc::statx = unsafe { std::mem::zeroed() };
+    let ret = unsafe { libc::statx(libc::AT_FDCWD, path.as_ptr(), 0, mask, &mut buf) };
+    if ret != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mask = buf.stx_mask;
+
+    let birth_time = if mask & libc::STATX_BTIME != 0 {
+        Some(system_time(buf.stx_btime))
+    } else {
+        None
+    };
+
+    let mount_id = if mask & libc::STATX_MNT_ID != 0 {
+        Some(buf.stx_mnt_id)
+    } else {
+        None
+    };
+
+    // A zero alignment means the filesystem does not support direct I/O on
+    // this file.
+    let (dio_mem_align, dio_offset_align) =
+        if mask & libc::STATX_DIOALIGN != 0 && buf.stx_dio_mem_align != 0 {
+            (Some(buf.stx_dio_mem_align), Some(buf.stx_dio_offset_align))
+        } else {
+            (None, None)
+        };
+
+    Ok(Statx {
+        birth_time,
+        mount_id,
+        dio_mem_align,
+        dio_offset_align,
+    })
+}
+
+fn system_time(ts: libc::statx_timestamp) -> SystemTime {
+    if ts.tv_sec >= 0 {
+        UNIX_EPOCH + Duration::new(ts.tv_sec as u64, ts.tv_nsec)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(ts.tv_sec.unsigned_abs())
+            + Duration::from_nanos(u64::from(ts.tv_nsec))
+    }
+}
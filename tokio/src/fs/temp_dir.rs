@@ -0,0 +1,96 @@
+use crate::fs::asyncify;
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A temporary directory that is removed, along with its contents, when
+/// dropped.
+///
+/// The directory is created with a securely randomized name and, on Unix,
+/// mode `0o700`. Creation and cleanup are routed through the blocking thread
+/// pool, so no blocking filesystem calls are made from the async context.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs::{self, TempDir};
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// let dir = TempDir::new().await?;
+/// fs::write(dir.path().join("scratch.txt"), b"data").await?;
+///
+/// // Removes the directory and everything in it.
+/// dir.close().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TempDir {
+    path: Option<PathBuf>,
+}
+
+impl TempDir {
+    /// Creates a temporary directory in the operating system's temporary
+    /// directory.
+    pub async fn new() -> io::Result<TempDir> {
+        TempDir::new_in(std::env::temp_dir()).await
+    }
+
+    /// Creates a temporary directory in the given directory.
+    pub async fn new_in(dir: impl AsRef<Path>) -> io::Result<TempDir> {
+        let dir = dir.as_ref().to_owned();
+        let path = asyncify(move || create_in(&dir)).await?;
+        Ok(TempDir { path: Some(path) })
+    }
+
+    /// Returns the path of the temporary directory.
+    pub fn path(&self) -> &Path {
+        self.path.as_deref().unwrap()
+    }
+
+    /// Persists the temporary directory, returning its path.
+    ///
+    /// The directory is no longer removed on drop; it belongs to the caller.
+    pub fn into_path(mut self) -> PathBuf {
+        self.path.take().unwrap()
+    }
+
+    /// Removes the temporary directory and all of its contents.
+    ///
+    /// Dropping a `TempDir` removes it as well, but with no way to observe
+    /// errors and, inside a runtime, asynchronously on the blocking thread
+    /// pool. Call `close` to know that the directory is gone.
+    pub async fn close(mut self) -> io::Result<()> {
+        let path = self.path.take().unwrap();
+        asyncify(move || std::fs::remove_dir_all(path)).await
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.take() {
+            super::temp_file::remove_on_blocking_pool(move || {
+                let _ = std::fs::remove_dir_all(path);
+            });
+        }
+    }
+}
+
+fn create_in(dir: &Path) -> io::Result<PathBuf> {
+    loop {
+        let path = dir.join(format!(".tmp{}", super::temp_file::random_name()));
+
+        let mut builder = std::fs::DirBuilder::new();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+
+        match builder.create(&path) {
+            Ok(()) => return Ok(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
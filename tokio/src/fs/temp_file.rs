@@ -0,0 +1,267 @@
+use crate::fs::{asyncify, File};
+use crate::io::{AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
+
+use std::io::{self, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A temporary file that is deleted when dropped.
+///
+/// The file is created with [`O_TMPFILE`] on Linux where the filesystem
+/// supports it, in which case it never has a name and cannot leak; elsewhere
+/// a securely randomized name is used and the file is unlinked on drop or
+/// [`close`]. Creation, persisting, and cleanup are all routed through the
+/// blocking thread pool, so no blocking filesystem calls are made from the
+/// async context.
+///
+/// Reads and writes go through the contained [`File`], which `TempFile`
+/// implements [`AsyncRead`], [`AsyncWrite`], and [`AsyncSeek`] by
+/// delegation.
+///
+/// [`O_TMPFILE`]: https://man7.org/linux/man-pages/man2/open.2.html
+/// [`close`]: TempFile::close
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs::TempFile;
+/// use tokio::io::AsyncWriteExt;
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// let mut file = TempFile::new().await?;
+/// file.write_all(b"scratch data").await?;
+///
+/// // Either persist it...
+/// file.persist("output.bin").await?;
+/// // ...or let it drop, which deletes it.
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TempFile {
+    file: Option<File>,
+    // `None` for anonymous (`O_TMPFILE`) files.
+    path: Option<PathBuf>,
+}
+
+impl TempFile {
+    /// Creates a temporary file in the operating system's temporary
+    /// directory.
+    pub async fn new() -> io::Result<TempFile> {
+        TempFile::new_in(std::env::temp_dir()).await
+    }
+
+    /// Creates a temporary file in the given directory.
+    ///
+    /// Creating the file in a directory on the target filesystem allows a
+    /// later [`persist`] to complete with a rename instead of a copy.
+    ///
+    /// [`persist`]: TempFile::persist
+    pub async fn new_in(dir: impl AsRef<Path>) -> io::Result<TempFile> {
+        let dir = dir.as_ref().to_owned();
+        let (std, path) = asyncify(move || create_in(&dir)).await?;
+
+        Ok(TempFile {
+            file: Some(File::from_std(std)),
+            path,
+        })
+    }
+
+    /// Returns the path of the temporary file, or `None` if the file is
+    /// anonymous (`O_TMPFILE`).
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns a reference to the underlying [`File`].
+    pub fn file(&self) -> &File {
+        self.file.as_ref().unwrap()
+    }
+
+    /// Returns a mutable reference to the underlying [`File`].
+    pub fn file_mut(&mut self) -> &mut File {
+        self.file.as_mut().unwrap()
+    }
+
+    /// Persists the temporary file at the given path, returning the now
+    /// permanent [`File`].
+    ///
+    /// Anonymous files are linked into the filesystem; named ones are
+    /// renamed. In both cases `path` must be on the same filesystem as the
+    /// directory the file was created in. An existing file at `path` is
+    /// overwritten, except for anonymous files where the link fails with
+    /// [`AlreadyExists`](std::io::ErrorKind::AlreadyExists).
+    pub async fn persist(mut self, path: impl AsRef<Path>) -> io::Result<File> {
+        let mut file = self.file.take().unwrap();
+        std::future::poll_fn(|cx| Pin::new(&mut file).poll_flush(cx)).await?;
+        let to = path.as_ref().to_owned();
+
+        match self.path.take() {
+            Some(from) => asyncify(move || std::fs::rename(from, to)).await?,
+            None => {
+                let std = file.try_clone().await?.into_std().await;
+                asyncify(move || link_anonymous(&std, &to)).await?;
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Closes and deletes the temporary file.
+    ///
+    /// Dropping a `TempFile` deletes it as well, but with no way to observe
+    /// errors and, inside a runtime, asynchronously on the blocking thread
+    /// pool. Call `close` to know that the file is gone.
+    pub async fn close(mut self) -> io::Result<()> {
+        drop(self.file.take());
+        if let Some(path) = self.path.take() {
+            asyncify(move || std::fs::remove_file(path)).await?;
+        }
+        Ok(())
+    }
+}
+
+impl AsyncRead for TempFile {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(self.get_mut().file_mut()).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for TempFile {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(self.get_mut().file_mut()).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(self.get_mut().file_mut()).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(self.get_mut().file_mut()).poll_shutdown(cx)
+    }
+}
+
+impl AsyncSeek for TempFile {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        Pin::new(self.get_mut().file_mut()).start_seek(position)
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        Pin::new(self.get_mut().file_mut()).poll_complete(cx)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        drop(self.file.take());
+        if let Some(path) = self.path.take() {
+            remove_on_blocking_pool(move || {
+                let _ = std::fs::remove_file(path);
+            });
+        }
+    }
+}
+
+/// Runs a cleanup closure on the blocking pool when a runtime is available,
+/// inline otherwise.
+pub(super) fn remove_on_blocking_pool<F>(f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    #[cfg(feature = "rt")]
+    if crate::runtime::Handle::try_current().is_ok() {
+        crate::task::spawn_blocking(f);
+        return;
+    }
+
+    f();
+}
+
+/// Generates an unpredictable file name component.
+pub(super) fn random_name() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    // `RandomState` draws from the operating system's entropy source.
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    format!("{:016x}", hasher.finish())
+}
+
+fn create_in(dir: &Path) -> io::Result<(std::fs::File, Option<PathBuf>)> {
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        // Try an anonymous file first; not every filesystem supports it.
+        if let Ok(file) = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(libc::O_TMPFILE)
+            .mode(0o600)
+            .open(dir)
+        {
+            return Ok((file, None));
+        }
+    }
+
+    // A randomized name with `O_EXCL` guarantees we never open a file planted
+    // by another process.
+    let mut options = std::fs::OpenOptions::new();
+    options.read(true).write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    loop {
+        let path = dir.join(format!(".tmp{}", random_name()));
+        match options.open(&path) {
+            Ok(file) => return Ok((file, Some(path))),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn link_anonymous(file: &std::fs::File, to: &Path) -> io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::io::AsRawFd;
+
+    let src = CString::new(format!("/proc/self/fd/{}", file.as_raw_fd())).unwrap();
+    let dst = CString::new(to.as_os_str().as_bytes())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "path contained a nul byte"))?;
+
+    // SAFETY: both paths are valid C strings for the duration of the call.
+    let res = unsafe {
+        libc::linkat(
+            libc::AT_FDCWD,
+            src.as_ptr(),
+            libc::AT_FDCWD,
+            dst.as_ptr(),
+            libc::AT_SYMLINK_FOLLOW,
+        )
+    };
+    if res < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+fn link_anonymous(_file: &std::fs::File, _to: &Path) -> io::Result<()> {
+    // Anonymous files only exist on Linux; elsewhere `path` is always set.
+    unreachable!()
+}
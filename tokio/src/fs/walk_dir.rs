@@ -0,0 +1,223 @@
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::fs::{FileType, Metadata};
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+#[cfg(test)]
+use super::mocks::spawn_blocking;
+#[cfg(test)]
+use super::mocks::JoinHandle;
+#[cfg(not(test))]
+use crate::blocking::spawn_blocking;
+#[cfg(not(test))]
+use crate::blocking::JoinHandle;
+
+const CHUNK_SIZE: usize = 32;
+
+/// Returns a stream over the entries of a directory tree.
+///
+/// Entries are yielded depth first, directories before their contents, and
+/// each entry carries pre-fetched [`Metadata`]. Entries are read from the
+/// operating system in batches, making one hop to the blocking thread pool
+/// per batch rather than one per file; traversing a large tree is therefore
+/// much cheaper than recursing with [`read_dir`] and calling
+/// [`DirEntry::metadata`] per entry.
+///
+/// Symbolic links are not followed: a link to a directory is yielded as a
+/// single entry and its target is not descended into.
+///
+/// [`read_dir`]: super::read_dir
+/// [`DirEntry::metadata`]: super::DirEntry::metadata
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs;
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// let mut entries = fs::walk_dir(".").await?;
+///
+/// while let Some(entry) = entries.next_entry().await? {
+///     println!("{:?}: {} bytes", entry.path(), entry.metadata().len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn walk_dir(path: impl AsRef<Path>) -> io::Result<WalkDir> {
+    let path = path.as_ref().to_owned();
+    crate::fs::asyncify(|| -> io::Result<WalkDir> {
+        let mut stack = vec![std::fs::read_dir(path)?];
+        let mut buf = VecDeque::with_capacity(CHUNK_SIZE);
+        let remain = WalkDir::next_chunk(&mut buf, &mut stack);
+
+        Ok(WalkDir(State::Idle(Some((buf, stack, remain)))))
+    })
+    .await
+}
+
+/// Reads the entries of a directory tree.
+///
+/// This struct is returned from the [`walk_dir`] function of this module and
+/// will yield instances of [`WalkEntry`].
+///
+/// # Errors
+///
+/// This stream will return an [`Err`] if an entry or a subdirectory cannot be
+/// read; the traversal then continues with the remaining entries.
+///
+/// [`Err`]: std::result::Result::Err
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct WalkDir(State);
+
+#[derive(Debug)]
+enum State {
+    Idle(Option<(VecDeque<io::Result<WalkEntry>>, Vec<std::fs::ReadDir>, bool)>),
+    Pending(JoinHandle<(VecDeque<io::Result<WalkEntry>>, Vec<std::fs::ReadDir>, bool)>),
+}
+
+/// Entries returned by the [`WalkDir`] stream.
+///
+/// Unlike [`DirEntry`], the metadata of the entry is fetched eagerly while
+/// the directory is read, so inspecting it does not cost an extra trip to the
+/// blocking thread pool.
+///
+/// [`DirEntry`]: super::DirEntry
+#[derive(Debug)]
+pub struct WalkEntry {
+    path: PathBuf,
+    metadata: Metadata,
+}
+
+impl WalkEntry {
+    /// Returns the full path to the file that this entry represents.
+    ///
+    /// The full path is created by joining the path passed to `walk_dir` with
+    /// the path of the entry below it.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the bare file name of this entry without any other leading
+    /// path component.
+    pub fn file_name(&self) -> OsString {
+        self.path
+            .file_name()
+            .map(OsString::from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the metadata of the entry, fetched when the entry was read.
+    ///
+    /// Symbolic links are not followed, so for a symlink this is the metadata
+    /// of the link itself.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns the file type of the entry.
+    pub fn file_type(&self) -> FileType {
+        self.metadata.file_type()
+    }
+}
+
+impl WalkDir {
+    /// Returns the next entry in the directory tree.
+    ///
+    /// Returns `None` once the traversal is complete.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancellation safe.
+    pub async fn next_entry(&mut self) -> io::Result<Option<WalkEntry>> {
+        use std::future::poll_fn;
+        poll_fn(|cx| self.poll_next_entry(cx)).await
+    }
+
+    /// Polls for the next entry in the directory tree.
+    ///
+    /// This method returns:
+    ///
+    ///  * `Poll::Pending` if the next entry is not yet available.
+    ///  * `Poll::Ready(Ok(Some(entry)))` if the next entry is available.
+    ///  * `Poll::Ready(Ok(None))` if the traversal is complete.
+    ///  * `Poll::Ready(Err(err))` if an IO error occurred while reading the
+    ///    next entry.
+    ///
+    /// When the method returns `Poll::Pending`, the `Waker` in the provided
+    /// `Context` is scheduled to receive a wakeup when the next entry becomes
+    /// available on the underlying IO resource.
+    pub fn poll_next_entry(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<WalkEntry>>> {
+        loop {
+            match self.0 {
+                State::Idle(ref mut data) => {
+                    let (buf, _, ref remain) = data.as_mut().unwrap();
+
+                    if let Some(ent) = buf.pop_front() {
+                        return Poll::Ready(ent.map(Some));
+                    } else if !remain {
+                        return Poll::Ready(Ok(None));
+                    }
+
+                    let (mut buf, mut stack, _) = data.take().unwrap();
+
+                    self.0 = State::Pending(spawn_blocking(move || {
+                        let remain = WalkDir::next_chunk(&mut buf, &mut stack);
+                        (buf, stack, remain)
+                    }));
+                }
+                State::Pending(ref mut rx) => {
+                    self.0 = State::Idle(Some(ready!(Pin::new(rx).poll(cx))?));
+                }
+            }
+        }
+    }
+
+    fn next_chunk(
+        buf: &mut VecDeque<io::Result<WalkEntry>>,
+        stack: &mut Vec<std::fs::ReadDir>,
+    ) -> bool {
+        while buf.len() < CHUNK_SIZE {
+            let std = match stack.last_mut() {
+                Some(std) => std,
+                None => return false,
+            };
+
+            let entry = match std.next() {
+                Some(Ok(entry)) => entry,
+                Some(Err(e)) => {
+                    buf.push_back(Err(e));
+                    continue;
+                }
+                None => {
+                    stack.pop();
+                    continue;
+                }
+            };
+
+            let path = entry.path();
+            let metadata = match std::fs::symlink_metadata(&path) {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    buf.push_back(Err(e));
+                    continue;
+                }
+            };
+
+            if metadata.is_dir() {
+                match std::fs::read_dir(&path) {
+                    Ok(std) => stack.push(std),
+                    Err(e) => buf.push_back(Err(e)),
+                }
+            }
+
+            buf.push_back(Ok(WalkEntry { path, metadata }));
+        }
+
+        true
+    }
+}
@@ -0,0 +1,293 @@
+//! Filesystem event watching backed by inotify and the I/O driver.
+
+use crate::io::{Interest, PollEvented};
+
+use mio::unix::SourceFd;
+use std::collections::VecDeque;
+use std::ffi::{CStr, CString, OsStr};
+use std::io;
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::{AsRawFd, FromRawFd, OwnedFd};
+use std::path::{Path, PathBuf};
+
+/// Watches a path for filesystem events.
+///
+/// The returned [`Watcher`] reports created, modified, and removed entries
+/// through [`next`]. Watching a directory reports events for its direct
+/// entries; it is not recursive. The watcher is backed by `inotify` and the
+/// runtime's I/O driver, so no extra threads or channel bridging is needed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs;
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// let mut watcher = fs::watch(".")?;
+///
+/// while let Some(event) = watcher.next().await? {
+///     println!("{:?}: {:?}", event.kind(), event.path());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`next`]: Watcher::next
+///
+/// # Panics
+///
+/// This function panics if it is not called from within a runtime with
+/// IO enabled.
+#[track_caller]
+pub fn watch(path: impl AsRef<Path>) -> io::Result<Watcher> {
+    Watcher::new(path.as_ref())
+}
+
+const EVENT_MASK: u32 = libc::IN_CREATE
+    | libc::IN_MODIFY
+    | libc::IN_CLOSE_WRITE
+    | libc::IN_ATTRIB
+    | libc::IN_DELETE
+    | libc::IN_MOVED_FROM
+    | libc::IN_MOVED_TO
+    | libc::IN_DELETE_SELF
+    | libc::IN_MOVE_SELF;
+
+/// A filesystem event reported by a [`Watcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FsEvent {
+    path: PathBuf,
+    kind: FsEventKind,
+}
+
+impl FsEvent {
+    /// Returns the path the event refers to.
+    ///
+    /// For events on a watched directory's entries this is the entry's full
+    /// path; for events on the watched path itself it is the watched path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns what happened to the path.
+    pub fn kind(&self) -> FsEventKind {
+        self.kind
+    }
+}
+
+/// The kind of change a [`FsEvent`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FsEventKind {
+    /// The entry was created or moved into the watched directory.
+    Create,
+    /// The entry's contents or metadata were modified.
+    Modify,
+    /// The entry was removed or moved out of the watched directory.
+    Remove,
+}
+
+/// A stream of filesystem events for a watched path.
+///
+/// Created by [`watch`]. Events are reported in the order the operating
+/// system delivered them; a debounce window can be configured through
+/// [`set_debounce`] to coalesce bursts of identical events, such as the many
+/// modifications produced by writing a file in chunks.
+///
+/// The watch ends when the watched path itself is deleted, at which point
+/// [`next`] returns `None`.
+///
+/// [`next`]: Watcher::next
+/// [`set_debounce`]: Watcher::set_debounce
+#[derive(Debug)]
+pub struct Watcher {
+    io: PollEvented<InotifyIo>,
+    path: PathBuf,
+    pending: VecDeque<FsEvent>,
+    #[cfg(feature = "time")]
+    debounce: Option<std::time::Duration>,
+    done: bool,
+}
+
+#[derive(Debug)]
+struct InotifyIo {
+    fd: OwnedFd,
+}
+
+impl mio::event::Source for InotifyIo {
+    fn register(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &mio::Registry) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).deregister(registry)
+    }
+}
+
+impl Watcher {
+    #[track_caller]
+    fn new(path: &Path) -> io::Result<Watcher> {
+        // SAFETY: `inotify_init1` creates a new descriptor we then own.
+        let raw = unsafe { libc::inotify_init1(libc::IN_NONBLOCK | libc::IN_CLOEXEC) };
+        if raw < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(raw) };
+
+        let c_path = CString::new(path.as_os_str().as_bytes())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "path contained a nul byte"))?;
+        // SAFETY: the path is a valid C string and the descriptor is owned.
+        let wd = unsafe { libc::inotify_add_watch(raw, c_path.as_ptr(), EVENT_MASK) };
+        if wd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Watcher {
+            io: PollEvented::new(InotifyIo { fd })?,
+            path: path.to_owned(),
+            pending: VecDeque::new(),
+            #[cfg(feature = "time")]
+            debounce: None,
+            done: false,
+        })
+    }
+
+    /// Coalesces events that arrive within `window` of each other.
+    ///
+    /// After an event becomes ready, the watcher keeps draining events for
+    /// the duration of the window and drops consecutive duplicates, so a
+    /// burst of writes to one file is reported as a single
+    /// [`Modify`](FsEventKind::Modify) event. Debouncing is disabled by
+    /// default; events are then returned as soon as they arrive.
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn set_debounce(&mut self, window: Option<std::time::Duration>) {
+        self.debounce = window;
+    }
+
+    /// Waits for the next filesystem event.
+    ///
+    /// Returns `None` once the watched path itself has been removed and all
+    /// preceding events were observed.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. Events that were already read from the
+    /// operating system are buffered internally and are yielded by a later
+    /// call if this one is cancelled.
+    pub async fn next(&mut self) -> io::Result<Option<FsEvent>> {
+        while self.pending.is_empty() && !self.done {
+            self.fill_pending().await?;
+
+            #[cfg(feature = "time")]
+            if let Some(window) = self.debounce {
+                if !self.pending.is_empty() {
+                    let deadline = crate::time::Instant::now() + window;
+                    while !self.done {
+                        match crate::time::timeout_at(deadline, self.fill_pending()).await {
+                            Ok(res) => res?,
+                            Err(_) => break,
+                        }
+                    }
+                    self.coalesce();
+                }
+            }
+        }
+
+        Ok(self.pending.pop_front())
+    }
+
+    /// Reads one batch of events from the inotify descriptor into `pending`.
+    async fn fill_pending(&mut self) -> io::Result<()> {
+        // The buffer must be aligned for `inotify_event`, since events are
+        // referenced in place below. The kernel pads each event's name so
+        // that every event within the buffer stays aligned.
+        #[repr(align(4))]
+        struct EventBuf([u8; 4096]);
+        const _: () = assert!(mem::align_of::<libc::inotify_event>() <= 4);
+
+        let mut buf = EventBuf([0u8; 4096]);
+        let buf = &mut buf.0;
+        let n = self
+            .io
+            .registration()
+            .async_io(Interest::READABLE, || {
+                // SAFETY: the buffer outlives the call.
+                let res = unsafe {
+                    libc::read(
+                        self.io.fd.as_raw_fd(),
+                        buf.as_mut_ptr() as *mut libc::c_void,
+                        buf.len(),
+                    )
+                };
+                if res < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                Ok(res as usize)
+            })
+            .await?;
+
+        let mut offset = 0;
+        while offset + mem::size_of::<libc::inotify_event>() <= n {
+            // SAFETY: the kernel wrote a complete, suitably aligned event at
+            // this offset.
+            let event = unsafe { &*(buf[offset..].as_ptr() as *const libc::inotify_event) };
+            let name_len = event.len as usize;
+            let name_offset = offset + mem::size_of::<libc::inotify_event>();
+            offset = name_offset + name_len;
+
+            if event.mask & (libc::IN_DELETE_SELF | libc::IN_MOVE_SELF | libc::IN_IGNORED) != 0 {
+                self.done = true;
+                continue;
+            }
+
+            let kind = if event.mask & (libc::IN_CREATE | libc::IN_MOVED_TO) != 0 {
+                FsEventKind::Create
+            } else if event.mask & (libc::IN_DELETE | libc::IN_MOVED_FROM) != 0 {
+                FsEventKind::Remove
+            } else {
+                FsEventKind::Modify
+            };
+
+            let path = if name_len == 0 {
+                self.path.clone()
+            } else {
+                // SAFETY: the kernel nul-terminates the name within `len`.
+                let name = unsafe { CStr::from_ptr(buf[name_offset..].as_ptr().cast()) };
+                self.path.join(OsStr::from_bytes(name.to_bytes()))
+            };
+
+            self.pending.push_back(FsEvent { path, kind });
+        }
+
+        Ok(())
+    }
+
+    /// Drops consecutive duplicate events accumulated during a debounce
+    /// window.
+    #[cfg(feature = "time")]
+    fn coalesce(&mut self) {
+        let mut deduped: VecDeque<FsEvent> = VecDeque::with_capacity(self.pending.len());
+        for event in self.pending.drain(..) {
+            if deduped.back() != Some(&event) {
+                deduped.push_back(event);
+            }
+        }
+        self.pending = deduped;
+    }
+}
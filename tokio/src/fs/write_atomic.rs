@@ -0,0 +1,88 @@
+use crate::fs::asyncify;
+use crate::fs::temp_file::random_name;
+
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Writes a slice as the entire contents of a file, atomically.
+///
+/// The contents are written to a temporary file in the same directory as
+/// `path`, flushed to stable storage with `fsync`, and then renamed over the
+/// destination. Readers therefore observe either the old contents or the new
+/// contents in full — never a partially written file, even if the process
+/// crashes or power is lost mid-write. On Unix the containing directory is
+/// also fsynced (best-effort) so the rename itself survives a crash.
+///
+/// This is the pattern recommended for config files, state snapshots, and
+/// other small files where a torn write is unacceptable. For plain
+/// overwrites where atomicity does not matter, [`write`] is cheaper.
+///
+/// If the write or sync fails, the temporary file is removed and the
+/// destination is left untouched.
+///
+/// **Note**: the rename requires the temporary file and `path` to be on the
+/// same filesystem, which is why the temporary file is placed next to the
+/// destination rather than in the system temporary directory.
+///
+/// [`write`]: super::write
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs;
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// fs::write_atomic("config.json", b"{ \"key\": true }").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    let contents = crate::util::as_ref::upgrade(contents);
+
+    asyncify(move || write_atomic_sync(&path, contents.as_ref())).await
+}
+
+fn write_atomic_sync(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    // `create_new` with a randomized name guarantees we never write through
+    // a file planted by another process.
+    let (mut file, tmp_path) = loop {
+        let tmp_path = dir.join(format!(".tmp{}", random_name()));
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&tmp_path)
+        {
+            Ok(file) => break (file, tmp_path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    };
+
+    let res = file
+        .write_all(contents)
+        .and_then(|()| file.sync_all())
+        .and_then(|()| {
+            drop(file);
+            std::fs::rename(&tmp_path, path)
+        });
+
+    if res.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+        return res;
+    }
+
+    // Fsync the directory so the rename is durable. Best-effort: not every
+    // filesystem permits opening a directory for this.
+    #[cfg(unix)]
+    if let Ok(dir) = std::fs::File::open(dir) {
+        let _ = dir.sync_all();
+    }
+
+    Ok(())
+}
@@ -497,6 +497,99 @@ impl<T: AsRawFd> AsyncFd<T> {
         }))
     }
 
+    /// Polls for any of the requested ready states.
+    ///
+    /// If none of the requested states are ready, this method stores a clone
+    /// of the [`Waker`] from the provided [`Context`] for each direction in
+    /// `interest`, and the task is woken when one of them becomes ready. Only
+    /// the `Waker` from the `Context` passed to the most recent call for a
+    /// given direction is scheduled to receive a wakeup, so this method is
+    /// subject to the same mutual-exclusion requirements as
+    /// [`poll_read_ready`] and [`poll_write_ready`]. Wakers are only
+    /// registered for the directions in `interest`, so a task polling with
+    /// `Interest::READABLE` does not receive spurious wakeups when the file
+    /// descriptor becomes writable.
+    ///
+    /// If both directions are requested and both are ready, the returned
+    /// guard reports read readiness; the next call reports write readiness.
+    /// Check [`AsyncFdReadyGuard::ready`] to see which interests fired.
+    ///
+    /// This method is intended for cases where creating and pinning a future
+    /// via [`ready`] is not feasible. Where possible, using [`ready`] is
+    /// preferred, as this supports polling from multiple tasks at once.
+    ///
+    /// This method takes `&self`, so it is possible to call this method
+    /// concurrently with other methods on this struct. This method only
+    /// provides shared access to the inner IO resource when handling the
+    /// [`AsyncFdReadyGuard`].
+    ///
+    /// [`poll_read_ready`]: method@Self::poll_read_ready
+    /// [`poll_write_ready`]: method@Self::poll_write_ready
+    /// [`ready`]: method@Self::ready
+    /// [`Context`]: struct@std::task::Context
+    /// [`Waker`]: struct@std::task::Waker
+    pub fn poll_ready<'a>(
+        &'a self,
+        interest: Interest,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<AsyncFdReadyGuard<'a, T>>> {
+        let event = ready!(self.poll_ready_event(interest, cx))?;
+
+        Poll::Ready(Ok(AsyncFdReadyGuard {
+            async_fd: self,
+            event: Some(event),
+        }))
+    }
+
+    /// Polls for any of the requested ready states.
+    ///
+    /// This is identical to [`poll_ready`], except that it takes `&mut self`,
+    /// so it is possible to access the inner IO resource mutably when
+    /// handling the [`AsyncFdReadyMutGuard`].
+    ///
+    /// [`poll_ready`]: method@Self::poll_ready
+    pub fn poll_ready_mut<'a>(
+        &'a mut self,
+        interest: Interest,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<AsyncFdReadyMutGuard<'a, T>>> {
+        let event = ready!(self.poll_ready_event(interest, cx))?;
+
+        Poll::Ready(Ok(AsyncFdReadyMutGuard {
+            async_fd: self,
+            event: Some(event),
+        }))
+    }
+
+    /// Polls each direction in `interest`, returning the first ready event.
+    ///
+    /// Wakers remain registered for every requested direction that is still
+    /// pending, so the task is woken as soon as any of them becomes ready.
+    fn poll_ready_event(
+        &self,
+        interest: Interest,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<ReadyEvent>> {
+        let mut event = None;
+
+        if interest.is_readable() {
+            if let Poll::Ready(ev) = self.registration.poll_read_ready(cx)? {
+                event = Some(ev);
+            }
+        }
+
+        if interest.is_writable() && event.is_none() {
+            if let Poll::Ready(ev) = self.registration.poll_write_ready(cx)? {
+                event = Some(ev);
+            }
+        }
+
+        match event {
+            Some(event) => Poll::Ready(Ok(event)),
+            None => Poll::Pending,
+        }
+    }
+
     /// Waits for any of the requested ready states, returning a
     /// [`AsyncFdReadyGuard`] that must be dropped to resume
     /// polling for the requested ready states.
@@ -56,6 +56,51 @@ pub trait AsyncRead {
         cx: &mut Context<'_>,
         buf: &mut ReadBuf<'_>,
     ) -> Poll<io::Result<()>>;
+
+    /// Attempts to read from the `AsyncRead` into multiple buffers.
+    ///
+    /// Data is placed in each buffer in order, with the final buffer written
+    /// to possibly being only partially filled. On success, returns
+    /// `Poll::Ready(Ok(n))` where `n` is the number of bytes read. If `n` is
+    /// 0, it implies that EOF has been reached, unless none of the buffers
+    /// had remaining capacity.
+    ///
+    /// The default implementation reads into the first buffer with remaining
+    /// capacity using [`poll_read`], which is correct for any reader.
+    /// Readers that can fill several buffers with a single system call
+    /// (scatter input) should override this method along with
+    /// [`is_read_vectored`].
+    ///
+    /// [`poll_read`]: AsyncRead::poll_read
+    /// [`is_read_vectored`]: AsyncRead::is_read_vectored
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [ReadBuf<'_>],
+    ) -> Poll<io::Result<usize>> {
+        for buf in bufs {
+            if buf.remaining() > 0 {
+                let filled = buf.filled().len();
+                std::task::ready!(self.poll_read(cx, buf))?;
+                return Poll::Ready(Ok(buf.filled().len() - filled));
+            }
+        }
+        Poll::Ready(Ok(0))
+    }
+
+    /// Determines if this reader has an efficient [`poll_read_vectored`]
+    /// implementation.
+    ///
+    /// If a reader does not override the default [`poll_read_vectored`]
+    /// implementation, code using it may want to avoid splitting its data
+    /// across several buffers in the first place.
+    ///
+    /// The default implementation returns `false`.
+    ///
+    /// [`poll_read_vectored`]: AsyncRead::poll_read_vectored
+    fn is_read_vectored(&self) -> bool {
+        false
+    }
 }
 
 macro_rules! deref_async_read {
@@ -67,6 +112,18 @@ macro_rules! deref_async_read {
         ) -> Poll<io::Result<()>> {
             Pin::new(&mut **self).poll_read(cx, buf)
         }
+
+        fn poll_read_vectored(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &mut [ReadBuf<'_>],
+        ) -> Poll<io::Result<usize>> {
+            Pin::new(&mut **self).poll_read_vectored(cx, bufs)
+        }
+
+        fn is_read_vectored(&self) -> bool {
+            (**self).is_read_vectored()
+        }
     };
 }
 
@@ -90,6 +147,18 @@ where
     ) -> Poll<io::Result<()>> {
         crate::util::pin_as_deref_mut(self).poll_read(cx, buf)
     }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [ReadBuf<'_>],
+    ) -> Poll<io::Result<usize>> {
+        crate::util::pin_as_deref_mut(self).poll_read_vectored(cx, bufs)
+    }
+
+    fn is_read_vectored(&self) -> bool {
+        (**self).is_read_vectored()
+    }
 }
 
 impl AsyncRead for &[u8] {
@@ -208,6 +208,9 @@ pub use self::async_write::AsyncWrite;
 
 mod read_buf;
 pub use self::read_buf::ReadBuf;
+cfg_net! {
+    pub(crate) use self::read_buf::read_vectored_into;
+}
 
 // Re-export some types from `std::io` so that users don't have to deal
 // with conflicts when `use`ing `tokio::io` and `std::io`.
@@ -259,7 +262,7 @@ cfg_io_std! {
     pub use stderr::{stderr, Stderr};
 
     mod stdin;
-    pub use stdin::{stdin, Stdin};
+    pub use stdin::{stdin, stdin_locked, Stdin, StdinLock};
 
     mod stdout;
     pub use stdout::{stdout, Stdout};
@@ -267,16 +270,28 @@ cfg_io_std! {
 
 cfg_io_util! {
     mod split;
-    pub use split::{split, ReadHalf, WriteHalf};
+    pub use split::{
+        split, split_concurrent, ConcurrentReadHalf, ConcurrentWriteHalf, ConcurrentlySplittable,
+        ReadHalf, WriteHalf,
+    };
     mod join;
     pub use join::{join, Join};
 
     pub(crate) mod seek;
     pub(crate) mod util;
     pub use util::{
-        copy, copy_bidirectional, copy_bidirectional_with_sizes, copy_buf, duplex, empty, repeat, sink, simplex, AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt,
-        BufReader, BufStream, BufWriter, DuplexStream, Empty, Lines, Repeat, Sink, Split, Take, SimplexStream,
+        copy, copy_bidirectional, copy_bidirectional_with_sizes, copy_buf, duplex, empty, inspect_read, inspect_write, repeat, sink, simplex, tee, AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt,
+        BufReader, BufStream, BufWriter, DuplexStream, Empty, InspectReader, InspectWriter, InvalidUtf8Policy, Lines, Peek, Repeat, Sink, Split, Take, Tee, SimplexStream,
     };
+
+    cfg_time! {
+        pub use util::{duplex_with_options, CopyOptions, DeadlineStream, DuplexOptions};
+    }
+
+    cfg_net! {
+        #[cfg(target_os = "linux")]
+        pub use util::copy_bidirectional_splice;
+    }
 }
 
 cfg_not_io_util! {
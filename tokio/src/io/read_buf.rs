@@ -324,3 +324,35 @@ unsafe fn slice_assume_init(slice: &[MaybeUninit<u8>]) -> &[u8] {
 unsafe fn slice_assume_init_mut(slice: &mut [MaybeUninit<u8>]) -> &mut [u8] {
     &mut *(slice as *mut [MaybeUninit<u8>] as *mut [u8])
 }
+
+cfg_net! {
+    /// Performs a vectored read with `f` over the unfilled portions of
+    /// `bufs`, advancing them past the bytes that were read.
+    ///
+    /// The unfilled portions are initialized before being exposed as
+    /// `IoSliceMut`s, so `f` only ever sees initialized memory.
+    pub(crate) fn read_vectored_into(
+        bufs: &mut [ReadBuf<'_>],
+        f: impl FnOnce(&mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize>,
+    ) -> std::io::Result<usize> {
+        let mut slices: Vec<std::io::IoSliceMut<'_>> = bufs
+            .iter_mut()
+            .map(|buf| std::io::IoSliceMut::new(buf.initialize_unfilled()))
+            .collect();
+        let n = f(&mut slices)?;
+        drop(slices);
+
+        let mut remaining = n;
+        for buf in bufs.iter_mut() {
+            let amt = std::cmp::min(remaining, buf.remaining());
+            buf.advance(amt);
+            remaining -= amt;
+            if remaining == 0 {
+                break;
+            }
+        }
+        debug_assert_eq!(remaining, 0);
+
+        Ok(n)
+    }
+}
@@ -6,6 +6,7 @@
 
 use crate::io::{AsyncRead, AsyncWrite, ReadBuf};
 
+use std::cell::UnsafeCell;
 use std::fmt;
 use std::io;
 use std::pin::Pin;
@@ -24,9 +25,25 @@ cfg_io_util! {
         inner: Arc<Inner<T>>,
     }
 
+    /// The readable half of a value returned from
+    /// [`split_concurrent`](split_concurrent()).
+    pub struct ConcurrentReadHalf<T> {
+        inner: Arc<T>,
+    }
+
+    /// The writable half of a value returned from
+    /// [`split_concurrent`](split_concurrent()).
+    pub struct ConcurrentWriteHalf<T> {
+        inner: Arc<T>,
+    }
+
     /// Splits a single value implementing `AsyncRead + AsyncWrite` into separate
     /// `AsyncRead` and `AsyncWrite` handles.
     ///
+    /// Each call into either half takes an internal lock, as the two halves
+    /// may be driven from separate tasks. For types that support concurrent
+    /// reads and writes, [`split_concurrent`] avoids the lock entirely.
+    ///
     /// To restore this read/write object from its `ReadHalf` and
     /// `WriteHalf` use [`unsplit`](ReadHalf::unsplit()).
     pub fn split<T>(stream: T) -> (ReadHalf<T>, WriteHalf<T>)
@@ -36,7 +53,8 @@ cfg_io_util! {
         let is_write_vectored = stream.is_write_vectored();
 
         let inner = Arc::new(Inner {
-            stream: Mutex::new(stream),
+            lock: Mutex::new(()),
+            stream: UnsafeCell::new(stream),
             is_write_vectored,
         });
 
@@ -48,19 +66,108 @@ cfg_io_util! {
 
         (rd, wr)
     }
+
+    /// Splits a value that supports concurrent reads and writes into separate
+    /// `AsyncRead` and `AsyncWrite` handles, without any internal locking.
+    ///
+    /// This is equivalent to [`split`](split()), except that the two halves
+    /// call straight into the underlying stream through a shared reference
+    /// rather than serializing every operation through a `Mutex`, which
+    /// removes lock contention when the halves are driven from separate
+    /// tasks. It is only available for types that opt in via
+    /// [`ConcurrentlySplittable`].
+    ///
+    /// To restore this read/write object from its `ConcurrentReadHalf` and
+    /// `ConcurrentWriteHalf` use [`unsplit`](ConcurrentReadHalf::unsplit()).
+    pub fn split_concurrent<T>(stream: T) -> (ConcurrentReadHalf<T>, ConcurrentWriteHalf<T>)
+    where
+        T: ConcurrentlySplittable,
+    {
+        let inner = Arc::new(stream);
+
+        let rd = ConcurrentReadHalf {
+            inner: inner.clone(),
+        };
+
+        let wr = ConcurrentWriteHalf { inner };
+
+        (rd, wr)
+    }
+
+    /// Types whose read and write paths can be driven concurrently through a
+    /// shared reference, enabling a lock-free [`split_concurrent`].
+    ///
+    /// Implementors provide `&self` versions of the [`AsyncRead`] and
+    /// [`AsyncWrite`] poll methods. The two halves returned by
+    /// [`split_concurrent`] each hold a shared reference to the stream and
+    /// delegate to these methods, the same way [`TcpStream::split`] drives a
+    /// socket from both halves — no exclusive access to the stream is ever
+    /// required, so no locking is needed.
+    ///
+    /// Implementations must route reads and writes through interior
+    /// state that is itself safe to use from both halves at once, such as a
+    /// readiness-based socket or per-direction locks.
+    ///
+    /// [`TcpStream::split`]: crate::net::TcpStream::split
+    pub trait ConcurrentlySplittable: AsyncRead + AsyncWrite {
+        /// Attempts to read from the stream through a shared reference.
+        ///
+        /// This is the `&self` equivalent of [`AsyncRead::poll_read`].
+        fn poll_read_ref(
+            &self,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>>;
+
+        /// Attempts to write to the stream through a shared reference.
+        ///
+        /// This is the `&self` equivalent of [`AsyncWrite::poll_write`].
+        fn poll_write_ref(&self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>>;
+
+        /// Attempts to write from multiple buffers through a shared
+        /// reference.
+        ///
+        /// This is the `&self` equivalent of
+        /// [`AsyncWrite::poll_write_vectored`]. Like that method, it defaults
+        /// to writing the first non-empty buffer.
+        fn poll_write_vectored_ref(
+            &self,
+            cx: &mut Context<'_>,
+            bufs: &[io::IoSlice<'_>],
+        ) -> Poll<io::Result<usize>> {
+            let buf = bufs
+                .iter()
+                .find(|b| !b.is_empty())
+                .map_or(&[][..], |b| &**b);
+            self.poll_write_ref(cx, buf)
+        }
+
+        /// Attempts to flush the stream through a shared reference.
+        ///
+        /// This is the `&self` equivalent of [`AsyncWrite::poll_flush`].
+        fn poll_flush_ref(&self, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+
+        /// Attempts to shut down the write side of the stream through a
+        /// shared reference.
+        ///
+        /// This is the `&self` equivalent of [`AsyncWrite::poll_shutdown`].
+        fn poll_shutdown_ref(&self, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+    }
 }
 
 struct Inner<T> {
-    stream: Mutex<T>,
+    lock: Mutex<()>,
+    stream: UnsafeCell<T>,
     is_write_vectored: bool,
 }
 
 impl<T> Inner<T> {
     fn with_lock<R>(&self, f: impl FnOnce(Pin<&mut T>) -> R) -> R {
-        let mut guard = self.stream.lock().unwrap();
+        let _guard = self.lock.lock().unwrap();
 
-        // safety: we do not move the stream.
-        let stream = unsafe { Pin::new_unchecked(&mut *guard) };
+        // safety: we do not move the stream and it is only ever accessed
+        // while the mutex is held.
+        let stream = unsafe { Pin::new_unchecked(&mut *self.stream.get()) };
 
         f(stream)
     }
@@ -92,7 +199,7 @@ impl<T> ReadHalf<T> {
                 .ok()
                 .expect("`Arc::try_unwrap` failed");
 
-            inner.stream.into_inner().unwrap()
+            inner.stream.into_inner()
         } else {
             panic!("Unrelated `split::Write` passed to `split::Read::unsplit`.")
         }
@@ -164,3 +271,92 @@ impl<T: fmt::Debug> fmt::Debug for WriteHalf<T> {
         fmt.debug_struct("split::WriteHalf").finish()
     }
 }
+
+impl<T> ConcurrentReadHalf<T> {
+    /// Checks if this `ConcurrentReadHalf` and some `ConcurrentWriteHalf`
+    /// were split from the same stream.
+    pub fn is_pair_of(&self, other: &ConcurrentWriteHalf<T>) -> bool {
+        other.is_pair_of(self)
+    }
+
+    /// Reunites with a previously split `ConcurrentWriteHalf`.
+    ///
+    /// # Panics
+    ///
+    /// If this `ConcurrentReadHalf` and the given `ConcurrentWriteHalf` do
+    /// not originate from the same `split_concurrent` operation this method
+    /// will panic.
+    /// This can be checked ahead of time by calling [`is_pair_of()`](Self::is_pair_of).
+    #[track_caller]
+    pub fn unsplit(self, wr: ConcurrentWriteHalf<T>) -> T {
+        if self.is_pair_of(&wr) {
+            drop(wr);
+
+            Arc::try_unwrap(self.inner)
+                .ok()
+                .expect("`Arc::try_unwrap` failed")
+        } else {
+            panic!("Unrelated `split::ConcurrentWriteHalf` passed to `split::ConcurrentReadHalf::unsplit`.")
+        }
+    }
+}
+
+impl<T> ConcurrentWriteHalf<T> {
+    /// Checks if this `ConcurrentWriteHalf` and some `ConcurrentReadHalf`
+    /// were split from the same stream.
+    pub fn is_pair_of(&self, other: &ConcurrentReadHalf<T>) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T: ConcurrentlySplittable> AsyncRead for ConcurrentReadHalf<T> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.inner.poll_read_ref(cx, buf)
+    }
+}
+
+impl<T: ConcurrentlySplittable> AsyncWrite for ConcurrentWriteHalf<T> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, io::Error>> {
+        self.inner.poll_write_ref(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        self.inner.poll_flush_ref(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        self.inner.poll_shutdown_ref(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<Result<usize, io::Error>> {
+        self.inner.poll_write_vectored_ref(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ConcurrentReadHalf<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("split::ConcurrentReadHalf").finish()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ConcurrentWriteHalf<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("split::ConcurrentWriteHalf").finish()
+    }
+}
@@ -3,8 +3,10 @@ use crate::io::{AsyncRead, ReadBuf};
 
 use std::io;
 use std::pin::Pin;
+use std::sync::{Condvar, Mutex, OnceLock};
 use std::task::Context;
 use std::task::Poll;
+use std::task::Waker;
 
 cfg_io_std! {
     /// A handle to the standard input stream of a process.
@@ -50,6 +52,183 @@ cfg_io_std! {
             std,
         }
     }
+
+    /// An exclusive handle to the standard input stream of a process.
+    ///
+    /// Unlike [`Stdin`], this handle does not tie up a thread from the
+    /// blocking pool. Reads are performed by a single dedicated thread that
+    /// is shared by all `StdinLock` handles over the lifetime of the
+    /// process, and any bytes that thread has already read are retained when
+    /// a handle is dropped and delivered to the next one. This makes
+    /// in-flight reads safe to cancel: dropping a pending read future (for
+    /// example when a `select!` branch with user input loses to a shutdown
+    /// signal) neither loses input nor leaves a blocking-pool worker stuck
+    /// until the next keypress.
+    ///
+    /// Bytes are delivered as the underlying stream produces them, so this
+    /// handle is suitable for interactive input, including terminals placed
+    /// in raw mode where every keypress arrives individually.
+    ///
+    /// Created by the [`stdin_locked`] function.
+    ///
+    /// [`stdin_locked`]: fn@stdin_locked
+    #[derive(Debug)]
+    pub struct StdinLock {
+        shared: &'static Shared,
+    }
+
+    /// Constructs an exclusive handle to the standard input of the current
+    /// process.
+    ///
+    /// Unlike [`stdin`], the returned handle does not perform reads on the
+    /// blocking pool, so dropping it mid-read does not leave a pool worker
+    /// blocked on standard input. See [`StdinLock`] for details.
+    ///
+    /// Only one `StdinLock` may exist at a time; the previous handle must be
+    /// dropped before a new one is created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another `StdinLock` is still alive.
+    ///
+    /// [`stdin`]: fn@stdin
+    pub fn stdin_locked() -> StdinLock {
+        let shared = Shared::get();
+        let already_locked = {
+            let mut inner = shared.inner.lock().unwrap();
+            std::mem::replace(&mut inner.locked, true)
+        };
+        // Panic outside the critical section so the mutex is not poisoned.
+        assert!(
+            !already_locked,
+            "stdin_locked() called while another StdinLock is alive"
+        );
+        StdinLock { shared }
+    }
+}
+
+/// State shared between `StdinLock` handles and the dedicated reader thread.
+#[derive(Debug)]
+struct Shared {
+    inner: Mutex<SharedInner>,
+    /// Signalled when buffered bytes are consumed, unblocking the reader
+    /// thread once it has room to read ahead again.
+    readable: Condvar,
+}
+
+#[derive(Debug)]
+struct SharedInner {
+    /// Bytes read ahead by the reader thread, not yet handed out.
+    buf: Vec<u8>,
+    /// Whether a `StdinLock` handle is currently alive.
+    locked: bool,
+    /// Set once the reader thread observes end of input.
+    eof: bool,
+    /// A read error waiting to be delivered, if any.
+    error: Option<io::Error>,
+    waker: Option<Waker>,
+}
+
+/// Maximum number of bytes the reader thread buffers ahead of the consumer.
+const MAX_BUFFERED: usize = 8 * 1024;
+
+impl Shared {
+    fn get() -> &'static Shared {
+        static SHARED: OnceLock<Shared> = OnceLock::new();
+        SHARED.get_or_init(|| {
+            let shared = Shared {
+                inner: Mutex::new(SharedInner {
+                    buf: Vec::new(),
+                    locked: false,
+                    eof: false,
+                    error: None,
+                    waker: None,
+                }),
+                readable: Condvar::new(),
+            };
+
+            std::thread::Builder::new()
+                .name("tokio-stdin".to_string())
+                .spawn(|| Shared::get().read_loop())
+                .expect("failed to spawn stdin reader thread");
+
+            shared
+        })
+    }
+
+    /// Body of the dedicated reader thread. Reads standard input into the
+    /// shared buffer until end of input or an error, applying backpressure
+    /// once `MAX_BUFFERED` bytes are pending.
+    fn read_loop(&self) {
+        use std::io::Read;
+
+        let mut stdin = io::stdin();
+        let mut chunk = [0; 1024];
+        loop {
+            {
+                let mut inner = self.inner.lock().unwrap();
+                while inner.buf.len() >= MAX_BUFFERED {
+                    inner = self.readable.wait(inner).unwrap();
+                }
+            }
+
+            // Read without holding the lock so a pending read never blocks
+            // handle creation or destruction.
+            let res = stdin.read(&mut chunk);
+
+            let mut inner = self.inner.lock().unwrap();
+            match res {
+                Ok(0) => inner.eof = true,
+                Ok(n) => inner.buf.extend_from_slice(&chunk[..n]),
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => inner.error = Some(e),
+            }
+            if let Some(waker) = inner.waker.take() {
+                waker.wake();
+            }
+            if inner.eof || inner.error.is_some() {
+                return;
+            }
+        }
+    }
+}
+
+impl AsyncRead for StdinLock {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut inner = self.shared.inner.lock().unwrap();
+
+        if !inner.buf.is_empty() {
+            let n = inner.buf.len().min(buf.remaining());
+            buf.put_slice(&inner.buf[..n]);
+            inner.buf.drain(..n);
+            self.shared.readable.notify_one();
+            return Poll::Ready(Ok(()));
+        }
+
+        if let Some(e) = inner.error.take() {
+            inner.eof = true;
+            return Poll::Ready(Err(e));
+        }
+
+        if inner.eof {
+            return Poll::Ready(Ok(()));
+        }
+
+        inner.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for StdinLock {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.locked = false;
+        inner.waker = None;
+    }
 }
 
 #[cfg(unix)]
@@ -1,6 +1,7 @@
 use crate::io::util::fill_buf::{fill_buf, FillBuf};
 use crate::io::util::lines::{lines, Lines};
 use crate::io::util::read_line::{read_line, ReadLine};
+use crate::io::util::read_line_limited::{read_line_limited, ReadLineLimited};
 use crate::io::util::read_until::{read_until, ReadUntil};
 use crate::io::util::split::{split, Split};
 use crate::io::AsyncBufRead;
@@ -203,6 +204,71 @@ cfg_io_util! {
             read_line(self, buf)
         }
 
+        /// Reads all bytes until a newline (the 0xA byte) is reached,
+        /// appending at most `limit` bytes to the provided buffer.
+        ///
+        /// Equivalent to:
+        ///
+        /// ```ignore
+        /// async fn read_line_limited(&mut self, buf: &mut String, limit: usize) -> io::Result<usize>;
+        /// ```
+        ///
+        /// This behaves like [`read_line`], except that no more than `limit`
+        /// bytes are appended to `buf`. If the limit is reached before a
+        /// newline or EOF is found, the read fails with an
+        /// [`InvalidData`](std::io::ErrorKind::InvalidData) error. This
+        /// bounds the memory used per line when reading untrusted input.
+        ///
+        /// When the limit is hit, the bytes read so far have been consumed
+        /// from the stream and, if they were valid UTF-8, are left in `buf`.
+        ///
+        /// # Errors
+        ///
+        /// In addition to the limit error described above, this function has
+        /// the same error semantics as [`read_line`].
+        ///
+        /// # Cancel safety
+        ///
+        /// This method is not cancellation safe, for the same reason as
+        /// [`read_line`].
+        ///
+        /// [`read_line`]: AsyncBufReadExt::read_line
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::io::AsyncBufReadExt;
+        ///
+        /// use std::io::Cursor;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let mut cursor = Cursor::new(b"short\nthis line is far too long");
+        ///     let mut buf = String::new();
+        ///
+        ///     let num_bytes = cursor.read_line_limited(&mut buf, 16)
+        ///         .await
+        ///         .expect("first line fits within the limit");
+        ///
+        ///     assert_eq!(num_bytes, 6);
+        ///     assert_eq!(buf, "short\n");
+        ///     buf.clear();
+        ///
+        ///     let err = cursor.read_line_limited(&mut buf, 16).await.unwrap_err();
+        ///     assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        /// }
+        /// ```
+        fn read_line_limited<'a>(
+            &'a mut self,
+            buf: &'a mut String,
+            limit: usize,
+        ) -> ReadLineLimited<'a, Self>
+        where
+            Self: Unpin,
+        {
+            read_line_limited(self, buf, limit)
+        }
+
         /// Returns a stream of the contents of this reader split on the byte
         /// `byte`.
         ///
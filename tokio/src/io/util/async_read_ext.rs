@@ -10,6 +10,7 @@ use crate::io::util::read_int::{
     ReadU128, ReadU128Le, ReadU16, ReadU16Le, ReadU32, ReadU32Le, ReadU64, ReadU64Le, ReadU8,
 };
 use crate::io::util::read_to_end::{read_to_end, ReadToEnd};
+use crate::io::util::read_to_end_limited::{read_to_end_limited, ReadToEndLimited};
 use crate::io::util::read_to_string::{read_to_string, ReadToString};
 use crate::io::util::take::{take, Take};
 use crate::io::AsyncRead;
@@ -1340,6 +1341,62 @@ cfg_io_util! {
             read_to_end(self, buf)
         }
 
+        /// Reads all bytes until EOF in this source, appending them to `buf`,
+        /// failing if more than `limit` bytes are read.
+        ///
+        /// Equivalent to:
+        ///
+        /// ```ignore
+        /// async fn read_to_end_limited(&mut self, buf: &mut Vec<u8>, limit: usize) -> io::Result<usize>;
+        /// ```
+        ///
+        /// This behaves like [`read_to_end`], except that it stops with an
+        /// error of kind [`io::ErrorKind::InvalidData`] as soon as more than
+        /// `limit` bytes have been appended to `buf`. Unlike wrapping the
+        /// source with [`take`] — which silently truncates the input — this
+        /// makes oversized input an error, which protects against unbounded
+        /// allocation when reading untrusted data such as HTTP bodies.
+        ///
+        /// On failure, the bytes read so far remain in `buf`; since reads are
+        /// performed in chunks, `buf` may exceed `limit` by at most the size
+        /// of the final chunk.
+        ///
+        /// [`read_to_end`]: AsyncReadExt::read_to_end
+        /// [`take`]: AsyncReadExt::take
+        /// [`io::ErrorKind::InvalidData`]: std::io::ErrorKind::InvalidData
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::io::{self, AsyncReadExt};
+        ///
+        /// #[tokio::main]
+        /// async fn main() -> io::Result<()> {
+        ///     let mut body: &[u8] = b"a small body";
+        ///     let mut buffer = Vec::new();
+        ///
+        ///     // Succeeds: the input fits within the limit.
+        ///     let n = body.read_to_end_limited(&mut buffer, 1024).await?;
+        ///     assert_eq!(n, 12);
+        ///
+        ///     // Fails: more than four bytes of input.
+        ///     let mut body: &[u8] = b"a small body";
+        ///     let err = body.read_to_end_limited(&mut Vec::new(), 4).await.unwrap_err();
+        ///     assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        ///     Ok(())
+        /// }
+        /// ```
+        fn read_to_end_limited<'a>(
+            &'a mut self,
+            buf: &'a mut Vec<u8>,
+            limit: usize,
+        ) -> ReadToEndLimited<'a, Self>
+        where
+            Self: Unpin,
+        {
+            read_to_end_limited(self, buf, limit)
+        }
+
         /// Reads all bytes until EOF in this source, appending them to `buf`.
         ///
         /// Equivalent to:
@@ -88,6 +88,11 @@ impl<R: AsyncRead> BufReader<R> {
         &self.buf[self.pos..self.cap]
     }
 
+    /// Returns the number of bytes the internal buffer can hold at once.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
     /// Invalidates all data in the internal buffer.
     #[inline]
     fn discard_buffer(self: Pin<&mut Self>) {
@@ -97,6 +102,34 @@ impl<R: AsyncRead> BufReader<R> {
     }
 }
 
+impl<R: AsyncRead + AsyncSeek + Unpin> BufReader<R> {
+    /// Seeks relative to the current position.
+    ///
+    /// If the new position lies within the internal buffer, the buffer is
+    /// not discarded and the seek is a cheap cursor adjustment. Otherwise
+    /// this behaves like `seek` with [`SeekFrom::Current`], invalidating
+    /// the buffer. This makes small backwards and forwards seeks cheap for
+    /// parsers that probe around their current position.
+    pub async fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        use crate::io::AsyncSeekExt;
+
+        let pos = self.pos as u64;
+        if offset < 0 {
+            if let Some(new_pos) = pos.checked_sub(offset.unsigned_abs()) {
+                self.pos = new_pos as usize;
+                return Ok(());
+            }
+        } else if let Some(new_pos) = pos.checked_add(offset as u64) {
+            if new_pos <= self.cap as u64 {
+                self.pos = new_pos as usize;
+                return Ok(());
+            }
+        }
+
+        self.seek(SeekFrom::Current(offset)).await.map(drop)
+    }
+}
+
 impl<R: AsyncRead> AsyncRead for BufReader<R> {
     fn poll_read(
         mut self: Pin<&mut Self>,
@@ -117,6 +150,38 @@ impl<R: AsyncRead> AsyncRead for BufReader<R> {
         self.consume(amt);
         Poll::Ready(Ok(()))
     }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [ReadBuf<'_>],
+    ) -> Poll<io::Result<usize>> {
+        // If we don't have any buffered data and we're doing a massive
+        // scatter read (larger than our internal buffer), bypass our
+        // internal buffer entirely.
+        let total: usize = bufs.iter().map(|buf| buf.remaining()).sum();
+        if self.pos == self.cap && total >= self.buf.len() {
+            let res = ready!(self.as_mut().get_pin_mut().poll_read_vectored(cx, bufs));
+            self.discard_buffer();
+            return Poll::Ready(res);
+        }
+        let rem = ready!(self.as_mut().poll_fill_buf(cx))?;
+        let mut copied = 0;
+        for buf in bufs {
+            let amt = std::cmp::min(rem.len() - copied, buf.remaining());
+            buf.put_slice(&rem[copied..copied + amt]);
+            copied += amt;
+            if copied == rem.len() {
+                break;
+            }
+        }
+        self.consume(copied);
+        Poll::Ready(Ok(copied))
+    }
+
+    fn is_read_vectored(&self) -> bool {
+        self.inner.is_read_vectored()
+    }
 }
 
 impl<R: AsyncRead> AsyncBufRead for BufReader<R> {
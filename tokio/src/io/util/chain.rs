@@ -64,6 +64,22 @@ where
     pub fn into_inner(self) -> (T, U) {
         (self.first, self.second)
     }
+
+    /// Returns `true` if the first reader has reached EOF and subsequent
+    /// reads are served by the second reader.
+    pub fn is_first_done(&self) -> bool {
+        self.done_first
+    }
+
+    /// Replaces the second reader, returning the previous one.
+    ///
+    /// This may be called at any time, including before the first reader has
+    /// reached EOF, which allows queueing up the next part of a multi-part
+    /// stream while the current part is still being read. Any bytes the
+    /// previous second reader had already produced are not rolled back.
+    pub fn replace_second(&mut self, second: U) -> U {
+        std::mem::replace(&mut self.second, second)
+    }
 }
 
 impl<T, U> fmt::Debug for Chain<T, U>
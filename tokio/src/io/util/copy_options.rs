@@ -0,0 +1,216 @@
+use crate::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use crate::time::{sleep, Instant};
+
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+type ProgressFn = Box<dyn FnMut(u64) + Send>;
+
+/// Configures a [`copy`] operation with progress reporting and rate limiting.
+///
+/// The plain [`copy`] and [`copy_buf`] functions move bytes as fast as
+/// possible and report nothing until they finish. `CopyOptions` is the
+/// builder form used by transfer services that need to observe or bound the
+/// transfer while it runs:
+///
+/// * a progress callback invoked every N copied bytes, and
+/// * an optional throttle capping the transfer at a number of bytes per
+///   second.
+///
+/// # Example
+///
+/// ```no_run
+/// use tokio::io::CopyOptions;
+///
+/// # async fn dox() -> std::io::Result<()> {
+/// let mut reader = tokio::fs::File::open("source.bin").await?;
+/// let mut writer = tokio::fs::File::create("dest.bin").await?;
+///
+/// let n = CopyOptions::new()
+///     .progress(1024 * 1024, |copied| println!("{copied} bytes copied"))
+///     .max_bytes_per_sec(10 * 1024 * 1024)
+///     .copy(&mut reader, &mut writer)
+///     .await?;
+///
+/// println!("done: {n} bytes");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`copy`]: fn@super::copy::copy
+/// [`copy_buf`]: fn@super::copy_buf::copy_buf
+#[cfg_attr(docsrs, doc(cfg(all(feature = "io-util", feature = "time"))))]
+pub struct CopyOptions {
+    progress: Option<(u64, ProgressFn)>,
+    max_bytes_per_sec: Option<u64>,
+}
+
+impl CopyOptions {
+    /// Creates a new `CopyOptions` with no progress callback and no
+    /// throttle, equivalent to a plain [`copy`](fn@super::copy::copy).
+    pub fn new() -> Self {
+        Self {
+            progress: None,
+            max_bytes_per_sec: None,
+        }
+    }
+
+    /// Invokes `callback` with the total number of bytes copied so far,
+    /// every `every` copied bytes.
+    ///
+    /// The callback is also invoked once at the end of the transfer if any
+    /// bytes were copied since the last invocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is zero.
+    pub fn progress<F>(mut self, every: u64, callback: F) -> Self
+    where
+        F: FnMut(u64) + Send + 'static,
+    {
+        assert!(every > 0, "progress interval must be non-zero");
+        self.progress = Some((every, Box::new(callback)));
+        self
+    }
+
+    /// Caps the transfer rate at `limit` bytes per second.
+    ///
+    /// The throttle is applied over the lifetime of the transfer: after each
+    /// chunk is written, the copy sleeps long enough that the average rate
+    /// since the start does not exceed the limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    pub fn max_bytes_per_sec(mut self, limit: u64) -> Self {
+        assert!(limit > 0, "rate limit must be non-zero");
+        self.max_bytes_per_sec = Some(limit);
+        self
+    }
+
+    /// Asynchronously copies the entire contents of `reader` into `writer`,
+    /// applying the configured progress callback and rate limit.
+    ///
+    /// Completion semantics match [`copy`](fn@super::copy::copy): on success
+    /// the total number of copied bytes is returned and the writer has been
+    /// flushed.
+    pub async fn copy<R, W>(mut self, reader: &mut R, writer: &mut W) -> io::Result<u64>
+    where
+        R: AsyncRead + Unpin + ?Sized,
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut buf = vec![0; super::DEFAULT_BUF_SIZE];
+        let mut state = CopyState::new(&self);
+
+        loop {
+            let n = reader.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            writer.write_all(&buf[..n]).await?;
+            state.advance(n as u64, &mut self).await;
+        }
+
+        writer.flush().await?;
+        state.finish(&mut self);
+        Ok(state.copied)
+    }
+
+    /// Asynchronously copies the entire contents of `reader` into `writer`,
+    /// using the reader's internal buffer as
+    /// [`copy_buf`](fn@super::copy_buf::copy_buf) does.
+    pub async fn copy_buf<R, W>(mut self, reader: &mut R, writer: &mut W) -> io::Result<u64>
+    where
+        R: AsyncBufRead + Unpin + ?Sized,
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut state = CopyState::new(&self);
+
+        loop {
+            let chunk = reader.fill_buf().await?;
+            if chunk.is_empty() {
+                break;
+            }
+            let n = writer.write(chunk).await?;
+            if n == 0 {
+                return Err(io::ErrorKind::WriteZero.into());
+            }
+            reader.consume(n);
+            state.advance(n as u64, &mut self).await;
+        }
+
+        writer.flush().await?;
+        state.finish(&mut self);
+        Ok(state.copied)
+    }
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CopyOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CopyOptions")
+            .field("progress_every", &self.progress.as_ref().map(|(every, _)| every))
+            .field("max_bytes_per_sec", &self.max_bytes_per_sec)
+            .finish()
+    }
+}
+
+/// Book-keeping shared by `copy` and `copy_buf`.
+struct CopyState {
+    copied: u64,
+    next_progress: u64,
+    last_reported: u64,
+    start: Instant,
+}
+
+impl CopyState {
+    fn new(options: &CopyOptions) -> Self {
+        Self {
+            copied: 0,
+            next_progress: options.progress.as_ref().map_or(u64::MAX, |(every, _)| *every),
+            last_reported: 0,
+            start: Instant::now(),
+        }
+    }
+
+    async fn advance(&mut self, n: u64, options: &mut CopyOptions) {
+        self.copied += n;
+
+        if let Some((every, callback)) = &mut options.progress {
+            while self.copied >= self.next_progress {
+                callback(self.copied);
+                self.last_reported = self.copied;
+                self.next_progress = self.next_progress.saturating_add(*every);
+            }
+        }
+
+        if let Some(limit) = options.max_bytes_per_sec {
+            // The earliest time at which `copied` bytes are allowed to have
+            // been transferred, relative to the start of the copy.
+            let nanos = (self.copied as u128)
+                .saturating_mul(1_000_000_000)
+                .checked_div(limit as u128)
+                .unwrap_or(0);
+            let target = Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX));
+            let elapsed = self.start.elapsed();
+            if target > elapsed {
+                sleep(target - elapsed).await;
+            }
+        }
+    }
+
+    fn finish(&mut self, options: &mut CopyOptions) {
+        if let Some((_, callback)) = &mut options.progress {
+            if self.copied > self.last_reported {
+                callback(self.copied);
+                self.last_reported = self.copied;
+            }
+        }
+    }
+}
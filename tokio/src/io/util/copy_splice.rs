@@ -0,0 +1,177 @@
+//! Zero-copy bidirectional copying between TCP streams using `splice(2)`.
+
+use crate::io::Interest;
+use crate::net::TcpStream;
+
+use std::future::{poll_fn, Future};
+use std::io;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::task::Poll;
+
+/// Maximum number of bytes moved by a single `splice(2)` call.
+///
+/// Matches the default pipe capacity, so filling an empty pipe never blocks
+/// on the pipe side.
+const SPLICE_LEN: usize = 64 * 1024;
+
+/// Copies data in both directions between `a` and `b` without passing it
+/// through userspace.
+///
+/// This is the Linux-only, zero-copy counterpart of [`copy_bidirectional`].
+/// Each direction moves bytes through a kernel pipe with `splice(2)`, which
+/// lets proxies forward traffic without the double copy through a userspace
+/// buffer. Semantics otherwise match [`copy_bidirectional`]: when one stream
+/// reaches EOF the shutdown is forwarded to the other side and the opposite
+/// direction keeps running until it also completes.
+///
+/// If the kernel does not support `splice(2)` (for example under a seccomp
+/// policy that rejects the syscall), this function transparently falls back
+/// to the buffered [`copy_bidirectional`] path.
+///
+/// Returns the number of bytes copied from `a` to `b` and from `b` to `a`,
+/// in that order.
+///
+/// [`copy_bidirectional`]: super::copy_bidirectional::copy_bidirectional
+#[cfg_attr(docsrs, doc(cfg(all(feature = "io-util", feature = "net"))))]
+pub async fn copy_bidirectional_splice(
+    a: &mut TcpStream,
+    b: &mut TcpStream,
+) -> io::Result<(u64, u64)> {
+    if !splice_supported()? {
+        return super::copy_bidirectional::copy_bidirectional(a, b).await;
+    }
+
+    let (a, b) = (&*a, &*b);
+    let mut a_to_b = std::pin::pin!(splice_direction(a, b));
+    let mut b_to_a = std::pin::pin!(splice_direction(b, a));
+
+    let mut a_to_b_result = None;
+    let mut b_to_a_result = None;
+
+    poll_fn(|cx| {
+        if a_to_b_result.is_none() {
+            if let Poll::Ready(result) = a_to_b.as_mut().poll(cx) {
+                a_to_b_result = Some(result?);
+            }
+        }
+        if b_to_a_result.is_none() {
+            if let Poll::Ready(result) = b_to_a.as_mut().poll(cx) {
+                b_to_a_result = Some(result?);
+            }
+        }
+
+        match (a_to_b_result, b_to_a_result) {
+            (Some(a_to_b), Some(b_to_a)) => Poll::Ready(Ok((a_to_b, b_to_a))),
+            _ => Poll::Pending,
+        }
+    })
+    .await
+}
+
+/// Copies `src` to `dst` through a fresh pipe until `src` reaches EOF.
+async fn splice_direction(src: &TcpStream, dst: &TcpStream) -> io::Result<u64> {
+    let pipe = Pipe::new()?;
+    let mut copied = 0u64;
+
+    loop {
+        // Move a chunk from the source socket into the pipe. The pipe is
+        // empty at this point, so `WouldBlock` can only mean the socket has
+        // no data and `try_io` correctly clears its readiness.
+        let n = loop {
+            src.readable().await?;
+            match src.try_io(Interest::READABLE, || {
+                splice(src.as_raw_fd(), pipe.write_fd, SPLICE_LEN)
+            }) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+                Err(e) => return Err(e),
+            }
+        };
+
+        if n == 0 {
+            // EOF: forward the shutdown like the buffered copy does.
+            if unsafe { libc::shutdown(dst.as_raw_fd(), libc::SHUT_WR) } < 0 {
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::NotConnected {
+                    return Err(err);
+                }
+            }
+            return Ok(copied);
+        }
+
+        // Drain the pipe into the destination socket.
+        let mut remaining = n;
+        while remaining > 0 {
+            dst.writable().await?;
+            match dst.try_io(Interest::WRITABLE, || {
+                splice(pipe.read_fd, dst.as_raw_fd(), remaining)
+            }) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(n) => remaining -= n,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        copied += n as u64;
+    }
+}
+
+/// Checks once whether the kernel accepts the `splice(2)` syscall, by
+/// splicing between the two ends of an empty pipe.
+fn splice_supported() -> io::Result<bool> {
+    let probe = Pipe::new()?;
+    match splice(probe.read_fd, probe.write_fd, 1) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::WouldBlock => Ok(true),
+        Err(e) if matches!(e.raw_os_error(), Some(libc::ENOSYS | libc::EINVAL | libc::EPERM)) => {
+            Ok(false)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn splice(from: RawFd, to: RawFd, len: usize) -> io::Result<usize> {
+    let n = unsafe {
+        libc::splice(
+            from,
+            std::ptr::null_mut(),
+            to,
+            std::ptr::null_mut(),
+            len,
+            libc::SPLICE_F_MOVE | libc::SPLICE_F_NONBLOCK,
+        )
+    };
+    if n < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(n as usize)
+    }
+}
+
+struct Pipe {
+    read_fd: RawFd,
+    write_fd: RawFd,
+}
+
+impl Pipe {
+    fn new() -> io::Result<Pipe> {
+        let mut fds = [0; 2];
+        if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_NONBLOCK | libc::O_CLOEXEC) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Pipe {
+            read_fd: fds[0],
+            write_fd: fds[1],
+        })
+    }
+}
+
+impl Drop for Pipe {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.read_fd);
+            libc::close(self.write_fd);
+        }
+    }
+}
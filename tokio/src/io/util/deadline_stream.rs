@@ -0,0 +1,248 @@
+use crate::io::{AsyncRead, AsyncWrite, ReadBuf};
+use crate::time::{sleep, Sleep};
+
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+pin_project! {
+    /// An I/O stream with independent read and write idle timeouts.
+    ///
+    /// `DeadlineStream` wraps any [`AsyncRead`] + [`AsyncWrite`] value and
+    /// fails pending reads and writes that make no progress within the
+    /// configured timeout with an [`io::ErrorKind::TimedOut`] error. The
+    /// timers are *idle* timers: each one is armed when an operation returns
+    /// pending and reset whenever the operation completes, so a slow but
+    /// steadily progressing peer is never interrupted. This avoids wrapping
+    /// every individual read and write call in [`timeout`].
+    ///
+    /// Timeouts that are not set (the default) leave the corresponding
+    /// direction unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio::io::{AsyncReadExt, DeadlineStream};
+    /// use tokio::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let stream = TcpStream::connect("127.0.0.1:8080").await?;
+    ///
+    /// let mut stream = DeadlineStream::new(stream);
+    /// stream.set_read_timeout(Some(Duration::from_secs(30)));
+    ///
+    /// let mut buf = [0; 1024];
+    /// // Fails with `ErrorKind::TimedOut` if the peer sends nothing for 30s.
+    /// let n = stream.read(&mut buf).await?;
+    /// # let _ = n;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`timeout`]: crate::time::timeout
+    #[derive(Debug)]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "io-util", feature = "time"))))]
+    pub struct DeadlineStream<T> {
+        #[pin]
+        inner: T,
+        read_timeout: Option<Duration>,
+        write_timeout: Option<Duration>,
+        // Boxed so that the wrapper stays `Unpin` whenever `T` is, which the
+        // `AsyncReadExt`/`AsyncWriteExt` combinators require.
+        read_sleep: Option<Pin<Box<Sleep>>>,
+        write_sleep: Option<Pin<Box<Sleep>>>,
+    }
+}
+
+impl<T> DeadlineStream<T> {
+    /// Creates a new `DeadlineStream` with no timeouts configured.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            read_timeout: None,
+            write_timeout: None,
+            read_sleep: None,
+            write_sleep: None,
+        }
+    }
+
+    /// Returns the configured read idle timeout, if any.
+    pub fn read_timeout(&self) -> Option<Duration> {
+        self.read_timeout
+    }
+
+    /// Returns the configured write idle timeout, if any.
+    pub fn write_timeout(&self) -> Option<Duration> {
+        self.write_timeout
+    }
+
+    /// Sets the read idle timeout.
+    ///
+    /// A read that makes no progress for this long fails with
+    /// [`io::ErrorKind::TimedOut`]. `None` disables the timeout.
+    ///
+    /// If a read is currently pending, the new value takes effect the next
+    /// time its idle timer is armed.
+    pub fn set_read_timeout(&mut self, timeout: Option<Duration>) {
+        self.read_timeout = timeout;
+    }
+
+    /// Sets the write idle timeout.
+    ///
+    /// A write or flush that makes no progress for this long fails with
+    /// [`io::ErrorKind::TimedOut`]. `None` disables the timeout.
+    ///
+    /// If a write is currently pending, the new value takes effect the next
+    /// time its idle timer is armed.
+    pub fn set_write_timeout(&mut self, timeout: Option<Duration>) {
+        self.write_timeout = timeout;
+    }
+
+    /// Gets a reference to the underlying stream.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying stream.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Gets a pinned mutable reference to the underlying stream.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        self.project().inner
+    }
+
+    /// Consumes the `DeadlineStream`, returning the underlying stream.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+/// Drives a pending operation's idle timer, arming it on first use.
+///
+/// Returns `Ready` if the timer has expired; the caller should then fail the
+/// operation with `TimedOut`.
+fn poll_idle_timer(
+    sleep_slot: &mut Option<Pin<Box<Sleep>>>,
+    timeout: Option<Duration>,
+    cx: &mut Context<'_>,
+) -> Poll<()> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return Poll::Pending,
+    };
+
+    let timer = sleep_slot.get_or_insert_with(|| Box::pin(sleep(timeout)));
+    if timer.as_mut().poll(cx).is_ready() {
+        *sleep_slot = None;
+        Poll::Ready(())
+    } else {
+        Poll::Pending
+    }
+}
+
+fn timed_out(direction: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::TimedOut,
+        format!("{direction} idle timeout expired"),
+    )
+}
+
+impl<T: AsyncRead> AsyncRead for DeadlineStream<T> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let me = self.project();
+
+        match me.inner.poll_read(cx, buf) {
+            Poll::Ready(res) => {
+                *me.read_sleep = None;
+                Poll::Ready(res)
+            }
+            Poll::Pending => {
+                if poll_idle_timer(me.read_sleep, *me.read_timeout, cx).is_ready() {
+                    return Poll::Ready(Err(timed_out("read")));
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T: AsyncWrite> AsyncWrite for DeadlineStream<T> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let me = self.project();
+
+        match me.inner.poll_write(cx, buf) {
+            Poll::Ready(res) => {
+                *me.write_sleep = None;
+                Poll::Ready(res)
+            }
+            Poll::Pending => {
+                if poll_idle_timer(me.write_sleep, *me.write_timeout, cx).is_ready() {
+                    return Poll::Ready(Err(timed_out("write")));
+                }
+                Poll::Pending
+            }
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let me = self.project();
+
+        match me.inner.poll_write_vectored(cx, bufs) {
+            Poll::Ready(res) => {
+                *me.write_sleep = None;
+                Poll::Ready(res)
+            }
+            Poll::Pending => {
+                if poll_idle_timer(me.write_sleep, *me.write_timeout, cx).is_ready() {
+                    return Poll::Ready(Err(timed_out("write")));
+                }
+                Poll::Pending
+            }
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let me = self.project();
+
+        match me.inner.poll_flush(cx) {
+            Poll::Ready(res) => {
+                *me.write_sleep = None;
+                Poll::Ready(res)
+            }
+            Poll::Pending => {
+                if poll_idle_timer(me.write_sleep, *me.write_timeout, cx).is_ready() {
+                    return Poll::Ready(Err(timed_out("write")));
+                }
+                Poll::Pending
+            }
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // Shutdown is not subject to the write idle timeout; it usually
+        // completes locally and callers tend to wrap teardown separately.
+        self.project().inner.poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,207 @@
+use crate::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+pin_project! {
+    /// A reader returned by [`inspect_read`](inspect_read()) that invokes a
+    /// callback on each chunk of data as it is read.
+    #[must_use = "streams do nothing unless polled"]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+    pub struct InspectReader<R, F> {
+        #[pin]
+        reader: R,
+        f: F,
+    }
+}
+
+/// Creates a reader that invokes `f` on each chunk of data as it is read.
+///
+/// The callback is invoked with exactly the bytes supplied by each successful
+/// read before they are returned to the caller, which makes it easy to hash
+/// or log protocol traffic without a custom [`AsyncRead`] implementation. The
+/// callback is only invoked with an empty slice if the underlying reader
+/// returns without filling the buffer, which happens at EOF or when
+/// `poll_read` is called with a full buffer.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::io::{self, AsyncReadExt};
+///
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// let reader: &[u8] = b"hello";
+/// let mut seen = 0;
+///
+/// let mut contents = String::new();
+/// io::inspect_read(reader, |chunk| seen += chunk.len())
+///     .read_to_string(&mut contents)
+///     .await?;
+///
+/// assert_eq!(contents, "hello");
+/// assert_eq!(seen, 5);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+pub fn inspect_read<R, F>(reader: R, f: F) -> InspectReader<R, F>
+where
+    R: AsyncRead,
+    F: FnMut(&[u8]),
+{
+    InspectReader { reader, f }
+}
+
+impl<R, F> InspectReader<R, F> {
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Gets a pinned mutable reference to the underlying reader.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut R> {
+        self.project().reader
+    }
+
+    /// Consumes the `InspectReader`, returning the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead, F: FnMut(&[u8])> AsyncRead for InspectReader<R, F> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let me = self.project();
+        let filled = buf.filled().len();
+        ready!(me.reader.poll_read(cx, buf))?;
+        (me.f)(&buf.filled()[filled..]);
+        Poll::Ready(Ok(()))
+    }
+}
+
+pin_project! {
+    /// A writer returned by [`inspect_write`](inspect_write()) that invokes a
+    /// callback on each chunk of data as it is written.
+    #[must_use = "streams do nothing unless polled"]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+    pub struct InspectWriter<W, F> {
+        #[pin]
+        writer: W,
+        f: F,
+    }
+}
+
+/// Creates a writer that invokes `f` on each chunk of data as it is written.
+///
+/// The callback is invoked with the bytes the underlying writer actually
+/// accepted from each write, so it observes exactly the data that reaches the
+/// writer even when writes are short. It is never invoked with an empty
+/// slice. A vectored write can result in multiple invocations, at most one
+/// per buffer supplied to `poll_write_vectored`.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::io::{self, AsyncWriteExt};
+///
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// let mut written = 0;
+///
+/// let mut writer = io::inspect_write(Vec::new(), |chunk| written += chunk.len());
+/// writer.write_all(b"hello").await?;
+///
+/// assert_eq!(written, 5);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+pub fn inspect_write<W, F>(writer: W, f: F) -> InspectWriter<W, F>
+where
+    W: AsyncWrite,
+    F: FnMut(&[u8]),
+{
+    InspectWriter { writer, f }
+}
+
+impl<W, F> InspectWriter<W, F> {
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Gets a pinned mutable reference to the underlying writer.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut W> {
+        self.project().writer
+    }
+
+    /// Consumes the `InspectWriter`, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: AsyncWrite, F: FnMut(&[u8])> AsyncWrite for InspectWriter<W, F> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let me = self.project();
+        let n = ready!(me.writer.poll_write(cx, buf))?;
+        if n != 0 {
+            (me.f)(&buf[..n]);
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().writer.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().writer.poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let me = self.project();
+        let n = ready!(me.writer.poll_write_vectored(cx, bufs))?;
+        let mut rem = n;
+        for buf in bufs {
+            if rem == 0 {
+                break;
+            }
+            let size = rem.min(buf.len());
+            if size != 0 {
+                (me.f)(&buf[..size]);
+                rem -= size;
+            }
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.writer.is_write_vectored()
+    }
+}
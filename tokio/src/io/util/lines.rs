@@ -1,4 +1,5 @@
 use crate::io::util::read_line::read_line_internal;
+use crate::io::util::read_until::read_until_internal;
 use crate::io::AsyncBufRead;
 
 use pin_project_lite::pin_project;
@@ -26,9 +27,30 @@ pin_project! {
         buf: String,
         bytes: Vec<u8>,
         read: usize,
+        invalid_utf8: InvalidUtf8Policy,
     }
 }
 
+/// What [`Lines`] does with lines that are not valid UTF-8.
+///
+/// Configured with [`Lines::invalid_utf8_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+pub enum InvalidUtf8Policy {
+    /// Fail the read with an [`InvalidData`] error. This is the default, and
+    /// matches the error semantics of
+    /// [`read_line`](crate::io::AsyncBufReadExt::read_line).
+    ///
+    /// [`InvalidData`]: std::io::ErrorKind::InvalidData
+    Error,
+    /// Replace invalid sequences with `U+FFFD REPLACEMENT CHARACTER` and
+    /// yield the line.
+    Lossy,
+    /// Silently discard the line and continue with the next one.
+    Skip,
+}
+
 pub(crate) fn lines<R>(reader: R) -> Lines<R>
 where
     R: AsyncBufRead,
@@ -38,6 +60,7 @@ where
         buf: String::new(),
         bytes: Vec::new(),
         read: 0,
+        invalid_utf8: InvalidUtf8Policy::Error,
     }
 }
 
@@ -91,6 +114,39 @@ where
     }
 }
 
+impl<R> Lines<R> {
+    /// Sets how lines containing invalid UTF-8 are handled.
+    ///
+    /// By default invalid UTF-8 fails the read with an
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) error. Servers
+    /// reading untrusted input can instead choose to lossily convert or skip
+    /// such lines with [`InvalidUtf8Policy::Lossy`] or
+    /// [`InvalidUtf8Policy::Skip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::io::{AsyncBufReadExt, InvalidUtf8Policy};
+    ///
+    /// use std::io::Cursor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let cursor = Cursor::new(b"ok\n\xff\xff\nalso ok" as &[u8]);
+    ///
+    ///     let mut lines = cursor.lines().invalid_utf8_policy(InvalidUtf8Policy::Skip);
+    ///
+    ///     assert_eq!(lines.next_line().await.unwrap(), Some(String::from("ok")));
+    ///     assert_eq!(lines.next_line().await.unwrap(), Some(String::from("also ok")));
+    ///     assert_eq!(lines.next_line().await.unwrap(), None);
+    /// }
+    /// ```
+    pub fn invalid_utf8_policy(mut self, policy: InvalidUtf8Policy) -> Self {
+        self.invalid_utf8 = policy;
+        self
+    }
+}
+
 impl<R> Lines<R>
 where
     R: AsyncBufRead,
@@ -113,9 +169,37 @@ where
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<io::Result<Option<String>>> {
-        let me = self.project();
+        let mut me = self.project();
 
-        let n = ready!(read_line_internal(me.reader, cx, me.buf, me.bytes, me.read))?;
+        let n = match *me.invalid_utf8 {
+            InvalidUtf8Policy::Error => {
+                ready!(read_line_internal(me.reader, cx, me.buf, me.bytes, me.read))?
+            }
+            InvalidUtf8Policy::Lossy | InvalidUtf8Policy::Skip => loop {
+                let n = ready!(read_until_internal(
+                    me.reader.as_mut(),
+                    cx,
+                    b'\n',
+                    me.bytes,
+                    me.read
+                ))?;
+                match String::from_utf8(mem::take(me.bytes)) {
+                    Ok(line) => {
+                        *me.buf = line;
+                        break n;
+                    }
+                    Err(err) if *me.invalid_utf8 == InvalidUtf8Policy::Lossy => {
+                        *me.buf = String::from_utf8_lossy(err.as_bytes()).into_owned();
+                        break n;
+                    }
+                    // Skip: discard the line and read the next one. A final
+                    // invalid line without a trailing newline is followed by
+                    // an empty (and therefore valid) read that ends the
+                    // stream.
+                    Err(_) => {}
+                }
+            },
+        };
         debug_assert_eq!(*me.read, 0);
 
         if n == 0 && me.buf.is_empty() {
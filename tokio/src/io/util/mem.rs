@@ -92,6 +92,213 @@ pub struct SimplexStream {
     /// If the `write` side has filled the `max_buf_size` and returned
     /// `Poll::Pending`, this is the waker for that parked task.
     write_waker: Option<Waker>,
+    /// Traffic shaping applied to this stream, if it was created through
+    /// [`duplex_with_options`].
+    #[cfg(feature = "time")]
+    shaping: Option<Shaping>,
+}
+
+cfg_time! {
+    use crate::time::{sleep, Instant, Sleep};
+    use std::future::Future;
+    use std::time::Duration;
+
+    /// Options for [`duplex_with_options`], shaping the traffic through the
+    /// in-memory pipe.
+    ///
+    /// By default no shaping is applied and the pipe behaves like one
+    /// created with [`duplex`].
+    #[derive(Debug, Clone, Default)]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "io-util", feature = "time"))))]
+    pub struct DuplexOptions {
+        latency: Option<Duration>,
+        bytes_per_sec: Option<u64>,
+        chunk_size: Option<usize>,
+    }
+
+    impl DuplexOptions {
+        /// Creates a new set of options with no shaping configured.
+        pub fn new() -> DuplexOptions {
+            DuplexOptions::default()
+        }
+
+        /// Delays every read by `latency`, simulating propagation delay.
+        pub fn latency(mut self, latency: Duration) -> DuplexOptions {
+            self.latency = Some(latency);
+            self
+        }
+
+        /// Caps the transfer rate at `bytes_per_sec`, simulating a slow
+        /// link. The cap is enforced on the reading side as an average over
+        /// the lifetime of the stream.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `bytes_per_sec` is zero.
+        pub fn bandwidth(mut self, bytes_per_sec: u64) -> DuplexOptions {
+            assert!(bytes_per_sec > 0, "bandwidth must be non-zero");
+            self.bytes_per_sec = Some(bytes_per_sec);
+            self
+        }
+
+        /// Limits how many bytes a single read or write can transfer,
+        /// forcing the short reads and writes that real sockets produce.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `chunk_size` is zero.
+        pub fn chunk_size(mut self, chunk_size: usize) -> DuplexOptions {
+            assert!(chunk_size > 0, "chunk size must be non-zero");
+            self.chunk_size = Some(chunk_size);
+            self
+        }
+
+        fn shaping(&self) -> Shaping {
+            Shaping {
+                latency: self.latency,
+                bytes_per_sec: self.bytes_per_sec,
+                chunk_size: self.chunk_size,
+                delay: None,
+                started: None,
+                transferred: 0,
+            }
+        }
+    }
+
+    /// Like [`duplex`], but shapes the traffic according to `options`.
+    ///
+    /// This lets integration tests exercise slow-network behavior — added
+    /// latency, limited bandwidth and short reads and writes — without real
+    /// sockets. Both directions are shaped independently with the same
+    /// options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexOptions};
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() -> std::io::Result<()> {
+    /// let (mut client, mut server) = tokio::io::duplex_with_options(
+    ///     64,
+    ///     DuplexOptions::new().latency(Duration::from_millis(10)),
+    /// );
+    ///
+    /// client.write_all(b"ping").await?;
+    ///
+    /// let start = tokio::time::Instant::now();
+    /// let mut buf = [0u8; 4];
+    /// server.read_exact(&mut buf).await?;
+    /// assert!(start.elapsed() >= Duration::from_millis(10));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "io-util", feature = "time"))))]
+    pub fn duplex_with_options(
+        max_buf_size: usize,
+        options: DuplexOptions,
+    ) -> (DuplexStream, DuplexStream) {
+        let mut one = SimplexStream::new_unsplit(max_buf_size);
+        one.shaping = Some(options.shaping());
+        let mut two = SimplexStream::new_unsplit(max_buf_size);
+        two.shaping = Some(options.shaping());
+
+        let one = Arc::new(Mutex::new(one));
+        let two = Arc::new(Mutex::new(two));
+
+        (
+            DuplexStream {
+                read: one.clone(),
+                write: two.clone(),
+            },
+            DuplexStream {
+                read: two,
+                write: one,
+            },
+        )
+    }
+
+    /// Traffic shaping state for a [`SimplexStream`].
+    #[derive(Debug)]
+    struct Shaping {
+        latency: Option<Duration>,
+        bytes_per_sec: Option<u64>,
+        chunk_size: Option<usize>,
+        /// Timer gating the next read, armed when shaping requires a wait.
+        delay: Option<Pin<Box<Sleep>>>,
+        /// When the first byte was read; bandwidth accounting starts here.
+        started: Option<Instant>,
+        /// Total bytes read so far, for bandwidth accounting.
+        transferred: u64,
+    }
+
+    impl Shaping {
+        /// Gates a read behind the configured latency and bandwidth,
+        /// returning `Ready` once the next chunk may be delivered.
+        fn poll_throttle(&mut self, cx: &mut task::Context<'_>) -> Poll<()> {
+            if let Some(delay) = self.delay.as_mut() {
+                ready!(delay.as_mut().poll(cx));
+                self.delay = None;
+                return Poll::Ready(());
+            }
+
+            let mut wait = self.latency.unwrap_or_default();
+            if let Some(rate) = self.bytes_per_sec {
+                let now = Instant::now();
+                let started = *self.started.get_or_insert(now);
+                // The earliest time at which `transferred` bytes are allowed
+                // to have been delivered.
+                let nanos =
+                    (self.transferred as u128).saturating_mul(1_000_000_000) / rate as u128;
+                let target = started + Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX));
+                if target > now {
+                    wait = wait.max(target - now);
+                }
+            }
+
+            if wait == Duration::ZERO {
+                return Poll::Ready(());
+            }
+
+            let mut delay = Box::pin(sleep(wait));
+            if delay.as_mut().poll(cx).is_pending() {
+                self.delay = Some(delay);
+                return Poll::Pending;
+            }
+            Poll::Ready(())
+        }
+
+        fn clamp_chunk(&self, len: usize) -> usize {
+            match self.chunk_size {
+                Some(chunk) => len.min(chunk),
+                None => len,
+            }
+        }
+
+        /// Like [`clamp_chunk`], but additionally caps the chunk to the
+        /// number of bytes the configured bandwidth permits right now, so
+        /// that a large buffered read is delivered gradually rather than
+        /// all at once.
+        ///
+        /// [`clamp_chunk`]: Shaping::clamp_chunk
+        fn clamp_read(&self, len: usize) -> usize {
+            let len = self.clamp_chunk(len);
+            match (self.bytes_per_sec, self.started) {
+                (Some(rate), Some(started)) => {
+                    let elapsed = Instant::now().saturating_duration_since(started);
+                    // `poll_throttle` has already waited until at least one
+                    // more byte is due, so the budget is always positive.
+                    let budget = (elapsed.as_nanos().saturating_mul(rate as u128)
+                        / 1_000_000_000)
+                        .saturating_add(1)
+                        .saturating_sub(self.transferred as u128);
+                    len.min(usize::try_from(budget).unwrap_or(usize::MAX))
+                }
+                _ => len,
+            }
+        }
+    }
 }
 
 // ===== impl DuplexStream =====
@@ -172,6 +379,42 @@ impl AsyncWrite for DuplexStream {
     }
 }
 
+// The two poll paths only touch state behind per-direction mutexes, so they
+// may be driven concurrently through a shared reference.
+impl crate::io::ConcurrentlySplittable for DuplexStream {
+    fn poll_read_ref(
+        &self,
+        cx: &mut task::Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.read.lock()).poll_read(cx, buf)
+    }
+
+    fn poll_write_ref(
+        &self,
+        cx: &mut task::Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut *self.write.lock()).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored_ref(
+        &self,
+        cx: &mut task::Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut *self.write.lock()).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush_ref(&self, cx: &mut task::Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.write.lock()).poll_flush(cx)
+    }
+
+    fn poll_shutdown_ref(&self, cx: &mut task::Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.write.lock()).poll_shutdown(cx)
+    }
+}
+
 impl Drop for DuplexStream {
     fn drop(&mut self) {
         // notify the other side of the closure
@@ -226,6 +469,8 @@ impl SimplexStream {
             max_buf_size,
             read_waker: None,
             write_waker: None,
+            #[cfg(feature = "time")]
+            shaping: None,
         }
     }
 
@@ -251,10 +496,30 @@ impl SimplexStream {
         buf: &mut ReadBuf<'_>,
     ) -> Poll<std::io::Result<()>> {
         if self.buffer.has_remaining() {
-            let max = self.buffer.remaining().min(buf.remaining());
+            #[cfg(feature = "time")]
+            {
+                if let Some(shaping) = self.shaping.as_mut() {
+                    ready!(shaping.poll_throttle(cx));
+                }
+            }
+            let max = {
+                let max = self.buffer.remaining().min(buf.remaining());
+                #[cfg(feature = "time")]
+                let max = match self.shaping.as_ref() {
+                    Some(shaping) => shaping.clamp_read(max),
+                    None => max,
+                };
+                max
+            };
             buf.put_slice(&self.buffer[..max]);
             self.buffer.advance(max);
             if max > 0 {
+                #[cfg(feature = "time")]
+                {
+                    if let Some(shaping) = self.shaping.as_mut() {
+                        shaping.transferred += max as u64;
+                    }
+                }
                 // The passed `buf` might have been empty, don't wake up if
                 // no bytes have been moved.
                 if let Some(waker) = self.write_waker.take() {
@@ -284,7 +549,15 @@ impl SimplexStream {
             return Poll::Pending;
         }
 
-        let len = buf.len().min(avail);
+        let len = {
+            let len = buf.len().min(avail);
+            #[cfg(feature = "time")]
+            let len = match self.shaping.as_ref() {
+                Some(shaping) => shaping.clamp_chunk(len),
+                None => len,
+            };
+            len
+        };
         self.buffer.extend_from_slice(&buf[..len]);
         if let Some(waker) = self.read_waker.take() {
             waker.wake();
@@ -306,6 +579,15 @@ impl SimplexStream {
             return Poll::Pending;
         }
 
+        let avail = {
+            #[cfg(feature = "time")]
+            let avail = match self.shaping.as_ref() {
+                Some(shaping) => shaping.clamp_chunk(avail),
+                None => avail,
+            };
+            avail
+        };
+
         let mut rem = avail;
         for buf in bufs {
             if rem == 0 {
@@ -27,9 +27,26 @@ cfg_io_util! {
     mod copy;
     pub use copy::copy;
 
+    cfg_time! {
+        pub use mem::{duplex_with_options, DuplexOptions};
+
+        mod copy_options;
+        pub use copy_options::CopyOptions;
+
+        mod deadline_stream;
+        pub use deadline_stream::DeadlineStream;
+    }
+
     mod copy_bidirectional;
     pub use copy_bidirectional::{copy_bidirectional, copy_bidirectional_with_sizes};
 
+    cfg_net! {
+        #[cfg(target_os = "linux")]
+        mod copy_splice;
+        #[cfg(target_os = "linux")]
+        pub use copy_splice::copy_bidirectional_splice;
+    }
+
     mod copy_buf;
     pub use copy_buf::copy_buf;
 
@@ -38,8 +55,11 @@ cfg_io_util! {
 
     mod flush;
 
+    mod inspect;
+    pub use inspect::{inspect_read, inspect_write, InspectReader, InspectWriter};
+
     mod lines;
-    pub use lines::Lines;
+    pub use lines::{InvalidUtf8Policy, Lines};
 
     mod mem;
     pub use mem::{duplex, simplex, DuplexStream, SimplexStream};
@@ -49,9 +69,11 @@ cfg_io_util! {
     mod read_exact;
     mod read_int;
     mod read_line;
+    mod read_line_limited;
     mod fill_buf;
 
     mod read_to_end;
+    mod read_to_end_limited;
     mod vec_with_initialized;
     cfg_process! {
         pub(crate) use read_to_end::read_to_end;
@@ -60,6 +82,9 @@ cfg_io_util! {
     mod read_to_string;
     mod read_until;
 
+    mod peek;
+    pub use peek::Peek;
+
     mod repeat;
     pub use repeat::{repeat, Repeat};
 
@@ -74,6 +99,9 @@ cfg_io_util! {
     mod take;
     pub use take::Take;
 
+    mod tee;
+    pub use tee::{tee, Tee};
+
     mod write;
     mod write_vectored;
     mod write_all;
@@ -0,0 +1,170 @@
+use crate::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::{cmp, io::IoSlice};
+
+pin_project! {
+    /// A wrapper that adds [`peek`](Peek::peek) to any [`AsyncRead`].
+    ///
+    /// Peeked bytes are buffered inside the wrapper and handed back out by
+    /// subsequent reads, so peeking does not remove data from the stream.
+    /// This generalizes [`TcpStream::peek`] to arbitrary readers, which is
+    /// what protocol sniffing (for example distinguishing TLS from
+    /// plaintext on an accepted connection) needs once the stream is
+    /// wrapped in something other than a raw socket.
+    ///
+    /// If the inner type also implements [`AsyncWrite`], writes pass
+    /// straight through.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tokio::io::Peek;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use tokio::io::AsyncReadExt;
+    ///
+    /// let data: &[u8] = b"\x16\x03\x01rest of the stream";
+    /// let mut stream = Peek::new(data);
+    ///
+    /// // Sniff the first bytes without consuming them.
+    /// let mut header = [0; 3];
+    /// let n = stream.peek(&mut header).await?;
+    /// assert_eq!(&header[..n], b"\x16\x03\x01");
+    ///
+    /// // The peeked bytes are still there for the real read.
+    /// let mut all = Vec::new();
+    /// stream.read_to_end(&mut all).await?;
+    /// assert_eq!(all, b"\x16\x03\x01rest of the stream");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`TcpStream::peek`]: crate::net::TcpStream::peek
+    #[derive(Debug)]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+    pub struct Peek<T> {
+        #[pin]
+        inner: T,
+        buffer: Vec<u8>,
+    }
+}
+
+impl<T> Peek<T> {
+    /// Creates a new `Peek` wrapper around `inner`.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Returns the bytes that have been peeked but not yet read.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Reading from the underlying reader directly bypasses any peeked
+    /// bytes held by this wrapper.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Gets a pinned mutable reference to the underlying reader.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        self.project().inner
+    }
+
+    /// Consumes the wrapper, returning the underlying reader.
+    ///
+    /// Any bytes that were peeked but not read are lost.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: AsyncRead + Unpin> Peek<T> {
+    /// Receives data without consuming it, waiting until `buf` is filled or
+    /// the stream reaches EOF.
+    ///
+    /// Returns the number of bytes peeked, which is less than `buf.len()`
+    /// only if EOF was reached first. Successive calls return the same data
+    /// until it is consumed by a read.
+    pub async fn peek(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        use crate::io::AsyncReadExt;
+
+        let mut chunk = [0; 512];
+        while self.buffer.len() < buf.len() {
+            let wanted = cmp::min(buf.len() - self.buffer.len(), chunk.len());
+            let n = self.inner.read(&mut chunk[..wanted]).await?;
+            if n == 0 {
+                break;
+            }
+            self.buffer.extend_from_slice(&chunk[..n]);
+        }
+
+        let n = cmp::min(self.buffer.len(), buf.len());
+        buf[..n].copy_from_slice(&self.buffer[..n]);
+        Ok(n)
+    }
+}
+
+impl<T: AsyncRead> AsyncRead for Peek<T> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let me = self.project();
+
+        if !me.buffer.is_empty() {
+            let n = cmp::min(me.buffer.len(), buf.remaining());
+            buf.put_slice(&me.buffer[..n]);
+            me.buffer.drain(..n);
+            return Poll::Ready(Ok(()));
+        }
+
+        me.inner.poll_read(cx, buf)
+    }
+}
+
+impl<T: AsyncWrite> AsyncWrite for Peek<T> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.project().inner.poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.project().inner.poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_shutdown(cx)
+    }
+}
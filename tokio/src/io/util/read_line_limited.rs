@@ -0,0 +1,117 @@
+use crate::io::util::read_line::finish_string_read;
+use crate::io::AsyncBufRead;
+use crate::util::memchr;
+
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::io;
+use std::marker::PhantomPinned;
+use std::mem;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+pin_project! {
+    /// Future for the [`read_line_limited`](crate::io::AsyncBufReadExt::read_line_limited) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ReadLineLimited<'a, R: ?Sized> {
+        reader: &'a mut R,
+        // This is the buffer we were provided. It will be replaced with an empty string
+        // while reading to postpone utf-8 handling until after reading.
+        output: &'a mut String,
+        // The actual allocation of the string is moved into this vector instead.
+        buf: Vec<u8>,
+        // The number of bytes appended to buf. This can be less than buf.len() if
+        // the buffer was not empty when the operation was started.
+        read: usize,
+        // The maximum number of bytes to append before failing.
+        limit: usize,
+        // Make this future `!Unpin` for compatibility with async trait methods.
+        #[pin]
+        _pin: PhantomPinned,
+    }
+}
+
+pub(crate) fn read_line_limited<'a, R>(
+    reader: &'a mut R,
+    string: &'a mut String,
+    limit: usize,
+) -> ReadLineLimited<'a, R>
+where
+    R: AsyncBufRead + ?Sized + Unpin,
+{
+    ReadLineLimited {
+        reader,
+        buf: mem::take(string).into_bytes(),
+        output: string,
+        read: 0,
+        limit,
+        _pin: PhantomPinned,
+    }
+}
+
+/// Like `read_until_internal` with a newline delimiter, but appends at most
+/// `limit` bytes. Returns `None` if the limit was reached before a newline or
+/// EOF was found.
+fn read_until_newline_limited<R: AsyncBufRead + ?Sized>(
+    mut reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    buf: &mut Vec<u8>,
+    read: &mut usize,
+    limit: usize,
+) -> Poll<io::Result<Option<usize>>> {
+    loop {
+        let (done, used, exceeded) = {
+            let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
+            let remaining = limit - *read;
+            if remaining == 0 {
+                // The limit has been reached. Only more data in the stream
+                // makes that an error; EOF exactly at the limit is fine.
+                (false, 0, !available.is_empty())
+            } else {
+                let window = &available[..std::cmp::min(available.len(), remaining)];
+                if let Some(i) = memchr::memchr(b'\n', window) {
+                    buf.extend_from_slice(&window[..=i]);
+                    (true, i + 1, false)
+                } else {
+                    buf.extend_from_slice(window);
+                    (false, window.len(), false)
+                }
+            }
+        };
+        reader.as_mut().consume(used);
+        *read += used;
+        if exceeded {
+            return Poll::Ready(Ok(None));
+        }
+        if done || used == 0 {
+            return Poll::Ready(Ok(Some(mem::replace(read, 0))));
+        }
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized + Unpin> Future for ReadLineLimited<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let me = self.project();
+
+        let result = ready!(read_until_newline_limited(
+            Pin::new(*me.reader),
+            cx,
+            me.buf,
+            me.read,
+            *me.limit,
+        ))?;
+        let io_res = match result {
+            Some(n) => Ok(n),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "line length limit exceeded",
+            )),
+        };
+        let utf8_res = String::from_utf8(mem::take(me.buf));
+
+        finish_string_read(io_res, utf8_res, *me.read, me.output, false)
+    }
+}
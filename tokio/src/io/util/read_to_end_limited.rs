@@ -0,0 +1,69 @@
+use crate::io::util::read_to_end::poll_read_to_end;
+use crate::io::util::vec_with_initialized::VecWithInitialized;
+use crate::io::AsyncRead;
+
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::io;
+use std::marker::PhantomPinned;
+use std::mem;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+pin_project! {
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ReadToEndLimited<'a, R: ?Sized> {
+        reader: &'a mut R,
+        buf: VecWithInitialized<&'a mut Vec<u8>>,
+        // The number of bytes appended to buf. This can be less than buf.len() if
+        // the buffer was not empty when the operation was started.
+        read: usize,
+        limit: usize,
+        // Make this future `!Unpin` for compatibility with async trait methods.
+        #[pin]
+        _pin: PhantomPinned,
+    }
+}
+
+pub(crate) fn read_to_end_limited<'a, R>(
+    reader: &'a mut R,
+    buffer: &'a mut Vec<u8>,
+    limit: usize,
+) -> ReadToEndLimited<'a, R>
+where
+    R: AsyncRead + Unpin + ?Sized,
+{
+    ReadToEndLimited {
+        reader,
+        buf: VecWithInitialized::new(buffer),
+        read: 0,
+        limit,
+        _pin: PhantomPinned,
+    }
+}
+
+impl<A> Future for ReadToEndLimited<'_, A>
+where
+    A: AsyncRead + ?Sized + Unpin,
+{
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let me = self.project();
+
+        loop {
+            let num = ready!(poll_read_to_end(me.buf, Pin::new(&mut **me.reader), cx))?;
+            if num == 0 {
+                return Poll::Ready(Ok(mem::replace(me.read, 0)));
+            }
+            *me.read += num;
+            if *me.read > *me.limit {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "size limit exceeded",
+                )));
+            }
+        }
+    }
+}
@@ -86,6 +86,16 @@ impl<R: AsyncRead> AsyncRead for Take<R> {
         }
 
         let me = self.project();
+
+        // Fast path: the limit cannot be exceeded by this read, so pass
+        // `buf` straight through to the underlying reader.
+        if *me.limit_ >= buf.remaining() as u64 {
+            let filled = buf.filled().len();
+            ready!(me.inner.poll_read(cx, buf))?;
+            *me.limit_ -= (buf.filled().len() - filled) as u64;
+            return Poll::Ready(Ok(()));
+        }
+
         let mut b = buf.take(usize::try_from(*me.limit_).unwrap_or(usize::MAX));
 
         let buf_ptr = b.filled().as_ptr();
@@ -0,0 +1,139 @@
+use crate::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+pin_project! {
+    /// A reader returned by [`tee`](tee()) that copies everything it reads to
+    /// a secondary writer.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+    pub struct Tee<R, W> {
+        #[pin]
+        reader: R,
+        #[pin]
+        writer: W,
+        // Bytes handed to the caller that have not yet been written to the
+        // secondary writer.
+        buf: Vec<u8>,
+        written: usize,
+    }
+}
+
+/// Creates a reader that copies everything it reads to `writer`.
+///
+/// Bytes are handed to the caller as soon as the underlying reader produces
+/// them and are forwarded to the secondary writer before the *next* read, so
+/// the writer lags the reader by at most one read's worth of data. When the
+/// reader reaches EOF, any remaining bytes are forwarded and the writer is
+/// flushed.
+///
+/// This is useful for transparently logging or hashing protocol traffic
+/// without implementing a custom [`AsyncRead`] type.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::io::{self, AsyncReadExt};
+///
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// let reader: &[u8] = b"hello";
+/// let mut log = Vec::new();
+///
+/// let mut contents = String::new();
+/// io::tee(reader, &mut log).read_to_string(&mut contents).await?;
+///
+/// assert_eq!(contents, "hello");
+/// assert_eq!(log, b"hello");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+pub fn tee<R, W>(reader: R, writer: W) -> Tee<R, W>
+where
+    R: AsyncRead,
+    W: AsyncWrite,
+{
+    Tee {
+        reader,
+        writer,
+        buf: Vec::new(),
+        written: 0,
+    }
+}
+
+impl<R, W> Tee<R, W> {
+    /// Gets references to the underlying reader and writer.
+    pub fn get_ref(&self) -> (&R, &W) {
+        (&self.reader, &self.writer)
+    }
+
+    /// Gets mutable references to the underlying reader and writer.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader or writer as doing so may corrupt the internal
+    /// state of this `Tee`.
+    pub fn get_mut(&mut self) -> (&mut R, &mut W) {
+        (&mut self.reader, &mut self.writer)
+    }
+
+    /// Gets pinned mutable references to the underlying reader and writer.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader or writer as doing so may corrupt the internal
+    /// state of this `Tee`.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut R>, Pin<&mut W>) {
+        let me = self.project();
+        (me.reader, me.writer)
+    }
+
+    /// Consumes the `Tee`, returning the wrapped reader and writer.
+    ///
+    /// Note that bytes read since the last successful read may not have been
+    /// forwarded to the writer yet.
+    pub fn into_inner(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+}
+
+impl<R: AsyncRead, W: AsyncWrite> AsyncRead for Tee<R, W> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+
+        // Forward bytes from the previous read to the secondary writer.
+        while *me.written < me.buf.len() {
+            let n = ready!(me.writer.as_mut().poll_write(cx, &me.buf[*me.written..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "tee writer closed",
+                )));
+            }
+            *me.written += n;
+        }
+        me.buf.clear();
+        *me.written = 0;
+
+        let filled = buf.filled().len();
+        ready!(me.reader.poll_read(cx, buf))?;
+
+        let new = &buf.filled()[filled..];
+        if new.is_empty() {
+            // EOF: everything has been forwarded, flush the writer so that
+            // the copy is complete once the caller observes end of stream.
+            ready!(me.writer.poll_flush(cx))?;
+        } else {
+            me.buf.extend_from_slice(new);
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
@@ -47,7 +47,31 @@ cfg_net! {
 
         mod udp;
         #[doc(inline)]
-        pub use udp::UdpSocket;
+        pub use udp::{BufAddr, UdpSocket};
+
+        mod multicast;
+        pub use multicast::MulticastGroup;
+
+        #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(any(target_os = "android", target_os = "linux", target_os = "macos")))
+        )]
+        pub mod vsock;
+        #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+        pub use vsock::{VsockAddr, VsockListener, VsockStream};
+
+        #[cfg(unix)]
+        #[cfg_attr(docsrs, doc(cfg(unix)))]
+        mod raw;
+        #[cfg(unix)]
+        pub use raw::RawSocket;
+
+        #[cfg(unix)]
+        #[cfg_attr(docsrs, doc(cfg(unix)))]
+        mod ping;
+        #[cfg(unix)]
+        pub use ping::Ping;
     }
 }
 
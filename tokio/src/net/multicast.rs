@@ -0,0 +1,264 @@
+use crate::net::UdpSocket;
+
+use std::io;
+use std::net::{IpAddr, Ipv4Addr};
+
+/// A multicast group membership for a [`UdpSocket`].
+///
+/// `MulticastGroup` gathers the scattered multicast socket options — group
+/// membership, outgoing interface, source filtering, TTL and loopback — into
+/// one place, and leaves the group again when dropped.
+///
+/// A group is configured with the builder-style methods and entered with
+/// [`join`]. Keep the returned value alive for as long as the membership
+/// should last; to observe errors while leaving, call [`leave`] instead of
+/// dropping.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::net::{MulticastGroup, UdpSocket};
+/// use std::io;
+///
+/// #[tokio::main]
+/// async fn main() -> io::Result<()> {
+///     let socket = UdpSocket::bind("0.0.0.0:5353").await?;
+///
+///     let _membership = MulticastGroup::new(&socket, "239.1.2.3".parse().unwrap())
+///         .ttl(4)
+///         .loopback(false)
+///         .join()?;
+///
+///     let mut buf = [0u8; 1500];
+///     let (n, addr) = socket.recv_from(&mut buf).await?;
+///     println!("{} bytes from {}", n, addr);
+///
+///     // The group is left when `_membership` goes out of scope.
+///     Ok(())
+/// }
+/// ```
+///
+/// [`join`]: MulticastGroup::join
+/// [`leave`]: MulticastGroup::leave
+#[derive(Debug)]
+pub struct MulticastGroup<'a> {
+    socket: &'a UdpSocket,
+    group: IpAddr,
+    interface: Interface,
+    source: Option<Ipv4Addr>,
+    ttl: Option<u32>,
+    loopback: Option<bool>,
+    joined: bool,
+}
+
+#[derive(Debug)]
+enum Interface {
+    Default,
+    Index(u32),
+    V4(Ipv4Addr),
+}
+
+impl<'a> MulticastGroup<'a> {
+    /// Creates a membership configuration for `group` on `socket`.
+    ///
+    /// The group is not entered until [`join`](MulticastGroup::join) is
+    /// called.
+    pub fn new(socket: &'a UdpSocket, group: IpAddr) -> MulticastGroup<'a> {
+        MulticastGroup {
+            socket,
+            group,
+            interface: Interface::Default,
+            source: None,
+            ttl: None,
+            loopback: None,
+            joined: false,
+        }
+    }
+
+    /// Selects the interface to receive the group on by its local IPv4
+    /// address.
+    ///
+    /// Only valid for IPv4 groups. By default the operating system chooses
+    /// an interface.
+    pub fn interface_v4(mut self, interface: Ipv4Addr) -> MulticastGroup<'a> {
+        self.interface = Interface::V4(interface);
+        self
+    }
+
+    /// Selects the interface to receive the group on by interface index.
+    ///
+    /// By default the operating system chooses an interface.
+    pub fn interface_index(mut self, index: u32) -> MulticastGroup<'a> {
+        self.interface = Interface::Index(index);
+        self
+    }
+
+    /// Selects the interface to receive the group on by name, e.g. `"eth0"`.
+    ///
+    /// Returns an error if no interface with the given name exists.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn interface_name(self, name: &str) -> io::Result<MulticastGroup<'a>> {
+        let name = std::ffi::CString::new(name)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "interface name contains null byte"))?;
+        // SAFETY: `name` is a valid, null-terminated C string.
+        let index = unsafe { libc::if_nametoindex(name.as_ptr()) };
+        if index == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(self.interface_index(index))
+    }
+
+    /// Restricts the membership to datagrams sent by `source`
+    /// (source-specific multicast, IGMPv3).
+    ///
+    /// Only valid for IPv4 groups, and only together with the default
+    /// interface or one selected by [`interface_v4`].
+    ///
+    /// [`interface_v4`]: MulticastGroup::interface_v4
+    pub fn source(mut self, source: Ipv4Addr) -> MulticastGroup<'a> {
+        self.source = Some(source);
+        self
+    }
+
+    /// Sets the time-to-live (IPv4) or hop limit (IPv6) for multicast
+    /// datagrams sent from the socket.
+    pub fn ttl(mut self, ttl: u32) -> MulticastGroup<'a> {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sets whether multicast datagrams sent from the socket are looped back
+    /// to local listeners.
+    pub fn loopback(mut self, loopback: bool) -> MulticastGroup<'a> {
+        self.loopback = Some(loopback);
+        self
+    }
+
+    /// Joins the group, applying the configured options.
+    ///
+    /// The membership lasts until the returned value is dropped or
+    /// [`leave`](MulticastGroup::leave) is called.
+    pub fn join(mut self) -> io::Result<MulticastGroup<'a>> {
+        let socket = self.socket.as_socket();
+
+        match self.group {
+            IpAddr::V4(group) => {
+                if let Some(source) = self.source {
+                    let interface = match self.interface {
+                        Interface::Default => Ipv4Addr::UNSPECIFIED,
+                        Interface::V4(addr) => addr,
+                        Interface::Index(_) => {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                "source-specific multicast requires an interface address",
+                            ));
+                        }
+                    };
+                    socket.join_ssm_v4(&source, &group, &interface)?;
+                } else {
+                    let interface = match self.interface {
+                        Interface::Default => {
+                            socket2::InterfaceIndexOrAddress::Address(Ipv4Addr::UNSPECIFIED)
+                        }
+                        Interface::V4(addr) => socket2::InterfaceIndexOrAddress::Address(addr),
+                        Interface::Index(index) => socket2::InterfaceIndexOrAddress::Index(index),
+                    };
+                    socket.join_multicast_v4_n(&group, &interface)?;
+                }
+
+                if let Some(ttl) = self.ttl {
+                    socket.set_multicast_ttl_v4(ttl)?;
+                }
+                if let Some(loopback) = self.loopback {
+                    socket.set_multicast_loop_v4(loopback)?;
+                }
+            }
+            IpAddr::V6(group) => {
+                if self.source.is_some() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "source-specific multicast is only supported for IPv4 groups",
+                    ));
+                }
+                let interface = match self.interface {
+                    Interface::Default => 0,
+                    Interface::Index(index) => index,
+                    Interface::V4(_) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "IPv6 groups select interfaces by index, not address",
+                        ));
+                    }
+                };
+                socket.join_multicast_v6(&group, interface)?;
+
+                if let Some(ttl) = self.ttl {
+                    socket.set_multicast_hops_v6(ttl)?;
+                }
+                if let Some(loopback) = self.loopback {
+                    socket.set_multicast_loop_v6(loopback)?;
+                }
+            }
+        }
+
+        self.joined = true;
+        Ok(self)
+    }
+
+    /// Returns the group address this membership refers to.
+    pub fn group(&self) -> IpAddr {
+        self.group
+    }
+
+    /// Leaves the group.
+    ///
+    /// This is also done when the value is dropped, but `leave` reports
+    /// errors instead of ignoring them.
+    pub fn leave(mut self) -> io::Result<()> {
+        self.joined = false;
+        self.leave_inner()
+    }
+
+    fn leave_inner(&self) -> io::Result<()> {
+        let socket = self.socket.as_socket();
+
+        match self.group {
+            IpAddr::V4(group) => {
+                if let Some(source) = self.source {
+                    let interface = match self.interface {
+                        Interface::V4(addr) => addr,
+                        _ => Ipv4Addr::UNSPECIFIED,
+                    };
+                    socket.leave_ssm_v4(&source, &group, &interface)
+                } else {
+                    let interface = match self.interface {
+                        Interface::Default => {
+                            socket2::InterfaceIndexOrAddress::Address(Ipv4Addr::UNSPECIFIED)
+                        }
+                        Interface::V4(addr) => socket2::InterfaceIndexOrAddress::Address(addr),
+                        Interface::Index(index) => socket2::InterfaceIndexOrAddress::Index(index),
+                    };
+                    socket.leave_multicast_v4_n(&group, &interface)
+                }
+            }
+            IpAddr::V6(group) => {
+                let interface = match self.interface {
+                    Interface::Index(index) => index,
+                    _ => 0,
+                };
+                socket.leave_multicast_v6(&group, interface)
+            }
+        }
+    }
+}
+
+impl Drop for MulticastGroup<'_> {
+    fn drop(&mut self) {
+        if self.joined {
+            // Nothing sensible can be done with an error here; `leave`
+            // exists for callers who need to observe it.
+            let _ = self.leave_inner();
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Async ICMP echo ("ping") support built on the I/O driver.
+
+use crate::net::RawSocket;
+
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::time::{Duration, Instant};
+
+const ECHO_REQUEST_V4: u8 = 8;
+const ECHO_REPLY_V4: u8 = 0;
+const ECHO_REQUEST_V6: u8 = 128;
+const ECHO_REPLY_V6: u8 = 129;
+
+/// An ICMP echo client for health checking remote hosts.
+///
+/// `Ping` sends ICMP echo requests and measures the round-trip time of the
+/// matching replies. Where the operating system allows it (Linux with
+/// `net.ipv4.ping_group_range` covering the process, and macOS), an
+/// unprivileged `SOCK_DGRAM` ICMP socket is used; otherwise a raw socket is
+/// created, which requires elevated privileges (`CAP_NET_RAW` on Linux).
+///
+/// Each call to [`ping`] sends the next echo request in the sequence, so
+/// calling it in a loop yields a stream of round-trip time samples. A `Ping`
+/// speaks either ICMPv4 or ICMPv6 depending on which constructor was used,
+/// and a single instance must not be shared between concurrent pings: replies
+/// are matched by sequence number, so interleaved calls would steal each
+/// other's responses.
+///
+/// [`ping`]: Ping::ping
+#[derive(Debug)]
+pub struct Ping {
+    socket: RawSocket,
+    ident: u16,
+    seq: u16,
+    v6: bool,
+    raw: bool,
+}
+
+impl Ping {
+    /// Creates an ICMPv4 echo client.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[track_caller]
+    pub fn new_v4() -> io::Result<Ping> {
+        Ping::new(socket2::Domain::IPV4, socket2::Protocol::ICMPV4, false)
+    }
+
+    /// Creates an ICMPv6 echo client.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[track_caller]
+    pub fn new_v6() -> io::Result<Ping> {
+        Ping::new(socket2::Domain::IPV6, socket2::Protocol::ICMPV6, true)
+    }
+
+    #[track_caller]
+    fn new(domain: socket2::Domain, protocol: socket2::Protocol, v6: bool) -> io::Result<Ping> {
+        let (socket, raw) = match socket2::Socket::new(domain, socket2::Type::DGRAM, Some(protocol))
+        {
+            Ok(socket) => (socket, false),
+            Err(_) => (
+                socket2::Socket::new(domain, socket2::Type::RAW, Some(protocol))?,
+                true,
+            ),
+        };
+        socket.set_nonblocking(true)?;
+
+        // Raw sockets see every ICMP message the host receives, so each
+        // instance needs a distinct identifier to tell its replies apart.
+        static NEXT_IDENT: AtomicU16 = AtomicU16::new(0);
+        let ident = (std::process::id() as u16).wrapping_add(NEXT_IDENT.fetch_add(1, Ordering::Relaxed));
+
+        Ok(Ping {
+            socket: RawSocket::from_socket(socket.into())?,
+            ident,
+            seq: 0,
+            v6,
+            raw,
+        })
+    }
+
+    /// Sends a single echo request to `addr` and waits for the matching
+    /// reply, returning the round-trip time.
+    ///
+    /// The address family of `addr` must match the constructor this `Ping`
+    /// was created with. This method waits indefinitely if the host does not
+    /// answer; use [`ping_timeout`] to bound the wait.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is not cancel safe. If it is cancelled after the request
+    /// was sent, the reply is left queued on the socket and is discarded by
+    /// the next call.
+    ///
+    /// [`ping_timeout`]: Ping::ping_timeout
+    pub async fn ping(&mut self, addr: IpAddr) -> io::Result<Duration> {
+        let seq = self.seq;
+        self.seq = self.seq.wrapping_add(1);
+
+        let packet = self.echo_request(seq);
+        let start = Instant::now();
+        self.socket
+            .send_to(&packet, SocketAddr::new(addr, 0))
+            .await?;
+
+        let mut buf = [0u8; 512];
+        loop {
+            let n = self.socket.recv(&mut buf).await?;
+            if self.is_reply(&buf[..n], seq) {
+                return Ok(start.elapsed());
+            }
+        }
+    }
+
+    cfg_time! {
+        /// Sends a single echo request to `addr` and waits at most `timeout`
+        /// for the matching reply, returning the round-trip time.
+        ///
+        /// If no reply arrives within the timeout, an error of kind
+        /// [`ErrorKind::TimedOut`](std::io::ErrorKind::TimedOut) is returned.
+        pub async fn ping_timeout(
+            &mut self,
+            addr: IpAddr,
+            timeout: Duration,
+        ) -> io::Result<Duration> {
+            match crate::time::timeout(timeout, self.ping(addr)).await {
+                Ok(res) => res,
+                Err(_) => Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "ping timed out",
+                )),
+            }
+        }
+    }
+
+    fn echo_request(&self, seq: u16) -> [u8; 16] {
+        let mut packet = [0u8; 16];
+        packet[0] = if self.v6 {
+            ECHO_REQUEST_V6
+        } else {
+            ECHO_REQUEST_V4
+        };
+        packet[4..6].copy_from_slice(&self.ident.to_be_bytes());
+        packet[6..8].copy_from_slice(&seq.to_be_bytes());
+
+        // The kernel computes the ICMPv6 checksum; ICMPv4 is ours to fill in.
+        if !self.v6 {
+            let checksum = checksum(&packet);
+            packet[2..4].copy_from_slice(&checksum.to_be_bytes());
+        }
+
+        packet
+    }
+
+    fn is_reply(&self, packet: &[u8], seq: u16) -> bool {
+        // Raw ICMPv4 sockets (and macOS datagram ICMP sockets) deliver the IP
+        // header in front of the ICMP message; detect it by the version
+        // nibble, which is never 4 for a bare echo reply.
+        let packet = if !self.v6 && !packet.is_empty() && packet[0] >> 4 == 4 {
+            let header_len = usize::from(packet[0] & 0x0f) * 4;
+            match packet.get(header_len..) {
+                Some(rest) => rest,
+                None => return false,
+            }
+        } else {
+            packet
+        };
+
+        if packet.len() < 8 {
+            return false;
+        }
+        let reply = if self.v6 { ECHO_REPLY_V6 } else { ECHO_REPLY_V4 };
+        if packet[0] != reply {
+            return false;
+        }
+
+        // Datagram ICMP sockets rewrite the identifier to a kernel-assigned
+        // one and demultiplex replies per socket, so only the sequence number
+        // is checked there.
+        if self.raw && packet[4..6] != self.ident.to_be_bytes() {
+            return false;
+        }
+        packet[6..8] == seq.to_be_bytes()
+    }
+}
+
+fn checksum(data: &[u8]) -> u16 {
+    let mut sum = 0u32;
+    for chunk in data.chunks(2) {
+        let word = if chunk.len() == 2 {
+            u16::from_be_bytes([chunk[0], chunk[1]])
+        } else {
+            u16::from_be_bytes([chunk[0], 0])
+        };
+        sum += u32::from(word);
+    }
+    while sum >> 16 != 0 {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
@@ -0,0 +1,359 @@
+//! Raw socket types integrated with the I/O driver.
+
+use crate::io::{Interest, PollEvented};
+
+use mio::unix::SourceFd;
+use std::io;
+use std::net::SocketAddr;
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+
+/// A raw socket registered with the I/O driver.
+///
+/// Raw sockets exchange packets below the transport layer: ICMP datagrams
+/// through [`new_icmp_v4`]/[`new_icmp_v6`], link-layer frames through
+/// [`new_packet`] on Linux, or any other `SOCK_RAW` combination through
+/// [`from_socket`]. This lets ping utilities and network scanners use the
+/// runtime's readiness machinery instead of hand-rolling an
+/// [`AsyncFd`](crate::io::unix::AsyncFd) wrapper around libc calls.
+///
+/// Creating raw sockets requires elevated privileges on most systems
+/// (`CAP_NET_RAW` on Linux).
+///
+/// [`new_icmp_v4`]: RawSocket::new_icmp_v4
+/// [`new_icmp_v6`]: RawSocket::new_icmp_v6
+/// [`new_packet`]: RawSocket::new_packet
+/// [`from_socket`]: RawSocket::from_socket
+#[derive(Debug)]
+pub struct RawSocket {
+    io: PollEvented<RawIo>,
+}
+
+/// An owned raw descriptor registered with mio through `SourceFd`.
+#[derive(Debug)]
+struct RawIo {
+    fd: OwnedFd,
+}
+
+impl mio::event::Source for RawIo {
+    fn register(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &mio::Registry) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).deregister(registry)
+    }
+}
+
+fn new_raw_fd(domain: libc::c_int, protocol: libc::c_int) -> io::Result<OwnedFd> {
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "illumos",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    {
+        // SAFETY: `socket` creates a new descriptor we then own.
+        let fd = unsafe {
+            libc::socket(
+                domain,
+                libc::SOCK_RAW | libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC,
+                protocol,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "illumos",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    )))]
+    {
+        // SAFETY: `socket` creates a new descriptor we then own.
+        let raw = unsafe { libc::socket(domain, libc::SOCK_RAW, protocol) };
+        if raw < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(raw) };
+        // SAFETY: `raw` is a valid, owned descriptor.
+        unsafe {
+            if libc::fcntl(raw, libc::F_SETFL, libc::O_NONBLOCK) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if libc::fcntl(raw, libc::F_SETFD, libc::FD_CLOEXEC) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(fd)
+    }
+}
+
+impl RawSocket {
+    /// Creates an IPv4 ICMP socket.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[track_caller]
+    pub fn new_icmp_v4() -> io::Result<RawSocket> {
+        RawSocket::from_fd(new_raw_fd(libc::AF_INET, libc::IPPROTO_ICMP)?)
+    }
+
+    /// Creates an IPv6 ICMP socket.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[track_caller]
+    pub fn new_icmp_v6() -> io::Result<RawSocket> {
+        RawSocket::from_fd(new_raw_fd(libc::AF_INET6, libc::IPPROTO_ICMPV6)?)
+    }
+
+    /// Creates an `AF_PACKET` socket receiving link-layer frames for the
+    /// given ethertype, e.g. `libc::ETH_P_ALL` for all protocols.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "android", target_os = "linux"))))]
+    #[track_caller]
+    pub fn new_packet(protocol: u16) -> io::Result<RawSocket> {
+        // The packet protocol is passed in network byte order.
+        RawSocket::from_fd(new_raw_fd(
+            libc::AF_PACKET,
+            libc::c_int::from(protocol.to_be()),
+        )?)
+    }
+
+    /// Wraps an already created raw socket.
+    ///
+    /// This is the escape hatch for domain and protocol combinations without
+    /// a dedicated constructor. The descriptor must refer to a socket in
+    /// non-blocking mode.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[track_caller]
+    pub fn from_socket(fd: OwnedFd) -> io::Result<RawSocket> {
+        RawSocket::from_fd(fd)
+    }
+
+    #[track_caller]
+    fn from_fd(fd: OwnedFd) -> io::Result<RawSocket> {
+        let io = PollEvented::new(RawIo { fd })?;
+        Ok(RawSocket { io })
+    }
+
+    /// Waits for the socket to become readable.
+    pub async fn readable(&self) -> io::Result<()> {
+        self.io.registration().readiness(Interest::READABLE).await?;
+        Ok(())
+    }
+
+    /// Waits for the socket to become writable.
+    pub async fn writable(&self) -> io::Result<()> {
+        self.io.registration().readiness(Interest::WRITABLE).await?;
+        Ok(())
+    }
+
+    /// Receives a single packet, returning how many bytes were read.
+    ///
+    /// A packet larger than `buf` is truncated to fit.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, it is guaranteed that no packets were received.
+    pub async fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.io
+            .registration()
+            .async_io(Interest::READABLE, || self.recv_inner(buf))
+            .await
+    }
+
+    /// Tries to receive a single packet without waiting.
+    ///
+    /// If no packet is queued, this returns an error of kind
+    /// [`ErrorKind::WouldBlock`](std::io::ErrorKind::WouldBlock).
+    pub fn try_recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.io
+            .registration()
+            .try_io(Interest::READABLE, || self.recv_inner(buf))
+    }
+
+    /// Receives a single packet, returning how many bytes were read and the
+    /// source address.
+    ///
+    /// The address is `None` for address families that cannot be represented
+    /// as a [`SocketAddr`], such as `AF_PACKET`.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, it is guaranteed that no packets were received.
+    pub async fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, Option<SocketAddr>)> {
+        self.io
+            .registration()
+            .async_io(Interest::READABLE, || self.recv_from_inner(buf))
+            .await
+    }
+
+    /// Sends a packet on a connected raw socket.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, it is guaranteed that the packet was not sent.
+    pub async fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.io
+            .registration()
+            .async_io(Interest::WRITABLE, || self.send_inner(buf))
+            .await
+    }
+
+    /// Tries to send a packet on a connected raw socket without waiting.
+    ///
+    /// If the socket is not ready to send, this returns an error of kind
+    /// [`ErrorKind::WouldBlock`](std::io::ErrorKind::WouldBlock).
+    pub fn try_send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.io
+            .registration()
+            .try_io(Interest::WRITABLE, || self.send_inner(buf))
+    }
+
+    /// Sends a packet to the given address.
+    ///
+    /// For ICMP sockets the port of `addr` is ignored.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, it is guaranteed that the packet was not sent.
+    pub async fn send_to(&self, buf: &[u8], addr: SocketAddr) -> io::Result<usize> {
+        let addr = socket2::SockAddr::from(addr);
+        self.io
+            .registration()
+            .async_io(Interest::WRITABLE, || self.send_to_inner(buf, &addr))
+            .await
+    }
+
+    fn recv_inner(&self, buf: &mut [u8]) -> io::Result<usize> {
+        // SAFETY: the buffer outlives the call.
+        let res = unsafe {
+            libc::recv(
+                self.io.fd.as_raw_fd(),
+                buf.as_mut_ptr() as *mut libc::c_void,
+                buf.len(),
+                0,
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+
+    fn recv_from_inner(&self, buf: &mut [u8]) -> io::Result<(usize, Option<SocketAddr>)> {
+        let mut storage = socket2::SockAddrStorage::zeroed();
+        let mut len = storage.size_of();
+
+        // SAFETY: the buffer and address storage outlive the call.
+        let res = unsafe {
+            libc::recvfrom(
+                self.io.fd.as_raw_fd(),
+                buf.as_mut_ptr() as *mut libc::c_void,
+                buf.len(),
+                0,
+                (&mut storage) as *mut socket2::SockAddrStorage as *mut libc::sockaddr,
+                &mut len,
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: the kernel initialized `len` bytes of the address storage.
+        let addr = unsafe { socket2::SockAddr::new(storage, len) }.as_socket();
+        Ok((res as usize, addr))
+    }
+
+    fn send_inner(&self, buf: &[u8]) -> io::Result<usize> {
+        // SAFETY: the buffer outlives the call.
+        let res = unsafe {
+            libc::send(
+                self.io.fd.as_raw_fd(),
+                buf.as_ptr() as *const libc::c_void,
+                buf.len(),
+                0,
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+
+    fn send_to_inner(&self, buf: &[u8], addr: &socket2::SockAddr) -> io::Result<usize> {
+        // SAFETY: the buffer and address outlive the call.
+        let res = unsafe {
+            libc::sendto(
+                self.io.fd.as_raw_fd(),
+                buf.as_ptr() as *const libc::c_void,
+                buf.len(),
+                0,
+                addr.as_ptr().cast(),
+                addr.len(),
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+}
+
+impl AsRawFd for RawSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.io.fd.as_raw_fd()
+    }
+}
+
+impl AsFd for RawSocket {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+use std::io;
+use std::mem;
+use std::os::unix::io::RawFd;
+use std::time::Duration;
+
+/// Kernel TCP statistics for a [`TcpStream`].
+///
+/// This is a snapshot of the kernel's `TCP_INFO` socket option, returned by
+/// [`TcpStream::tcp_info`]. Fields that the running kernel does not report
+/// are zero.
+///
+/// [`TcpStream`]: crate::net::TcpStream
+/// [`TcpStream::tcp_info`]: crate::net::TcpStream::tcp_info
+#[derive(Clone, Copy)]
+pub struct TcpInfo {
+    info: libc::tcp_info,
+}
+
+impl TcpInfo {
+    pub(super) fn query(fd: RawFd) -> io::Result<TcpInfo> {
+        // Zero-initialize so that fields an older kernel does not fill in
+        // read as zero rather than garbage.
+        let mut info: libc::tcp_info = unsafe { mem::zeroed() };
+        let mut len = mem::size_of::<libc::tcp_info>() as libc::socklen_t;
+
+        // SAFETY: the pointer and length describe a valid, writable buffer
+        // and the kernel writes at most `len` bytes into it.
+        let res = unsafe {
+            libc::getsockopt(
+                fd,
+                libc::IPPROTO_TCP,
+                libc::TCP_INFO,
+                &mut info as *mut libc::tcp_info as *mut libc::c_void,
+                &mut len,
+            )
+        };
+
+        if res != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(TcpInfo { info })
+    }
+
+    /// Returns the smoothed round-trip time estimate.
+    pub fn rtt(&self) -> Duration {
+        Duration::from_micros(u64::from(self.info.tcpi_rtt))
+    }
+
+    /// Returns the round-trip time variance.
+    pub fn rtt_variance(&self) -> Duration {
+        Duration::from_micros(u64::from(self.info.tcpi_rttvar))
+    }
+
+    /// Returns the sender congestion window, in segments.
+    pub fn congestion_window(&self) -> u32 {
+        self.info.tcpi_snd_cwnd
+    }
+
+    /// Returns the number of unrecovered retransmission timeouts for the
+    /// segment currently at the head of the send queue.
+    pub fn retransmits(&self) -> u32 {
+        u32::from(self.info.tcpi_retransmits)
+    }
+
+    /// Returns the total number of segments retransmitted over the lifetime
+    /// of the connection.
+    pub fn total_retransmits(&self) -> u32 {
+        self.info.tcpi_total_retrans
+    }
+
+    /// Returns the most recent goodput delivery rate estimate, in bytes per
+    /// second.
+    ///
+    /// Returns zero on kernels that do not report a delivery rate.
+    pub fn delivery_rate(&self) -> u64 {
+        self.info.tcpi_delivery_rate
+    }
+
+    /// Returns the number of segments currently sent but unacknowledged.
+    pub fn unacked(&self) -> u32 {
+        self.info.tcpi_unacked
+    }
+
+    /// Returns the total number of bytes acknowledged by the peer.
+    pub fn bytes_acked(&self) -> u64 {
+        self.info.tcpi_bytes_acked
+    }
+}
+
+impl fmt::Debug for TcpInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TcpInfo")
+            .field("rtt", &self.rtt())
+            .field("rtt_variance", &self.rtt_variance())
+            .field("congestion_window", &self.congestion_window())
+            .field("retransmits", &self.retransmits())
+            .field("total_retransmits", &self.total_retransmits())
+            .field("delivery_rate", &self.delivery_rate())
+            .field("unacked", &self.unacked())
+            .field("bytes_acked", &self.bytes_acked())
+            .finish()
+    }
+}
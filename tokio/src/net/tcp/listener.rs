@@ -4,6 +4,7 @@ use crate::util::check_socket_for_blocking;
 
 cfg_not_wasi! {
     use crate::net::{to_socket_addrs, ToSocketAddrs};
+    use std::time::Duration;
 }
 
 use std::fmt;
@@ -124,6 +125,121 @@ impl TcpListener {
             let listener = mio::net::TcpListener::bind(addr)?;
             TcpListener::new(listener)
         }
+
+        /// Creates `n` listeners bound to the same address, sharing the port
+        /// through `SO_REUSEPORT`.
+        ///
+        /// The kernel distributes incoming connections across the listeners,
+        /// so each can run its own accept loop — typically one per runtime
+        /// worker — without contending on a single accept queue. The option
+        /// is set before binding on every socket, as the platform requires.
+        ///
+        /// Binding with a port number of 0 requests that the OS assign a
+        /// port; the remaining listeners then join the port chosen for the
+        /// first one.
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// use tokio::net::TcpListener;
+        ///
+        /// use std::io;
+        ///
+        /// #[tokio::main]
+        /// async fn main() -> io::Result<()> {
+        ///     let listeners = TcpListener::bind_reuseport_cluster("127.0.0.1:8080", 4).await?;
+        ///
+        ///     for listener in listeners {
+        ///         tokio::spawn(async move {
+        ///             loop {
+        ///                 let (socket, _) = listener.accept().await.unwrap();
+        ///                 drop(socket);
+        ///             }
+        ///         });
+        ///     }
+        ///     Ok(())
+        /// }
+        /// ```
+        #[cfg(all(
+            unix,
+            not(target_os = "solaris"),
+            not(target_os = "illumos"),
+            not(target_os = "cygwin"),
+        ))]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(
+                unix,
+                not(target_os = "solaris"),
+                not(target_os = "illumos"),
+                not(target_os = "cygwin"),
+            )))
+        )]
+        pub async fn bind_reuseport_cluster<A: ToSocketAddrs>(
+            addr: A,
+            n: usize,
+        ) -> io::Result<Vec<TcpListener>> {
+            if n == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "cluster size must be nonzero",
+                ));
+            }
+
+            let addrs = to_socket_addrs(addr).await?;
+
+            let mut last_err = None;
+
+            for addr in addrs {
+                match TcpListener::bind_reuseport_cluster_addr(addr, n) {
+                    Ok(listeners) => return Ok(listeners),
+                    Err(e) => last_err = Some(e),
+                }
+            }
+
+            Err(last_err.unwrap_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "could not resolve to any address",
+                )
+            }))
+        }
+
+        #[cfg(all(
+            unix,
+            not(target_os = "solaris"),
+            not(target_os = "illumos"),
+            not(target_os = "cygwin"),
+        ))]
+        fn bind_reuseport_cluster_addr(
+            mut addr: SocketAddr,
+            n: usize,
+        ) -> io::Result<Vec<TcpListener>> {
+            use crate::net::TcpSocket;
+
+            let mut listeners = Vec::with_capacity(n);
+
+            for _ in 0..n {
+                let socket = if addr.is_ipv4() {
+                    TcpSocket::new_v4()?
+                } else {
+                    TcpSocket::new_v6()?
+                };
+                socket.set_reuseport(true)?;
+                socket.bind(addr)?;
+                let listener = socket.listen(1024)?;
+
+                // With port 0, the remaining sockets must join the port the
+                // kernel picked for the first one.
+                if addr.port() == 0 {
+                    addr.set_port(listener.local_addr()?.port());
+                }
+
+                listeners.push(listener);
+            }
+
+            Ok(listeners)
+        }
     }
 
     /// Accepts a new incoming connection from this listener.
@@ -385,6 +501,258 @@ impl TcpListener {
     pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
         self.io.set_ttl(ttl)
     }
+
+    cfg_not_wasi! {
+        /// Creates a builder that applies socket options to every accepted
+        /// connection.
+        ///
+        /// Options configured on the returned [`AcceptBuilder`] are applied to
+        /// each stream accepted through it before the stream is returned, so
+        /// there is no window where a connection is live without the options
+        /// set and no per-accept configuration boilerplate at the call site.
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// use tokio::net::TcpListener;
+        ///
+        /// use std::io;
+        /// use std::time::Duration;
+        ///
+        /// #[tokio::main]
+        /// async fn main() -> io::Result<()> {
+        ///     let listener = TcpListener::bind("127.0.0.1:8080").await?;
+        ///     let acceptor = listener
+        ///         .builder()
+        ///         .nodelay(true)
+        ///         .keepalive(Duration::from_secs(60));
+        ///
+        ///     loop {
+        ///         let (_stream, addr) = acceptor.accept().await?;
+        ///         println!("new client: {:?}", addr);
+        ///     }
+        /// }
+        /// ```
+        pub fn builder(&self) -> AcceptBuilder<'_> {
+            AcceptBuilder {
+                listener: self,
+                nodelay: None,
+                keepalive: None,
+                #[cfg(any(
+                    target_os = "android",
+                    target_os = "fuchsia",
+                    target_os = "linux",
+                    target_os = "cygwin",
+                ))]
+                user_timeout: None,
+                recv_buffer_size: None,
+                send_buffer_size: None,
+            }
+        }
+    }
+}
+
+cfg_not_wasi! {
+    /// Applies a set of socket options to every accepted connection.
+    ///
+    /// Created by [`TcpListener::builder`]. Streams accepted through
+    /// [`accept`] have the configured options applied before they are
+    /// returned; options that were not configured are left at their system
+    /// defaults.
+    ///
+    /// [`accept`]: AcceptBuilder::accept
+    #[derive(Debug, Clone)]
+    pub struct AcceptBuilder<'a> {
+        listener: &'a TcpListener,
+        nodelay: Option<bool>,
+        keepalive: Option<socket2::TcpKeepalive>,
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "cygwin",
+        ))]
+        user_timeout: Option<Duration>,
+        recv_buffer_size: Option<u32>,
+        send_buffer_size: Option<u32>,
+    }
+
+    impl<'a> AcceptBuilder<'a> {
+        /// Sets the value of the `TCP_NODELAY` option on accepted streams.
+        ///
+        /// If set, this option disables the Nagle algorithm. This means that
+        /// segments are always sent as soon as possible, even if there is only
+        /// a small amount of data.
+        pub fn nodelay(mut self, nodelay: bool) -> AcceptBuilder<'a> {
+            self.nodelay = Some(nodelay);
+            self
+        }
+
+        /// Enables TCP keepalive on accepted streams, sending the first probe
+        /// after the connection has been idle for `time`.
+        pub fn keepalive(mut self, time: Duration) -> AcceptBuilder<'a> {
+            self.keepalive = Some(
+                self.keepalive
+                    .unwrap_or_else(socket2::TcpKeepalive::new)
+                    .with_time(time),
+            );
+            self
+        }
+
+        /// Enables TCP keepalive on accepted streams, sending subsequent
+        /// probes `interval` apart.
+        #[cfg(not(any(
+            target_os = "openbsd",
+            target_os = "redox",
+            target_os = "solaris",
+            target_os = "illumos",
+            target_os = "haiku",
+            target_os = "aix",
+        )))]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(not(any(
+                target_os = "openbsd",
+                target_os = "redox",
+                target_os = "solaris",
+                target_os = "illumos",
+                target_os = "haiku",
+                target_os = "aix",
+            ))))
+        )]
+        pub fn keepalive_interval(mut self, interval: Duration) -> AcceptBuilder<'a> {
+            self.keepalive = Some(
+                self.keepalive
+                    .unwrap_or_else(socket2::TcpKeepalive::new)
+                    .with_interval(interval),
+            );
+            self
+        }
+
+        /// Enables TCP keepalive on accepted streams, dropping a connection
+        /// after `retries` unanswered probes.
+        #[cfg(not(any(
+            target_os = "openbsd",
+            target_os = "redox",
+            target_os = "solaris",
+            target_os = "illumos",
+            target_os = "haiku",
+            target_os = "aix",
+        )))]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(not(any(
+                target_os = "openbsd",
+                target_os = "redox",
+                target_os = "solaris",
+                target_os = "illumos",
+                target_os = "haiku",
+                target_os = "aix",
+            ))))
+        )]
+        pub fn keepalive_retries(mut self, retries: u32) -> AcceptBuilder<'a> {
+            self.keepalive = Some(
+                self.keepalive
+                    .unwrap_or_else(socket2::TcpKeepalive::new)
+                    .with_retries(retries),
+            );
+            self
+        }
+
+        /// Sets the value of the `TCP_USER_TIMEOUT` option on accepted
+        /// streams.
+        ///
+        /// This specifies the maximum amount of time that transmitted data may
+        /// remain unacknowledged before the connection is forcibly closed.
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "cygwin",
+        ))]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(any(
+                target_os = "android",
+                target_os = "fuchsia",
+                target_os = "linux",
+                target_os = "cygwin",
+            )))
+        )]
+        pub fn user_timeout(mut self, timeout: Duration) -> AcceptBuilder<'a> {
+            self.user_timeout = Some(timeout);
+            self
+        }
+
+        /// Sets the size of the `SO_RCVBUF` option on accepted streams.
+        ///
+        /// Changes the size of the operating system's receive buffer
+        /// associated with the socket.
+        pub fn recv_buffer_size(mut self, size: u32) -> AcceptBuilder<'a> {
+            self.recv_buffer_size = Some(size);
+            self
+        }
+
+        /// Sets the size of the `SO_SNDBUF` option on accepted streams.
+        ///
+        /// Changes the size of the operating system's send buffer associated
+        /// with the socket.
+        pub fn send_buffer_size(mut self, size: u32) -> AcceptBuilder<'a> {
+            self.send_buffer_size = Some(size);
+            self
+        }
+
+        /// Accepts a new incoming connection and applies the configured
+        /// options to it.
+        ///
+        /// If applying an option fails, the connection is dropped and the
+        /// error is returned.
+        ///
+        /// # Cancel safety
+        ///
+        /// This method is cancel safe. If the method is used as the event in a
+        /// [`tokio::select!`](crate::select) statement and some other branch
+        /// completes first, then it is guaranteed that no new connections were
+        /// accepted by this method.
+        pub async fn accept(&self) -> io::Result<(TcpStream, SocketAddr)> {
+            let (stream, addr) = self.listener.accept().await?;
+            self.apply(&stream)?;
+            Ok((stream, addr))
+        }
+
+        fn apply(&self, stream: &TcpStream) -> io::Result<()> {
+            let sock = socket2::SockRef::from(stream);
+
+            if let Some(nodelay) = self.nodelay {
+                sock.set_tcp_nodelay(nodelay)?;
+            }
+
+            if let Some(ref keepalive) = self.keepalive {
+                sock.set_keepalive(true)?;
+                sock.set_tcp_keepalive(keepalive)?;
+            }
+
+            #[cfg(any(
+                target_os = "android",
+                target_os = "fuchsia",
+                target_os = "linux",
+                target_os = "cygwin",
+            ))]
+            if self.user_timeout.is_some() {
+                sock.set_tcp_user_timeout(self.user_timeout)?;
+            }
+
+            if let Some(size) = self.recv_buffer_size {
+                sock.set_recv_buffer_size(size as usize)?;
+            }
+
+            if let Some(size) = self.send_buffer_size {
+                sock.set_send_buffer_size(size as usize)?;
+            }
+
+            Ok(())
+        }
+    }
 }
 
 impl TryFrom<net::TcpListener> for TcpListener {
@@ -3,9 +3,16 @@
 pub(crate) mod listener;
 
 cfg_not_wasi! {
+    pub use listener::AcceptBuilder;
+
     pub(crate) mod socket;
 }
 
+#[cfg(target_os = "linux")]
+mod info;
+#[cfg(target_os = "linux")]
+pub use info::TcpInfo;
+
 mod split;
 pub use split::{ReadHalf, WriteHalf};
 
@@ -138,6 +138,136 @@ impl TcpStream {
             TcpStream::connect_mio(sys).await
         }
 
+        cfg_time! {
+            /// Opens a TCP connection using the Happy Eyeballs algorithm
+            /// ([RFC 8305]).
+            ///
+            /// `addr` is resolved as in [`connect`], but instead of trying the
+            /// resolved addresses one at a time, attempts are raced: the
+            /// addresses are interleaved by family (alternating IPv6 and
+            /// IPv4), a new attempt is started every 250 milliseconds while
+            /// earlier ones have neither succeeded nor failed, and the first
+            /// connection to become established wins. The remaining attempts
+            /// are aborted. This avoids the long stalls that sequential
+            /// connecting produces when a host publishes unreachable
+            /// addresses of one family.
+            ///
+            /// If all attempts fail, the error from the last attempt to fail
+            /// is returned.
+            ///
+            /// Use [`connect_happy_eyeballs_with_delay`] to customize the
+            /// delay between attempts.
+            ///
+            /// [RFC 8305]: https://datatracker.ietf.org/doc/html/rfc8305
+            /// [`connect`]: TcpStream::connect
+            /// [`connect_happy_eyeballs_with_delay`]: TcpStream::connect_happy_eyeballs_with_delay
+            ///
+            /// # Examples
+            ///
+            /// ```no_run
+            /// use tokio::net::TcpStream;
+            ///
+            /// use std::io;
+            ///
+            /// #[tokio::main]
+            /// async fn main() -> io::Result<()> {
+            ///     let stream = TcpStream::connect_happy_eyeballs("example.com:80").await?;
+            ///
+            ///     println!("connected to {:?}", stream.peer_addr()?);
+            ///     Ok(())
+            /// }
+            /// ```
+            pub async fn connect_happy_eyeballs<A: ToSocketAddrs>(addr: A) -> io::Result<TcpStream> {
+                // The "Connection Attempt Delay" recommended by RFC 8305 section 5.
+                TcpStream::connect_happy_eyeballs_with_delay(addr, std::time::Duration::from_millis(250)).await
+            }
+
+            /// Opens a TCP connection using the Happy Eyeballs algorithm with
+            /// a custom delay between connection attempts.
+            ///
+            /// This is equivalent to [`connect_happy_eyeballs`], except that
+            /// the next attempt is started `delay` after the previous one
+            /// rather than after 250 milliseconds. RFC 8305 recommends a
+            /// delay between 100 milliseconds and 2 seconds.
+            ///
+            /// [`connect_happy_eyeballs`]: TcpStream::connect_happy_eyeballs
+            pub async fn connect_happy_eyeballs_with_delay<A: ToSocketAddrs>(
+                addr: A,
+                delay: std::time::Duration,
+            ) -> io::Result<TcpStream> {
+                use crate::time::{sleep, Sleep};
+                use std::future::Future;
+                use std::pin::Pin;
+                use std::task::Poll;
+
+                let addrs = to_socket_addrs(addr).await?;
+                let mut addrs = interleave_by_family(addrs.collect()).into_iter();
+
+                type Attempt = Pin<Box<dyn Future<Output = io::Result<TcpStream>> + Send>>;
+                let mut attempts: Vec<Attempt> = Vec::new();
+                let mut timer: Option<Pin<Box<Sleep>>> = None;
+                let mut start_now = true;
+                let mut last_err = None;
+
+                poll_fn(move |cx| loop {
+                    // Check whether it is time to start the next attempt.
+                    let start = start_now
+                        || match timer.as_mut() {
+                            Some(t) => t.as_mut().poll(cx).is_ready(),
+                            None => attempts.is_empty(),
+                        };
+
+                    if start {
+                        start_now = false;
+                        timer = None;
+
+                        if let Some(addr) = addrs.next() {
+                            attempts.push(Box::pin(TcpStream::connect_addr(addr)));
+                            let mut t = Box::pin(sleep(delay));
+                            if t.as_mut().poll(cx).is_pending() {
+                                timer = Some(t);
+                            } else {
+                                // A zero delay: start the next attempt too.
+                                start_now = true;
+                                continue;
+                            }
+                        } else if attempts.is_empty() {
+                            return Poll::Ready(Err(last_err.take().unwrap_or_else(|| {
+                                io::Error::new(
+                                    io::ErrorKind::InvalidInput,
+                                    "could not resolve to any address",
+                                )
+                            })));
+                        }
+                    }
+
+                    let mut failed = false;
+                    let mut i = 0;
+                    while i < attempts.len() {
+                        match attempts[i].as_mut().poll(cx) {
+                            Poll::Ready(Ok(stream)) => return Poll::Ready(Ok(stream)),
+                            Poll::Ready(Err(e)) => {
+                                last_err = Some(e);
+                                drop(attempts.swap_remove(i));
+                                failed = true;
+                            }
+                            Poll::Pending => i += 1,
+                        }
+                    }
+
+                    if failed {
+                        // RFC 8305 section 5: a failed attempt starts the
+                        // next one immediately.
+                        start_now = true;
+                        continue;
+                    }
+
+                    return Poll::Pending;
+                })
+                .await
+            }
+        }
+
         pub(crate) async fn connect_mio(sys: mio::net::TcpStream) -> io::Result<TcpStream> {
             let stream = TcpStream::new(sys)?;
 
@@ -1107,6 +1237,43 @@ impl TcpStream {
             .await
     }
 
+    cfg_time! {
+        /// Receives data on the socket from the remote address to which it is
+        /// connected, without removing that data from the queue, waiting at
+        /// most `timeout`.
+        ///
+        /// This behaves like [`peek`], except that if no data arrives within
+        /// `timeout` an error of kind [`ErrorKind::TimedOut`] is returned.
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// use tokio::net::TcpStream;
+        /// use std::error::Error;
+        /// use std::time::Duration;
+        ///
+        /// #[tokio::main]
+        /// async fn main() -> Result<(), Box<dyn Error>> {
+        ///     let stream = TcpStream::connect("127.0.0.1:8080").await?;
+        ///
+        ///     let mut buf = [0; 10];
+        ///     let n = stream.peek_timeout(&mut buf, Duration::from_secs(1)).await?;
+        ///     println!("peeked {} bytes", n);
+        ///
+        ///     Ok(())
+        /// }
+        /// ```
+        ///
+        /// [`peek`]: TcpStream::peek
+        /// [`ErrorKind::TimedOut`]: std::io::ErrorKind::TimedOut
+        pub async fn peek_timeout(&self, buf: &mut [u8], timeout: Duration) -> io::Result<usize> {
+            match crate::time::timeout(timeout, self.peek(buf)).await {
+                Ok(res) => res,
+                Err(_) => Err(io::Error::new(io::ErrorKind::TimedOut, "peek timed out")),
+            }
+        }
+    }
+
     /// Shuts down the read, write, or both halves of this connection.
     ///
     /// This function will cause all pending and future I/O on the specified
@@ -1124,6 +1291,65 @@ impl TcpStream {
         }
     }
 
+    cfg_time! {
+        /// Closes the connection gracefully, waiting at most `timeout` for
+        /// the peer to finish.
+        ///
+        /// This shuts down the write half so the peer sees EOF, then reads
+        /// and discards incoming data until the peer closes its side. Simply
+        /// dropping a stream with unread data makes many kernels send an
+        /// `RST`, which can discard data the peer has not yet read; draining
+        /// first avoids that loss.
+        ///
+        /// Returns an error of kind [`ErrorKind::TimedOut`] if the peer does
+        /// not close its side within `timeout`; the connection is still
+        /// closed when this happens.
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// use tokio::io::AsyncWriteExt;
+        /// use tokio::net::TcpStream;
+        /// use std::error::Error;
+        /// use std::time::Duration;
+        ///
+        /// #[tokio::main]
+        /// async fn main() -> Result<(), Box<dyn Error>> {
+        ///     let mut stream = TcpStream::connect("127.0.0.1:8080").await?;
+        ///     stream.write_all(b"request").await?;
+        ///
+        ///     stream.close_graceful(Duration::from_secs(5)).await?;
+        ///     Ok(())
+        /// }
+        /// ```
+        ///
+        /// [`ErrorKind::TimedOut`]: std::io::ErrorKind::TimedOut
+        pub async fn close_graceful(self, timeout: Duration) -> io::Result<()> {
+            self.shutdown_std(Shutdown::Write)?;
+
+            let drain = async {
+                let mut buf = [0u8; 4096];
+                loop {
+                    self.readable().await?;
+                    match self.try_read(&mut buf) {
+                        Ok(0) => return Ok(()),
+                        Ok(_) => {}
+                        Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+            };
+
+            match crate::time::timeout(timeout, drain).await {
+                Ok(res) => res,
+                Err(_) => Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "peer did not close the connection within the timeout",
+                )),
+            }
+        }
+    }
+
     /// Gets the value of the `TCP_NODELAY` option on this socket.
     ///
     /// For more information about this option, see [`set_nodelay`].
@@ -1245,6 +1471,39 @@ impl TcpStream {
         socket2::SockRef::from(self).set_tcp_quickack(quickack)
     }
 
+    /// Reads the kernel's `TCP_INFO` statistics for this stream.
+    ///
+    /// The returned [`TcpInfo`] is a snapshot of connection quality metrics
+    /// such as the round-trip time estimate, the congestion window and
+    /// retransmission counters, which is useful for monitoring connections
+    /// without dropping to raw socket APIs.
+    ///
+    /// See [`man 7 tcp`](https://man7.org/linux/man-pages/man7/tcp.7.html)
+    /// for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::TcpStream;
+    ///
+    /// # async fn dox() -> Result<(), Box<dyn std::error::Error>> {
+    /// let stream = TcpStream::connect("127.0.0.1:8080").await?;
+    ///
+    /// let info = stream.tcp_info()?;
+    /// println!("rtt: {:?}", info.rtt());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`TcpInfo`]: crate::net::tcp::TcpInfo
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+    pub fn tcp_info(&self) -> io::Result<super::TcpInfo> {
+        use std::os::unix::io::AsRawFd;
+
+        super::TcpInfo::query(self.as_raw_fd())
+    }
+
     cfg_not_wasi! {
         /// Reads the linger duration for this socket by getting the `SO_LINGER`
         /// option.
@@ -1382,6 +1641,27 @@ impl TcpStream {
         unsafe { self.io.poll_read(cx, buf) }
     }
 
+    pub(crate) fn poll_read_vectored_priv(
+        &self,
+        cx: &mut Context<'_>,
+        bufs: &mut [ReadBuf<'_>],
+    ) -> Poll<io::Result<usize>> {
+        use std::io::Read;
+
+        loop {
+            let ev = ready!(self.io.registration().poll_read_ready(cx))?;
+
+            match crate::io::read_vectored_into(bufs, |slices| (&*self.io).read_vectored(slices))
+            {
+                Ok(n) => return Poll::Ready(Ok(n)),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    self.io.registration().clear_readiness(ev);
+                }
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+    }
+
     pub(super) fn poll_write_priv(
         &self,
         cx: &mut Context<'_>,
@@ -1421,6 +1701,18 @@ impl AsyncRead for TcpStream {
     ) -> Poll<io::Result<()>> {
         self.poll_read_priv(cx, buf)
     }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [ReadBuf<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.poll_read_vectored_priv(cx, bufs)
+    }
+
+    fn is_read_vectored(&self) -> bool {
+        true
+    }
 }
 
 impl AsyncWrite for TcpStream {
@@ -1456,6 +1748,42 @@ impl AsyncWrite for TcpStream {
     }
 }
 
+cfg_io_util! {
+    // All I/O goes through `&self` readiness operations on the shared socket;
+    // the read and write paths do not touch common mutable state.
+    impl crate::io::ConcurrentlySplittable for TcpStream {
+        fn poll_read_ref(
+            &self,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            self.poll_read_priv(cx, buf)
+        }
+
+        fn poll_write_ref(&self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+            self.poll_write_priv(cx, buf)
+        }
+
+        fn poll_write_vectored_ref(
+            &self,
+            cx: &mut Context<'_>,
+            bufs: &[io::IoSlice<'_>],
+        ) -> Poll<io::Result<usize>> {
+            self.poll_write_vectored_priv(cx, bufs)
+        }
+
+        fn poll_flush_ref(&self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            // tcp flush is a no-op
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown_ref(&self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.shutdown_std(std::net::Shutdown::Write)?;
+            Poll::Ready(Ok(()))
+        }
+    }
+}
+
 impl fmt::Debug for TcpStream {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.io.fmt(f)
@@ -1513,3 +1841,41 @@ mod sys {
         }
     }
 }
+
+cfg_not_wasi! {
+    /// Interleaves addresses by family, as described by RFC 8305 section 4.
+    ///
+    /// The resolver's order is preserved within each family, and the family
+    /// of the first resolved address keeps its preferred position.
+    #[cfg(feature = "time")]
+    fn interleave_by_family(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+        let prefer_v6 = addrs.first().is_some_and(SocketAddr::is_ipv6);
+        let (preferred, other): (Vec<_>, Vec<_>) = addrs
+            .into_iter()
+            .partition(|addr| addr.is_ipv6() == prefer_v6);
+
+        let mut out = Vec::with_capacity(preferred.len() + other.len());
+        let mut preferred = preferred.into_iter();
+        let mut other = other.into_iter();
+        loop {
+            match (preferred.next(), other.next()) {
+                (Some(a), Some(b)) => {
+                    out.push(a);
+                    out.push(b);
+                }
+                (Some(a), None) => {
+                    out.push(a);
+                    out.extend(preferred);
+                    break;
+                }
+                (None, Some(b)) => {
+                    out.push(b);
+                    out.extend(other);
+                    break;
+                }
+                (None, None) => break,
+            }
+        }
+        out
+    }
+}
@@ -122,6 +122,42 @@ cfg_net! {
     pub struct UdpSocket {
         io: PollEvented<mio::net::UdpSocket>,
     }
+
+    /// A receive slot for [`UdpSocket::recv_many`].
+    ///
+    /// Each slot wraps a caller-provided buffer. After a successful receive,
+    /// [`payload`](BufAddr::payload) is the datagram that was written into
+    /// the buffer and [`addr`](BufAddr::addr) is the address it came from.
+    #[derive(Debug)]
+    pub struct BufAddr<'a> {
+        buf: &'a mut [u8],
+        len: usize,
+        addr: Option<SocketAddr>,
+    }
+}
+
+impl<'a> BufAddr<'a> {
+    /// Creates an empty slot backed by `buf`.
+    pub fn new(buf: &'a mut [u8]) -> BufAddr<'a> {
+        BufAddr {
+            buf,
+            len: 0,
+            addr: None,
+        }
+    }
+
+    /// Returns the datagram received into this slot.
+    ///
+    /// Returns an empty slice if the slot has not been filled yet.
+    pub fn payload(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Returns the address the datagram was received from, or `None` if the
+    /// slot has not been filled yet.
+    pub fn addr(&self) -> Option<SocketAddr> {
+        self.addr
+    }
 }
 
 impl UdpSocket {
@@ -273,7 +309,7 @@ impl UdpSocket {
         }
     }
 
-    fn as_socket(&self) -> socket2::SockRef<'_> {
+    pub(crate) fn as_socket(&self) -> socket2::SockRef<'_> {
         socket2::SockRef::from(self)
     }
 
@@ -1703,6 +1739,89 @@ impl UdpSocket {
             .await
     }
 
+    /// Returns the size of the next datagram waiting on the socket, without
+    /// removing it from the queue.
+    ///
+    /// This lets servers size a receive buffer exactly before reading a
+    /// variable-length datagram. On platforms that support it this is done
+    /// with `MSG_PEEK | MSG_TRUNC` and no copy; elsewhere the datagram is
+    /// peeked into a scratch buffer.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, it is guaranteed that no messages were received on
+    /// this socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::UdpSocket;
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:8080").await?;
+    ///
+    ///     let len = socket.peek_len().await?;
+    ///     let mut buf = vec![0u8; len];
+    ///     let (n, addr) = socket.recv_from(&mut buf).await?;
+    ///     assert_eq!(n, len);
+    ///     println!("{} bytes from {}", n, addr);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn peek_len(&self) -> io::Result<usize> {
+        self.io
+            .registration()
+            .async_io(Interest::READABLE, || self.peek_len_inner())
+            .await
+    }
+
+    /// Tries to return the size of the next datagram waiting on the socket,
+    /// without removing it from the queue.
+    ///
+    /// This behaves like [`peek_len`], except that if no datagram is queued
+    /// it returns an error of kind [`ErrorKind::WouldBlock`] right away
+    /// instead of waiting. This function is usually paired with
+    /// `readable()`.
+    ///
+    /// [`peek_len`]: UdpSocket::peek_len
+    /// [`ErrorKind::WouldBlock`]: std::io::ErrorKind::WouldBlock
+    pub fn try_peek_len(&self) -> io::Result<usize> {
+        self.io
+            .registration()
+            .try_io(Interest::READABLE, || self.peek_len_inner())
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn peek_len_inner(&self) -> io::Result<usize> {
+        use std::os::unix::io::AsRawFd;
+
+        // With `MSG_TRUNC`, `recv` reports the real datagram length even
+        // though nothing is copied out.
+        let res = unsafe {
+            libc::recv(
+                self.io.as_raw_fd(),
+                std::ptr::null_mut(),
+                0,
+                libc::MSG_PEEK | libc::MSG_TRUNC,
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+
+    #[cfg(not(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))]
+    fn peek_len_inner(&self) -> io::Result<usize> {
+        // Large enough for the maximum UDP payload.
+        let mut buf = [0u8; 65536];
+        self.io.peek_from(&mut buf).map(|(len, _)| len)
+    }
+
     /// Receives data from the socket, without removing it from the input queue.
     /// On success, returns the sending address of the datagram.
     ///
@@ -2149,6 +2268,277 @@ impl UdpSocket {
     pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         self.io.take_error()
     }
+
+    /// Sends a batch of datagrams on the socket, returning how many were
+    /// sent.
+    ///
+    /// Each entry is a payload and the address to send it to. On Linux the
+    /// whole batch is submitted with a single `sendmmsg(2)` call; elsewhere
+    /// the datagrams are sent one at a time. In both cases at most
+    /// [`BATCH_SIZE`](UdpSocket::BATCH_SIZE) datagrams are sent per call, and
+    /// fewer may be sent if the send buffer fills up mid-batch, so callers
+    /// should resubmit the remainder.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If `send_many` is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, then it is guaranteed that none of the datagrams were
+    /// sent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::UdpSocket;
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:0").await?;
+    ///     let dst = "127.0.0.1:8080".parse().unwrap();
+    ///
+    ///     let sent = socket
+    ///         .send_many(&[(&b"hello"[..], dst), (&b"world"[..], dst)])
+    ///         .await?;
+    ///     println!("sent {} datagrams", sent);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_many(&self, datagrams: &[(&[u8], SocketAddr)]) -> io::Result<usize> {
+        if datagrams.is_empty() {
+            return Ok(0);
+        }
+        self.io
+            .registration()
+            .async_io(Interest::WRITABLE, || self.send_many_inner(datagrams))
+            .await
+    }
+
+    /// Tries to send a batch of datagrams on the socket, returning how many
+    /// were sent.
+    ///
+    /// This behaves like [`send_many`], except that if the socket is not
+    /// ready to send, it returns an error of kind
+    /// [`ErrorKind::WouldBlock`] right away instead of waiting. This function
+    /// is usually paired with `writable()`.
+    ///
+    /// [`send_many`]: UdpSocket::send_many
+    /// [`ErrorKind::WouldBlock`]: std::io::ErrorKind::WouldBlock
+    pub fn try_send_many(&self, datagrams: &[(&[u8], SocketAddr)]) -> io::Result<usize> {
+        if datagrams.is_empty() {
+            return Ok(0);
+        }
+        self.io
+            .registration()
+            .try_io(Interest::WRITABLE, || self.send_many_inner(datagrams))
+    }
+
+    /// Receives a batch of datagrams on the socket, returning how many were
+    /// received.
+    ///
+    /// Payloads, lengths and source addresses are recorded in the first `n`
+    /// entries of `slots`, where `n` is the returned count. On Linux the
+    /// whole batch is received with a single `recvmmsg(2)` call; elsewhere
+    /// the datagrams are received one at a time. At most
+    /// [`BATCH_SIZE`](UdpSocket::BATCH_SIZE) datagrams are received per call.
+    /// The method completes as soon as at least one datagram is available, so
+    /// fewer than `slots.len()` entries may be filled.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If `recv_many` is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, it is guaranteed that no messages were received on
+    /// this socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::{BufAddr, UdpSocket};
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:8080").await?;
+    ///
+    ///     let mut bufs = vec![[0u8; 1500]; 16];
+    ///     let mut slots: Vec<BufAddr<'_>> =
+    ///         bufs.iter_mut().map(|buf| BufAddr::new(buf)).collect();
+    ///
+    ///     let n = socket.recv_many(&mut slots).await?;
+    ///     for slot in &slots[..n] {
+    ///         println!(
+    ///             "{} bytes from {:?}",
+    ///             slot.payload().len(),
+    ///             slot.addr().unwrap(),
+    ///         );
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn recv_many(&self, slots: &mut [BufAddr<'_>]) -> io::Result<usize> {
+        if slots.is_empty() {
+            return Ok(0);
+        }
+        self.io
+            .registration()
+            .async_io(Interest::READABLE, || self.recv_many_inner(slots))
+            .await
+    }
+
+    /// Tries to receive a batch of datagrams on the socket, returning how
+    /// many were received.
+    ///
+    /// This behaves like [`recv_many`], except that if there are no datagrams
+    /// to receive, it returns an error of kind [`ErrorKind::WouldBlock`]
+    /// right away instead of waiting. This function is usually paired with
+    /// `readable()`.
+    ///
+    /// [`recv_many`]: UdpSocket::recv_many
+    /// [`ErrorKind::WouldBlock`]: std::io::ErrorKind::WouldBlock
+    pub fn try_recv_many(&self, slots: &mut [BufAddr<'_>]) -> io::Result<usize> {
+        if slots.is_empty() {
+            return Ok(0);
+        }
+        self.io
+            .registration()
+            .try_io(Interest::READABLE, || self.recv_many_inner(slots))
+    }
+
+    /// The maximum number of datagrams processed by a single [`send_many`] or
+    /// [`recv_many`] call.
+    ///
+    /// [`send_many`]: UdpSocket::send_many
+    /// [`recv_many`]: UdpSocket::recv_many
+    pub const BATCH_SIZE: usize = 32;
+
+    #[cfg(target_os = "linux")]
+    fn send_many_inner(&self, datagrams: &[(&[u8], SocketAddr)]) -> io::Result<usize> {
+        use std::os::unix::io::AsRawFd;
+
+        let n = datagrams.len().min(Self::BATCH_SIZE);
+
+        // The `SockAddr`s must outlive the syscall, as the headers point
+        // into them.
+        let addrs: Vec<socket2::SockAddr> = datagrams[..n]
+            .iter()
+            .map(|(_, addr)| socket2::SockAddr::from(*addr))
+            .collect();
+
+        let mut iovecs: Vec<libc::iovec> = Vec::with_capacity(n);
+        let mut hdrs: Vec<libc::mmsghdr> = Vec::with_capacity(n);
+        for (i, (payload, _)) in datagrams[..n].iter().enumerate() {
+            iovecs.push(libc::iovec {
+                iov_base: payload.as_ptr() as *mut libc::c_void,
+                iov_len: payload.len(),
+            });
+            let mut hdr: libc::mmsghdr = unsafe { std::mem::zeroed() };
+            hdr.msg_hdr.msg_iov = &mut iovecs[i];
+            hdr.msg_hdr.msg_iovlen = 1;
+            hdr.msg_hdr.msg_name = addrs[i].as_ptr() as *mut libc::c_void;
+            hdr.msg_hdr.msg_namelen = addrs[i].len();
+            hdrs.push(hdr);
+        }
+
+        // SAFETY: the headers, iovecs and addresses all outlive the call.
+        let res = unsafe {
+            libc::sendmmsg(
+                self.io.as_raw_fd(),
+                hdrs.as_mut_ptr(),
+                n as libc::c_uint,
+                0,
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn send_many_inner(&self, datagrams: &[(&[u8], SocketAddr)]) -> io::Result<usize> {
+        let n = datagrams.len().min(Self::BATCH_SIZE);
+        let mut sent = 0;
+        for (payload, addr) in &datagrams[..n] {
+            match self.io.send_to(payload, *addr) {
+                Ok(_) => sent += 1,
+                // Match `sendmmsg`: report an error only if nothing was
+                // sent; otherwise the error resurfaces on the next call.
+                Err(e) if sent == 0 => return Err(e),
+                Err(_) => break,
+            }
+        }
+        Ok(sent)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn recv_many_inner(&self, slots: &mut [BufAddr<'_>]) -> io::Result<usize> {
+        use std::os::unix::io::AsRawFd;
+
+        let n = slots.len().min(Self::BATCH_SIZE);
+
+        let mut storage: Vec<socket2::SockAddrStorage> =
+            (0..n).map(|_| socket2::SockAddrStorage::zeroed()).collect();
+        let mut iovecs: Vec<libc::iovec> = Vec::with_capacity(n);
+        let mut hdrs: Vec<libc::mmsghdr> = Vec::with_capacity(n);
+        for (i, slot) in slots[..n].iter_mut().enumerate() {
+            iovecs.push(libc::iovec {
+                iov_base: slot.buf.as_mut_ptr().cast(),
+                iov_len: slot.buf.len(),
+            });
+            let mut hdr: libc::mmsghdr = unsafe { std::mem::zeroed() };
+            hdr.msg_hdr.msg_iov = &mut iovecs[i];
+            hdr.msg_hdr.msg_iovlen = 1;
+            hdr.msg_hdr.msg_name = (&mut storage[i]) as *mut socket2::SockAddrStorage as *mut _;
+            hdr.msg_hdr.msg_namelen = storage[i].size_of();
+            hdrs.push(hdr);
+        }
+
+        // SAFETY: the headers, iovecs, buffers and address storage all
+        // outlive the call.
+        let res = unsafe {
+            libc::recvmmsg(
+                self.io.as_raw_fd(),
+                hdrs.as_mut_ptr(),
+                n as libc::c_uint,
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let count = res as usize;
+        for (i, (slot, storage)) in slots[..count].iter_mut().zip(storage).enumerate() {
+            slot.len = hdrs[i].msg_len as usize;
+            // SAFETY: the kernel initialized `msg_namelen` bytes of the
+            // address storage.
+            slot.addr = unsafe { socket2::SockAddr::new(storage, hdrs[i].msg_hdr.msg_namelen) }
+                .as_socket();
+        }
+        Ok(count)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn recv_many_inner(&self, slots: &mut [BufAddr<'_>]) -> io::Result<usize> {
+        let n = slots.len().min(Self::BATCH_SIZE);
+        let mut received = 0;
+        for slot in &mut slots[..n] {
+            match self.io.recv_from(slot.buf) {
+                Ok((len, addr)) => {
+                    slot.len = len;
+                    slot.addr = Some(addr);
+                    received += 1;
+                }
+                // Match `recvmmsg`: report an error only if nothing was
+                // received.
+                Err(e) if received == 0 => return Err(e),
+                Err(_) => break,
+            }
+        }
+        Ok(received)
+    }
 }
 
 impl TryFrom<std::net::UdpSocket> for UdpSocket {
@@ -0,0 +1,130 @@
+//! `SCM_RIGHTS` ancillary data support shared by `UnixStream` and
+//! `UnixDatagram`.
+
+use std::io;
+use std::mem;
+use std::os::unix::io::{BorrowedFd, FromRawFd, OwnedFd, RawFd};
+
+/// The maximum number of file descriptors that can travel in a single
+/// `SCM_RIGHTS` message. This matches the kernel's `SCM_MAX_FD` limit.
+pub(crate) const MAX_FDS: usize = 253;
+
+// Space for one cmsg header plus `MAX_FDS` descriptors, aligned like
+// `cmsghdr` by using a `u64` backing array.
+const CMSG_BUF_LEN: usize =
+    (mem::size_of::<libc::cmsghdr>() + MAX_FDS * mem::size_of::<RawFd>() + 7) / 8;
+
+pub(crate) fn send_with_fds(
+    socket: RawFd,
+    buf: &[u8],
+    fds: &[BorrowedFd<'_>],
+) -> io::Result<usize> {
+    let mut iov = libc::iovec {
+        iov_base: buf.as_ptr() as *mut libc::c_void,
+        iov_len: buf.len(),
+    };
+    let mut cmsg_buf = [0u64; CMSG_BUF_LEN];
+
+    let mut msg: libc::msghdr = unsafe { mem::zeroed() };
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+
+    if !fds.is_empty() {
+        let payload_len = fds.len() * mem::size_of::<RawFd>();
+        msg.msg_control = cmsg_buf.as_mut_ptr().cast();
+        // SAFETY: `CMSG_SPACE` only performs arithmetic on its argument.
+        msg.msg_controllen = unsafe { libc::CMSG_SPACE(payload_len as libc::c_uint) } as _;
+
+        // SAFETY: `msg_control` points at a zeroed, suitably aligned buffer
+        // large enough for the header and payload.
+        unsafe {
+            let cmsg = libc::CMSG_FIRSTHDR(&msg);
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+            (*cmsg).cmsg_len = libc::CMSG_LEN(payload_len as libc::c_uint) as _;
+            let data = libc::CMSG_DATA(cmsg).cast::<RawFd>();
+            for (i, fd) in fds.iter().enumerate() {
+                use std::os::unix::io::AsRawFd;
+                data.add(i).write_unaligned(fd.as_raw_fd());
+            }
+        }
+    }
+
+    // SAFETY: `msg` refers only to live stack buffers.
+    let res = unsafe { libc::sendmsg(socket, &msg, 0) };
+    if res < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(res as usize)
+}
+
+pub(crate) fn recv_with_fds(
+    socket: RawFd,
+    buf: &mut [u8],
+    fds: &mut Vec<OwnedFd>,
+) -> io::Result<usize> {
+    let mut iov = libc::iovec {
+        iov_base: buf.as_mut_ptr().cast(),
+        iov_len: buf.len(),
+    };
+    let mut cmsg_buf = [0u64; CMSG_BUF_LEN];
+
+    let mut msg: libc::msghdr = unsafe { mem::zeroed() };
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+    msg.msg_control = cmsg_buf.as_mut_ptr().cast();
+    msg.msg_controllen = mem::size_of_val(&cmsg_buf) as _;
+
+    // SAFETY: `msg` refers only to live stack buffers.
+    let res = unsafe { libc::recvmsg(socket, &mut msg, cloexec_flag()) };
+    if res < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // SAFETY: the kernel initialized `msg_controllen` bytes of control data;
+    // the CMSG macros walk only that region.
+    unsafe {
+        let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+        while !cmsg.is_null() {
+            if (*cmsg).cmsg_level == libc::SOL_SOCKET && (*cmsg).cmsg_type == libc::SCM_RIGHTS {
+                let payload_len =
+                    (*cmsg).cmsg_len as usize - libc::CMSG_LEN(0) as usize;
+                let data = libc::CMSG_DATA(cmsg).cast::<RawFd>();
+                for i in 0..payload_len / mem::size_of::<RawFd>() {
+                    let fd = data.add(i).read_unaligned();
+                    fds.push(OwnedFd::from_raw_fd(fd));
+                }
+            }
+            cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+        }
+    }
+
+    Ok(res as usize)
+}
+
+fn cloexec_flag() -> libc::c_int {
+    // `MSG_CMSG_CLOEXEC` closes the race between receiving a descriptor and
+    // marking it close-on-exec, but is not available everywhere.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    {
+        libc::MSG_CMSG_CLOEXEC
+    }
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    )))]
+    {
+        0
+    }
+}
@@ -5,7 +5,11 @@ use crate::util::check_socket_for_blocking;
 use std::fmt;
 use std::io;
 use std::net::Shutdown;
-use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
+#[cfg(target_os = "android")]
+use std::os::android::net::SocketAddrExt;
+#[cfg(target_os = "linux")]
+use std::os::linux::net::SocketAddrExt;
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 use std::os::unix::net;
 use std::path::Path;
 use std::task::{ready, Context, Poll};
@@ -400,6 +404,44 @@ impl UnixDatagram {
         UnixDatagram::new(socket)
     }
 
+    /// Creates a new `UnixDatagram` bound to an abstract-namespace address.
+    ///
+    /// `name` is the abstract name without the leading null byte, and unlike
+    /// the path accepted by [`bind`] it need not be valid UTF-8. If `name` is
+    /// empty, the kernel chooses a unique abstract address (autobind); the
+    /// assigned name can be recovered through
+    /// [`local_addr`](UnixDatagram::local_addr).
+    ///
+    /// Abstract sockets have no filesystem presence and disappear when the
+    /// last descriptor referring to them is closed.
+    ///
+    /// [`bind`]: UnixDatagram::bind
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "linux", target_os = "android"))))]
+    #[track_caller]
+    pub fn bind_abstract<N>(name: N) -> io::Result<UnixDatagram>
+    where
+        N: AsRef<[u8]>,
+    {
+        let name = name.as_ref();
+
+        if name.is_empty() {
+            let socket = net::UnixDatagram::unbound()?;
+            crate::net::unix::autobind(socket.as_raw_fd())?;
+            socket.set_nonblocking(true)?;
+            return UnixDatagram::from_std(socket);
+        }
+
+        let addr = net::SocketAddr::from_abstract_name(name)?;
+        let socket = mio::net::UnixDatagram::bind_addr(&addr)?;
+        UnixDatagram::new(socket)
+    }
+
     /// Creates an unnamed pair of connected sockets.
     ///
     /// This function will create a pair of interconnected Unix sockets for
@@ -829,6 +871,69 @@ impl UnixDatagram {
             .try_io(Interest::READABLE, || self.io.recv(buf))
     }
 
+    /// Sends a datagram to the connected peer along with file descriptors as
+    /// `SCM_RIGHTS` ancillary data.
+    ///
+    /// The descriptors are duplicated into the receiving process, where they
+    /// arrive alongside the datagram via [`recv_with_fds`]. At most 253
+    /// descriptors can be sent per call, matching the kernel's per-message
+    /// limit. The socket must be connected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than 253 descriptors are passed.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, then it is guaranteed that the datagram was not sent.
+    ///
+    /// [`recv_with_fds`]: UnixDatagram::recv_with_fds
+    pub async fn send_with_fds(&self, buf: &[u8], fds: &[BorrowedFd<'_>]) -> io::Result<usize> {
+        assert!(
+            fds.len() <= crate::net::unix::ancillary::MAX_FDS,
+            "at most 253 file descriptors can be sent per message"
+        );
+        self.io
+            .registration()
+            .async_io(Interest::WRITABLE, || {
+                crate::net::unix::ancillary::send_with_fds(self.as_raw_fd(), buf, fds)
+            })
+            .await
+    }
+
+    /// Receives a datagram from the connected peer along with any file
+    /// descriptors sent as `SCM_RIGHTS` ancillary data.
+    ///
+    /// The datagram is read into `buf` and received descriptors are appended
+    /// to `fds` as owned descriptors, so they are closed when dropped. Where
+    /// the platform supports it, descriptors are received with close-on-exec
+    /// already set.
+    ///
+    /// Returns the number of bytes read. See [`send_with_fds`] for the
+    /// sending side.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, then it is guaranteed that no datagram was received.
+    ///
+    /// [`send_with_fds`]: UnixDatagram::send_with_fds
+    pub async fn recv_with_fds(
+        &self,
+        buf: &mut [u8],
+        fds: &mut Vec<OwnedFd>,
+    ) -> io::Result<usize> {
+        self.io
+            .registration()
+            .async_io(Interest::READABLE, || {
+                crate::net::unix::ancillary::recv_with_fds(self.as_raw_fd(), buf, fds)
+            })
+            .await
+    }
+
     cfg_io_util! {
         /// Tries to receive data from the socket without waiting.
         ///
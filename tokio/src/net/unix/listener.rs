@@ -1,5 +1,5 @@
 use crate::io::{Interest, PollEvented};
-use crate::net::unix::{SocketAddr, UnixStream};
+use crate::net::unix::{SocketAddr, UCred, UnixStream};
 use crate::util::check_socket_for_blocking;
 
 use std::fmt;
@@ -95,6 +95,48 @@ impl UnixListener {
         Ok(UnixListener { io })
     }
 
+    /// Creates a new `UnixListener` bound to an abstract-namespace address.
+    ///
+    /// `name` is the abstract name without the leading null byte, and unlike
+    /// the path accepted by [`bind`] it need not be valid UTF-8. If `name` is
+    /// empty, the kernel chooses a unique abstract address (autobind); the
+    /// assigned name can be recovered through
+    /// [`local_addr`](UnixListener::local_addr).
+    ///
+    /// Abstract sockets have no filesystem presence and disappear when the
+    /// last descriptor referring to them is closed.
+    ///
+    /// [`bind`]: UnixListener::bind
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "linux", target_os = "android"))))]
+    #[track_caller]
+    pub fn bind_abstract<N>(name: N) -> io::Result<UnixListener>
+    where
+        N: AsRef<[u8]>,
+    {
+        let name = name.as_ref();
+
+        if name.is_empty() {
+            let socket =
+                socket2::Socket::new(socket2::Domain::UNIX, socket2::Type::STREAM, None)?;
+            crate::net::unix::autobind(socket.as_raw_fd())?;
+            socket.listen(1024)?;
+            socket.set_nonblocking(true)?;
+            let listener =
+                unsafe { mio::net::UnixListener::from_raw_fd(socket.into_raw_fd()) };
+            return UnixListener::new(listener);
+        }
+
+        let addr = StdSocketAddr::from_abstract_name(name)?;
+        let listener = mio::net::UnixListener::bind_addr(&addr)?;
+        UnixListener::new(listener)
+    }
+
     /// Creates new [`UnixListener`] from a [`std::os::unix::net::UnixListener`].
     ///
     /// This function is intended to be used to wrap a `UnixListener` from the
@@ -202,6 +244,47 @@ impl UnixListener {
         Ok((stream, addr))
     }
 
+    /// Accepts a new incoming connection to this listener, returning the
+    /// peer's credentials along with the stream.
+    ///
+    /// This retrieves the credentials immediately after the connection is
+    /// accepted, before any application I/O happens, which is what
+    /// authorization checks want. It is equivalent to calling
+    /// [`accept`](UnixListener::accept) followed by
+    /// [`UnixStream::peer_cred`], except that a credential lookup failure
+    /// rejects the connection as a whole.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If the method is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, then it is guaranteed that no new connections were
+    /// accepted by this method.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::UnixListener;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let dir = tempfile::tempdir().unwrap();
+    ///     let path = dir.path().join("the.sock");
+    ///     let listener = UnixListener::bind(path).unwrap();
+    ///     match listener.accept_with_cred().await {
+    ///         Ok((_socket, addr, cred)) => {
+    ///             println!("new client {:?} with uid {}", addr, cred.uid());
+    ///         }
+    ///         Err(e) => println!("couldn't get client: {:?}", e),
+    ///     }
+    /// }
+    /// ```
+    pub async fn accept_with_cred(&self) -> io::Result<(UnixStream, SocketAddr, UCred)> {
+        let (stream, addr) = self.accept().await?;
+        let cred = stream.peer_cred()?;
+        Ok((stream, addr, cred))
+    }
+
     /// Polls to accept a new incoming connection to this listener.
     ///
     /// If there is no connection to accept, `Poll::Pending` is returned and the
@@ -5,6 +5,8 @@
 #[doc(hidden)]
 pub mod datagram;
 
+pub(crate) mod ancillary;
+
 pub(crate) mod listener;
 
 pub(crate) mod socket;
@@ -37,3 +39,25 @@ pub type gid_t = u32;
 /// A type representing process and process group IDs.
 #[allow(non_camel_case_types)]
 pub type pid_t = i32;
+
+/// Binds `fd` with only the address family set, which asks the kernel to
+/// pick a unique abstract-namespace address (autobind).
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn autobind(fd: std::os::unix::io::RawFd) -> std::io::Result<()> {
+    let mut addr: libc::sockaddr_un = unsafe { std::mem::zeroed() };
+    addr.sun_family = libc::AF_UNIX as libc::sa_family_t;
+
+    // SAFETY: the address is a valid `sockaddr_un`; passing only the family's
+    // length is how autobind is requested.
+    let res = unsafe {
+        libc::bind(
+            fd,
+            &addr as *const libc::sockaddr_un as *const libc::sockaddr,
+            std::mem::size_of::<libc::sa_family_t>() as libc::socklen_t,
+        )
+    };
+    if res != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
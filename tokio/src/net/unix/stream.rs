@@ -15,11 +15,11 @@ use std::os::android::net::SocketAddrExt;
 use std::os::linux::net::SocketAddrExt;
 #[cfg(any(target_os = "linux", target_os = "android"))]
 use std::os::unix::ffi::OsStrExt;
-use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 use std::os::unix::net::{self, SocketAddr as StdSocketAddr};
 use std::path::Path;
 use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::task::{ready, Context, Poll};
 
 cfg_io_util! {
     use bytes::BufMut;
@@ -98,6 +98,25 @@ impl UnixStream {
         Ok(stream)
     }
 
+    /// Connects to an abstract-namespace socket named by `name`.
+    ///
+    /// `name` is the abstract name without the leading null byte, and unlike
+    /// the path accepted by [`connect`] it need not be valid UTF-8. The peer
+    /// is typically a listener created with
+    /// [`UnixListener::bind_abstract`](crate::net::UnixListener::bind_abstract).
+    ///
+    /// [`connect`]: UnixStream::connect
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "linux", target_os = "android"))))]
+    pub async fn connect_abstract<N>(name: N) -> io::Result<UnixStream>
+    where
+        N: AsRef<[u8]>,
+    {
+        let addr = StdSocketAddr::from_abstract_name(name.as_ref())?;
+        let stream = mio::net::UnixStream::connect_addr(&addr)?;
+        UnixStream::connect_mio(stream).await
+    }
+
     /// Waits for any of the requested ready states.
     ///
     /// This function is usually paired with `try_read()` or `try_write()`. It
@@ -940,6 +959,74 @@ impl UnixStream {
         ucred::get_peer_cred(self)
     }
 
+    /// Sends data along with file descriptors as `SCM_RIGHTS` ancillary data.
+    ///
+    /// The descriptors are duplicated into the receiving process, where they
+    /// arrive alongside the bytes read by [`recv_with_fds`]. At most 253
+    /// descriptors can be sent per call, matching the kernel's per-message
+    /// limit. This is the standard mechanism used by privilege-separated
+    /// daemons to hand sockets and files across process boundaries.
+    ///
+    /// Returns the number of bytes of `buf` that were sent. Note that, as
+    /// with any stream write, not all of `buf` may be accepted; the
+    /// descriptors are attached to the first byte and are sent as long as at
+    /// least one byte was.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than 253 descriptors are passed.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, then it is guaranteed that nothing was sent.
+    ///
+    /// [`recv_with_fds`]: UnixStream::recv_with_fds
+    pub async fn send_with_fds(&self, buf: &[u8], fds: &[BorrowedFd<'_>]) -> io::Result<usize> {
+        assert!(
+            fds.len() <= crate::net::unix::ancillary::MAX_FDS,
+            "at most 253 file descriptors can be sent per message"
+        );
+        self.io
+            .registration()
+            .async_io(Interest::WRITABLE, || {
+                crate::net::unix::ancillary::send_with_fds(self.as_raw_fd(), buf, fds)
+            })
+            .await
+    }
+
+    /// Receives data along with any file descriptors sent as `SCM_RIGHTS`
+    /// ancillary data.
+    ///
+    /// Bytes are read into `buf` and received descriptors are appended to
+    /// `fds` as owned descriptors, so they are closed when dropped. Where the
+    /// platform supports it, descriptors are received with close-on-exec
+    /// already set.
+    ///
+    /// Returns the number of bytes read. See [`send_with_fds`] for the
+    /// sending side.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, then it is guaranteed that nothing was received.
+    ///
+    /// [`send_with_fds`]: UnixStream::send_with_fds
+    pub async fn recv_with_fds(
+        &self,
+        buf: &mut [u8],
+        fds: &mut Vec<OwnedFd>,
+    ) -> io::Result<usize> {
+        self.io
+            .registration()
+            .async_io(Interest::READABLE, || {
+                crate::net::unix::ancillary::recv_with_fds(self.as_raw_fd(), buf, fds)
+            })
+            .await
+    }
+
     /// Returns the value of the `SO_ERROR` option.
     pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         self.io.take_error()
@@ -1004,6 +1091,18 @@ impl AsyncRead for UnixStream {
     ) -> Poll<io::Result<()>> {
         self.poll_read_priv(cx, buf)
     }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [ReadBuf<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.poll_read_vectored_priv(cx, bufs)
+    }
+
+    fn is_read_vectored(&self) -> bool {
+        true
+    }
 }
 
 impl AsyncWrite for UnixStream {
@@ -1037,6 +1136,41 @@ impl AsyncWrite for UnixStream {
     }
 }
 
+cfg_io_util! {
+    // As for `TcpStream`, all I/O goes through `&self` readiness operations
+    // on the shared socket.
+    impl crate::io::ConcurrentlySplittable for UnixStream {
+        fn poll_read_ref(
+            &self,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            self.poll_read_priv(cx, buf)
+        }
+
+        fn poll_write_ref(&self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+            self.poll_write_priv(cx, buf)
+        }
+
+        fn poll_write_vectored_ref(
+            &self,
+            cx: &mut Context<'_>,
+            bufs: &[io::IoSlice<'_>],
+        ) -> Poll<io::Result<usize>> {
+            self.poll_write_vectored_priv(cx, bufs)
+        }
+
+        fn poll_flush_ref(&self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown_ref(&self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.shutdown_std(std::net::Shutdown::Write)?;
+            Poll::Ready(Ok(()))
+        }
+    }
+}
+
 impl UnixStream {
     // == Poll IO functions that takes `&self` ==
     //
@@ -1053,6 +1187,25 @@ impl UnixStream {
         unsafe { self.io.poll_read(cx, buf) }
     }
 
+    pub(crate) fn poll_read_vectored_priv(
+        &self,
+        cx: &mut Context<'_>,
+        bufs: &mut [ReadBuf<'_>],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            let ev = ready!(self.io.registration().poll_read_ready(cx))?;
+
+            match crate::io::read_vectored_into(bufs, |slices| (&*self.io).read_vectored(slices))
+            {
+                Ok(n) => return Poll::Ready(Ok(n)),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    self.io.registration().clear_readiness(ev);
+                }
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+    }
+
     pub(crate) fn poll_write_priv(
         &self,
         cx: &mut Context<'_>,
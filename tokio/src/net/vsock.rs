@@ -0,0 +1,503 @@
+//! Virtio socket (`AF_VSOCK`) types for host↔guest communication.
+
+use crate::io::{AsyncRead, AsyncWrite, Interest, PollEvented, ReadBuf};
+
+use mio::unix::SourceFd;
+use std::fmt;
+use std::io;
+use std::mem;
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A vsock address, made up of a context ID identifying the virtual machine
+/// and a port.
+///
+/// Context IDs play the role IP addresses do for TCP; the well-known ones are
+/// available as associated constants.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct VsockAddr {
+    cid: u32,
+    port: u32,
+}
+
+impl VsockAddr {
+    /// Wildcard context ID, used to bind to any CID.
+    pub const CID_ANY: u32 = u32::MAX;
+
+    /// The context ID of the hypervisor itself.
+    pub const CID_HYPERVISOR: u32 = 0;
+
+    /// Loopback context ID, addressing the local machine.
+    pub const CID_LOCAL: u32 = 1;
+
+    /// The context ID of the host, as seen from a guest.
+    pub const CID_HOST: u32 = 2;
+
+    /// Creates an address from a context ID and a port.
+    pub fn new(cid: u32, port: u32) -> VsockAddr {
+        VsockAddr { cid, port }
+    }
+
+    /// Returns the context ID.
+    pub fn cid(&self) -> u32 {
+        self.cid
+    }
+
+    /// Returns the port.
+    pub fn port(&self) -> u32 {
+        self.port
+    }
+
+    fn to_raw(self) -> libc::sockaddr_vm {
+        let mut addr: libc::sockaddr_vm = unsafe { mem::zeroed() };
+        addr.svm_family = libc::AF_VSOCK as libc::sa_family_t;
+        addr.svm_cid = self.cid;
+        addr.svm_port = self.port;
+        #[cfg(target_os = "macos")]
+        {
+            addr.svm_len = mem::size_of::<libc::sockaddr_vm>() as u8;
+        }
+        addr
+    }
+
+    fn from_raw(raw: &libc::sockaddr_vm) -> VsockAddr {
+        VsockAddr::new(raw.svm_cid, raw.svm_port)
+    }
+}
+
+impl fmt::Display for VsockAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.cid, self.port)
+    }
+}
+
+/// An owned `AF_VSOCK` descriptor registered with mio through `SourceFd`.
+#[derive(Debug)]
+struct VsockIo {
+    fd: OwnedFd,
+}
+
+impl VsockIo {
+    fn new_socket() -> io::Result<VsockIo> {
+        #[cfg(not(target_os = "macos"))]
+        let fd = {
+            // SAFETY: `socket` creates a new descriptor we then own.
+            let fd = unsafe {
+                libc::socket(
+                    libc::AF_VSOCK,
+                    libc::SOCK_STREAM | libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC,
+                    0,
+                )
+            };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            unsafe { OwnedFd::from_raw_fd(fd) }
+        };
+
+        #[cfg(target_os = "macos")]
+        let fd = {
+            // SAFETY: `socket` creates a new descriptor we then own.
+            let raw = unsafe { libc::socket(libc::AF_VSOCK, libc::SOCK_STREAM, 0) };
+            if raw < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let fd = unsafe { OwnedFd::from_raw_fd(raw) };
+            set_nonblocking_cloexec(raw)?;
+            fd
+        };
+
+        Ok(VsockIo { fd })
+    }
+
+    fn local_addr(&self) -> io::Result<VsockAddr> {
+        let mut raw: libc::sockaddr_vm = unsafe { mem::zeroed() };
+        let mut len = mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t;
+        // SAFETY: the pointer and length describe a valid, writable buffer.
+        let res = unsafe {
+            libc::getsockname(
+                self.fd.as_raw_fd(),
+                &mut raw as *mut libc::sockaddr_vm as *mut libc::sockaddr,
+                &mut len,
+            )
+        };
+        if res != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(VsockAddr::from_raw(&raw))
+    }
+
+    fn peer_addr(&self) -> io::Result<VsockAddr> {
+        let mut raw: libc::sockaddr_vm = unsafe { mem::zeroed() };
+        let mut len = mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t;
+        // SAFETY: the pointer and length describe a valid, writable buffer.
+        let res = unsafe {
+            libc::getpeername(
+                self.fd.as_raw_fd(),
+                &mut raw as *mut libc::sockaddr_vm as *mut libc::sockaddr,
+                &mut len,
+            )
+        };
+        if res != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(VsockAddr::from_raw(&raw))
+    }
+
+    fn take_error(&self) -> io::Result<Option<io::Error>> {
+        let mut err: libc::c_int = 0;
+        let mut len = mem::size_of::<libc::c_int>() as libc::socklen_t;
+        // SAFETY: the pointer and length describe a valid `c_int`.
+        let res = unsafe {
+            libc::getsockopt(
+                self.fd.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_ERROR,
+                &mut err as *mut libc::c_int as *mut libc::c_void,
+                &mut len,
+            )
+        };
+        if res != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if err == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(io::Error::from_raw_os_error(err)))
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn set_nonblocking_cloexec(fd: RawFd) -> io::Result<()> {
+    // SAFETY: `fd` is a valid, owned descriptor.
+    unsafe {
+        if libc::fcntl(fd, libc::F_SETFL, libc::O_NONBLOCK) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+impl mio::event::Source for VsockIo {
+    fn register(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &mio::Registry) -> io::Result<()> {
+        SourceFd(&self.fd.as_raw_fd()).deregister(registry)
+    }
+}
+
+impl io::Read for &VsockIo {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // SAFETY: the buffer outlives the call.
+        let res = unsafe {
+            libc::read(
+                self.fd.as_raw_fd(),
+                buf.as_mut_ptr() as *mut libc::c_void,
+                buf.len(),
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+}
+
+impl io::Write for &VsockIo {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // SAFETY: the buffer outlives the call.
+        let res = unsafe {
+            libc::write(
+                self.fd.as_raw_fd(),
+                buf.as_ptr() as *const libc::c_void,
+                buf.len(),
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+
+    fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+        // SAFETY: the slices outlive the call; `IoSlice` is ABI-compatible
+        // with `iovec`.
+        let res = unsafe {
+            libc::writev(
+                self.fd.as_raw_fd(),
+                bufs.as_ptr().cast(),
+                bufs.len().min(libc::c_int::MAX as usize) as libc::c_int,
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A vsock stream between the local machine and a virtual machine (or the
+/// reverse), created by connecting or accepting.
+///
+/// Reading and writing go through the [`AsyncRead`] and [`AsyncWrite`]
+/// traits, exactly as for [`TcpStream`](crate::net::TcpStream).
+#[derive(Debug)]
+pub struct VsockStream {
+    io: PollEvented<VsockIo>,
+}
+
+impl VsockStream {
+    /// Opens a vsock connection to the given address.
+    ///
+    /// From a guest, use [`VsockAddr::CID_HOST`] to reach the host; from the
+    /// host, use the CID assigned to the target virtual machine.
+    pub async fn connect(addr: VsockAddr) -> io::Result<VsockStream> {
+        let io = VsockIo::new_socket()?;
+        let raw = addr.to_raw();
+
+        // SAFETY: the address outlives the call.
+        let res = unsafe {
+            libc::connect(
+                io.fd.as_raw_fd(),
+                &raw as *const libc::sockaddr_vm as *const libc::sockaddr,
+                mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t,
+            )
+        };
+        if res != 0 {
+            let err = io::Error::last_os_error();
+            if err.raw_os_error() != Some(libc::EINPROGRESS) {
+                return Err(err);
+            }
+        }
+
+        let io = PollEvented::new(io)?;
+        let stream = VsockStream { io };
+
+        // The connection is established once the socket becomes writable.
+        std::future::poll_fn(|cx| stream.io.registration().poll_write_ready(cx)).await?;
+
+        if let Some(e) = stream.io.take_error()? {
+            return Err(e);
+        }
+
+        Ok(stream)
+    }
+
+    fn new(io: VsockIo) -> io::Result<VsockStream> {
+        let io = PollEvented::new(io)?;
+        Ok(VsockStream { io })
+    }
+
+    /// Returns the local address this stream is bound to.
+    pub fn local_addr(&self) -> io::Result<VsockAddr> {
+        self.io.local_addr()
+    }
+
+    /// Returns the address of the remote peer.
+    pub fn peer_addr(&self) -> io::Result<VsockAddr> {
+        self.io.peer_addr()
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.io.take_error()
+    }
+}
+
+impl AsyncRead for VsockStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        // Safety: `VsockIo::read` only writes into the buffer.
+        unsafe { self.io.poll_read(cx, buf) }
+    }
+}
+
+impl AsyncWrite for VsockStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.io.poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.io.poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // vsock flush is a no-op
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // SAFETY: shutting down a valid descriptor has no memory effects.
+        let res = unsafe { libc::shutdown(self.io.fd.as_raw_fd(), libc::SHUT_WR) };
+        if res != 0 {
+            return Poll::Ready(Err(io::Error::last_os_error()));
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsRawFd for VsockStream {
+    fn as_raw_fd(&self) -> RawFd {
+        self.io.fd.as_raw_fd()
+    }
+}
+
+impl AsFd for VsockStream {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
+
+/// A vsock socket listening for incoming connections.
+#[derive(Debug)]
+pub struct VsockListener {
+    io: PollEvented<VsockIo>,
+}
+
+impl VsockListener {
+    /// Creates a new listener bound to the given address.
+    ///
+    /// Bind with [`VsockAddr::CID_ANY`] to accept connections from any
+    /// context.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a runtime with
+    /// IO enabled.
+    #[track_caller]
+    pub fn bind(addr: VsockAddr) -> io::Result<VsockListener> {
+        let io = VsockIo::new_socket()?;
+        let raw = addr.to_raw();
+
+        // SAFETY: the address outlives the call.
+        let res = unsafe {
+            libc::bind(
+                io.fd.as_raw_fd(),
+                &raw as *const libc::sockaddr_vm as *const libc::sockaddr,
+                mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t,
+            )
+        };
+        if res != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: listening on a bound descriptor has no memory effects.
+        let res = unsafe { libc::listen(io.fd.as_raw_fd(), 1024) };
+        if res != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let io = PollEvented::new(io)?;
+        Ok(VsockListener { io })
+    }
+
+    /// Accepts a new incoming connection.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If it is used as the event in a
+    /// [`tokio::select!`](crate::select) statement and some other branch
+    /// completes first, it is guaranteed that no connections were accepted.
+    pub async fn accept(&self) -> io::Result<(VsockStream, VsockAddr)> {
+        let (io, addr) = self
+            .io
+            .registration()
+            .async_io(Interest::READABLE, || self.accept_inner())
+            .await?;
+
+        Ok((VsockStream::new(io)?, addr))
+    }
+
+    fn accept_inner(&self) -> io::Result<(VsockIo, VsockAddr)> {
+        let mut raw: libc::sockaddr_vm = unsafe { mem::zeroed() };
+        let mut len = mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t;
+
+        #[cfg(not(target_os = "macos"))]
+        // SAFETY: the address buffer outlives the call and we own the
+        // returned descriptor.
+        let fd = unsafe {
+            libc::accept4(
+                self.io.fd.as_raw_fd(),
+                &mut raw as *mut libc::sockaddr_vm as *mut libc::sockaddr,
+                &mut len,
+                libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC,
+            )
+        };
+        #[cfg(target_os = "macos")]
+        // SAFETY: as above.
+        let fd = unsafe {
+            libc::accept(
+                self.io.fd.as_raw_fd(),
+                &mut raw as *mut libc::sockaddr_vm as *mut libc::sockaddr,
+                &mut len,
+            )
+        };
+
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+        #[cfg(target_os = "macos")]
+        set_nonblocking_cloexec(fd.as_raw_fd())?;
+
+        Ok((VsockIo { fd }, VsockAddr::from_raw(&raw)))
+    }
+
+    /// Returns the local address this listener is bound to.
+    pub fn local_addr(&self) -> io::Result<VsockAddr> {
+        self.io.local_addr()
+    }
+}
+
+impl AsRawFd for VsockListener {
+    fn as_raw_fd(&self) -> RawFd {
+        self.io.fd.as_raw_fd()
+    }
+}
+
+impl AsFd for VsockListener {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+}
@@ -2690,3 +2690,156 @@ unsafe fn named_pipe_info(handle: RawHandle) -> io::Result<PipeInfo> {
         max_instances,
     })
 }
+
+cfg_io_util! {
+    use crate::io::AsyncReadExt;
+    use bytes::{Bytes, BytesMut};
+    use std::future::poll_fn;
+
+    /// A named pipe end that [`MessageStream`] can wrap.
+    ///
+    /// This trait is sealed and implemented for [`NamedPipeServer`] and
+    /// [`NamedPipeClient`].
+    pub trait MessagePipe: AsyncRead + AsyncWrite + Unpin + sealed::Sealed {}
+
+    impl MessagePipe for NamedPipeServer {}
+    impl MessagePipe for NamedPipeClient {}
+
+    mod sealed {
+        pub trait Sealed {}
+        impl Sealed for super::NamedPipeServer {}
+        impl Sealed for super::NamedPipeClient {}
+    }
+
+    /// A wrapper around a message-mode named pipe that sends and receives
+    /// whole messages.
+    ///
+    /// In message mode the operating system delivers each write as a unit,
+    /// but reading a message larger than the supplied buffer hands back the
+    /// message in chunks, which callers then have to reassemble. This type
+    /// handles the reassembly internally and yields each complete message as
+    /// [`Bytes`].
+    ///
+    /// The pipe must have been created with [`PipeMode::Message`].
+    ///
+    /// Messages are transferred internally in chunks of 4 KiB. A message
+    /// whose length is an exact multiple of the chunk size can only be
+    /// delimited once the bytes following it arrive, so with such messages
+    /// the boundary to the next message is not preserved. Protocols with
+    /// messages that can reach this size should carry explicit lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::windows::named_pipe::{ClientOptions, MessageStream, PipeMode};
+    /// use std::error::Error;
+    ///
+    /// const PIPE_NAME: &str = r"\\.\pipe\tokio-named-pipe-message";
+    ///
+    /// # async fn dox() -> Result<(), Box<dyn Error>> {
+    /// let client = ClientOptions::new()
+    ///     .pipe_mode(PipeMode::Message)
+    ///     .open(PIPE_NAME)?;
+    ///
+    /// let mut stream = MessageStream::new(client);
+    /// stream.send(b"ping").await?;
+    ///
+    /// while let Some(message) = stream.recv().await? {
+    ///     println!("received {} byte message", message.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[derive(Debug)]
+    pub struct MessageStream<T> {
+        pipe: T,
+    }
+
+    /// The size of the chunks a message is transferred in, matching the
+    /// internal transfer buffer.
+    const MESSAGE_CHUNK: usize = 4 * 1024;
+
+    impl<T: MessagePipe> MessageStream<T> {
+        /// Creates a new message stream wrapping `pipe`.
+        ///
+        /// The pipe must have been opened with [`PipeMode::Message`];
+        /// wrapping a byte-mode pipe yields arbitrarily delimited chunks.
+        pub fn new(pipe: T) -> MessageStream<T> {
+            MessageStream { pipe }
+        }
+
+        /// Receives the next complete message.
+        ///
+        /// Returns `None` once the pipe has been closed by the peer. If the
+        /// pipe closes in the middle of a message, an error of kind
+        /// [`ErrorKind::UnexpectedEof`] is returned.
+        ///
+        /// # Cancel safety
+        ///
+        /// This method is not cancel safe. If it is dropped before
+        /// completing, part of a message may have been consumed from the
+        /// pipe.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+        pub async fn recv(&mut self) -> io::Result<Option<Bytes>> {
+            let mut message = BytesMut::new();
+            let mut chunk = [0u8; MESSAGE_CHUNK];
+
+            loop {
+                let n = self.pipe.read(&mut chunk).await?;
+
+                if n == 0 {
+                    if message.is_empty() {
+                        return Ok(None);
+                    }
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "pipe closed in the middle of a message",
+                    ));
+                }
+
+                message.extend_from_slice(&chunk[..n]);
+
+                // A short chunk marks the end of the message; a full one
+                // means the message continues.
+                if n < MESSAGE_CHUNK {
+                    return Ok(Some(message.freeze()));
+                }
+            }
+        }
+
+        /// Sends `message` as a single message.
+        ///
+        /// # Cancel safety
+        ///
+        /// This method is cancel safe. If it is dropped before completing,
+        /// the message was either sent in full or not at all.
+        pub async fn send(&mut self, message: &[u8]) -> io::Result<()> {
+            // A message-mode pipe delivers each write as one message, so the
+            // write must not be split across calls.
+            let n = poll_fn(|cx| Pin::new(&mut self.pipe).poll_write(cx, message)).await?;
+            if n != message.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "failed to send whole message",
+                ));
+            }
+            Ok(())
+        }
+
+        /// Returns a reference to the underlying pipe.
+        pub fn get_ref(&self) -> &T {
+            &self.pipe
+        }
+
+        /// Returns a mutable reference to the underlying pipe.
+        pub fn get_mut(&mut self) -> &mut T {
+            &mut self.pipe
+        }
+
+        /// Consumes the stream, returning the underlying pipe.
+        pub fn into_inner(self) -> T {
+            self.pipe
+        }
+    }
+}
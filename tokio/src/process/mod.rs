@@ -274,6 +274,10 @@ cfg_windows! {
 pub struct Command {
     std: StdCommand,
     kill_on_drop: bool,
+    #[cfg(unix)]
+    pgroup: Option<i32>,
+    #[cfg(unix)]
+    kill_process_group_on_drop: bool,
 }
 
 pub(crate) struct SpawnedChild {
@@ -789,6 +793,227 @@ impl Command {
     #[cfg_attr(docsrs, doc(cfg(unix)))]
     pub fn process_group(&mut self, pgroup: i32) -> &mut Command {
         self.std.process_group(pgroup);
+        self.pgroup = Some(pgroup);
+        self
+    }
+
+    /// Sets a resource limit for the child process.
+    ///
+    /// The limit is applied with `setrlimit(2)` between fork and exec, so
+    /// the program runs under it from its first instruction — without the
+    /// hand-written `pre_exec` closure this would otherwise require, which
+    /// is easy to get wrong in async-signal-safety terms.
+    ///
+    /// `soft` is the value the kernel enforces; `hard` is the ceiling the
+    /// child may raise its soft limit to. Passing a `soft` greater than
+    /// `hard`, or a `hard` above the current hard limit (without privilege),
+    /// causes the spawn to fail.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::process::{Command, Resource};
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// // Run the job with at most 64 open files and no core dumps.
+    /// let status = Command::new("job")
+    ///     .rlimit(Resource::Nofile, 64, 64)
+    ///     .rlimit(Resource::Core, 0, 0)
+    ///     .status()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn rlimit(&mut self, resource: Resource, soft: u64, hard: u64) -> &mut Command {
+        let resource = resource.as_raw();
+
+        // SAFETY: the closure only performs the async-signal-safe
+        // `setrlimit` call.
+        unsafe {
+            self.std.pre_exec(move || {
+                let lim = libc::rlimit {
+                    rlim_cur: soft as libc::rlim_t,
+                    rlim_max: hard as libc::rlim_t,
+                };
+                if libc::setrlimit(resource as _, &lim) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+
+        self
+    }
+
+    /// Places the child process in the given cgroup before exec.
+    ///
+    /// `path` is the cgroup directory (for example
+    /// `/sys/fs/cgroup/myjobs`); the child's pid is written to its
+    /// `cgroup.procs` file between fork and exec, so the program is
+    /// accounted to the cgroup from its first instruction. Writing the pid
+    /// from the parent instead would leave a window where early allocations
+    /// escape the cgroup's limits.
+    ///
+    /// The cgroup must already exist and the spawning process must have
+    /// permission to attach to it; otherwise the spawn fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::process::Command;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let status = Command::new("job")
+    ///     .cgroup("/sys/fs/cgroup/myjobs")?
+    ///     .status()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "android", target_os = "linux"))))]
+    pub fn cgroup(&mut self, path: impl AsRef<Path>) -> io::Result<&mut Command> {
+        use std::os::unix::ffi::OsStringExt;
+
+        let procs = path.as_ref().join("cgroup.procs");
+        let procs = std::ffi::CString::new(procs.into_os_string().into_vec())?;
+
+        // SAFETY: the closure only performs async-signal-safe calls
+        // (`open`, `write`, `close`) on memory allocated before the fork.
+        unsafe {
+            self.std.pre_exec(move || {
+                let fd = libc::open(procs.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+                if fd < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                // Writing "0" attaches the writing process itself.
+                let ret = libc::write(fd, b"0".as_ptr().cast(), 1);
+                let err = io::Error::last_os_error();
+                libc::close(fd);
+                if ret != 1 {
+                    return Err(err);
+                }
+                Ok(())
+            });
+        }
+
+        Ok(self)
+    }
+
+    /// Maps file descriptors of this process to specific descriptor numbers
+    /// in the child, beyond the three stdio handles.
+    ///
+    /// Each `(parent_fd, child_fd)` pair makes the descriptor currently open
+    /// as `parent_fd` in this process available to the child as `child_fd`.
+    /// This is how listening sockets are handed to worker processes without
+    /// resorting to an unsafe [`pre_exec`] block.
+    ///
+    /// The mappings are applied between fork and exec using only
+    /// async-signal-safe calls: every parent descriptor is first duplicated
+    /// to a scratch number above all targets, so mappings may swap or shift
+    /// descriptors freely without clobbering each other. Close-on-exec is
+    /// handled for both directions — the duplicated descriptor given to the
+    /// child never has `FD_CLOEXEC` set (even if the parent's did), and the
+    /// scratch duplicates always do, so nothing leaks.
+    ///
+    /// The parent descriptors must still be open when the command is
+    /// spawned. An error is returned if the same `child_fd` is given twice.
+    ///
+    /// [`pre_exec`]: std::os::unix::process::CommandExt::pre_exec
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::os::fd::AsRawFd;
+    /// use tokio::net::TcpListener;
+    /// use tokio::process::Command;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let listener = TcpListener::bind("127.0.0.1:8080").await?;
+    ///
+    /// // The worker accepts connections from fd 3.
+    /// let child = Command::new("worker")
+    ///     .fd_mappings(&[(listener.as_raw_fd(), 3)])?
+    ///     .spawn()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn fd_mappings(
+        &mut self,
+        mappings: &[(std::os::fd::RawFd, std::os::fd::RawFd)],
+    ) -> io::Result<&mut Command> {
+        let mut child_fds: Vec<_> = mappings.iter().map(|&(_, child_fd)| child_fd).collect();
+        child_fds.sort_unstable();
+        if child_fds.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "duplicate child file descriptor in fd mappings",
+            ));
+        }
+
+        let mappings = mappings.to_vec();
+        // Scratch descriptors are allocated above every target so the dup2
+        // pass cannot overwrite a mapping that has not been staged yet.
+        let min_scratch = child_fds.last().map_or(0, |max| max + 1);
+        let mut scratch = vec![0; mappings.len()];
+
+        // SAFETY: the closure only calls async-signal-safe functions
+        // (`fcntl` and `dup2`) and mutates memory allocated before the fork.
+        unsafe {
+            self.std.pre_exec(move || {
+                for (slot, &(parent_fd, _)) in scratch.iter_mut().zip(&mappings) {
+                    let fd = libc::fcntl(parent_fd, libc::F_DUPFD_CLOEXEC, min_scratch);
+                    if fd < 0 {
+                        return Err(io::Error::last_os_error());
+                    }
+                    *slot = fd;
+                }
+                for (&fd, &(_, child_fd)) in scratch.iter().zip(&mappings) {
+                    // `dup2` clears `FD_CLOEXEC` on the target, so the
+                    // mapped descriptor survives the exec.
+                    if libc::dup2(fd, child_fd) < 0 {
+                        return Err(io::Error::last_os_error());
+                    }
+                }
+                Ok(())
+            });
+        }
+
+        Ok(self)
+    }
+
+    /// Controls whether the child's whole process group is killed when the
+    /// [`Child`] handle is dropped.
+    ///
+    /// [`kill_on_drop`] only signals the direct child, so any grandchildren
+    /// it has spawned keep running. With this option enabled, the child is
+    /// placed in its own process group at spawn (as if by
+    /// [`process_group`]`(0)`, unless a group was configured explicitly) and
+    /// dropping the [`Child`] sends `SIGKILL` to the entire group, taking
+    /// the descendants down with it.
+    ///
+    /// Enabling this option implies [`kill_on_drop`]`(true)`. Note that a
+    /// descendant that has moved itself to a different process group — a
+    /// daemon, or anything calling `setsid` — escapes the group and will
+    /// not be signalled.
+    ///
+    /// The caveats on [`kill_on_drop`] about zombie processes apply here as
+    /// well; prefer [`kill_tree`] where possible.
+    ///
+    /// [`kill_on_drop`]: Command::kill_on_drop
+    /// [`process_group`]: Command::process_group
+    /// [`kill_tree`]: Child::kill_tree
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn kill_process_group_on_drop(&mut self, kill: bool) -> &mut Command {
+        self.kill_process_group_on_drop = kill;
+        if kill {
+            self.kill_on_drop = true;
+        }
         self
     }
 
@@ -861,6 +1086,9 @@ impl Command {
     /// running on the system).
     #[inline]
     pub fn spawn(&mut self) -> io::Result<Child> {
+        #[cfg(unix)]
+        self.apply_group_default();
+
         // On two lines to circumvent a mutable borrow check failure.
         let child = self.std.spawn()?;
         self.build_child(child)
@@ -935,11 +1163,26 @@ impl Command {
         &mut self,
         with: impl FnOnce(&mut StdCommand) -> io::Result<StdChild>,
     ) -> io::Result<Child> {
+        #[cfg(unix)]
+        self.apply_group_default();
+
         // On two lines to circumvent a mutable borrow check failure.
         let child = with(&mut self.std)?;
         self.build_child(child)
     }
 
+    /// Applies the process group implied by
+    /// [`kill_process_group_on_drop`] when none was configured explicitly.
+    ///
+    /// [`kill_process_group_on_drop`]: Command::kill_process_group_on_drop
+    #[cfg(unix)]
+    fn apply_group_default(&mut self) {
+        if self.kill_process_group_on_drop && self.pgroup.is_none() {
+            self.std.process_group(0);
+            self.pgroup = Some(0);
+        }
+    }
+
     /// Small indirection for the spawn implementations.
     ///
     /// This is introduced for [`Self::spawn`] and [`Self::spawn_with`] to use:
@@ -948,6 +1191,17 @@ impl Command {
     /// monomorphization bloat by taking in an already-spawned child process
     /// instead of a command and custom spawn function.
     fn build_child(&self, child: StdChild) -> io::Result<Child> {
+        // A configured group of 0 means the child leads its own group, whose
+        // id is the child's pid.
+        #[cfg(unix)]
+        let pgid = self.pgroup.map(|pgroup| {
+            if pgroup == 0 {
+                child.id() as i32
+            } else {
+                pgroup
+            }
+        });
+
         let spawned_child = imp::build_child(child)?;
 
         Ok(Child {
@@ -955,6 +1209,10 @@ impl Command {
                 inner: spawned_child.child,
                 kill_on_drop: self.kill_on_drop,
             }),
+            #[cfg(unix)]
+            pgid,
+            #[cfg(unix)]
+            kill_process_group_on_drop: self.kill_process_group_on_drop,
             stdin: spawned_child.stdin.map(|inner| ChildStdin { inner }),
             stdout: spawned_child.stdout.map(|inner| ChildStdout { inner }),
             stderr: spawned_child.stderr.map(|inner| ChildStderr { inner }),
@@ -1071,6 +1329,84 @@ impl Command {
         async { child?.wait_with_output().await }
     }
 
+    /// Executes the command as a child process with a deadline, collecting
+    /// all of its output.
+    ///
+    /// Like [`output`], but if the child has not exited within `timeout` it
+    /// is killed — along with its process group, if one was configured — and
+    /// whatever it wrote to stdout and stderr before dying is still
+    /// returned. A timeout is visible in the returned [`Output`] as the
+    /// `SIGKILL` termination reported by its `status` field.
+    ///
+    /// This packages the supervisor pattern of racing [`wait`] against a
+    /// timer while draining the output pipes; doing so by hand is easy to
+    /// get wrong in ways that deadlock on a full pipe buffer.
+    ///
+    /// [`output`]: Command::output
+    /// [`wait`]: Child::wait
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use tokio::process::Command;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let output = Command::new("flaky-build")
+    ///     .output_timeout(Duration::from_secs(60))
+    ///     .await?;
+    ///
+    /// if !output.status.success() {
+    ///     // Partial logs are available even if the build was killed.
+    ///     eprintln!("{}", String::from_utf8_lossy(&output.stdout));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn output_timeout(
+        &mut self,
+        timeout: std::time::Duration,
+    ) -> impl Future<Output = io::Result<Output>> {
+        self.std.stdout(Stdio::piped());
+        self.std.stderr(Stdio::piped());
+
+        let child = self.spawn();
+
+        async move {
+            let mut child = child?;
+            child.stdin.take();
+
+            let mut stdout_pipe = child.stdout.take();
+            let mut stderr_pipe = child.stderr.take();
+
+            let stdout_fut = read_pipe_to_end(&mut stdout_pipe);
+            let stderr_fut = read_pipe_to_end(&mut stderr_pipe);
+
+            let wait_fut = async {
+                match child.wait_timeout(timeout).await? {
+                    Some(status) => Ok(status),
+                    // The child was killed on expiry; `wait` returns the
+                    // already-collected status.
+                    None => child.wait().await,
+                }
+            };
+
+            let (status, stdout, stderr) =
+                crate::future::try_join3(wait_fut, stdout_fut, stderr_fut).await?;
+
+            drop(stdout_pipe);
+            drop(stderr_pipe);
+
+            Ok(Output {
+                status,
+                stdout,
+                stderr,
+            })
+        }
+    }
+
     /// Returns the boolean value that was previously set by [`Command::kill_on_drop`].
     ///
     /// Note that if you have not previously called [`Command::kill_on_drop`], the
@@ -1097,6 +1433,10 @@ impl From<StdCommand> for Command {
         Command {
             std,
             kill_on_drop: false,
+            #[cfg(unix)]
+            pgroup: None,
+            #[cfg(unix)]
+            kill_process_group_on_drop: false,
         }
     }
 }
@@ -1176,6 +1516,14 @@ enum FusedChild {
 pub struct Child {
     child: FusedChild,
 
+    /// The group the child was spawned into, if [`Command::process_group`]
+    /// was used (directly or implied).
+    #[cfg(unix)]
+    pgid: Option<i32>,
+
+    #[cfg(unix)]
+    kill_process_group_on_drop: bool,
+
     /// The handle for writing to the child's standard input (stdin), if it has
     /// been captured. To avoid partially moving the `child` and thus blocking
     /// yourself from calling functions on `child` while using `stdin`, you might
@@ -1329,6 +1677,197 @@ impl Child {
         Ok(())
     }
 
+    /// Attempts to force the child's whole process group to exit, but does
+    /// not wait for the request to take effect.
+    ///
+    /// See [`kill_tree`] for the semantics; this is its non-waiting
+    /// equivalent, mirroring [`start_kill`].
+    ///
+    /// [`kill_tree`]: Child::kill_tree
+    /// [`start_kill`]: Child::start_kill
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn start_kill_tree(&mut self) -> io::Result<()> {
+        let pgid = self.pgid.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "child was not spawned in its own process group; \
+                 use Command::process_group or kill_process_group_on_drop",
+            )
+        })?;
+
+        match &mut self.child {
+            FusedChild::Child(child) => {
+                // Signal the whole group first; the regular kill path then
+                // takes care of the direct child and disarms kill-on-drop.
+                let ret = unsafe { libc::kill(-pgid, libc::SIGKILL) };
+                // The group being gone already is not an error.
+                if ret != 0 {
+                    let err = io::Error::last_os_error();
+                    if err.raw_os_error() != Some(libc::ESRCH) {
+                        return Err(err);
+                    }
+                }
+                child.kill()
+            }
+            FusedChild::Done(_) => Ok(()),
+        }
+    }
+
+    /// Forces the child and all its descendants to exit.
+    ///
+    /// Sends `SIGKILL` to the child's process group, then waits for the
+    /// direct child to be reaped, mirroring [`kill`]. Because the signal
+    /// goes to the whole group, grandchildren spawned by the child are
+    /// killed as well — the case [`kill`] does not cover.
+    ///
+    /// The child must have been spawned in its own process group, either
+    /// with [`Command::process_group`] or implied by
+    /// [`Command::kill_process_group_on_drop`]; otherwise an error of kind
+    /// [`InvalidInput`] is returned. Descendants that have moved themselves
+    /// to another process group are not signalled.
+    ///
+    /// [`kill`]: Child::kill
+    /// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::process::Command;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut child = Command::new("sh")
+    ///         .args(["-c", "sleep 100 & wait"])
+    ///         .process_group(0)
+    ///         .spawn()
+    ///         .unwrap();
+    ///
+    ///     // Kills both `sh` and the `sleep` it spawned.
+    ///     child.kill_tree().await.expect("kill_tree failed");
+    /// }
+    /// ```
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub async fn kill_tree(&mut self) -> io::Result<()> {
+        self.start_kill_tree()?;
+        self.wait().await?;
+        Ok(())
+    }
+
+    /// Waits for the child to exit, killing it if it takes longer than
+    /// `timeout`.
+    ///
+    /// If the child exits within the timeout, its exit status is returned as
+    /// `Some`. On expiry the child is sent `SIGKILL` — along with its whole
+    /// process group, if it was spawned into one — reaped, and `None` is
+    /// returned.
+    ///
+    /// Either way, the child has been waited on when this returns `Ok`, so
+    /// no zombie process is left behind.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use tokio::process::Command;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let mut child = Command::new("sleep").arg("100").spawn()?;
+    ///
+    /// match child.wait_timeout(Duration::from_secs(1)).await? {
+    ///     Some(status) => println!("exited with {status}"),
+    ///     None => println!("timed out and was killed"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub async fn wait_timeout(
+        &mut self,
+        timeout: std::time::Duration,
+    ) -> io::Result<Option<ExitStatus>> {
+        // `wait` is cancel safe, so timing it out cannot lose the child.
+        match crate::time::timeout(timeout, self.wait()).await {
+            Ok(res) => res.map(Some),
+            Err(_) => {
+                #[cfg(unix)]
+                if self.pgid.is_some() {
+                    self.start_kill_tree()?;
+                } else {
+                    self.start_kill()?;
+                }
+                #[cfg(not(unix))]
+                self.start_kill()?;
+
+                self.wait().await?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns a merged, line-oriented stream of the child's stdout and
+    /// stderr.
+    ///
+    /// Lines are yielded in arrival order across both pipes, each tagged
+    /// with its origin as [`OutputLine::Stdout`] or [`OutputLine::Stderr`].
+    /// Interleaving two separate [`lines`] readers loses that ordering and
+    /// requires a manual select loop; this does the merging internally.
+    ///
+    /// Both stdout and stderr must have been configured with
+    /// [`Stdio::piped`] and not yet taken from the child; otherwise an error
+    /// of kind [`InvalidInput`] is returned. The pipes are moved out of the
+    /// child, so this can be called at most once.
+    ///
+    /// [`lines`]: crate::io::AsyncBufReadExt::lines
+    /// [`Stdio::piped`]: std::process::Stdio::piped
+    /// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::process::Stdio;
+    /// use tokio::process::{Command, OutputLine};
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let mut child = Command::new("make")
+    ///     .stdout(Stdio::piped())
+    ///     .stderr(Stdio::piped())
+    ///     .spawn()?;
+    ///
+    /// let mut output = child.merged_output_stream()?;
+    /// while let Some(line) = output.next_line().await? {
+    ///     match line {
+    ///         OutputLine::Stdout(line) => println!("out: {line}"),
+    ///         OutputLine::Stderr(line) => println!("err: {line}"),
+    ///     }
+    /// }
+    ///
+    /// child.wait().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "io-util")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+    pub fn merged_output_stream(&mut self) -> io::Result<MergedOutput> {
+        let missing = |pipe| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("child {pipe} was not piped or was already taken"),
+            )
+        };
+        let stdout = self.stdout.take().ok_or_else(|| missing("stdout"))?;
+        let stderr = self.stderr.take().ok_or_else(|| missing("stderr"))?;
+
+        Ok(MergedOutput {
+            stdout: Some(crate::io::BufReader::new(stdout)),
+            stderr: Some(crate::io::BufReader::new(stderr)),
+            stdout_buf: Vec::new(),
+            stderr_buf: Vec::new(),
+        })
+    }
+
     /// Waits for the child to exit completely, returning the status that it
     /// exited with. This function will continue to have the same return value
     /// after it has been called at least once.
@@ -1446,21 +1985,92 @@ impl Child {
     pub async fn wait_with_output(mut self) -> io::Result<Output> {
         use crate::future::try_join3;
 
-        async fn read_to_end<A: AsyncRead + Unpin>(io: &mut Option<A>) -> io::Result<Vec<u8>> {
-            let mut vec = Vec::new();
-            if let Some(io) = io.as_mut() {
-                crate::io::util::read_to_end(io, &mut vec).await?;
-            }
-            Ok(vec)
+        let mut stdout_pipe = self.stdout.take();
+        let mut stderr_pipe = self.stderr.take();
+
+        let stdout_fut = read_pipe_to_end(&mut stdout_pipe);
+        let stderr_fut = read_pipe_to_end(&mut stderr_pipe);
+
+        let (status, stdout, stderr) = try_join3(self.wait(), stdout_fut, stderr_fut).await?;
+
+        // Drop happens after `try_join` due to <https://github.com/tokio-rs/tokio/issues/4309>
+        drop(stdout_pipe);
+        drop(stderr_pipe);
+
+        Ok(Output {
+            status,
+            stdout,
+            stderr,
+        })
+    }
+
+    /// Writes `input` to the child's stdin while collecting its output, then
+    /// waits for it to exit.
+    ///
+    /// The write and the reads run concurrently, avoiding the pipe-buffer
+    /// deadlock that occurs when the input is written to completion before
+    /// any output is read: the child blocks writing to a full stdout pipe
+    /// while the parent blocks writing to a full stdin pipe. Stdin is closed
+    /// once `input` has been written, so the child sees end-of-input.
+    ///
+    /// A `BrokenPipe` error from the write is ignored, as the child closing
+    /// its stdin early (or exiting) is not a failure of this call.
+    ///
+    /// The child's stdin must have been created with `Stdio::piped`; stdout
+    /// and stderr are collected if piped and returned empty otherwise, as
+    /// with [`wait_with_output`].
+    ///
+    /// [`wait_with_output`]: Child::wait_with_output
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::process::Stdio;
+    /// use tokio::process::Command;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let child = Command::new("rev")
+    ///     .stdin(Stdio::piped())
+    ///     .stdout(Stdio::piped())
+    ///     .spawn()?;
+    ///
+    /// let output = child.communicate(b"hello").await?;
+    /// assert_eq!(output.stdout, b"olleh");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "io-util")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+    pub async fn communicate(mut self, input: impl AsRef<[u8]>) -> io::Result<Output> {
+        use crate::future::try_join3;
+        use crate::io::AsyncWriteExt;
+
+        let stdin = self.stdin.take();
+        if stdin.is_none() && !input.as_ref().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "child stdin is not piped",
+            ));
         }
 
         let mut stdout_pipe = self.stdout.take();
         let mut stderr_pipe = self.stderr.take();
 
-        let stdout_fut = read_to_end(&mut stdout_pipe);
-        let stderr_fut = read_to_end(&mut stderr_pipe);
+        let stdout_fut = read_pipe_to_end(&mut stdout_pipe);
+        let stderr_fut = read_pipe_to_end(&mut stderr_pipe);
 
-        let (status, stdout, stderr) = try_join3(self.wait(), stdout_fut, stderr_fut).await?;
+        let wait_fut = async {
+            if let Some(mut stdin) = stdin {
+                match stdin.write_all(input.as_ref()).await {
+                    Err(ref e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+                    res => res?,
+                }
+                // Dropping the handle closes the pipe, signalling EOF.
+            }
+            self.wait().await
+        };
+
+        let (status, stdout, stderr) = try_join3(wait_fut, stdout_fut, stderr_fut).await?;
 
         // Drop happens after `try_join` due to <https://github.com/tokio-rs/tokio/issues/4309>
         drop(stdout_pipe);
@@ -1474,6 +2084,220 @@ impl Child {
     }
 }
 
+/// A process resource whose limits can be set with [`Command::rlimit`].
+///
+/// Each variant names the corresponding `RLIMIT_*` constant from
+/// `setrlimit(2)`.
+#[cfg(unix)]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Resource {
+    /// CPU time, in seconds (`RLIMIT_CPU`).
+    Cpu,
+    /// Largest file the process may create, in bytes (`RLIMIT_FSIZE`).
+    FileSize,
+    /// Size of the data segment, in bytes (`RLIMIT_DATA`).
+    Data,
+    /// Size of the stack, in bytes (`RLIMIT_STACK`).
+    Stack,
+    /// Largest core file the process may dump, in bytes (`RLIMIT_CORE`).
+    Core,
+    /// Number of open file descriptors (`RLIMIT_NOFILE`).
+    Nofile,
+    /// Size of the process's virtual address space, in bytes (`RLIMIT_AS`).
+    AddressSpace,
+    /// Bytes of memory that may be locked into RAM (`RLIMIT_MEMLOCK`).
+    Memlock,
+    /// Number of processes the user may create (`RLIMIT_NPROC`).
+    Nproc,
+}
+
+#[cfg(unix)]
+impl Resource {
+    fn as_raw(self) -> libc::c_int {
+        match self {
+            Resource::Cpu => libc::RLIMIT_CPU as libc::c_int,
+            Resource::FileSize => libc::RLIMIT_FSIZE as libc::c_int,
+            Resource::Data => libc::RLIMIT_DATA as libc::c_int,
+            Resource::Stack => libc::RLIMIT_STACK as libc::c_int,
+            Resource::Core => libc::RLIMIT_CORE as libc::c_int,
+            Resource::Nofile => libc::RLIMIT_NOFILE as libc::c_int,
+            Resource::AddressSpace => libc::RLIMIT_AS as libc::c_int,
+            Resource::Memlock => libc::RLIMIT_MEMLOCK as libc::c_int,
+            Resource::Nproc => libc::RLIMIT_NPROC as libc::c_int,
+        }
+    }
+}
+
+/// A line of child output, tagged with the pipe it arrived on.
+///
+/// Yielded by [`MergedOutput::next_line`]. The line does not include the
+/// trailing newline (a final `\r` is stripped as well, matching [`lines`]).
+///
+/// [`lines`]: crate::io::AsyncBufReadExt::lines
+#[cfg(feature = "io-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum OutputLine {
+    /// A line read from the child's standard output.
+    Stdout(String),
+    /// A line read from the child's standard error.
+    Stderr(String),
+}
+
+/// Merged, line-oriented view of a child's stdout and stderr.
+///
+/// Returned by [`Child::merged_output_stream`]. Lines are yielded in the
+/// order they arrive across both pipes, which reading the two pipes with
+/// separate [`lines`] readers cannot guarantee without a manual select loop.
+///
+/// [`lines`]: crate::io::AsyncBufReadExt::lines
+#[cfg(feature = "io-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+#[derive(Debug)]
+pub struct MergedOutput {
+    stdout: Option<crate::io::BufReader<ChildStdout>>,
+    stderr: Option<crate::io::BufReader<ChildStderr>>,
+    stdout_buf: Vec<u8>,
+    stderr_buf: Vec<u8>,
+}
+
+#[cfg(feature = "io-util")]
+impl MergedOutput {
+    /// Returns the next line of output from either pipe, or `None` once both
+    /// pipes have reached end of file.
+    ///
+    /// A final line without a trailing newline is yielded as-is before the
+    /// stream ends.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. Partially read lines are buffered
+    /// internally, so dropping the future does not lose data.
+    pub async fn next_line(&mut self) -> io::Result<Option<OutputLine>> {
+        std::future::poll_fn(|cx| self.poll_next_line(cx)).await
+    }
+
+    fn poll_next_line(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<OutputLine>>> {
+        // Stdout is polled first, so simultaneous arrivals favor it
+        // deterministically.
+        let mut pending = false;
+
+        match poll_line(&mut self.stdout, &mut self.stdout_buf, cx) {
+            Poll::Ready(Ok(Some(line))) => {
+                return Poll::Ready(Ok(Some(OutputLine::Stdout(line))))
+            }
+            Poll::Ready(Ok(None)) => {}
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => pending = true,
+        }
+
+        match poll_line(&mut self.stderr, &mut self.stderr_buf, cx) {
+            Poll::Ready(Ok(Some(line))) => {
+                return Poll::Ready(Ok(Some(OutputLine::Stderr(line))))
+            }
+            Poll::Ready(Ok(None)) => {}
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => pending = true,
+        }
+
+        if pending {
+            Poll::Pending
+        } else {
+            // Both pipes have reached end of file.
+            Poll::Ready(Ok(None))
+        }
+    }
+}
+
+/// Polls one pipe for the next complete line, accumulating partial lines in
+/// `acc`.
+///
+/// Returns `Ready(Ok(None))` once the pipe has reached end of file; the
+/// reader is dropped so it is not polled again, and an unterminated final
+/// line is yielded first.
+#[cfg(feature = "io-util")]
+fn poll_line<R: AsyncRead + Unpin>(
+    reader_opt: &mut Option<crate::io::BufReader<R>>,
+    acc: &mut Vec<u8>,
+    cx: &mut Context<'_>,
+) -> Poll<io::Result<Option<String>>> {
+    use crate::io::AsyncBufRead;
+
+    loop {
+        let reader = match reader_opt.as_mut() {
+            Some(reader) => reader,
+            None => return Poll::Ready(Ok(None)),
+        };
+
+        let consumed = {
+            let buf = match ready!(Pin::new(&mut *reader).poll_fill_buf(cx)) {
+                Ok(buf) => buf,
+                Err(e) => return Poll::Ready(Err(e)),
+            };
+
+            if buf.is_empty() {
+                *reader_opt = None;
+                if acc.is_empty() {
+                    return Poll::Ready(Ok(None));
+                }
+                return Poll::Ready(Ok(Some(finish_line(std::mem::take(acc)))));
+            }
+
+            match buf.iter().position(|&b| b == b'\n') {
+                Some(pos) => {
+                    acc.extend_from_slice(&buf[..=pos]);
+                    pos + 1
+                }
+                None => {
+                    acc.extend_from_slice(buf);
+                    buf.len()
+                }
+            }
+        };
+        Pin::new(&mut *reader).consume(consumed);
+
+        if acc.last() == Some(&b'\n') {
+            return Poll::Ready(Ok(Some(finish_line(std::mem::take(acc)))));
+        }
+    }
+}
+
+#[cfg(feature = "io-util")]
+fn finish_line(mut line: Vec<u8>) -> String {
+    if line.last() == Some(&b'\n') {
+        line.pop();
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+    }
+    String::from_utf8_lossy(&line).into_owned()
+}
+
+async fn read_pipe_to_end<A: AsyncRead + Unpin>(io: &mut Option<A>) -> io::Result<Vec<u8>> {
+    let mut vec = Vec::new();
+    if let Some(io) = io.as_mut() {
+        crate::io::util::read_to_end(io, &mut vec).await?;
+    }
+    Ok(vec)
+}
+
+#[cfg(unix)]
+impl Drop for Child {
+    fn drop(&mut self) {
+        // Group-kill before the inner drop guard signals and reaps the
+        // direct child.
+        if self.kill_process_group_on_drop {
+            if let (Some(pgid), FusedChild::Child(_)) = (self.pgid, &self.child) {
+                unsafe {
+                    libc::kill(-pgid, libc::SIGKILL);
+                }
+            }
+        }
+    }
+}
+
 /// The standard input stream for spawned children.
 ///
 /// This type implements the `AsyncWrite` trait to pass data to the stdin
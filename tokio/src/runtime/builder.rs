@@ -59,6 +59,11 @@ pub struct Builder {
     /// Whether or not the clock should start paused.
     start_paused: bool,
 
+    /// Granularity at which the time driver rounds up timer deadlines.
+    ///
+    /// `None` uses the driver's native 1 ms resolution.
+    timer_resolution: Option<Duration>,
+
     /// The number of worker threads, used by Runtime.
     ///
     /// Only used when not using the current-thread executor.
@@ -269,6 +274,9 @@ impl Builder {
             // The clock starts not-paused
             start_paused: false,
 
+            // Timers fire at the driver's native 1 ms resolution
+            timer_resolution: None,
+
             // Read from environment variable first in multi-threaded mode.
             // Default to lazy auto-detection (one thread per CPU core)
             worker_threads: None,
@@ -944,6 +952,7 @@ impl Builder {
             enable_time: self.enable_time,
             start_paused: self.start_paused,
             nevents: self.nevents,
+            timer_resolution: self.timer_resolution,
         }
     }
 
@@ -1579,6 +1588,40 @@ cfg_time! {
             self.enable_time = true;
             self
         }
+
+        /// Sets the resolution at which the time driver fires timers.
+        ///
+        /// Timer deadlines are rounded up to the next multiple of
+        /// `resolution`, so timers whose deadlines land in the same
+        /// resolution window are coalesced into a single wakeup. This trades
+        /// timer precision for throughput: services managing very large
+        /// numbers of timeouts can use a coarse resolution (for example
+        /// 10 ms) to batch expiry processing.
+        ///
+        /// The default resolution is 1 ms, the driver's native granularity.
+        /// Resolutions finer than 1 ms have no effect.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `resolution` is zero.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use std::time::Duration;
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .enable_time()
+        ///     .timer_resolution(Duration::from_millis(10))
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        pub fn timer_resolution(&mut self, resolution: Duration) -> &mut Self {
+            assert!(!resolution.is_zero(), "timer resolution must be non-zero");
+            self.timer_resolution = Some(resolution);
+            self
+        }
     }
 }
 
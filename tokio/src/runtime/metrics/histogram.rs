@@ -201,6 +201,15 @@ impl Histogram {
     pub(crate) fn bucket_range(&self, bucket: usize) -> Range<u64> {
         self.histogram_type.bucket_range(bucket)
     }
+
+    /// Records a single value directly into the shared buckets.
+    ///
+    /// Unlike the batch path used by the runtime workers, this increments the
+    /// bucket atomically and can be called concurrently from any thread.
+    pub(crate) fn record(&self, value: u64) {
+        let bucket = self.histogram_type.value_to_bucket(value);
+        self.buckets[bucket].add(1, Relaxed);
+    }
 }
 
 impl HistogramBatch {
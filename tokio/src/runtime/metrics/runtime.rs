@@ -1109,4 +1109,98 @@ impl RuntimeMetrics {
                     .unwrap_or(0)
             }
     }
+
+    feature! {
+        #![all(
+            tokio_unstable,
+            feature = "time"
+        )]
+            /// Returns the number of timers currently registered with the
+            /// runtime's timer wheel.
+            ///
+            /// This includes all pending [`Sleep`], [`Interval`] and
+            /// [`Timeout`] instances, and can be used to monitor timer wheel
+            /// occupancy.
+            ///
+            /// Returns zero if the time driver is disabled.
+            ///
+            /// [`Sleep`]: crate::time::Sleep
+            /// [`Interval`]: crate::time::Interval
+            /// [`Timeout`]: crate::time::Timeout
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.timer_count();
+            ///     println!("{} timers are registered with the runtime's timer wheel.", n);
+            /// }
+            /// ```
+            pub fn timer_count(&self) -> usize {
+                self.handle
+                    .inner
+                    .driver()
+                    .time
+                    .as_ref()
+                    .map(|h| h.timer_count())
+                    .unwrap_or(0)
+            }
+    }
+
+    feature! {
+        #![all(
+            tokio_unstable,
+            target_has_atomic = "64",
+            feature = "time"
+        )]
+            /// Returns the number of timers created over the lifetime of the
+            /// runtime.
+            ///
+            /// Returns zero if the time driver is disabled.
+            pub fn timers_created_count(&self) -> u64 {
+                self.with_timer_metrics(|m| m.created.load(Relaxed))
+            }
+
+            /// Returns the number of timers whose deadline was reached over
+            /// the lifetime of the runtime.
+            ///
+            /// Returns zero if the time driver is disabled.
+            pub fn timers_fired_count(&self) -> u64 {
+                self.with_timer_metrics(|m| m.fired.load(Relaxed))
+            }
+
+            /// Returns the number of timers cancelled before their deadline
+            /// over the lifetime of the runtime.
+            ///
+            /// Together with [`timers_created_count`] and
+            /// [`timers_fired_count`], this can be used to track down "sleep
+            /// leaks": a timer that was created but neither fired nor
+            /// cancelled is still alive.
+            ///
+            /// Returns zero if the time driver is disabled.
+            ///
+            /// [`timers_created_count`]: RuntimeMetrics::timers_created_count
+            /// [`timers_fired_count`]: RuntimeMetrics::timers_fired_count
+            pub fn timers_cancelled_count(&self) -> u64 {
+                self.with_timer_metrics(|m| m.cancelled.load(Relaxed))
+            }
+
+            fn with_timer_metrics<F>(&self, f: F) -> u64
+            where
+                F: Fn(&crate::runtime::time::TimerMetrics) -> u64,
+            {
+                self.handle
+                    .inner
+                    .driver()
+                    .time
+                    .as_ref()
+                    .map(|h| f(h.timer_metrics()))
+                    .unwrap_or(0)
+            }
+    }
 }
@@ -481,7 +481,16 @@ impl TimerEntry {
     #[track_caller]
     pub(crate) fn new(handle: scheduler::Handle, deadline: Instant) -> Self {
         // Panic if the time driver is not enabled
-        let _ = handle.driver().time();
+        let time = handle.driver().time();
+
+        #[cfg(tokio_unstable)]
+        time.inner
+            .metrics
+            .created
+            .add(1, std::sync::atomic::Ordering::Relaxed);
+
+        #[cfg(not(tokio_unstable))]
+        let _ = time;
 
         Self {
             driver: handle,
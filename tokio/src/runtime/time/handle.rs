@@ -18,6 +18,18 @@ impl Handle {
         self.inner.is_shutdown()
     }
 
+    /// Returns the number of timers currently registered with the wheel.
+    #[cfg(all(tokio_unstable, feature = "rt"))]
+    pub(crate) fn timer_count(&self) -> usize {
+        self.inner.lock().wheel.len()
+    }
+
+    /// Returns the lifetime timer counters for this driver.
+    #[cfg(all(tokio_unstable, feature = "rt"))]
+    pub(crate) fn timer_metrics(&self) -> &super::TimerMetrics {
+        &self.inner.metrics
+    }
+
     /// Track that the driver is being unparked
     pub(crate) fn unpark(&self) {
         #[cfg(feature = "test-util")]
@@ -25,6 +25,9 @@ use crate::time::error::Error;
 use crate::time::{Clock, Duration};
 use crate::util::WakeList;
 
+#[cfg(tokio_unstable)]
+use crate::util::metric_atomics::MetricAtomicU64;
+
 use std::fmt;
 use std::{num::NonZeroU64, ptr::NonNull};
 
@@ -97,6 +100,10 @@ struct Inner {
     /// True if the driver is being shutdown.
     is_shutdown: AtomicBool,
 
+    /// Counters for created/fired/cancelled timers, used for leak detection.
+    #[cfg(tokio_unstable)]
+    metrics: TimerMetrics,
+
     // When `true`, a call to `park_timeout` should immediately return and time
     // should not advance. One reason for this to be `true` is if the task
     // passed to `Runtime::block_on` called `task::yield_now()`.
@@ -107,6 +114,24 @@ struct Inner {
     did_wake: AtomicBool,
 }
 
+/// Lifetime counters for the timers managed by a driver.
+///
+/// A timer that has been created but neither fired nor cancelled is still
+/// alive; a steadily growing difference between `created` and
+/// `fired + cancelled` points at a sleep leak.
+#[cfg(tokio_unstable)]
+#[derive(Debug, Default)]
+pub(crate) struct TimerMetrics {
+    /// Number of timers created over the lifetime of the runtime.
+    pub(super) created: MetricAtomicU64,
+
+    /// Number of timers whose deadline was reached.
+    pub(super) fired: MetricAtomicU64,
+
+    /// Number of timers cancelled before their deadline was reached.
+    pub(super) cancelled: MetricAtomicU64,
+}
+
 /// Time state shared which must be protected by a `Mutex`
 struct InnerState {
     /// The earliest time at which we promise to wake up without unparking.
@@ -123,8 +148,12 @@ impl Driver {
     /// thread and `time_source` to get the current time and convert to ticks.
     ///
     /// Specifying the source of time is useful when testing.
-    pub(crate) fn new(park: IoStack, clock: &Clock) -> (Driver, Handle) {
-        let time_source = TimeSource::new(clock);
+    pub(crate) fn new(
+        park: IoStack,
+        clock: &Clock,
+        resolution: Option<Duration>,
+    ) -> (Driver, Handle) {
+        let time_source = TimeSource::new(clock, resolution);
 
         let handle = Handle {
             time_source,
@@ -135,6 +164,9 @@ impl Driver {
                 }),
                 is_shutdown: AtomicBool::new(false),
 
+                #[cfg(tokio_unstable)]
+                metrics: TimerMetrics::default(),
+
                 #[cfg(feature = "test-util")]
                 did_wake: AtomicBool::new(false),
             },
@@ -227,7 +259,8 @@ impl Driver {
                 // yield in `Runtime::block_on`). In this case, we don't
                 // advance the clock.
                 if !handle.did_wake() {
-                    // Simulate advancing time
+                    // Simulate advancing time, in steps if so configured
+                    let duration = clock.clamp_auto_advance(duration);
                     if let Err(msg) = clock.advance(duration) {
                         panic!("{}", msg);
                     }
@@ -255,6 +288,9 @@ impl Handle {
     pub(self) fn process_at_time(&self, mut now: u64) {
         let mut waker_list = WakeList::new();
 
+        #[cfg(tokio_unstable)]
+        let mut fired: u64 = 0;
+
         let mut lock = self.inner.lock();
 
         if now < lock.wheel.elapsed() {
@@ -270,6 +306,11 @@ impl Handle {
         while let Some(entry) = lock.wheel.poll(now) {
             debug_assert!(unsafe { entry.is_pending() });
 
+            #[cfg(tokio_unstable)]
+            {
+                fired += 1;
+            }
+
             // SAFETY: We hold the driver lock, and just removed the entry from any linked lists.
             if let Some(waker) = unsafe { entry.fire(Ok(())) } {
                 waker_list.push(waker);
@@ -292,6 +333,12 @@ impl Handle {
 
         drop(lock);
 
+        #[cfg(tokio_unstable)]
+        self.inner
+            .metrics
+            .fired
+            .add(fired, std::sync::atomic::Ordering::Relaxed);
+
         waker_list.wake_all();
     }
 
@@ -311,6 +358,12 @@ impl Handle {
 
             if entry.as_ref().might_be_registered() {
                 lock.wheel.remove(entry);
+
+                #[cfg(tokio_unstable)]
+                self.inner
+                    .metrics
+                    .cancelled
+                    .add(1, std::sync::atomic::Ordering::Relaxed);
             }
 
             entry.as_ref().handle().fire(Ok(()));
@@ -5,18 +5,43 @@ use crate::time::{Clock, Duration, Instant};
 #[derive(Debug)]
 pub(crate) struct TimeSource {
     start_time: Instant,
+
+    /// Granularity, in milliseconds, at which timer deadlines are rounded up.
+    ///
+    /// A resolution greater than one coalesces timers that expire within the
+    /// same window into a single wakeup, trading precision for throughput.
+    resolution_ms: u64,
 }
 
 impl TimeSource {
-    pub(crate) fn new(clock: &Clock) -> Self {
+    pub(crate) fn new(clock: &Clock, resolution: Option<Duration>) -> Self {
+        let resolution_ms = resolution
+            .map(|r| {
+                u64::try_from(r.as_millis())
+                    .unwrap_or(MAX_SAFE_MILLIS_DURATION)
+                    .clamp(1, MAX_SAFE_MILLIS_DURATION)
+            })
+            .unwrap_or(1);
+
         Self {
             start_time: clock.now(),
+            resolution_ms,
         }
     }
 
     pub(crate) fn deadline_to_tick(&self, t: Instant) -> u64 {
         // Round up to the end of a ms
-        self.instant_to_tick(t + Duration::from_nanos(999_999))
+        let tick = self.instant_to_tick(t + Duration::from_nanos(999_999));
+
+        if self.resolution_ms > 1 {
+            // Round up to the next resolution boundary so that deadlines
+            // within the same window share a wakeup.
+            (tick.saturating_add(self.resolution_ms - 1) / self.resolution_ms)
+                .saturating_mul(self.resolution_ms)
+                .min(MAX_SAFE_MILLIS_DURATION)
+        } else {
+            tick
+        }
     }
 
     pub(crate) fn instant_to_tick(&self, t: Instant) -> u64 {
@@ -267,3 +267,18 @@ fn instant_to_tick_max() {
 
     assert!(handle.time_source.instant_to_tick(long_future) <= MAX_SAFE_MILLIS_DURATION);
 }
+
+#[test]
+#[cfg(not(loom))]
+fn coarse_resolution_rounds_deadlines_up() {
+    use super::TimeSource;
+
+    let clock = crate::time::Clock::new(true, false);
+    let source = TimeSource::new(&clock, Some(Duration::from_millis(10)));
+    let start = source.start_time();
+
+    // Deadlines within a window round up to the end of the window.
+    assert_eq!(source.deadline_to_tick(start + Duration::from_millis(1)), 10);
+    assert_eq!(source.deadline_to_tick(start + Duration::from_millis(10)), 10);
+    assert_eq!(source.deadline_to_tick(start + Duration::from_millis(11)), 20);
+}
@@ -40,6 +40,10 @@ pub(crate) struct Wheel {
 
     /// Entries queued for firing
     pending: EntryList,
+
+    /// Number of entries currently registered with the wheel, including
+    /// entries queued for firing. Used for occupancy metrics.
+    occupied: usize,
 }
 
 /// Number of levels. Each level has 64 slots. By using 6 levels with 64 slots
@@ -57,9 +61,16 @@ impl Wheel {
             elapsed: 0,
             levels: Box::new(array::from_fn(Level::new)),
             pending: EntryList::new(),
+            occupied: 0,
         }
     }
 
+    /// Returns the number of entries currently registered with the wheel.
+    #[cfg(all(tokio_unstable, feature = "rt"))]
+    pub(crate) fn len(&self) -> usize {
+        self.occupied
+    }
+
     /// Returns the number of milliseconds that have elapsed since the timing
     /// wheel's creation.
     pub(crate) fn elapsed(&self) -> u64 {
@@ -111,6 +122,8 @@ impl Wheel {
                 .unwrap_or(true)
         });
 
+        self.occupied += 1;
+
         Ok(when)
     }
 
@@ -132,6 +145,8 @@ impl Wheel {
                 self.levels[level].remove_entry(item);
             }
         }
+
+        self.occupied -= 1;
     }
 
     /// Instant at which to poll.
@@ -142,7 +157,7 @@ impl Wheel {
     /// Advances the timer up to the instant represented by `now`.
     pub(crate) fn poll(&mut self, now: u64) -> Option<TimerHandle> {
         loop {
-            if let Some(handle) = self.pending.pop_back() {
+            if let Some(handle) = self.pop_pending() {
                 return Some(handle);
             }
 
@@ -163,7 +178,18 @@ impl Wheel {
             }
         }
 
-        self.pending.pop_back()
+        self.pop_pending()
+    }
+
+    /// Pops an entry queued for firing, keeping the occupancy count in sync.
+    fn pop_pending(&mut self) -> Option<TimerHandle> {
+        let handle = self.pending.pop_back();
+
+        if handle.is_some() {
+            self.occupied -= 1;
+        }
+
+        handle
     }
 
     /// Returns the instant at which the next timeout expires.
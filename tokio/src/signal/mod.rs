@@ -50,6 +50,12 @@ mod ctrl_c;
 #[cfg(feature = "signal")]
 pub use ctrl_c::ctrl_c;
 
+#[cfg(all(any(unix, windows), feature = "signal", feature = "rt"))]
+mod reload;
+#[cfg(all(any(unix, windows), feature = "signal", feature = "rt"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "signal", feature = "rt"))))]
+pub use reload::{ReloadSubscription, Reloader};
+
 pub(crate) mod registry;
 
 mod os {
@@ -0,0 +1,189 @@
+//! Coalesced reload notifications with acknowledgment.
+
+use crate::sync::watch;
+
+use std::io;
+use std::sync::{Arc, Mutex};
+
+/// Multiplexes reload signals into a broadcast "configuration generation"
+/// channel with acknowledgment.
+///
+/// A `Reloader` listens for `SIGHUP` on Unix ("ctrl-break" on Windows) and
+/// bumps a generation counter each time the signal arrives. Any number of
+/// subsystems can [`subscribe`] to generation changes without each creating
+/// its own signal listener, and can [`acknowledge`] a generation once they
+/// have applied it — letting a supervisor [wait] until a reload has taken
+/// effect everywhere.
+///
+/// Like the underlying signal streams, notifications are coalesced: a
+/// subscriber that falls behind observes only the latest generation, not
+/// every intermediate one.
+///
+/// [`subscribe`]: Reloader::subscribe
+/// [`acknowledge`]: ReloadSubscription::acknowledge
+/// [wait]: Reloader::acknowledged
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::signal::Reloader;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let reloader = Reloader::new()?;
+///
+///     let mut subscription = reloader.subscribe();
+///     tokio::spawn(async move {
+///         while let Some(generation) = subscription.changed().await {
+///             // Re-read the configuration ...
+///             println!("applying config generation {generation}");
+///             subscription.acknowledge();
+///         }
+///     });
+///
+///     // Elsewhere: trigger a reload and wait for it to take effect.
+///     reloader.trigger();
+///     reloader.acknowledged(reloader.generation()).await;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Reloader {
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    generation: watch::Sender<u64>,
+    acks: Mutex<Vec<watch::Receiver<u64>>>,
+}
+
+impl Reloader {
+    /// Creates a new reloader and starts listening for the platform's reload
+    /// signal.
+    ///
+    /// The background listener task runs for the remaining lifetime of the
+    /// runtime, matching the underlying signal handler which is never
+    /// unregistered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if registering the signal listener fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside the context of a Tokio runtime.
+    #[track_caller]
+    pub fn new() -> io::Result<Reloader> {
+        #[cfg(unix)]
+        let mut signal =
+            crate::signal::unix::signal(crate::signal::unix::SignalKind::hangup())?;
+        #[cfg(windows)]
+        let mut signal = crate::signal::windows::ctrl_break()?;
+
+        let shared = Arc::new(Shared {
+            generation: watch::channel(0).0,
+            acks: Mutex::new(Vec::new()),
+        });
+
+        let task_shared = shared.clone();
+        crate::spawn(async move {
+            while signal.recv().await.is_some() {
+                task_shared.generation.send_modify(|generation| *generation += 1);
+            }
+        });
+
+        Ok(Reloader { shared })
+    }
+
+    /// Returns the current configuration generation.
+    ///
+    /// The generation starts at zero and is incremented by each reload
+    /// signal and each call to [`trigger`].
+    ///
+    /// [`trigger`]: Reloader::trigger
+    pub fn generation(&self) -> u64 {
+        *self.shared.generation.borrow()
+    }
+
+    /// Triggers a reload without a signal, notifying all subscriptions.
+    ///
+    /// This serves admin endpoints and platforms where sending the reload
+    /// signal is inconvenient, and keeps tests deterministic.
+    pub fn trigger(&self) {
+        self.shared.generation.send_modify(|generation| *generation += 1);
+    }
+
+    /// Creates a new subscription, starting at the current generation.
+    ///
+    /// The subscription is only woken for generations newer than the one
+    /// current at the time of this call.
+    pub fn subscribe(&self) -> ReloadSubscription {
+        let generation = self.shared.generation.subscribe();
+        let seen = *generation.borrow();
+        let (ack_tx, ack_rx) = watch::channel(seen);
+
+        let mut acks = self.shared.acks.lock().unwrap();
+        // Drop the bookkeeping for subscriptions that no longer exist.
+        acks.retain(|ack| ack.has_changed().is_ok());
+        acks.push(ack_rx);
+
+        ReloadSubscription {
+            generation,
+            ack: ack_tx,
+            seen,
+        }
+    }
+
+    /// Waits until every live subscription has acknowledged `generation`.
+    ///
+    /// Subscriptions dropped before acknowledging do not block this call,
+    /// and subscriptions created after it only count towards later
+    /// generations.
+    pub async fn acknowledged(&self, generation: u64) {
+        let acks: Vec<_> = self.shared.acks.lock().unwrap().clone();
+        for mut ack in acks {
+            // An error means the subscription was dropped, which cannot hold
+            // up the reload any longer.
+            let _ = ack.wait_for(|acked| *acked >= generation).await;
+        }
+    }
+}
+
+/// A subscription to reload events, created by [`Reloader::subscribe`].
+#[derive(Debug)]
+pub struct ReloadSubscription {
+    generation: watch::Receiver<u64>,
+    ack: watch::Sender<u64>,
+    seen: u64,
+}
+
+impl ReloadSubscription {
+    /// Waits for a configuration generation newer than the last one seen and
+    /// returns it.
+    ///
+    /// `None` is returned if the runtime is shutting down and no further
+    /// reload events can be observed.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. Dropping the future does not lose the
+    /// reload event; the next call observes the latest generation.
+    pub async fn changed(&mut self) -> Option<u64> {
+        match self.generation.changed().await {
+            Ok(()) => {
+                self.seen = *self.generation.borrow_and_update();
+                Some(self.seen)
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Acknowledges the generation last returned by [`changed`], marking it
+    /// as applied by this subscriber.
+    ///
+    /// [`changed`]: ReloadSubscription::changed
+    pub fn acknowledge(&self) {
+        self.ack.send_replace(self.seen);
+    }
+}
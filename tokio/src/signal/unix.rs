@@ -18,7 +18,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Once;
 use std::task::{Context, Poll};
 
-pub(crate) type OsStorage = Box<[SignalInfo]>;
+pub(crate) type OsStorage = Box<[SignalSlot]>;
 
 impl Init for OsStorage {
     fn init() -> Self {
@@ -32,7 +32,7 @@ impl Init for OsStorage {
         #[cfg(any(target_os = "linux", target_os = "illumos"))]
         let possible = 0..=libc::SIGRTMAX();
 
-        possible.map(|_| SignalInfo::default()).collect()
+        possible.map(|_| SignalSlot::default()).collect()
     }
 }
 
@@ -213,6 +213,36 @@ impl SignalKind {
     pub const fn window_change() -> Self {
         Self(libc::SIGWINCH)
     }
+
+    /// Represents the real-time signal `SIGRTMIN + offset`.
+    ///
+    /// Real-time signals are application-defined, are queued rather than
+    /// coalesced by the kernel, and are delivered in order. Note that the
+    /// coalescing caveats of [`Signal`] still apply on top of the kernel's
+    /// queueing. By default, the process is terminated by these signals.
+    ///
+    /// `SIGRTMIN` is a runtime value (the C library reserves a few of the
+    /// lowest real-time signals for itself), which is why this constructor is
+    /// not `const`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `SIGRTMIN + offset` is outside the
+    /// `SIGRTMIN..=SIGRTMAX` range supported by the platform.
+    #[cfg(any(target_os = "illumos", target_os = "linux"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(target_os = "illumos", target_os = "linux")))
+    )]
+    pub fn realtime(offset: std::os::raw::c_int) -> Self {
+        let signum = libc::SIGRTMIN() + offset;
+        assert!(
+            (libc::SIGRTMIN()..=libc::SIGRTMAX()).contains(&signum),
+            "SIGRTMIN+{} is outside the supported real-time signal range",
+            offset,
+        );
+        Self(signum)
+    }
 }
 
 impl From<std::os::raw::c_int> for SignalKind {
@@ -227,22 +257,120 @@ impl From<SignalKind> for std::os::raw::c_int {
     }
 }
 
-pub(crate) struct SignalInfo {
+pub(crate) struct SignalSlot {
     event_info: EventInfo,
     init: Once,
     initialized: AtomicBool,
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    siginfo: SiginfoSlot,
 }
 
-impl Default for SignalInfo {
-    fn default() -> SignalInfo {
-        SignalInfo {
+impl Default for SignalSlot {
+    fn default() -> SignalSlot {
+        SignalSlot {
             event_info: EventInfo::default(),
             init: Once::new(),
             initialized: AtomicBool::new(false),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            siginfo: SiginfoSlot::default(),
+        }
+    }
+}
+
+/// Details of the most recently delivered instance of a signal, captured by
+/// the signal handler with atomic stores so it remains async-signal safe.
+///
+/// Signals are coalesced, so by the time a listener observes the slot a later
+/// delivery may have overwritten an earlier one; the contents always describe
+/// *some* delivery of the signal, just not necessarily the first.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Default)]
+struct SiginfoSlot {
+    pid: std::sync::atomic::AtomicI32,
+    uid: std::sync::atomic::AtomicU32,
+    status: std::sync::atomic::AtomicI32,
+    code: std::sync::atomic::AtomicI32,
+    set: AtomicBool,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl SiginfoSlot {
+    fn store(&self, signal: libc::c_int, info: &libc::siginfo_t) {
+        // SAFETY: the `si_pid`/`si_uid`/`si_status` union fields are plain
+        // integers; whether their values are meaningful depends on `si_code`,
+        // which `load` checks before exposing them.
+        unsafe {
+            self.pid.store(info.si_pid(), Ordering::Relaxed);
+            self.uid.store(info.si_uid(), Ordering::Relaxed);
+            if signal == libc::SIGCHLD {
+                self.status.store(info.si_status(), Ordering::Relaxed);
+            }
+        }
+        self.code.store(info.si_code, Ordering::Relaxed);
+        self.set.store(true, Ordering::Release);
+    }
+
+    fn load(&self, signal: libc::c_int) -> SignalInfo {
+        if !self.set.load(Ordering::Acquire) {
+            return SignalInfo::default();
+        }
+
+        let code = self.code.load(Ordering::Relaxed);
+        // A non-positive `si_code` (`SI_USER`, `SI_QUEUE`, `SI_TKILL`, ...)
+        // means the signal was sent by a process, so the sender fields are
+        // populated. The `CLD_*` codes carry the child's pid and uid.
+        let has_sender = code <= 0 || signal == libc::SIGCHLD;
+        let is_child = signal == libc::SIGCHLD && code > 0;
+
+        SignalInfo {
+            pid: has_sender.then(|| self.pid.load(Ordering::Relaxed)),
+            uid: has_sender.then(|| self.uid.load(Ordering::Relaxed)),
+            status: is_child.then(|| self.status.load(Ordering::Relaxed)),
         }
     }
 }
 
+/// Details about a received signal, as reported by the operating system.
+///
+/// Returned by [`Signal::recv_info`]. All fields are optional because the
+/// kernel only populates them for some origins: the sender's pid and uid are
+/// known when the signal was sent by a process (or, for `SIGCHLD`, identify
+/// the child), and the exit status is only carried by `SIGCHLD`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(target_os = "android", target_os = "linux")))
+)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SignalInfo {
+    pid: Option<i32>,
+    uid: Option<u32>,
+    status: Option<i32>,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl SignalInfo {
+    /// Returns the pid of the process that sent the signal, if it was sent by
+    /// a process. For `SIGCHLD` this is the pid of the child that changed
+    /// state.
+    pub fn sender_pid(&self) -> Option<i32> {
+        self.pid
+    }
+
+    /// Returns the real uid of the process that sent the signal, if it was
+    /// sent by a process. For `SIGCHLD` this is the real uid of the child.
+    pub fn sender_uid(&self) -> Option<u32> {
+        self.uid
+    }
+
+    /// For `SIGCHLD`, returns the child's exit code or the number of the
+    /// signal that stopped or killed it, depending on the cause of the state
+    /// change. Returns `None` for every other signal.
+    pub fn child_status(&self) -> Option<i32> {
+        self.status
+    }
+}
+
 /// Our global signal handler for all signals registered by this module.
 ///
 /// The purpose of this signal handler is to primarily:
@@ -260,6 +388,18 @@ fn action(globals: &'static Globals, signal: libc::c_int) {
     drop(sender.write(&[1]));
 }
 
+/// Like `action`, but first records the `siginfo_t` details so they can be
+/// reported by `Signal::recv_info`. The stores are atomic, keeping the
+/// handler async-signal safe.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn action_info(globals: &'static Globals, signal: libc::c_int, info: &libc::siginfo_t) {
+    if let Some(slot) = globals.storage().get(signal as EventId) {
+        slot.siginfo.store(signal, info);
+    }
+
+    action(globals, signal);
+}
+
 /// Enables this module to receive signal notifications for the `signal`
 /// provided.
 ///
@@ -284,9 +424,21 @@ fn signal_enable(signal: SignalKind, handle: &Handle) -> io::Result<()> {
     };
     let mut registered = Ok(());
     siginfo.init.call_once(|| {
-        registered = unsafe {
-            signal_hook_registry::register(signal, move || action(globals, signal)).map(|_| ())
-        };
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        {
+            registered = unsafe {
+                signal_hook_registry::register_sigaction(signal, move |info| {
+                    action_info(globals, signal, info)
+                })
+                .map(|_| ())
+            };
+        }
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        {
+            registered = unsafe {
+                signal_hook_registry::register(signal, move || action(globals, signal)).map(|_| ())
+            };
+        }
         if registered.is_ok() {
             siginfo.initialized.store(true, Ordering::Relaxed);
         }
@@ -374,6 +526,8 @@ fn signal_enable(signal: SignalKind, handle: &Handle) -> io::Result<()> {
 #[derive(Debug)]
 pub struct Signal {
     inner: RxFuture,
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    kind: SignalKind,
 }
 
 /// Creates a new listener which will receive notifications when the current
@@ -410,6 +564,8 @@ pub fn signal(kind: SignalKind) -> io::Result<Signal> {
 
     Ok(Signal {
         inner: RxFuture::new(rx),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        kind,
     })
 }
 
@@ -457,6 +613,51 @@ impl Signal {
         self.inner.recv().await
     }
 
+    /// Receives the next signal notification event along with the details the
+    /// kernel reported about its origin.
+    ///
+    /// `None` is returned if no more events can be received by this stream.
+    /// See [`SignalInfo`] for which details are available for which signals.
+    ///
+    /// Because signal notifications are coalesced, the returned details
+    /// describe the most recent delivery of the signal, which is not
+    /// necessarily the one that triggered the wakeup. Supervisors reacting to
+    /// `SIGCHLD` should therefore still poll all of their children rather
+    /// than only the reported pid.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe, with the same guarantee as [`recv`].
+    ///
+    /// [`recv`]: Signal::recv
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use tokio::signal::unix::{signal, SignalKind};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut stream = signal(SignalKind::terminate())?;
+    ///
+    ///     if let Some(info) = stream.recv_info().await {
+    ///         println!("got SIGTERM from pid {:?}", info.sender_pid());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(target_os = "android", target_os = "linux")))
+    )]
+    pub async fn recv_info(&mut self) -> Option<SignalInfo> {
+        self.inner.recv().await?;
+
+        let slot = globals().storage().get(self.kind.0 as EventId)?;
+        Some(slot.siginfo.load(self.kind.0))
+    }
+
     /// Polls to receive the next signal notification event, outside of an
     /// `async` context.
     ///
@@ -496,6 +697,77 @@ impl Signal {
     }
 }
 
+/// Blocks delivery of the given signals to the calling thread, returning a
+/// guard that restores the thread's previous signal mask when dropped.
+///
+/// This is intended for applications doing their own signal-driven IPC: a
+/// dedicated thread can consume a set of signals with `sigwaitinfo(2)` or a
+/// `signalfd` while every other thread keeps them masked. Threads spawned
+/// while the guard is held inherit the mask, so masking early in `main`
+/// before the runtime starts covers all worker threads.
+///
+/// The mask is per-thread state. On a multi-threaded runtime a task may
+/// migrate between worker threads, so holding the guard across an `.await`
+/// masks (and later unmasks) whichever threads the task happens to run on —
+/// almost never what is intended. Scope the guard to synchronous code.
+///
+/// Masked signals are not delivered to the handlers installed by [`signal`],
+/// so listeners on other threads are unaffected while notifications for the
+/// masked thread are deferred until the guard is dropped.
+///
+/// # Errors
+///
+/// Returns an error if a signal number is invalid or if updating the mask
+/// fails.
+pub fn mask(signals: &[SignalKind]) -> io::Result<SignalMaskGuard> {
+    // SAFETY: the sigset functions only write to the set we hand them.
+    unsafe {
+        let mut set: libc::sigset_t = std::mem::zeroed();
+        libc::sigemptyset(&mut set);
+        for kind in signals {
+            if libc::sigaddset(&mut set, kind.0) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+
+        let mut old: libc::sigset_t = std::mem::zeroed();
+        let ret = libc::pthread_sigmask(libc::SIG_BLOCK, &set, &mut old);
+        if ret != 0 {
+            return Err(io::Error::from_raw_os_error(ret));
+        }
+
+        Ok(SignalMaskGuard {
+            old,
+            _not_send: std::marker::PhantomData,
+        })
+    }
+}
+
+/// Restores the thread signal mask captured by [`mask`] when dropped.
+///
+/// The guard is `!Send`: it must be dropped on the thread whose mask it
+/// holds, as restoring it anywhere else would alter the wrong thread.
+#[must_use = "the signal mask is restored when the guard is dropped"]
+pub struct SignalMaskGuard {
+    old: libc::sigset_t,
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl std::fmt::Debug for SignalMaskGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SignalMaskGuard").finish()
+    }
+}
+
+impl Drop for SignalMaskGuard {
+    fn drop(&mut self) {
+        // SAFETY: `old` is a mask previously returned by `pthread_sigmask`.
+        unsafe {
+            libc::pthread_sigmask(libc::SIG_SETMASK, &self.old, std::ptr::null_mut());
+        }
+    }
+}
+
 // Work around for abstracting streams internally
 #[cfg(feature = "process")]
 pub(crate) trait InternalStream {
@@ -539,6 +811,6 @@ mod tests {
     #[test]
     fn into_c_int() {
         let value: std::os::raw::c_int = SignalKind::interrupt().into();
-        assert_eq!(value, libc::SIGINT as _);
+        assert_eq!(value, libc::SIGINT);
     }
 }
@@ -4,6 +4,12 @@
 //! "ctrl-break", "ctrl-logoff", "ctrl-shutdown", and "ctrl-close"
 //! notifications. These events are listened for via the `SetConsoleCtrlHandler`
 //! function which receives the corresponding `windows_sys` event type.
+//!
+//! For processes running as a Windows service, the module also provides
+//! listeners for the service control events (stop, pause, continue). The
+//! service control handler itself must be registered by the application (or a
+//! service helper crate); forwarding the received controls with
+//! [`dispatch_service_control`] delivers them to the corresponding listeners.
 
 #![cfg(any(windows, docsrs))]
 #![cfg_attr(docsrs, doc(cfg(all(windows, feature = "signal"))))]
@@ -428,6 +434,216 @@ impl CtrlShutdown {
     }
 }
 
+/// A service control event forwarded to tokio with [`dispatch_service_control`].
+///
+/// The variants correspond to the `SERVICE_CONTROL_STOP`,
+/// `SERVICE_CONTROL_PAUSE` and `SERVICE_CONTROL_CONTINUE` control codes
+/// delivered to a Windows service's control handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceControl {
+    /// The service is being asked to stop (`SERVICE_CONTROL_STOP`).
+    Stop,
+    /// The service is being asked to pause (`SERVICE_CONTROL_PAUSE`).
+    Pause,
+    /// The service is being asked to resume (`SERVICE_CONTROL_CONTINUE`).
+    Continue,
+}
+
+/// Forwards a service control event to the listeners registered with
+/// [`service_stop`], [`service_pause`] and [`service_continue`].
+///
+/// Tokio cannot register the service control handler itself — that is owned
+/// by the application's service entry point (typically via a crate such as
+/// `windows-service`). Call this function from the registered handler to
+/// bridge the event into tokio's signal infrastructure.
+///
+/// Returns `true` if at least one listener received the event, which the
+/// handler can use to decide whether to report the control code as handled
+/// to the service control manager.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::signal::windows::{self, ServiceControl};
+///
+/// // Inside the service control handler registered by the application:
+/// fn control_handler(code: u32) {
+///     if code == 1 {
+///         // SERVICE_CONTROL_STOP
+///         windows::dispatch_service_control(ServiceControl::Stop);
+///     }
+/// }
+/// ```
+pub fn dispatch_service_control(control: ServiceControl) -> bool {
+    self::imp::dispatch_service_control(control)
+}
+
+/// Creates a new listener which receives service "stop" controls forwarded
+/// with [`dispatch_service_control`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::signal::windows::service_stop;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     // A listener of service stop events.
+///     let mut signal = service_stop()?;
+///
+///     signal.recv().await;
+///     println!("got service stop. Cleaning up before exiting");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn service_stop() -> io::Result<ServiceStop> {
+    Ok(ServiceStop {
+        inner: self::imp::service_stop()?,
+    })
+}
+
+/// Represents a listener which receives service "stop" controls forwarded with
+/// [`dispatch_service_control`].
+///
+/// A forwarded control notifies *all* listeners listening for
+/// this event. Moreover, the notifications **are coalesced** if they aren't processed
+/// quickly enough. This means that if two notifications are received back-to-back,
+/// then the listener may only receive one item about the two notifications.
+#[must_use = "listeners do nothing unless polled"]
+#[derive(Debug)]
+pub struct ServiceStop {
+    inner: RxFuture,
+}
+
+impl ServiceStop {
+    /// Receives the next service stop event.
+    ///
+    /// `None` is returned if no more events can be received by this listener.
+    pub async fn recv(&mut self) -> Option<()> {
+        self.inner.recv().await
+    }
+
+    /// Polls to receive the next service stop event, outside of an
+    /// `async` context.
+    ///
+    /// `None` is returned if no more events can be received by this listener.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.inner.poll_recv(cx)
+    }
+}
+
+/// Creates a new listener which receives service "pause" controls forwarded
+/// with [`dispatch_service_control`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::signal::windows::service_pause;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     // A listener of service pause events.
+///     let mut signal = service_pause()?;
+///
+///     signal.recv().await;
+///     println!("got service pause. Suspending background work");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn service_pause() -> io::Result<ServicePause> {
+    Ok(ServicePause {
+        inner: self::imp::service_pause()?,
+    })
+}
+
+/// Represents a listener which receives service "pause" controls forwarded with
+/// [`dispatch_service_control`].
+///
+/// A forwarded control notifies *all* listeners listening for
+/// this event. Moreover, the notifications **are coalesced** if they aren't processed
+/// quickly enough. This means that if two notifications are received back-to-back,
+/// then the listener may only receive one item about the two notifications.
+#[must_use = "listeners do nothing unless polled"]
+#[derive(Debug)]
+pub struct ServicePause {
+    inner: RxFuture,
+}
+
+impl ServicePause {
+    /// Receives the next service pause event.
+    ///
+    /// `None` is returned if no more events can be received by this listener.
+    pub async fn recv(&mut self) -> Option<()> {
+        self.inner.recv().await
+    }
+
+    /// Polls to receive the next service pause event, outside of an
+    /// `async` context.
+    ///
+    /// `None` is returned if no more events can be received by this listener.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.inner.poll_recv(cx)
+    }
+}
+
+/// Creates a new listener which receives service "continue" controls forwarded
+/// with [`dispatch_service_control`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::signal::windows::service_continue;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     // A listener of service continue events.
+///     let mut signal = service_continue()?;
+///
+///     signal.recv().await;
+///     println!("got service continue. Resuming background work");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn service_continue() -> io::Result<ServiceContinue> {
+    Ok(ServiceContinue {
+        inner: self::imp::service_continue()?,
+    })
+}
+
+/// Represents a listener which receives service "continue" controls forwarded
+/// with [`dispatch_service_control`].
+///
+/// A forwarded control notifies *all* listeners listening for
+/// this event. Moreover, the notifications **are coalesced** if they aren't processed
+/// quickly enough. This means that if two notifications are received back-to-back,
+/// then the listener may only receive one item about the two notifications.
+#[must_use = "listeners do nothing unless polled"]
+#[derive(Debug)]
+pub struct ServiceContinue {
+    inner: RxFuture,
+}
+
+impl ServiceContinue {
+    /// Receives the next service continue event.
+    ///
+    /// `None` is returned if no more events can be received by this listener.
+    pub async fn recv(&mut self) -> Option<()> {
+        self.inner.recv().await
+    }
+
+    /// Polls to receive the next service continue event, outside of an
+    /// `async` context.
+    ///
+    /// `None` is returned if no more events can be received by this listener.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.inner.poll_recv(cx)
+    }
+}
+
 /// Creates a new listener which receives "ctrl-logoff" notifications sent to the
 /// process.
 ///
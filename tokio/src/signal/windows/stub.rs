@@ -23,3 +23,19 @@ pub(super) fn ctrl_logoff() -> io::Result<RxFuture> {
 pub(super) fn ctrl_shutdown() -> io::Result<RxFuture> {
     panic!()
 }
+
+pub(super) fn service_stop() -> io::Result<RxFuture> {
+    panic!()
+}
+
+pub(super) fn service_pause() -> io::Result<RxFuture> {
+    panic!()
+}
+
+pub(super) fn service_continue() -> io::Result<RxFuture> {
+    panic!()
+}
+
+pub(super) fn dispatch_service_control(_control: super::ServiceControl) -> bool {
+    panic!()
+}
@@ -4,6 +4,8 @@ use std::sync::Once;
 use crate::signal::registry::{globals, EventId, EventInfo, Init, Storage};
 use crate::signal::RxFuture;
 
+use super::ServiceControl;
+
 use windows_sys::Win32::Foundation::BOOL;
 use windows_sys::Win32::System::Console as console;
 
@@ -27,12 +29,55 @@ pub(super) fn ctrl_shutdown() -> io::Result<RxFuture> {
     new(console::CTRL_SHUTDOWN_EVENT)
 }
 
+pub(super) fn service_stop() -> io::Result<RxFuture> {
+    new_service(SERVICE_STOP_ID)
+}
+
+pub(super) fn service_pause() -> io::Result<RxFuture> {
+    new_service(SERVICE_PAUSE_ID)
+}
+
+pub(super) fn service_continue() -> io::Result<RxFuture> {
+    new_service(SERVICE_CONTINUE_ID)
+}
+
 fn new(signum: u32) -> io::Result<RxFuture> {
     global_init()?;
     let rx = globals().register_listener(signum as EventId);
     Ok(RxFuture::new(rx))
 }
 
+// Service control events are delivered by the application's own service
+// control handler via `dispatch_service_control`, so unlike the console
+// events no global handler needs to be installed.
+fn new_service(event_id: EventId) -> io::Result<RxFuture> {
+    Ok(RxFuture::new(globals().register_listener(event_id)))
+}
+
+// The console control events occupy the low event ids; the service control
+// events are mapped into a disjoint range.
+const SERVICE_STOP_ID: EventId = 256;
+const SERVICE_PAUSE_ID: EventId = 257;
+const SERVICE_CONTINUE_ID: EventId = 258;
+
+fn service_event_id(control: ServiceControl) -> EventId {
+    match control {
+        ServiceControl::Stop => SERVICE_STOP_ID,
+        ServiceControl::Pause => SERVICE_PAUSE_ID,
+        ServiceControl::Continue => SERVICE_CONTINUE_ID,
+    }
+}
+
+pub(super) fn dispatch_service_control(control: ServiceControl) -> bool {
+    let globals = globals();
+    globals.record_event(service_event_id(control));
+
+    // The service control handler runs on a thread owned by the service
+    // control manager, not in a signal handler context, so the broadcast can
+    // happen inline just as in the console handler below.
+    globals.broadcast()
+}
+
 fn event_requires_infinite_sleep_in_handler(signum: u32) -> bool {
     // Returning from the handler function of those events immediately terminates the process.
     // So for async systems, the easiest solution is to simply never return from
@@ -55,6 +100,9 @@ pub(crate) struct OsStorage {
     ctrl_c: EventInfo,
     ctrl_logoff: EventInfo,
     ctrl_shutdown: EventInfo,
+    service_stop: EventInfo,
+    service_pause: EventInfo,
+    service_continue: EventInfo,
 }
 
 impl Init for OsStorage {
@@ -65,12 +113,22 @@ impl Init for OsStorage {
             ctrl_c: Default::default(),
             ctrl_logoff: Default::default(),
             ctrl_shutdown: Default::default(),
+            service_stop: Default::default(),
+            service_pause: Default::default(),
+            service_continue: Default::default(),
         }
     }
 }
 
 impl Storage for OsStorage {
     fn event_info(&self, id: EventId) -> Option<&EventInfo> {
+        match id {
+            SERVICE_STOP_ID => return Some(&self.service_stop),
+            SERVICE_PAUSE_ID => return Some(&self.service_pause),
+            SERVICE_CONTINUE_ID => return Some(&self.service_continue),
+            _ => {}
+        }
+
         match u32::try_from(id) {
             Ok(console::CTRL_BREAK_EVENT) => Some(&self.ctrl_break),
             Ok(console::CTRL_CLOSE_EVENT) => Some(&self.ctrl_close),
@@ -90,6 +148,9 @@ impl Storage for OsStorage {
         f(&self.ctrl_c);
         f(&self.ctrl_logoff);
         f(&self.ctrl_shutdown);
+        f(&self.service_stop);
+        f(&self.service_pause);
+        f(&self.service_continue);
     }
 }
 
@@ -254,6 +315,33 @@ mod tests {
         });
     }
 
+    #[test]
+    fn service_controls() {
+        let rt = rt();
+
+        rt.block_on(async {
+            let mut stop = assert_ok!(crate::signal::windows::service_stop());
+            let mut pause = assert_ok!(crate::signal::windows::service_pause());
+            let mut resume = assert_ok!(crate::signal::windows::service_continue());
+
+            // Forward the controls as a service control handler would.
+            assert!(crate::signal::windows::dispatch_service_control(
+                ServiceControl::Stop
+            ));
+            stop.recv().await.unwrap();
+
+            assert!(crate::signal::windows::dispatch_service_control(
+                ServiceControl::Pause
+            ));
+            pause.recv().await.unwrap();
+
+            assert!(crate::signal::windows::dispatch_service_control(
+                ServiceControl::Continue
+            ));
+            resume.recv().await.unwrap();
+        });
+    }
+
     fn rt() -> Runtime {
         crate::runtime::Builder::new_current_thread()
             .build()
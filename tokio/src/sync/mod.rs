@@ -470,6 +470,12 @@ cfg_sync! {
     pub(crate) mod batch_semaphore;
     pub use batch_semaphore::{AcquireError, TryAcquireError};
 
+    #[cfg(feature = "time")]
+    mod rate_limiter;
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "sync", feature = "time"))))]
+    pub use rate_limiter::RateLimiter;
+
     mod semaphore;
     pub use semaphore::{Semaphore, SemaphorePermit, OwnedSemaphorePermit};
 
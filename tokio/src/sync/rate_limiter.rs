@@ -0,0 +1,192 @@
+use crate::time::{sleep_until, Duration, Instant};
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+/// An asynchronous token-bucket rate limiter.
+///
+/// A `RateLimiter` hands out permits at a configured steady rate: `rate`
+/// permits per `per`. Permits that are not used accumulate, up to the burst
+/// capacity, so a consumer that falls behind may briefly catch up before being
+/// limited to the steady rate again.
+///
+/// Unlike a [`Semaphore`], permits are not returned to the limiter; they are
+/// replenished by the passage of time. Because the limiter only takes `&self`,
+/// it can be wrapped in an [`Arc`] and shared so that several tasks — or
+/// several streams — draw from one quota.
+///
+/// Waiters are not strictly FIFO: when a permit becomes available, any of the
+/// tasks waiting in [`acquire`] may obtain it.
+///
+/// [`Semaphore`]: crate::sync::Semaphore
+/// [`acquire`]: RateLimiter::acquire
+///
+/// # Examples
+///
+/// ```
+/// use tokio::sync::RateLimiter;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     // At most 100 requests per second, with bursts of up to 100.
+///     let limiter = RateLimiter::new(100, Duration::from_secs(1));
+///
+///     limiter.acquire().await;
+///     // send a request
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Nanoseconds between two permits at the steady rate.
+    interval: u128,
+    burst: u64,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    /// Permits that can be handed out immediately.
+    available: u64,
+    /// The instant the bucket was last refilled to `available` permits.
+    refilled: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter that releases `rate` permits per `per`,
+    /// with a burst capacity of `rate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is zero or `per` is zero.
+    #[track_caller]
+    pub fn new(rate: u64, per: Duration) -> Self {
+        Self::with_burst(rate, per, rate)
+    }
+
+    /// Creates a new rate limiter that releases `rate` permits per `per` and
+    /// allows at most `burst` unused permits to accumulate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate`, `per`, or `burst` is zero.
+    #[track_caller]
+    pub fn with_burst(rate: u64, per: Duration, burst: u64) -> Self {
+        assert!(rate > 0, "`rate` must be non-zero.");
+        assert!(per > Duration::ZERO, "`per` must be non-zero.");
+        assert!(burst > 0, "`burst` must be non-zero.");
+
+        let interval = per.as_nanos() / u128::from(rate);
+        assert!(
+            interval > 0,
+            "`rate` must not exceed one permit per nanosecond."
+        );
+
+        RateLimiter {
+            interval,
+            burst,
+            state: Mutex::new(State {
+                available: burst,
+                refilled: Instant::now(),
+            }),
+        }
+    }
+
+    /// Acquires a permit, waiting until one is available.
+    pub async fn acquire(&self) {
+        self.acquire_many(1).await;
+    }
+
+    /// Acquires `n` permits, waiting until all of them have been released.
+    ///
+    /// Permits are drawn from the bucket as they become available, so a call
+    /// for more permits than the burst capacity is satisfied over several
+    /// refill intervals. If the returned future is dropped before it
+    /// completes, permits already drawn are lost.
+    pub async fn acquire_many(&self, n: u64) {
+        let mut remaining = n;
+        loop {
+            let deadline = match self.take_up_to(&mut remaining) {
+                Ok(()) => return,
+                Err(deadline) => deadline,
+            };
+
+            sleep_until(deadline).await;
+        }
+    }
+
+    /// Acquires a permit, waiting until one is available.
+    ///
+    /// This is equivalent to [`acquire`], but takes `Arc<Self>` so the
+    /// returned future does not borrow the limiter. This is mostly useful for
+    /// adapters that store the future, such as
+    /// [`StreamExt::rate_limit`](https://docs.rs/tokio-stream/latest/tokio_stream/trait.StreamExt.html#method.rate_limit).
+    ///
+    /// [`acquire`]: RateLimiter::acquire
+    pub async fn acquire_owned(self: Arc<Self>) {
+        self.acquire().await;
+    }
+
+    /// Acquires `n` permits, waiting until all of them have been released.
+    ///
+    /// This is equivalent to [`acquire_many`], but takes `Arc<Self>` so the
+    /// returned future does not borrow the limiter.
+    ///
+    /// [`acquire_many`]: RateLimiter::acquire_many
+    pub async fn acquire_many_owned(self: Arc<Self>, n: u64) {
+        self.acquire_many(n).await;
+    }
+
+    /// Tries to acquire a permit without waiting.
+    ///
+    /// Returns `true` if a permit was acquired.
+    pub fn try_acquire(&self) -> bool {
+        let mut n = 1;
+        self.take_up_to(&mut n).is_ok()
+    }
+
+    /// Takes up to `n` permits, decrementing `n` by the number taken, or
+    /// reports when waiting for the remainder should resume.
+    fn take_up_to(&self, n: &mut u64) -> Result<(), Instant> {
+        if *n == 0 {
+            return Ok(());
+        }
+
+        let mut state = self.state.lock().unwrap();
+
+        // Refill the bucket with the permits released since the last refill,
+        // keeping the remainder by only advancing `refilled` in whole permit
+        // intervals.
+        let elapsed = state.refilled.elapsed().as_nanos();
+        let released = elapsed / self.interval;
+        let new = std::cmp::min(released, u128::from(self.burst - state.available)) as u64;
+        state.available += new;
+        if state.available == self.burst {
+            state.refilled = Instant::now();
+        } else {
+            state.refilled += nanos_to_duration(self.interval * u128::from(new));
+        }
+
+        let taken = std::cmp::min(*n, state.available);
+        state.available -= taken;
+        *n -= taken;
+
+        if *n == 0 {
+            Ok(())
+        } else {
+            // Sleep until the remaining permits have been released at the
+            // steady rate, but never past the point where the bucket is
+            // full, since permits released after that are lost.
+            let wait = std::cmp::min(*n, self.burst);
+            Err(state.refilled + nanos_to_duration(self.interval * u128::from(wait)))
+        }
+    }
+}
+
+fn nanos_to_duration(nanos: u128) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
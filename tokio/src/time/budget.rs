@@ -0,0 +1,86 @@
+use crate::time::{timeout_at, Duration, Instant, Timeout};
+
+use std::future::IntoFuture;
+
+/// Creates a latency budget of `total` duration, starting now.
+///
+/// The returned [`Budget`] tracks a single deadline `total` from the moment of
+/// the call. Wrapping each of a sequence of operations with
+/// [`Budget::timeout_remaining`] gives every operation only the time that is
+/// left, so the whole sequence is bounded by `total` rather than by the sum of
+/// per-operation timeouts. This implements per-request total latency budgets
+/// across N downstream calls.
+///
+/// If the total would overflow the clock, the budget is effectively
+/// unlimited.
+///
+/// # Examples
+///
+/// ```
+/// use tokio::time::{self, Duration};
+///
+/// # async fn first_call() {}
+/// # async fn second_call() {}
+/// # async fn dox() {
+/// let budget = time::budget(Duration::from_millis(500));
+///
+/// // Each call gets whatever is left of the 500ms, not 500ms each.
+/// budget.timeout_remaining(first_call()).await.unwrap();
+/// budget.timeout_remaining(second_call()).await.unwrap();
+/// # }
+/// ```
+pub fn budget(total: Duration) -> Budget {
+    let deadline = match Instant::now().checked_add(total) {
+        Some(deadline) => deadline,
+        None => Instant::far_future(),
+    };
+
+    Budget { deadline }
+}
+
+/// A total latency budget created by [`budget`].
+///
+/// The budget is a fixed deadline: [`remaining`] shrinks as time passes, and
+/// [`timeout_remaining`] wraps a future with whatever is left. The handle is
+/// `Copy`, so it can be passed freely down a call stack.
+///
+/// [`remaining`]: Budget::remaining
+/// [`timeout_remaining`]: Budget::timeout_remaining
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    deadline: Instant,
+}
+
+impl Budget {
+    /// Returns the instant at which the budget runs out.
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// Returns the time left in the budget, or [`Duration::ZERO`] if it is
+    /// exhausted.
+    pub fn remaining(&self) -> Duration {
+        self.deadline.saturating_duration_since(Instant::now())
+    }
+
+    /// Returns `true` if the budget has run out.
+    pub fn is_exhausted(&self) -> bool {
+        self.deadline <= Instant::now()
+    }
+
+    /// Requires `future` to complete before the budget runs out.
+    ///
+    /// Equivalent to [`timeout_at`]`(self.deadline(), future)`: the future
+    /// gets the remaining budget, not the original total. Once the budget is
+    /// exhausted, wrapped futures that do not complete on first poll fail
+    /// with [`Elapsed`].
+    ///
+    /// [`timeout_at`]: crate::time::timeout_at()
+    /// [`Elapsed`]: crate::time::error::Elapsed
+    pub fn timeout_remaining<F>(&self, future: F) -> Timeout<F::IntoFuture>
+    where
+        F: IntoFuture,
+    {
+        timeout_at(self.deadline, future)
+    }
+}
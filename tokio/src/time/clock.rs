@@ -89,6 +89,40 @@ cfg_test_util! {
 
         /// Number of `inhibit_auto_advance` calls still in effect.
         auto_advance_inhibit_count: usize,
+
+        /// How the clock advances when the runtime is idle.
+        auto_advance: AutoAdvance,
+    }
+
+    /// Defines how a paused clock advances when the runtime has no work to do.
+    ///
+    /// Passed to [`pause_with`] to choose between the default auto-advancing
+    /// behavior and fully manual control via [`advance`].
+    ///
+    /// [`pause_with`]: crate::time::pause_with
+    /// [`advance`]: crate::time::advance
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum AutoAdvance {
+        /// When the runtime is idle, jump the clock directly to the next
+        /// pending timer.
+        ///
+        /// This is the behavior of [`pause`](crate::time::pause) and the
+        /// default for a paused clock.
+        OnIdle,
+
+        /// Never advance the clock automatically.
+        ///
+        /// Time only moves through explicit calls to
+        /// [`advance`](crate::time::advance). A task that awaits a timer
+        /// without something else advancing the clock will wait forever.
+        Never,
+
+        /// When the runtime is idle, advance the clock by at most the given
+        /// step at a time, instead of jumping directly to the next timer.
+        ///
+        /// The step must be non-zero.
+        Step(Duration),
     }
 
     /// Pauses time.
@@ -136,6 +170,37 @@ cfg_test_util! {
         });
     }
 
+    /// Pauses time, configuring how the clock advances while the runtime is
+    /// idle.
+    ///
+    /// This is [`pause`] with an explicit [`AutoAdvance`] strategy:
+    /// `pause_with(AutoAdvance::OnIdle)` is equivalent to `pause()`. Use
+    /// [`AutoAdvance::Never`] for fully deterministic tests where time only
+    /// moves through [`advance`], or [`AutoAdvance::Step`] to advance the
+    /// clock in fixed increments instead of jumping straight to the next
+    /// timer.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`pause`], or if `auto_advance` is
+    /// [`AutoAdvance::Step`] with a zero step.
+    ///
+    /// [`pause`]: crate::time::pause
+    /// [`advance`]: crate::time::advance
+    #[track_caller]
+    pub fn pause_with(auto_advance: AutoAdvance) {
+        if let AutoAdvance::Step(step) = auto_advance {
+            assert!(!step.is_zero(), "auto-advance step must be non-zero");
+        }
+
+        with_clock(|maybe_clock| {
+            match maybe_clock {
+                Some(clock) => clock.pause_with(auto_advance),
+                None => Err("time cannot be frozen from outside the Tokio runtime"),
+            }
+        });
+    }
+
     /// Resumes time.
     ///
     /// Clears the saved `Instant::now()` value. Subsequent calls to
@@ -245,6 +310,7 @@ cfg_test_util! {
                     base: now,
                     unfrozen: Some(now),
                     auto_advance_inhibit_count: 0,
+                    auto_advance: AutoAdvance::OnIdle,
                 }),
             };
 
@@ -258,6 +324,10 @@ cfg_test_util! {
         }
 
         pub(crate) fn pause(&self) -> Result<(), &'static str> {
+            self.pause_with(AutoAdvance::OnIdle)
+        }
+
+        pub(crate) fn pause_with(&self, auto_advance: AutoAdvance) -> Result<(), &'static str> {
             let mut inner = self.inner.lock();
 
             if !inner.enable_pausing {
@@ -274,6 +344,7 @@ cfg_test_util! {
             };
             inner.base += elapsed;
             inner.unfrozen = None;
+            inner.auto_advance = auto_advance;
 
             Ok(())
         }
@@ -291,7 +362,19 @@ cfg_test_util! {
 
         pub(crate) fn can_auto_advance(&self) -> bool {
             let inner = self.inner.lock();
-            inner.unfrozen.is_none() && inner.auto_advance_inhibit_count == 0
+            inner.unfrozen.is_none()
+                && inner.auto_advance_inhibit_count == 0
+                && inner.auto_advance != AutoAdvance::Never
+        }
+
+        /// Limits an auto-advance to the configured step, if any.
+        pub(crate) fn clamp_auto_advance(&self, duration: Duration) -> Duration {
+            let inner = self.inner.lock();
+
+            match inner.auto_advance {
+                AutoAdvance::Step(step) => std::cmp::min(duration, step),
+                _ => duration,
+            }
         }
 
         pub(crate) fn advance(&self, duration: Duration) -> Result<(), &'static str> {
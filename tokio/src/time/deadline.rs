@@ -0,0 +1,114 @@
+use crate::time::{Duration, Instant};
+
+use std::future::Future;
+
+// Expanded by hand from `task_local!`: macro-expanded `#[macro_export]`
+// macros cannot be invoked by absolute path from within the defining crate.
+static DEADLINE: crate::task::LocalKey<Instant> = {
+    std::thread_local! {
+        static __KEY: std::cell::RefCell<Option<Instant>> = const { std::cell::RefCell::new(None) };
+    }
+
+    crate::task::LocalKey { inner: __KEY }
+};
+
+/// A point in time propagated through the task-local context.
+///
+/// A `Deadline` represents the instant by which all work in a scope must be
+/// finished. Entering a scope with [`Deadline::scope`] stores the deadline in
+/// a task-local, and [`timeout`] and [`timeout_at`] automatically clamp their
+/// own deadline to the stored one. This lets nested operations respect the
+/// outermost deadline without threading remaining-time budgets through every
+/// call in between.
+///
+/// Nested scopes only ever tighten the deadline: entering a scope with a later
+/// deadline than the current one keeps the earlier deadline.
+///
+/// # Examples
+///
+/// A request handler sets an overall budget once; the database call deep in
+/// the stack cannot outlive it even though it asks for more time:
+///
+/// ```
+/// use tokio::time::{timeout, Deadline, Duration};
+///
+/// async fn query_database() -> Result<(), tokio::time::error::Elapsed> {
+///     // This asks for 30 seconds, but runs under the 10 millisecond budget
+///     // set by the caller, so it gives up after 10 milliseconds.
+///     timeout(Duration::from_secs(30), std::future::pending::<()>()).await
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     # tokio::time::pause();
+///     let res = Deadline::after(Duration::from_millis(10))
+///         .scope(query_database())
+///         .await;
+///     assert!(res.is_err());
+/// }
+/// ```
+///
+/// [`timeout`]: crate::time::timeout()
+/// [`timeout_at`]: crate::time::timeout_at()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Deadline(Instant);
+
+impl Deadline {
+    /// Creates a deadline at the given instant.
+    pub fn at(instant: Instant) -> Deadline {
+        Deadline(instant)
+    }
+
+    /// Creates a deadline `duration` from now.
+    ///
+    /// If the duration overflows the clock, the deadline is effectively
+    /// "never".
+    pub fn after(duration: Duration) -> Deadline {
+        match Instant::now().checked_add(duration) {
+            Some(instant) => Deadline(instant),
+            None => Deadline(Instant::far_future()),
+        }
+    }
+
+    /// Returns the instant at which this deadline expires.
+    pub fn instant(self) -> Instant {
+        self.0
+    }
+
+    /// Returns the time remaining until the deadline, or [`Duration::ZERO`]
+    /// if it has already passed.
+    pub fn remaining(self) -> Duration {
+        self.0.saturating_duration_since(Instant::now())
+    }
+
+    /// Returns `true` if the deadline has passed.
+    pub fn is_elapsed(self) -> bool {
+        self.0 <= Instant::now()
+    }
+
+    /// Returns the deadline of the current task-local scope, if one is set.
+    ///
+    /// This is the innermost (and therefore earliest) deadline established by
+    /// an enclosing [`Deadline::scope`] call on the current task.
+    pub fn current() -> Option<Deadline> {
+        DEADLINE.try_with(|deadline| Deadline(*deadline)).ok()
+    }
+
+    /// Runs the provided future with this deadline set as the task-local
+    /// deadline.
+    ///
+    /// If an enclosing scope already established an earlier deadline, the
+    /// earlier deadline is kept: a nested scope can only tighten the budget,
+    /// never extend it.
+    pub async fn scope<F>(self, f: F) -> F::Output
+    where
+        F: Future,
+    {
+        let deadline = match Deadline::current() {
+            Some(outer) if outer.0 < self.0 => outer.0,
+            _ => self.0,
+        };
+
+        DEADLINE.scope(deadline, f).await
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::error;
 use std::fmt;
+use std::time::Duration;
 
 /// Errors encountered by the timer implementation.
 ///
@@ -44,7 +45,7 @@ impl From<Kind> for Error {
 /// This error is returned when a timeout expires before the function was able
 /// to finish.
 #[derive(Debug, PartialEq, Eq)]
-pub struct Elapsed(());
+pub struct Elapsed(Option<Duration>);
 
 #[derive(Debug)]
 pub(crate) enum InsertError {
@@ -103,8 +104,21 @@ impl fmt::Display for Error {
 // ===== impl Elapsed =====
 
 impl Elapsed {
-    pub(crate) fn new() -> Self {
-        Elapsed(())
+    pub(crate) fn with_ran(ran: Duration) -> Self {
+        Elapsed(Some(ran))
+    }
+
+    /// Returns how long the inner future actually ran before the deadline
+    /// elapsed, measured from its first poll.
+    ///
+    /// Returns `None` if the timeout expired before the inner future was
+    /// first polled, or if the source of the error did not track this
+    /// information.
+    ///
+    /// Retry logic can use this to tell a near-miss from a future that made
+    /// no progress at all.
+    pub fn ran(&self) -> Option<Duration> {
+        self.0
     }
 }
 
@@ -0,0 +1,90 @@
+use crate::runtime::metrics::HistogramBuilder;
+use crate::runtime::HistogramConfiguration;
+use crate::time::Duration;
+
+use std::ops::Range;
+
+/// A latency histogram with lock-free recording.
+///
+/// `Histogram` uses the same bucketing implementations as the runtime's
+/// poll-time histogram, configured through [`HistogramConfiguration`]. This
+/// lets applications record request latencies with the same bucket layout
+/// they already use for runtime metrics, so the two can be exported and
+/// compared side by side.
+///
+/// Recording is a single atomic increment on the target bucket, so a
+/// `Histogram` can be shared across tasks (for example in an `Arc`) and
+/// recorded into concurrently without locking.
+///
+/// **Note**: This is an [unstable API][unstable]. The public API of this type
+/// may break in 1.x releases. See [the documentation on unstable
+/// features][unstable] for details.
+///
+/// [unstable]: crate#unstable-features
+///
+/// # Examples
+///
+/// ```
+/// use tokio::runtime::{HistogramConfiguration, LogHistogram};
+/// use tokio::time::{Duration, Histogram, Instant};
+///
+/// let histogram = Histogram::new(HistogramConfiguration::log(LogHistogram::default()));
+///
+/// let start = Instant::now();
+/// // ... handle a request ...
+/// histogram.record(start.elapsed());
+///
+/// for bucket in 0..histogram.num_buckets() {
+///     let range = histogram.bucket_range(bucket);
+///     let count = histogram.bucket_count(bucket);
+///     println!("{range:?}: {count}");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Histogram {
+    inner: crate::runtime::metrics::Histogram,
+}
+
+impl Histogram {
+    /// Creates a new histogram with the given bucket configuration.
+    pub fn new(configuration: HistogramConfiguration) -> Histogram {
+        let builder = HistogramBuilder {
+            histogram_type: configuration.inner,
+            legacy: None,
+        };
+
+        Histogram {
+            inner: builder.build(),
+        }
+    }
+
+    /// Records a single duration.
+    ///
+    /// Durations beyond the range of the last bucket are counted in the last
+    /// bucket.
+    pub fn record(&self, value: Duration) {
+        let nanos = u64::try_from(value.as_nanos()).unwrap_or(u64::MAX);
+        self.inner.record(nanos);
+    }
+
+    /// Returns the number of buckets.
+    pub fn num_buckets(&self) -> usize {
+        self.inner.num_buckets()
+    }
+
+    /// Returns the range of durations counted by the given bucket.
+    pub fn bucket_range(&self, bucket: usize) -> Range<Duration> {
+        let range = self.inner.bucket_range(bucket);
+
+        Range {
+            start: Duration::from_nanos(range.start),
+            end: Duration::from_nanos(range.end),
+        }
+    }
+
+    /// Returns the number of values recorded into the given bucket.
+    #[cfg(target_has_atomic = "64")]
+    pub fn bucket_count(&self, bucket: usize) -> u64 {
+        self.inner.get(bucket)
+    }
+}
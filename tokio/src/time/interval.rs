@@ -460,6 +460,69 @@ impl Interval {
     /// [`Context`] passed to the most recent call is scheduled to receive a
     /// wakeup.
     pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Instant> {
+        self.poll_tick_info(cx).map(|tick| tick.scheduled())
+    }
+
+    /// Completes when the next instant in the interval has been reached,
+    /// returning details about the tick.
+    ///
+    /// This is [`tick`] with extra reporting: in addition to the instant the
+    /// tick was scheduled for, the returned [`Tick`] carries the instant the
+    /// tick actually fired and the number of ticks that were skipped since
+    /// the previous one under [`MissedTickBehavior::Skip`]. Metrics pipelines
+    /// can use this to track how many intervals were dropped.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancellation safe. If `tick_info` is used as the branch
+    /// in a `tokio::select!` and another branch completes first, then no tick
+    /// has been consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::time;
+    ///
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut interval = time::interval(Duration::from_millis(10));
+    ///
+    ///     let tick = interval.tick_info().await;
+    ///     assert!(tick.actual() >= tick.scheduled());
+    ///     assert_eq!(tick.skipped(), 0);
+    /// }
+    /// ```
+    ///
+    /// [`tick`]: Interval::tick
+    pub async fn tick_info(&mut self) -> Tick {
+        #[cfg(all(tokio_unstable, feature = "tracing"))]
+        let resource_span = self.resource_span.clone();
+        #[cfg(all(tokio_unstable, feature = "tracing"))]
+        let tick = trace::async_op(
+            || poll_fn(|cx| self.poll_tick_info(cx)),
+            resource_span,
+            "Interval::tick_info",
+            "poll_tick_info",
+            false,
+        );
+        #[cfg(not(all(tokio_unstable, feature = "tracing")))]
+        let tick = poll_fn(|cx| self.poll_tick_info(cx));
+
+        tick.await
+    }
+
+    /// Polls for the next instant in the interval to be reached, returning
+    /// details about the tick.
+    ///
+    /// This is the poll-based equivalent of [`tick_info`]; it behaves like
+    /// [`poll_tick`] but reports the scheduled instant, the actual instant
+    /// and the number of skipped ticks through the returned [`Tick`].
+    ///
+    /// [`tick_info`]: Interval::tick_info
+    /// [`poll_tick`]: Interval::poll_tick
+    pub fn poll_tick_info(&mut self, cx: &mut Context<'_>) -> Poll<Tick> {
         // Wait for the delay to be done
         ready!(Pin::new(&mut self.delay).poll(cx));
 
@@ -475,7 +538,14 @@ impl Interval {
         // However, if a tick took excessively long and we are now behind,
         // schedule the next tick according to how the user specified with
         // `MissedTickBehavior`
+        let mut skipped = 0;
         let next = if now > timeout + Duration::from_millis(5) {
+            if self.missed_tick_behavior == MissedTickBehavior::Skip {
+                // Whole periods between the scheduled tick and now will never
+                // be yielded; report them as skipped.
+                skipped = ((now - timeout).as_nanos() / self.period.as_nanos()) as u64;
+            }
+
             self.missed_tick_behavior
                 .next_timeout(timeout, now, self.period)
         } else {
@@ -490,7 +560,11 @@ impl Interval {
         self.delay.as_mut().reset_without_reregister(next);
 
         // Return the time when we were scheduled to tick
-        Poll::Ready(timeout)
+        Poll::Ready(Tick {
+            scheduled: timeout,
+            actual: now,
+            skipped,
+        })
     }
 
     /// Resets the interval to complete one period after the current time.
@@ -641,3 +715,43 @@ impl Interval {
         self.period
     }
 }
+
+/// Details about a single tick of an [`Interval`], returned by
+/// [`Interval::tick_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tick {
+    scheduled: Instant,
+    actual: Instant,
+    skipped: u64,
+}
+
+impl Tick {
+    /// Returns the instant the tick was scheduled to fire at.
+    ///
+    /// This is the value that [`Interval::tick`] would have returned.
+    pub fn scheduled(&self) -> Instant {
+        self.scheduled
+    }
+
+    /// Returns the instant the tick actually fired at.
+    pub fn actual(&self) -> Instant {
+        self.actual
+    }
+
+    /// Returns how far behind schedule the tick fired.
+    ///
+    /// Equivalent to `actual() - scheduled()`, saturating to zero if the tick
+    /// fired early.
+    pub fn delay(&self) -> Duration {
+        self.actual.saturating_duration_since(self.scheduled)
+    }
+
+    /// Returns the number of ticks that were skipped since the previous tick.
+    ///
+    /// This is only non-zero under [`MissedTickBehavior::Skip`], where whole
+    /// periods that pass while the interval is not being polled are dropped
+    /// rather than fired late.
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+}
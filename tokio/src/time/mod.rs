@@ -87,20 +87,38 @@
 mod clock;
 pub(crate) use self::clock::Clock;
 cfg_test_util! {
-    pub use clock::{advance, pause, resume};
+    pub use clock::{advance, pause, pause_with, resume, AutoAdvance};
+}
+
+mod budget;
+pub use budget::{budget, Budget};
+
+cfg_rt! {
+    mod deadline;
+    pub use deadline::Deadline;
 }
 
 pub mod error;
 
+cfg_rt! {
+    cfg_unstable_metrics! {
+        mod histogram;
+        pub use histogram::Histogram;
+    }
+}
+
 mod instant;
 pub use self::instant::Instant;
 
 mod interval;
-pub use interval::{interval, interval_at, Interval, MissedTickBehavior};
+pub use interval::{interval, interval_at, Interval, MissedTickBehavior, Tick};
 
 mod sleep;
 pub use sleep::{sleep, sleep_until, Sleep};
 
+mod sleep_system;
+pub use sleep_system::sleep_until_system;
+
 mod timeout;
 #[doc(inline)]
 pub use timeout::{timeout, timeout_at, Timeout};
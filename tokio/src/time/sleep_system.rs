@@ -0,0 +1,69 @@
+use crate::time::{sleep, Duration};
+
+use std::time::SystemTime;
+
+/// Maximum length of a single nap taken while waiting for the wall clock to
+/// reach the deadline. Keeping individual naps short bounds how long it takes
+/// to notice a wall-clock jump (NTP step, manual adjustment, suspend/resume).
+const MAX_NAP: Duration = Duration::from_secs(1);
+
+/// Waits until the system (wall) clock reaches `deadline`.
+///
+/// Unlike [`sleep_until`], which measures time using the monotonic clock, this
+/// function repeatedly consults [`SystemTime::now`] and therefore tracks
+/// wall-clock adjustments such as NTP steps, manual clock changes, and time
+/// that passes while the machine is suspended. This makes it suitable for
+/// schedulers that need to fire "at 02:00" rather than "in eight hours".
+///
+/// The wall clock is sampled roughly once per second while waiting, so the
+/// future completes within about a second of the system clock reaching the
+/// deadline, even if the clock jumps forward past it.
+///
+/// # Clock moving backwards
+///
+/// If the system clock is set backwards while this future is pending, the
+/// future keeps waiting until the wall clock reaches `deadline` again. In
+/// other words, the deadline is always interpreted against the current
+/// reading of the system clock; a backwards jump extends the wait and a
+/// forwards jump shortens it. If `deadline` is already in the past when the
+/// future is first polled, it completes immediately.
+///
+/// # Cancellation
+///
+/// Canceling this future is done by dropping it. No additional cleanup work
+/// is required.
+///
+/// # Examples
+///
+/// Wait until 10 ms from now on the wall clock:
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use tokio::time::sleep_until_system;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     sleep_until_system(SystemTime::now() + Duration::from_millis(10)).await;
+///     println!("the wall clock reached the deadline");
+/// }
+/// ```
+///
+/// # Panics
+///
+/// This function panics if called outside of a runtime with the time driver
+/// enabled, for the same reasons as [`sleep`].
+///
+/// [`sleep_until`]: crate::time::sleep_until
+/// [`sleep`]: crate::time::sleep()
+pub async fn sleep_until_system(deadline: SystemTime) {
+    loop {
+        // `duration_since` fails when `deadline` is not in the future, which
+        // also covers the clock having jumped forwards past the deadline.
+        let remaining = match deadline.duration_since(SystemTime::now()) {
+            Ok(remaining) if !remaining.is_zero() => remaining,
+            _ => return,
+        };
+
+        sleep(std::cmp::min(remaining, MAX_NAP)).await;
+    }
+}
@@ -32,9 +32,14 @@ use std::task::{self, Poll};
 /// this function is guaranteed to complete immediately with an [`Ok`] variant
 /// no matter the provided duration.
 ///
+/// If the current task runs inside a [`Deadline::scope`], the timeout is
+/// clamped to the task-local deadline, so the effective deadline is whichever
+/// comes first.
+///
 /// [`Ok`]: std::result::Result::Ok
 /// [`Result`]: std::result::Result
 /// [`Elapsed`]: crate::time::error::Elapsed
+/// [`Deadline::scope`]: crate::time::Deadline::scope
 ///
 /// # Cancellation
 ///
@@ -90,6 +95,7 @@ where
     let location = trace::caller_location();
 
     let deadline = Instant::now().checked_add(duration);
+    let deadline = clamp_to_task_deadline(deadline);
     let delay = match deadline {
         Some(deadline) => Sleep::new_timeout(deadline, location),
         None => Sleep::far_future(location),
@@ -109,9 +115,14 @@ where
 /// this function is guaranteed to complete immediately with an [`Ok`] variant
 /// no matter the provided deadline.
 ///
+/// If the current task runs inside a [`Deadline::scope`], the timeout is
+/// clamped to the task-local deadline, so the effective deadline is whichever
+/// comes first.
+///
 /// [`Ok`]: std::result::Result::Ok
 /// [`Result`]: std::result::Result
 /// [`Elapsed`]: crate::time::error::Elapsed
+/// [`Deadline::scope`]: crate::time::Deadline::scope
 ///
 /// # Cancellation
 ///
@@ -146,12 +157,35 @@ pub fn timeout_at<F>(deadline: Instant, future: F) -> Timeout<F::IntoFuture>
 where
     F: IntoFuture,
 {
+    let deadline = match clamp_to_task_deadline(Some(deadline)) {
+        Some(deadline) => deadline,
+        None => unreachable!(),
+    };
     let delay = sleep_until(deadline);
 
     Timeout {
         value: future.into_future(),
         delay,
+        start: None,
+    }
+}
+
+/// Clamps a timeout deadline to the task-local [`Deadline`], if one is set.
+///
+/// `None` means "no deadline" and is only tightened, never returned, when a
+/// task-local deadline exists.
+///
+/// [`Deadline`]: crate::time::Deadline
+fn clamp_to_task_deadline(deadline: Option<Instant>) -> Option<Instant> {
+    #[cfg(feature = "rt")]
+    if let Some(inherited) = crate::time::Deadline::current() {
+        return Some(match deadline {
+            Some(deadline) => std::cmp::min(deadline, inherited.instant()),
+            None => inherited.instant(),
+        });
     }
+
+    deadline
 }
 
 pin_project! {
@@ -163,12 +197,54 @@ pin_project! {
         value: T,
         #[pin]
         delay: Sleep,
+        // Instant at which the inner future was first polled, used to report
+        // how long it ran if the deadline elapses.
+        start: Option<Instant>,
     }
 }
 
 impl<T> Timeout<T> {
     pub(crate) fn new_with_delay(value: T, delay: Sleep) -> Timeout<T> {
-        Timeout { value, delay }
+        Timeout {
+            value,
+            delay,
+            start: None,
+        }
+    }
+
+    /// Extends the deadline by `duration`.
+    ///
+    /// The new deadline is computed from the current deadline, not from the
+    /// current instant, so repeated extensions accumulate. This is useful for
+    /// retry logic that wants to grant a near-miss a little more time without
+    /// re-wrapping the inner future.
+    ///
+    /// To call this method, you will usually combine the call with
+    /// [`Pin::as_mut`], which lets you call the method without consuming the
+    /// `Timeout` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::time::{timeout, Duration};
+    ///
+    /// # async fn dox() {
+    /// let fut = timeout(Duration::from_millis(10), std::future::pending::<()>());
+    /// tokio::pin!(fut);
+    ///
+    /// // Grant the operation another 10 milliseconds.
+    /// fut.as_mut().extend(Duration::from_millis(10));
+    /// # }
+    /// ```
+    ///
+    /// [`Pin::as_mut`]: fn@std::pin::Pin::as_mut
+    pub fn extend(self: Pin<&mut Self>, duration: Duration) {
+        let me = self.project();
+        let deadline = match me.delay.deadline().checked_add(duration) {
+            Some(deadline) => deadline,
+            None => Instant::far_future(),
+        };
+        me.delay.reset(deadline);
     }
 
     /// Gets a reference to the underlying value in this timeout.
@@ -196,6 +272,8 @@ where
     fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
         let me = self.project();
 
+        let start = *me.start.get_or_insert_with(Instant::now);
+
         let had_budget_before = coop::has_budget_remaining();
 
         // First, try polling the future
@@ -203,7 +281,7 @@ where
             return Poll::Ready(Ok(v));
         }
 
-        poll_delay(had_budget_before, me.delay, cx).map(Err)
+        poll_delay(had_budget_before, start, me.delay, cx).map(Err)
     }
 }
 
@@ -211,11 +289,12 @@ where
 // amount of code that gets duplicated during monomorphization.
 fn poll_delay(
     had_budget_before: bool,
+    start: Instant,
     delay: Pin<&mut Sleep>,
     cx: &mut task::Context<'_>,
 ) -> Poll<Elapsed> {
     let delay_poll = || match delay.poll(cx) {
-        Poll::Ready(()) => Poll::Ready(Elapsed::new()),
+        Poll::Ready(()) => Poll::Ready(Elapsed::with_ran(start.elapsed())),
         Poll::Pending => Poll::Pending,
     };
 
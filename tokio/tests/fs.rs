@@ -15,6 +15,68 @@ async fn path_read_write() {
     assert_eq!(out, b"bytes");
 }
 
+#[tokio::test]
+async fn metadata_many_batches_results() {
+    let temp = tempdir();
+    let dir = temp.path();
+
+    assert_ok!(fs::write(dir.join("a"), b"aa").await);
+    assert_ok!(fs::write(dir.join("b"), b"bbbb").await);
+
+    let results = assert_ok!(
+        fs::metadata_many([dir.join("a"), dir.join("missing"), dir.join("b")]).await
+    );
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().len(), 2);
+    assert_eq!(
+        results[1].as_ref().unwrap_err().kind(),
+        std::io::ErrorKind::NotFound
+    );
+    assert_eq!(results[2].as_ref().unwrap().len(), 4);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // No `statx` in miri.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+async fn statx_reports_extended_fields() {
+    let temp = tempdir();
+    let path = temp.path().join("foo");
+    assert_ok!(fs::write(&path, b"bytes").await);
+
+    let statx = assert_ok!(fs::statx(&path).await);
+
+    // Which fields are present depends on the kernel and filesystem; only
+    // sanity-check the ones that are.
+    if let Some(btime) = statx.birth_time() {
+        assert!(btime <= std::time::SystemTime::now());
+    }
+    let _ = statx.mount_id();
+    let _ = (statx.dio_mem_align(), statx.dio_offset_align());
+
+    let err = fs::statx(temp.path().join("missing")).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[tokio::test]
+async fn write_atomic_replaces_contents() {
+    let temp = tempdir();
+    let path = temp.path().join("config");
+
+    // Creates the file when missing...
+    assert_ok!(fs::write_atomic(&path, b"one").await);
+    assert_eq!(assert_ok!(fs::read(&path).await), b"one");
+
+    // ...and atomically replaces it when present.
+    assert_ok!(fs::write_atomic(&path, b"two").await);
+    assert_eq!(assert_ok!(fs::read(&path).await), b"two");
+
+    // No temporary files are left behind.
+    let mut entries = std::fs::read_dir(temp.path()).unwrap();
+    assert_eq!(entries.next().unwrap().unwrap().file_name(), "config");
+    assert!(entries.next().is_none());
+}
+
 fn tempdir() -> tempfile::TempDir {
     tempfile::tempdir().unwrap()
 }
@@ -39,3 +39,54 @@ async fn copy_permissions() {
 
     assert_eq!(from_perms, to_perms);
 }
+
+#[tokio::test]
+async fn reflink_clones_or_reports_unsupported() {
+    let dir = tempdir().unwrap();
+
+    let from_path = dir.path().join("foo.txt");
+    let to_path = dir.path().join("bar.txt");
+
+    fs::write(&from_path, b"Hello File!").await.unwrap();
+
+    match fs::reflink(&from_path, &to_path).await {
+        Ok(()) => {
+            let from = fs::read(&from_path).await.unwrap();
+            let to = fs::read(&to_path).await.unwrap();
+            assert_eq!(from, to);
+        }
+        Err(e) => {
+            // Not all filesystems support cloning; the destination must not
+            // be left behind either way.
+            eprintln!("reflink not supported here: {e}");
+            assert!(!to_path.exists());
+        }
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // Too slow under miri.
+async fn copy_cancelled_cleans_up_destination() {
+    let dir = tempdir().unwrap();
+
+    let from_path = dir.path().join("foo.bin");
+    let to_path = dir.path().join("bar.bin");
+
+    // Enough data that the copy spans several cancellation checks.
+    let data = vec![0xa5u8; 16 * 1024 * 1024];
+    fs::write(&from_path, &data).await.unwrap();
+
+    let handle = tokio::spawn(fs::copy(from_path, to_path.clone()));
+    tokio::task::yield_now().await;
+    handle.abort();
+
+    // Give the blocking pool time to observe the cancellation.
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    // Either the copy was cancelled and the partial destination removed, or
+    // it completed before the abort was observed; a partial file must never
+    // be left behind.
+    if to_path.exists() {
+        assert_eq!(fs::read(&to_path).await.unwrap(), data);
+    }
+}
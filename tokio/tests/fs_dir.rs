@@ -124,3 +124,64 @@ async fn read_dir_entry_info() {
     assert!(first_entry.metadata().await.unwrap().is_file());
     assert!(first_entry.file_type().await.unwrap().is_file());
 }
+
+#[tokio::test]
+async fn read_dir_next_batch() {
+    let temp_dir = tempdir().unwrap();
+
+    for i in 0..100 {
+        fs::write(temp_dir.path().join(format!("{i:03}.txt")), b"x")
+            .await
+            .unwrap();
+    }
+
+    let mut dir = fs::read_dir(temp_dir.path()).await.unwrap();
+
+    let mut names = Vec::new();
+    loop {
+        let batch = assert_ok!(dir.next_batch(64).await);
+        if batch.is_empty() {
+            break;
+        }
+        assert!(batch.len() <= 64);
+        names.extend(
+            batch
+                .iter()
+                .map(|e| e.file_name().to_str().unwrap().to_string()),
+        );
+    }
+
+    names.sort(); // because the order is not guaranteed
+    assert_eq!(names.len(), 100);
+    assert_eq!(names[0], "000.txt");
+    assert_eq!(names[99], "099.txt");
+}
+
+#[tokio::test]
+async fn walk_dir_recursive() {
+    let temp_dir = tempdir().unwrap();
+    let base = temp_dir.path();
+
+    fs::create_dir(base.join("sub")).await.unwrap();
+    fs::write(base.join("a.txt"), b"aa").await.unwrap();
+    fs::write(base.join("sub").join("b.txt"), b"bbb").await.unwrap();
+
+    let mut entries = fs::walk_dir(base).await.unwrap();
+
+    let mut seen = Vec::new();
+    while let Some(entry) = assert_ok!(entries.next_entry().await) {
+        let relative = entry.path().strip_prefix(base).unwrap().to_owned();
+        if entry.file_name() == "a.txt" {
+            assert_eq!(entry.metadata().len(), 2);
+            assert!(entry.file_type().is_file());
+        }
+        seen.push(relative);
+    }
+
+    seen.sort();
+    let expected = ["a.txt", "sub", "sub/b.txt"]
+        .iter()
+        .map(std::path::PathBuf::from)
+        .collect::<Vec<_>>();
+    assert_eq!(seen, expected);
+}
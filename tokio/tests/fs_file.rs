@@ -287,3 +287,157 @@ async fn windows_handle() {
     let file = File::create(tempfile.path()).await.unwrap();
     assert!(file.as_raw_handle() as u64 > 0);
 }
+
+#[tokio::test]
+async fn read_at_and_write_at() {
+    let tempfile = tempfile();
+    tempfile.as_file().write_all(HELLO).unwrap();
+
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .open(tempfile.path())
+        .await
+        .unwrap();
+
+    let mut buf = [0u8; 5];
+    let n = file.read_at(&mut buf, 6).await.unwrap();
+    assert_eq!(&buf[..n], &HELLO[6..6 + n]);
+
+    file.write_at(b"WORLD", 6).await.unwrap();
+
+    let contents = std::fs::read(tempfile.path()).unwrap();
+    assert_eq!(&contents[..6], &HELLO[..6]);
+    assert_eq!(&contents[6..11], b"WORLD");
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // No `fcntl` in miri.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+async fn direct_io_read_write() {
+    use tokio::fs::{AlignedBuf, OpenOptions};
+
+    let tempfile = tempfile();
+
+    let file = match OpenOptions::new()
+        .read(true)
+        .write(true)
+        .direct(true)
+        .open(tempfile.path())
+        .await
+    {
+        Ok(file) => file,
+        Err(err) => {
+            // tmpfs and some CI filesystems do not support O_DIRECT.
+            eprintln!("skipping direct_io_read_write; open failed: {err}");
+            return;
+        }
+    };
+
+    // Misaligned length and offset are rejected up front.
+    let mut small = [0u8; 10];
+    let err = file.read_at(&mut small, 3).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+    let mut buf = AlignedBuf::zeroed(4096, 4096);
+    buf[..5].copy_from_slice(b"hello");
+    file.write_at(&buf, 0).await.unwrap();
+
+    let mut out = AlignedBuf::zeroed(4096, 4096);
+    let n = file.read_at(&mut out, 0).await.unwrap();
+    assert_eq!(n, 4096);
+    assert_eq!(&out[..5], b"hello");
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // No `flock` in miri.
+#[cfg(any(unix, windows))]
+async fn advisory_locking() {
+    let tempfile = tempfile();
+
+    let file = File::open(tempfile.path()).await.unwrap();
+    let other = File::open(tempfile.path()).await.unwrap();
+
+    // Shared locks coexist.
+    let shared_a = file.lock_shared().await.unwrap();
+    let shared_b = other.try_lock_shared().unwrap();
+
+    // An exclusive lock is refused while shared locks are held.
+    let err = other.try_lock_exclusive().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WouldBlock);
+
+    shared_a.unlock().await.unwrap();
+    drop(shared_b);
+
+    // The drop release happens on the blocking pool; waiting for the
+    // exclusive lock parks a blocking thread until it is through.
+    let guard = file.lock_exclusive().await.unwrap();
+
+    let err = other.try_lock_shared().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WouldBlock);
+    drop(guard);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // No `fallocate` in miri.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+async fn allocate_punch_hole_and_sync_range() {
+    let tempfile = tempfile();
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .open(tempfile.path())
+        .await
+        .unwrap();
+
+    // Preallocation grows the file without writing.
+    if let Err(err) = file.allocate(1024 * 1024).await {
+        // Not every filesystem supports fallocate.
+        eprintln!("skipping allocate_punch_hole_and_sync_range; allocate failed: {err}");
+        return;
+    }
+    assert_eq!(file.metadata().await.unwrap().len(), 1024 * 1024);
+
+    file.write_at(b"hello", 0).await.unwrap();
+
+    // Punching the written range back out leaves zeroes and keeps the size.
+    if file.punch_hole(0, 4096).await.is_ok() {
+        let mut buf = [0xffu8; 5];
+        file.read_at(&mut buf, 0).await.unwrap();
+        assert_eq!(buf, [0u8; 5]);
+        assert_eq!(file.metadata().await.unwrap().len(), 1024 * 1024);
+    }
+
+    file.sync_file_range(0, 0, false).await.unwrap();
+    file.sync_file_range(0, 0, true).await.unwrap();
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)] // No `mmap` in miri.
+#[cfg(unix)]
+async fn mmap_read_and_write() {
+    use tokio::fs::{Mmap, MmapMut};
+
+    let tempfile = tempfile();
+    std::fs::write(tempfile.path(), b"hello world").unwrap();
+
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .open(tempfile.path())
+        .await
+        .unwrap();
+
+    // SAFETY: the file is not resized while the mappings are alive.
+    let mut map = unsafe { MmapMut::map_mut(&file).await.unwrap() };
+    assert_eq!(&map[..], b"hello world");
+    map[..5].copy_from_slice(b"HELLO");
+    map.flush_async().await.unwrap();
+
+    assert_eq!(std::fs::read(tempfile.path()).unwrap(), b"HELLO world");
+
+    // SAFETY: as above.
+    let map = unsafe { Mmap::map(&file).await.unwrap() };
+    let bytes = map.into_bytes();
+    assert_eq!(&bytes[..], b"HELLO world");
+}
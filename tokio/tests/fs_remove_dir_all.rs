@@ -29,3 +29,32 @@ async fn remove_dir_all() {
         Err(_) => println!("ignored try_exists error after remove_dir_all"),
     };
 }
+
+#[tokio::test]
+#[cfg(unix)]
+async fn remove_dir_all_does_not_follow_symlinks() {
+    let temp_dir = tempdir().unwrap();
+
+    let outside_dir = temp_dir.path().join("outside");
+    fs::create_dir(&outside_dir).await.unwrap();
+    let outside_file = outside_dir.join("keep.txt");
+    fs::write(&outside_file, b"Hello File!").await.unwrap();
+
+    let test_dir = temp_dir.path().join("test");
+    fs::create_dir(&test_dir).await.unwrap();
+    fs::symlink(&outside_dir, test_dir.join("link"))
+        .await
+        .unwrap();
+
+    fs::remove_dir_all(&test_dir).await.unwrap();
+
+    // The link is removed with the directory, but its target is untouched.
+    assert!(!test_dir.exists());
+    assert!(outside_file.exists());
+
+    // A symlink itself is rejected, matching `std::fs::remove_dir_all`.
+    let link_path = temp_dir.path().join("root_link");
+    fs::symlink(&outside_dir, &link_path).await.unwrap();
+    fs::remove_dir_all(&link_path).await.unwrap_err();
+    assert!(outside_file.exists());
+}
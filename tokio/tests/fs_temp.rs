@@ -0,0 +1,79 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", not(target_os = "wasi"), not(miri)))]
+
+use tokio::fs::{self, TempDir, TempFile};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+use std::io::SeekFrom;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn temp_file_read_write_close() {
+    let dir = tempdir().unwrap();
+    let mut file = TempFile::new_in(dir.path()).await.unwrap();
+
+    file.write_all(b"scratch").await.unwrap();
+    file.seek(SeekFrom::Start(0)).await.unwrap();
+
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents).await.unwrap();
+    assert_eq!(contents, b"scratch");
+
+    let path = file.path().map(ToOwned::to_owned);
+    file.close().await.unwrap();
+    if let Some(path) = path {
+        assert!(!path.exists());
+    }
+}
+
+#[tokio::test]
+async fn temp_file_persist() {
+    let dir = tempdir().unwrap();
+    let mut file = TempFile::new_in(dir.path()).await.unwrap();
+    file.write_all(b"keep me").await.unwrap();
+
+    let target = dir.path().join("kept.txt");
+    file.persist(&target).await.unwrap();
+
+    assert_eq!(fs::read(&target).await.unwrap(), b"keep me");
+}
+
+#[tokio::test]
+async fn temp_file_removed_on_drop() {
+    let dir = tempdir().unwrap();
+    let file = TempFile::new_in(dir.path()).await.unwrap();
+    let path = file.path().map(ToOwned::to_owned);
+    drop(file);
+
+    if let Some(path) = path {
+        // Cleanup happens on the blocking pool; give it a moment.
+        for _ in 0..100 {
+            if !path.exists() {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("temporary file was not removed");
+    }
+}
+
+#[tokio::test]
+async fn temp_dir_close_removes_contents() {
+    let dir = TempDir::new().await.unwrap();
+    let path = dir.path().to_owned();
+
+    fs::write(path.join("a.txt"), b"a").await.unwrap();
+    fs::create_dir(path.join("sub")).await.unwrap();
+
+    dir.close().await.unwrap();
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn temp_dir_into_path_persists() {
+    let dir = TempDir::new().await.unwrap();
+    let path = dir.into_path();
+
+    assert!(path.exists());
+    std::fs::remove_dir_all(&path).unwrap();
+}
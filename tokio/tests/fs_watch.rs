@@ -0,0 +1,69 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", any(target_os = "android", target_os = "linux"), not(miri)))]
+
+use tokio::fs::{self, FsEventKind};
+
+use std::time::Duration;
+
+#[tokio::test]
+async fn watch_create_modify_remove() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut watcher = fs::watch(dir.path()).unwrap();
+
+    let path = dir.path().join("a.txt");
+    fs::write(&path, b"hello").await.unwrap();
+
+    let event = watcher.next().await.unwrap().unwrap();
+    assert_eq!(event.kind(), FsEventKind::Create);
+    assert_eq!(event.path(), path);
+
+    // Writing produces some sequence of modify events for the same path.
+    let event = watcher.next().await.unwrap().unwrap();
+    assert_eq!(event.kind(), FsEventKind::Modify);
+    assert_eq!(event.path(), path);
+
+    fs::remove_file(&path).await.unwrap();
+    while let Some(event) = watcher.next().await.unwrap() {
+        if event.kind() == FsEventKind::Remove {
+            assert_eq!(event.path(), path);
+            return;
+        }
+    }
+    panic!("no remove event seen");
+}
+
+#[tokio::test]
+async fn watch_ends_when_removed() {
+    let dir = tempfile::tempdir().unwrap();
+    let inner = dir.path().join("watched");
+    std::fs::create_dir(&inner).unwrap();
+
+    let mut watcher = fs::watch(&inner).unwrap();
+    std::fs::remove_dir(&inner).unwrap();
+
+    while let Some(_event) = watcher.next().await.unwrap() {}
+}
+
+#[tokio::test]
+async fn watch_debounce_coalesces() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut watcher = fs::watch(dir.path()).unwrap();
+    watcher.set_debounce(Some(Duration::from_millis(50)));
+
+    let path = dir.path().join("burst.txt");
+    {
+        use std::io::Write;
+        let mut file = std::fs::File::create(&path).unwrap();
+        for _ in 0..16 {
+            file.write_all(b"chunk").unwrap();
+            file.flush().unwrap();
+        }
+    }
+
+    let event = watcher.next().await.unwrap().unwrap();
+    assert_eq!(event.kind(), FsEventKind::Create);
+
+    let event = watcher.next().await.unwrap().unwrap();
+    assert_eq!(event.kind(), FsEventKind::Modify);
+    assert_eq!(event.path(), path);
+}
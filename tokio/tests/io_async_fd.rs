@@ -958,3 +958,77 @@ async fn try_with_interest() {
 
     assert!(Arc::ptr_eq(&original, &returned));
 }
+
+#[tokio::test]
+async fn poll_ready_combined_interest() {
+    use tokio::io::Ready;
+
+    let (a, mut b) = socketpair();
+
+    let afd_a = AsyncFd::new(a).unwrap();
+
+    // Nothing to read yet, so only write readiness is reported.
+    let ready = std::future::poll_fn(|cx| {
+        afd_a
+            .poll_ready(Interest::READABLE | Interest::WRITABLE, cx)
+            .map_ok(|guard| guard.ready())
+    })
+    .await
+    .unwrap();
+    assert!(ready.is_writable());
+    assert!(!ready.is_readable());
+
+    // Read-only interest is not woken by writability.
+    assert_pending!(poll!(std::future::poll_fn(|cx| afd_a
+        .poll_ready(Interest::READABLE, cx)
+        .map_ok(drop))));
+
+    b.write_all(b"0").unwrap();
+
+    let ready = std::future::poll_fn(|cx| {
+        afd_a
+            .poll_ready(Interest::READABLE, cx)
+            .map_ok(|guard| guard.ready())
+    })
+    .await
+    .unwrap();
+    assert_eq!(ready, Ready::READABLE);
+}
+
+#[tokio::test]
+async fn poll_ready_mut_combined_interest() {
+    use tokio::io::Ready;
+
+    let (a, mut b) = socketpair();
+
+    let mut afd_a = AsyncFd::new(a).unwrap();
+    b.write_all(b"0").unwrap();
+
+    // Both directions are ready; read readiness is reported first. Consume
+    // it so that the following poll reports write readiness instead.
+    let ready = std::future::poll_fn(|cx| {
+        afd_a
+            .poll_ready_mut(Interest::READABLE | Interest::WRITABLE, cx)
+            .map_ok(|mut guard| {
+                let ready = guard.ready();
+                if ready.is_readable() {
+                    let mut buf = [0u8; 1];
+                    guard.get_inner_mut().read_exact(&mut buf).unwrap();
+                    guard.clear_ready_matching(Ready::READABLE);
+                }
+                ready
+            })
+    })
+    .await
+    .unwrap();
+    assert_eq!(ready, Ready::READABLE);
+
+    let ready = std::future::poll_fn(|cx| {
+        afd_a
+            .poll_ready_mut(Interest::READABLE | Interest::WRITABLE, cx)
+            .map_ok(|guard| guard.ready())
+    })
+    .await
+    .unwrap();
+    assert!(ready.is_writable());
+}
@@ -87,13 +87,13 @@ async fn test_buffered_reader() {
     let nread = reader.read(&mut buf).await.unwrap();
     assert_eq!(nread, 3);
     assert_eq!(buf, [5, 6, 7]);
-    assert_eq!(reader.buffer(), []);
+    assert_eq!(reader.buffer(), [] as [u8; 0]);
 
     let mut buf = [0, 0];
     let nread = reader.read(&mut buf).await.unwrap();
     assert_eq!(nread, 2);
     assert_eq!(buf, [0, 1]);
-    assert_eq!(reader.buffer(), []);
+    assert_eq!(reader.buffer(), [] as [u8; 0]);
 
     let mut buf = [0];
     let nread = reader.read(&mut buf).await.unwrap();
@@ -105,12 +105,12 @@ async fn test_buffered_reader() {
     let nread = reader.read(&mut buf).await.unwrap();
     assert_eq!(nread, 1);
     assert_eq!(buf, [3, 0, 0]);
-    assert_eq!(reader.buffer(), []);
+    assert_eq!(reader.buffer(), [] as [u8; 0]);
 
     let nread = reader.read(&mut buf).await.unwrap();
     assert_eq!(nread, 1);
     assert_eq!(buf, [4, 0, 0]);
-    assert_eq!(reader.buffer(), []);
+    assert_eq!(reader.buffer(), [] as [u8; 0]);
 
     assert_eq!(reader.read(&mut buf).await.unwrap(), 0);
 }
@@ -231,13 +231,13 @@ async fn maybe_pending() {
     let nread = reader.read(&mut buf).await.unwrap();
     assert_eq!(nread, 3);
     assert_eq!(buf, [5, 6, 7]);
-    assert_eq!(reader.buffer(), []);
+    assert_eq!(reader.buffer(), [] as [u8; 0]);
 
     let mut buf = [0, 0];
     let nread = reader.read(&mut buf).await.unwrap();
     assert_eq!(nread, 2);
     assert_eq!(buf, [0, 1]);
-    assert_eq!(reader.buffer(), []);
+    assert_eq!(reader.buffer(), [] as [u8; 0]);
 
     let mut buf = [0];
     let nread = reader.read(&mut buf).await.unwrap();
@@ -249,12 +249,12 @@ async fn maybe_pending() {
     let nread = reader.read(&mut buf).await.unwrap();
     assert_eq!(nread, 1);
     assert_eq!(buf, [3, 0, 0]);
-    assert_eq!(reader.buffer(), []);
+    assert_eq!(reader.buffer(), [] as [u8; 0]);
 
     let nread = reader.read(&mut buf).await.unwrap();
     assert_eq!(nread, 1);
     assert_eq!(buf, [4, 0, 0]);
-    assert_eq!(reader.buffer(), []);
+    assert_eq!(reader.buffer(), [] as [u8; 0]);
 
     assert_eq!(reader.read(&mut buf).await.unwrap(), 0);
 }
@@ -274,7 +274,7 @@ async fn maybe_pending_buf_read() {
     assert_eq!(v, [0]);
     v.clear();
     reader.read_until(9, &mut v).await.unwrap();
-    assert_eq!(v, []);
+    assert_eq!(v, [] as [u8; 0]);
 }
 
 // https://github.com/rust-lang/futures-rs/pull/1573#discussion_r281162309
@@ -377,3 +377,37 @@ async fn test_fill_buf_wrapper() {
     read.consume(b"foo bar".len());
     assert_eq!(read.fill_buf().await.unwrap(), b"");
 }
+
+#[tokio::test]
+async fn test_buffered_reader_seek_relative() {
+    let inner: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7];
+    let mut reader = BufReader::with_capacity(4, Cursor::new(inner));
+
+    // Fill the buffer with [0, 1, 2, 3].
+    assert_eq!(run_fill_buf!(reader).unwrap(), &[0, 1, 2, 3][..]);
+
+    // A forward seek within the buffer keeps it intact.
+    reader.seek_relative(2).await.unwrap();
+    assert_eq!(reader.buffer(), &[2, 3][..]);
+
+    // A backward seek within the buffer keeps it intact too.
+    reader.seek_relative(-1).await.unwrap();
+    assert_eq!(reader.buffer(), &[1, 2, 3][..]);
+
+    let mut byte = [0];
+    reader.read_exact(&mut byte).await.unwrap();
+    assert_eq!(byte, [1]);
+
+    // Seeking past the buffered data falls back to a real seek and
+    // discards the buffer.
+    reader.seek_relative(4).await.unwrap();
+    assert!(reader.buffer().is_empty());
+    assert_eq!(run_fill_buf!(reader).unwrap(), &[6, 7][..]);
+}
+
+#[tokio::test]
+async fn test_buffered_reader_capacity() {
+    let inner: &[u8] = &[0, 1, 2, 3];
+    let reader = BufReader::with_capacity(16, Cursor::new(inner));
+    assert_eq!(reader.capacity(), 16);
+}
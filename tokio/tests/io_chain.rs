@@ -14,3 +14,21 @@ async fn chain() {
     assert_ok!(rd.read_to_end(&mut buf).await);
     assert_eq!(buf, b"hello world");
 }
+
+#[tokio::test]
+async fn chain_replace_second() {
+    let mut buf = Vec::new();
+    let rd1: &[u8] = b"part one, ";
+    let rd2: &[u8] = b"placeholder";
+
+    let mut rd = rd1.chain(rd2);
+    assert!(!rd.is_first_done());
+
+    // The next part may be queued before the first reader hits EOF.
+    let old = rd.replace_second(b"part two".as_slice());
+    assert_eq!(old, b"placeholder");
+
+    assert_ok!(rd.read_to_end(&mut buf).await);
+    assert_eq!(buf, b"part one, part two");
+    assert!(rd.is_first_done());
+}
@@ -6,6 +6,7 @@ use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBu
 use tokio_test::assert_ok;
 
 use std::pin::Pin;
+use std::time::Duration;
 use std::task::{ready, Context, Poll};
 
 #[tokio::test]
@@ -99,3 +100,69 @@ async fn copy_is_cooperative() {
         _ = tokio::task::yield_now() => {}
     }
 }
+
+#[tokio::test]
+async fn copy_options_reports_progress() {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    let mut reader: &[u8] = &[0; 10_000];
+    let mut writer: Vec<u8> = vec![];
+
+    let calls = Arc::new(AtomicU64::new(0));
+    let last = Arc::new(AtomicU64::new(0));
+
+    let n = {
+        let calls = calls.clone();
+        let last = last.clone();
+        io::CopyOptions::new()
+            .progress(4096, move |copied| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                last.store(copied, Ordering::Relaxed);
+            })
+            .copy(&mut reader, &mut writer)
+            .await
+            .unwrap()
+    };
+
+    assert_eq!(n, 10_000);
+    assert_eq!(writer.len(), 10_000);
+    // Two interval reports plus the final report for the tail.
+    assert_eq!(calls.load(Ordering::Relaxed), 3);
+    assert_eq!(last.load(Ordering::Relaxed), 10_000);
+}
+
+#[tokio::test(start_paused = true)]
+async fn copy_options_throttles_transfer_rate() {
+    let mut reader: &[u8] = &[0; 4096];
+    let mut writer: Vec<u8> = vec![];
+
+    let start = tokio::time::Instant::now();
+    let n = io::CopyOptions::new()
+        .max_bytes_per_sec(1024)
+        .copy(&mut reader, &mut writer)
+        .await
+        .unwrap();
+
+    assert_eq!(n, 4096);
+    // 4096 bytes at 1024 bytes/sec should take about four seconds.
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed >= Duration::from_secs(4) && elapsed < Duration::from_secs(5),
+        "elapsed: {elapsed:?}"
+    );
+}
+
+#[tokio::test]
+async fn copy_options_copy_buf() {
+    let mut reader: &[u8] = b"hello world";
+    let mut writer: Vec<u8> = vec![];
+
+    let n = io::CopyOptions::new()
+        .copy_buf(&mut reader, &mut writer)
+        .await
+        .unwrap();
+
+    assert_eq!(n, 11);
+    assert_eq!(writer, b"hello world");
+}
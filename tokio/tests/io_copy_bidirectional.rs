@@ -166,3 +166,59 @@ async fn copy_bidirectional_is_cooperative() {
         _ = tokio::task::yield_now() => {}
     }
 }
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn splice_proxies_both_directions() {
+    let (mut c1, mut s1) = make_socketpair().await;
+    let (mut c2, mut s2) = make_socketpair().await;
+
+    let proxy = tokio::spawn(async move { io::copy_bidirectional_splice(&mut s1, &mut s2).await });
+
+    c1.write_all(b"ping").await.unwrap();
+    let mut buf = [0; 4];
+    c2.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"ping");
+
+    c2.write_all(b"pong!").await.unwrap();
+    let mut buf = [0; 5];
+    c1.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"pong!");
+
+    // Closing one end propagates EOF through the proxy in both directions.
+    drop(c1);
+    let mut rest = Vec::new();
+    c2.read_to_end(&mut rest).await.unwrap();
+    assert!(rest.is_empty());
+    drop(c2);
+
+    let (a_to_b, b_to_a) = proxy.await.unwrap().unwrap();
+    assert_eq!(a_to_b, 4);
+    assert_eq!(b_to_a, 5);
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn splice_large_transfer() {
+    let (mut c1, mut s1) = make_socketpair().await;
+    let (mut c2, mut s2) = make_socketpair().await;
+
+    let proxy = tokio::spawn(async move { io::copy_bidirectional_splice(&mut s1, &mut s2).await });
+
+    const LEN: usize = 1024 * 1024;
+    let data = vec![0x5a; LEN];
+    let writer = tokio::spawn(async move {
+        c1.write_all(&data).await.unwrap();
+        drop(c1);
+    });
+
+    let mut received = Vec::new();
+    c2.read_to_end(&mut received).await.unwrap();
+    assert_eq!(received.len(), LEN);
+    assert!(received.iter().all(|&b| b == 0x5a));
+
+    writer.await.unwrap();
+    drop(c2);
+    let (a_to_b, _) = proxy.await.unwrap().unwrap();
+    assert_eq!(a_to_b, LEN as u64);
+}
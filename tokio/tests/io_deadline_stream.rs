@@ -0,0 +1,79 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt, DeadlineStream};
+use tokio::time::{self, Duration};
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[tokio::test(start_paused = true)]
+async fn read_times_out_when_idle() {
+    let (a, mut b) = duplex(64);
+
+    let mut a = DeadlineStream::new(a);
+    a.set_read_timeout(Some(ms(50)));
+
+    let mut buf = [0; 8];
+    let err = a.read(&mut buf).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+
+    // The stream remains usable after a timeout.
+    b.write_all(b"hello").await.unwrap();
+    let n = a.read(&mut buf).await.unwrap();
+    assert_eq!(&buf[..n], b"hello");
+}
+
+#[tokio::test(start_paused = true)]
+async fn progress_resets_read_timer() {
+    let (a, mut b) = duplex(64);
+
+    let mut a = DeadlineStream::new(a);
+    a.set_read_timeout(Some(ms(50)));
+
+    // A slow peer that always responds within the idle timeout never trips
+    // it, even once the total elapsed time exceeds the timeout.
+    tokio::spawn(async move {
+        for _ in 0..4 {
+            time::sleep(ms(30)).await;
+            b.write_all(b"x").await.unwrap();
+        }
+    });
+
+    let mut buf = [0; 8];
+    for _ in 0..4 {
+        let n = a.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..n], b"x");
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn write_times_out_when_peer_stalls() {
+    // Fill the duplex buffer so further writes block.
+    let (a, _b) = duplex(4);
+
+    let mut a = DeadlineStream::new(a);
+    a.set_write_timeout(Some(ms(50)));
+
+    a.write_all(b"1234").await.unwrap();
+
+    let err = a.write_all(b"more").await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+}
+
+#[tokio::test(start_paused = true)]
+async fn timeouts_are_independent() {
+    let (a, _b) = duplex(4);
+
+    let mut a = DeadlineStream::new(a);
+    a.set_read_timeout(Some(ms(50)));
+    assert_eq!(a.read_timeout(), Some(ms(50)));
+    assert_eq!(a.write_timeout(), None);
+
+    // With no write timeout set, a blocked write stays pending while the
+    // read timer (armed separately) is unaffected.
+    a.write_all(b"1234").await.unwrap();
+    let pending_write = time::timeout(ms(200), a.write_all(b"more")).await;
+    assert!(pending_write.is_err(), "write should not time out on its own");
+}
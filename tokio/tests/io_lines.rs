@@ -17,3 +17,44 @@ async fn lines_inherent() {
     assert_eq!(b, "");
     assert!(assert_ok!(st.next_line().await).is_none());
 }
+
+#[tokio::test]
+async fn lines_invalid_utf8_error_by_default() {
+    let rd: &[u8] = b"ok\n\xff\xff\nnever reached\n";
+    let mut st = rd.lines();
+
+    let b = assert_ok!(st.next_line().await).unwrap();
+    assert_eq!(b, "ok");
+    let err = st.next_line().await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[tokio::test]
+async fn lines_invalid_utf8_lossy() {
+    use tokio::io::InvalidUtf8Policy;
+
+    let rd: &[u8] = b"ok\n\xff\xff\nbye\n";
+    let mut st = rd.lines().invalid_utf8_policy(InvalidUtf8Policy::Lossy);
+
+    let b = assert_ok!(st.next_line().await).unwrap();
+    assert_eq!(b, "ok");
+    let b = assert_ok!(st.next_line().await).unwrap();
+    assert_eq!(b, "\u{fffd}\u{fffd}");
+    let b = assert_ok!(st.next_line().await).unwrap();
+    assert_eq!(b, "bye");
+    assert!(assert_ok!(st.next_line().await).is_none());
+}
+
+#[tokio::test]
+async fn lines_invalid_utf8_skip() {
+    use tokio::io::InvalidUtf8Policy;
+
+    let rd: &[u8] = b"ok\n\xff\xff\nbye\n\xff";
+    let mut st = rd.lines().invalid_utf8_policy(InvalidUtf8Policy::Skip);
+
+    let b = assert_ok!(st.next_line().await).unwrap();
+    assert_eq!(b, "ok");
+    let b = assert_ok!(st.next_line().await).unwrap();
+    assert_eq!(b, "bye");
+    assert!(assert_ok!(st.next_line().await).is_none());
+}
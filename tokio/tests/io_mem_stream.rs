@@ -1,7 +1,8 @@
 #![warn(rust_2018_idioms)]
 #![cfg(feature = "full")]
 
-use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+use tokio::io::{self, duplex, AsyncReadExt, AsyncWriteExt};
+use std::time::Duration;
 
 #[tokio::test]
 async fn ping_pong() {
@@ -119,3 +120,54 @@ async fn duplex_is_cooperative() {
         _ = tokio::task::yield_now() => {}
     }
 }
+
+#[tokio::test(start_paused = true)]
+async fn duplex_options_latency() {
+    let (mut a, mut b) = io::duplex_with_options(
+        64,
+        io::DuplexOptions::new().latency(Duration::from_millis(20)),
+    );
+
+    a.write_all(b"ping").await.unwrap();
+
+    let start = tokio::time::Instant::now();
+    let mut buf = [0; 4];
+    b.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"ping");
+    assert!(start.elapsed() >= Duration::from_millis(20));
+}
+
+#[tokio::test(start_paused = true)]
+async fn duplex_options_bandwidth() {
+    let (mut a, mut b) = io::duplex_with_options(
+        8192,
+        io::DuplexOptions::new().bandwidth(1024),
+    );
+
+    a.write_all(&[0; 4096]).await.unwrap();
+
+    let start = tokio::time::Instant::now();
+    let mut buf = [0; 4096];
+    b.read_exact(&mut buf).await.unwrap();
+    // 4096 bytes at 1024 bytes/sec takes about four seconds.
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed >= Duration::from_secs(3) && elapsed < Duration::from_secs(5),
+        "elapsed: {elapsed:?}"
+    );
+}
+
+#[tokio::test]
+async fn duplex_options_chunk_size() {
+    let (mut a, mut b) = io::duplex_with_options(64, io::DuplexOptions::new().chunk_size(3));
+
+    // Writes are capped at the chunk size...
+    assert_eq!(a.write(b"abcdefgh").await.unwrap(), 3);
+    a.write_all(b"defgh").await.unwrap();
+
+    // ...and so are reads.
+    let mut buf = [0; 8];
+    let n = b.read(&mut buf).await.unwrap();
+    assert_eq!(n, 3);
+    assert_eq!(&buf[..n], b"abc");
+}
@@ -0,0 +1,85 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt, Peek};
+
+#[tokio::test]
+async fn peek_then_read() {
+    let data: &[u8] = b"hello world";
+    let mut rd = Peek::new(data);
+
+    let mut header = [0; 5];
+    assert_eq!(rd.peek(&mut header).await.unwrap(), 5);
+    assert_eq!(&header, b"hello");
+
+    // Peeking is idempotent.
+    assert_eq!(rd.peek(&mut header).await.unwrap(), 5);
+    assert_eq!(&header, b"hello");
+    assert_eq!(rd.buffer(), b"hello");
+
+    // Reads see the peeked bytes first.
+    let mut all = Vec::new();
+    rd.read_to_end(&mut all).await.unwrap();
+    assert_eq!(all, b"hello world");
+}
+
+#[tokio::test]
+async fn peek_past_eof() {
+    let data: &[u8] = b"hi";
+    let mut rd = Peek::new(data);
+
+    let mut buf = [0; 8];
+    assert_eq!(rd.peek(&mut buf).await.unwrap(), 2);
+    assert_eq!(&buf[..2], b"hi");
+
+    let mut all = Vec::new();
+    rd.read_to_end(&mut all).await.unwrap();
+    assert_eq!(all, b"hi");
+    assert_eq!(rd.peek(&mut buf).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn peek_waits_for_enough_data() {
+    let (a, mut b) = duplex(64);
+    let mut rd = Peek::new(a);
+
+    tokio::spawn(async move {
+        b.write_all(b"ab").await.unwrap();
+        tokio::task::yield_now().await;
+        b.write_all(b"cd").await.unwrap();
+    });
+
+    let mut buf = [0; 4];
+    assert_eq!(rd.peek(&mut buf).await.unwrap(), 4);
+    assert_eq!(&buf, b"abcd");
+}
+
+#[tokio::test]
+async fn partial_read_keeps_remainder() {
+    let data: &[u8] = b"abcdef";
+    let mut rd = Peek::new(data);
+
+    let mut buf = [0; 4];
+    assert_eq!(rd.peek(&mut buf).await.unwrap(), 4);
+
+    // Read less than was peeked; the rest stays buffered.
+    let mut two = [0; 2];
+    rd.read_exact(&mut two).await.unwrap();
+    assert_eq!(&two, b"ab");
+    assert_eq!(rd.buffer(), b"cd");
+
+    let mut rest = Vec::new();
+    rd.read_to_end(&mut rest).await.unwrap();
+    assert_eq!(rest, b"cdef");
+}
+
+#[tokio::test]
+async fn writes_pass_through() {
+    let (a, mut b) = duplex(64);
+    let mut stream = Peek::new(a);
+
+    stream.write_all(b"ping").await.unwrap();
+    let mut buf = [0; 4];
+    b.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"ping");
+}
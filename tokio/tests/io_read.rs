@@ -73,3 +73,24 @@ async fn read_buf_bad_async_read() {
     let mut buf = Vec::with_capacity(10);
     BadAsyncRead::new().read_buf(&mut buf).await.unwrap();
 }
+
+#[tokio::test]
+async fn default_poll_read_vectored_uses_first_buffer() {
+    use std::future::poll_fn;
+
+    let mut rd: &[u8] = b"hello world";
+    assert!(!rd.is_read_vectored());
+
+    let mut first = [0; 5];
+    let mut second = [0; 16];
+    let mut bufs = [ReadBuf::new(&mut first), ReadBuf::new(&mut second)];
+
+    let n = poll_fn(|cx| Pin::new(&mut rd).poll_read_vectored(cx, &mut bufs))
+        .await
+        .unwrap();
+
+    // The default implementation only fills the first buffer.
+    assert_eq!(n, 5);
+    assert_eq!(bufs[0].filled(), b"hello");
+    assert!(bufs[1].filled().is_empty());
+}
@@ -105,3 +105,40 @@ async fn read_line_fail_and_utf8_fail() {
     assert_eq!(err.to_string(), "The world has no end");
     assert_eq!(line.as_str(), "Foo");
 }
+
+#[tokio::test]
+async fn read_line_limited() {
+    let mut rd: &[u8] = b"first\nway too long to fit\nlast\n";
+    let mut buf = String::new();
+
+    let n = assert_ok!(rd.read_line_limited(&mut buf, 10).await);
+    assert_eq!(n, 6);
+    assert_eq!(buf, "first\n");
+    buf.clear();
+
+    let err = rd.read_line_limited(&mut buf, 10).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    // The bytes read before hitting the limit were consumed and kept.
+    assert_eq!(buf, "way too lo");
+    buf.clear();
+
+    // Recover by discarding the remainder of the oversized line.
+    let n = assert_ok!(rd.read_line(&mut buf).await);
+    assert_eq!(n, 10);
+    assert_eq!(buf, "ng to fit\n");
+    buf.clear();
+
+    let n = assert_ok!(rd.read_line_limited(&mut buf, 10).await);
+    assert_eq!(n, 5);
+    assert_eq!(buf, "last\n");
+}
+
+#[tokio::test]
+async fn read_line_limited_eof_within_limit() {
+    let mut rd: &[u8] = b"no newline";
+    let mut buf = String::new();
+
+    let n = assert_ok!(rd.read_line_limited(&mut buf, 10).await);
+    assert_eq!(n, 10);
+    assert_eq!(buf, "no newline");
+}
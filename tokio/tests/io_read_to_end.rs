@@ -1,6 +1,7 @@
 #![warn(rust_2018_idioms)]
 #![cfg(feature = "full")]
 
+use std::io;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
@@ -123,3 +124,32 @@ async fn read_to_end_grows_capacity_if_unfit() {
     // *4 since it doubles when it doesn't fit and again when reaching EOF
     assert_eq!(buf.capacity(), initial_capacity * 4);
 }
+
+#[tokio::test]
+async fn read_to_end_limited_within_limit() {
+    let mut rd: &[u8] = b"hello world";
+    let mut vec = Vec::new();
+
+    let n = assert_ok!(rd.read_to_end_limited(&mut vec, 64).await);
+    assert_eq!(n, 11);
+    assert_eq!(vec, b"hello world");
+}
+
+#[tokio::test]
+async fn read_to_end_limited_exceeds_limit() {
+    let mut rd: &[u8] = b"hello world";
+    let mut vec = Vec::new();
+
+    let err = rd.read_to_end_limited(&mut vec, 4).await.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[tokio::test]
+async fn read_to_end_limited_exact_limit() {
+    let mut rd: &[u8] = b"hello world";
+    let mut vec = Vec::new();
+
+    let n = assert_ok!(rd.read_to_end_limited(&mut vec, 11).await);
+    assert_eq!(n, 11);
+    assert_eq!(vec, b"hello world");
+}
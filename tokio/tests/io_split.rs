@@ -60,6 +60,14 @@ fn is_send_and_sync() {
     assert_bound::<WriteHalf<RW>>();
 }
 
+#[test]
+fn concurrent_halves_are_send_and_sync() {
+    fn assert_bound<T: Send + Sync>() {}
+
+    assert_bound::<tokio::io::ConcurrentReadHalf<tokio::io::DuplexStream>>();
+    assert_bound::<tokio::io::ConcurrentWriteHalf<tokio::io::DuplexStream>>();
+}
+
 #[test]
 fn split_stream_id() {
     let (r1, w1) = split(RW);
@@ -112,3 +120,31 @@ fn method_delegation() {
         assert!(w.shutdown().await.is_ok());
     });
 }
+
+#[test]
+fn split_concurrent_duplex() {
+    let (a, b) = tokio::io::duplex(64);
+    let (mut ra, mut wa) = tokio::io::split_concurrent(a);
+    let (mut rb, mut wb) = tokio::io::split_concurrent(b);
+
+    assert!(ra.is_pair_of(&wa));
+    assert!(!ra.is_pair_of(&wb));
+
+    tokio_test::block_on(async move {
+        wa.write_all(b"ping").await.unwrap();
+        let mut buf = [0; 4];
+        rb.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"ping");
+
+        wb.write_all(b"pong").await.unwrap();
+        ra.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"pong");
+    });
+}
+
+#[test]
+fn split_concurrent_unsplit() {
+    let (a, _b) = tokio::io::duplex(64);
+    let (r, w) = tokio::io::split_concurrent(a);
+    r.unsplit(w);
+}
@@ -0,0 +1,20 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", not(target_os = "wasi")))] // Wasi does not support threads
+
+use tokio::io::stdin_locked;
+
+// A single test, since the lock is process-global and the test harness runs
+// tests within a binary concurrently.
+#[tokio::test]
+async fn lock_is_exclusive_and_released_on_drop() {
+    let a = stdin_locked();
+
+    // A second handle cannot be created while the first is alive.
+    let err = std::panic::catch_unwind(stdin_locked).unwrap_err();
+    let msg = err.downcast_ref::<&str>().unwrap();
+    assert!(msg.contains("another StdinLock is alive"), "{msg}");
+
+    // Dropping a handle releases the lock, even if a read was in flight.
+    drop(a);
+    let _b = stdin_locked();
+}
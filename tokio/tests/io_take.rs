@@ -72,3 +72,16 @@ async fn bad_reader_fails() {
 
     BadReader::new().take(10).read_buf(&mut buf).await.unwrap();
 }
+
+#[tokio::test]
+async fn take_limit_exceeds_buf() {
+    // The pass-through path taken when the limit cannot be exceeded must
+    // still keep the limit accounting accurate.
+    let mut buf = [0; 8];
+    let rd: &[u8] = b"hello world";
+
+    let mut rd = rd.take(32);
+    let n = assert_ok!(rd.read(&mut buf).await);
+    assert_eq!(n, 8);
+    assert_eq!(rd.limit(), 24);
+}
@@ -0,0 +1,84 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use tokio::io::{duplex, inspect_read, inspect_write, tee, AsyncReadExt, AsyncWriteExt};
+use tokio_test::assert_ok;
+
+#[tokio::test]
+async fn tee_copies_to_writer() {
+    let reader: &[u8] = b"hello world";
+    let mut log = Vec::new();
+
+    let mut buf = Vec::new();
+    assert_ok!(tee(reader, &mut log).read_to_end(&mut buf).await);
+
+    assert_eq!(buf, b"hello world");
+    assert_eq!(log, b"hello world");
+}
+
+#[tokio::test]
+async fn tee_lags_by_at_most_one_read() {
+    let reader: &[u8] = b"abcdef";
+    let mut log = Vec::new();
+
+    let mut rd = tee(reader, &mut log);
+
+    let mut buf = [0; 3];
+    assert_ok!(rd.read_exact(&mut buf).await);
+    assert_eq!(&buf, b"abc");
+
+    // The second read forwards what the first one produced.
+    assert_ok!(rd.read_exact(&mut buf).await);
+    assert_eq!(&buf, b"def");
+
+    let (_, log) = rd.into_inner();
+    assert!(log.starts_with(b"abc"));
+}
+
+#[tokio::test]
+async fn tee_slow_writer_applies_backpressure() {
+    let (tx, mut rx) = duplex(4);
+    let reader: &[u8] = b"0123456789";
+
+    let mut rd = tee(reader, tx);
+
+    let copy = tokio::spawn(async move {
+        let mut out = Vec::new();
+        rd.read_to_end(&mut out).await.unwrap();
+        out
+    });
+
+    let mut teed = vec![0; 10];
+    assert_ok!(rx.read_exact(&mut teed).await);
+    assert_eq!(teed, b"0123456789");
+
+    assert_eq!(copy.await.unwrap(), b"0123456789");
+}
+
+#[tokio::test]
+async fn inspect_read_sees_all_chunks() {
+    let reader: &[u8] = b"hello";
+    let mut seen = Vec::new();
+
+    let mut buf = Vec::new();
+    assert_ok!(
+        inspect_read(reader, |chunk| seen.extend_from_slice(chunk))
+            .read_to_end(&mut buf)
+            .await
+    );
+
+    assert_eq!(buf, b"hello");
+    assert_eq!(seen, b"hello");
+}
+
+#[tokio::test]
+async fn inspect_write_sees_written_bytes() {
+    let mut seen = Vec::new();
+
+    let mut wr = inspect_write(Vec::new(), |chunk| seen.extend_from_slice(chunk));
+    assert_ok!(wr.write_all(b"hello ").await);
+    assert_ok!(wr.write_all(b"world").await);
+
+    assert_eq!(wr.get_ref(), b"hello world");
+    assert_eq!(seen, b"hello world");
+}
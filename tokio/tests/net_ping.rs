@@ -0,0 +1,32 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", unix, not(miri)))]
+
+use tokio::net::Ping;
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+
+#[tokio::test]
+async fn ping_localhost() {
+    // ICMP sockets need privileges or a permissive `ping_group_range`;
+    // skip where neither is available.
+    let mut ping = match Ping::new_v4() {
+        Ok(ping) => ping,
+        Err(e) => {
+            eprintln!("skipping test; cannot create ICMP socket: {e}");
+            return;
+        }
+    };
+
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let rtt = ping
+        .ping_timeout(localhost, Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert!(rtt < Duration::from_secs(5));
+
+    // A second request reuses the socket with the next sequence number.
+    ping.ping_timeout(localhost, Duration::from_secs(5))
+        .await
+        .unwrap();
+}
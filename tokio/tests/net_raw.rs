@@ -0,0 +1,66 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", unix, not(miri)))]
+
+use tokio::net::RawSocket;
+
+fn icmp_checksum(data: &[u8]) -> u16 {
+    let mut sum = 0u32;
+    for chunk in data.chunks(2) {
+        let word = u32::from(chunk[0]) << 8 | u32::from(*chunk.get(1).unwrap_or(&0));
+        sum += word;
+    }
+    while sum >> 16 != 0 {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+#[tokio::test]
+async fn icmp_echo_round_trip() {
+    // Raw sockets need CAP_NET_RAW; skip where it is not granted.
+    let socket = match RawSocket::new_icmp_v4() {
+        Ok(socket) => socket,
+        Err(_) => return,
+    };
+
+    // ICMP echo request: type 8, code 0, identifier, sequence number.
+    let mut packet = [0u8; 16];
+    packet[0] = 8;
+    packet[4..6].copy_from_slice(&0x1234u16.to_be_bytes());
+    packet[6..8].copy_from_slice(&1u16.to_be_bytes());
+    let checksum = icmp_checksum(&packet);
+    packet[2..4].copy_from_slice(&checksum.to_be_bytes());
+
+    let dst = "127.0.0.1:0".parse().unwrap();
+    let sent = socket.send_to(&packet, dst).await.unwrap();
+    assert_eq!(sent, packet.len());
+
+    // Raw IPv4 sockets receive the IP header followed by the ICMP message;
+    // keep reading until the matching echo reply shows up.
+    let mut buf = [0u8; 1500];
+    loop {
+        let (n, addr) = socket.recv_from(&mut buf).await.unwrap();
+        assert!(addr.is_some());
+
+        let header_len = usize::from(buf[0] & 0x0f) * 4;
+        let icmp = &buf[header_len..n];
+        // Echo reply with our identifier.
+        if icmp[0] == 0 && icmp[4..6] == 0x1234u16.to_be_bytes() {
+            break;
+        }
+    }
+}
+
+#[tokio::test]
+async fn try_recv_would_block() {
+    let socket = match RawSocket::new_icmp_v4() {
+        Ok(socket) => socket,
+        Err(_) => return,
+    };
+
+    let mut buf = [0u8; 64];
+    match socket.try_recv(&mut buf) {
+        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {}
+        res => panic!("expected WouldBlock, got {res:?}"),
+    }
+}
@@ -0,0 +1,50 @@
+#![cfg(all(unix, feature = "full", not(miri)))]
+#![warn(rust_2018_idioms)]
+
+use std::process::Stdio;
+use tokio::process::Command;
+
+#[tokio::test]
+async fn communicate_round_trips_large_input() {
+    // Well past the kernel pipe buffer, so writing the input up front
+    // without draining stdout would deadlock.
+    let input = vec![b'x'; 4 * 1024 * 1024];
+
+    let child = Command::new("cat")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let output = child.communicate(&input).await.unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(output.stdout, input);
+}
+
+#[tokio::test]
+async fn communicate_tolerates_early_stdin_close() {
+    // The child exits without reading its input; the resulting broken
+    // pipe on write is not an error.
+    let input = vec![b'x'; 4 * 1024 * 1024];
+
+    let child = Command::new("bash")
+        .args(["-c", "exec <&-; echo done"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let output = child.communicate(&input).await.unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"done\n");
+}
+
+#[tokio::test]
+async fn communicate_requires_piped_stdin_for_input() {
+    let child = Command::new("true").spawn().unwrap();
+
+    let err = child.communicate(b"ignored").await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
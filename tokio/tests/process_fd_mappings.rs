@@ -0,0 +1,89 @@
+#![cfg(all(unix, feature = "full", not(miri)))]
+#![warn(rust_2018_idioms)]
+
+use std::io::Read;
+use std::os::fd::{FromRawFd, OwnedFd, RawFd};
+use tokio::process::Command;
+
+fn pipe() -> (OwnedFd, OwnedFd) {
+    let mut fds = [0 as RawFd; 2];
+    assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+    unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) }
+}
+
+#[tokio::test]
+async fn fd_mappings_pass_descriptor_to_child() {
+    use std::os::fd::AsRawFd;
+
+    let (read_end, write_end) = pipe();
+
+    let status = Command::new("bash")
+        .args(["-c", "echo hello >&9"])
+        .fd_mappings(&[(write_end.as_raw_fd(), 9)])
+        .unwrap()
+        .status()
+        .await
+        .unwrap();
+    assert!(status.success());
+
+    // Close our copy so the read sees EOF after the child's output.
+    drop(write_end);
+
+    let mut msg = String::new();
+    std::fs::File::from(read_end)
+        .read_to_string(&mut msg)
+        .unwrap();
+    assert_eq!(msg, "hello\n");
+}
+
+#[tokio::test]
+async fn fd_mappings_may_swap_descriptors() {
+    use std::os::fd::AsRawFd;
+
+    let (read_a, write_a) = pipe();
+    let (read_b, write_b) = pipe();
+
+    // Map each write end to the raw fd number of the other, which only
+    // works if the mappings are staged before being applied.
+    let status = Command::new("bash")
+        .args([
+            "-c",
+            &format!(
+                "echo first >&{a}; echo second >&{b}",
+                a = write_b.as_raw_fd(),
+                b = write_a.as_raw_fd()
+            ),
+        ])
+        .fd_mappings(&[
+            (write_a.as_raw_fd(), write_b.as_raw_fd()),
+            (write_b.as_raw_fd(), write_a.as_raw_fd()),
+        ])
+        .unwrap()
+        .status()
+        .await
+        .unwrap();
+    assert!(status.success());
+
+    drop(write_a);
+    drop(write_b);
+
+    let mut msg = String::new();
+    std::fs::File::from(read_a)
+        .read_to_string(&mut msg)
+        .unwrap();
+    assert_eq!(msg, "first\n");
+
+    msg.clear();
+    std::fs::File::from(read_b)
+        .read_to_string(&mut msg)
+        .unwrap();
+    assert_eq!(msg, "second\n");
+}
+
+#[tokio::test]
+async fn fd_mappings_reject_duplicate_targets() {
+    let err = Command::new("true")
+        .fd_mappings(&[(0, 5), (1, 5)])
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
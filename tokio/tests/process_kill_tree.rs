@@ -0,0 +1,82 @@
+#![cfg(all(unix, feature = "process", not(miri)))]
+#![warn(rust_2018_idioms)]
+
+use std::io::ErrorKind;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+use tokio::time::sleep;
+use tokio_test::assert_ok;
+
+// Forks a grandchild that prints after a delay; if the whole tree is killed
+// promptly, nothing is ever printed and stdout reaches EOF immediately.
+const TREE_SCRIPT: &str = "
+   sh -c 'sleep 3; echo child ran' &
+   disown -a
+   sleep 10
+";
+
+#[tokio::test]
+async fn kill_tree_terminates_grandchildren() {
+    let mut cmd = Command::new("bash");
+    cmd.args(["-c", TREE_SCRIPT])
+        .process_group(0)
+        .stdout(Stdio::piped());
+
+    let e = cmd.spawn();
+    if e.is_err() && e.as_ref().unwrap_err().kind() == ErrorKind::NotFound {
+        println!("bash not available; skipping test");
+        return;
+    }
+    let mut child = e.unwrap();
+
+    // Let bash fork the grandchild before pulling the plug.
+    sleep(Duration::from_millis(200)).await;
+
+    let mut out = child.stdout.take().unwrap();
+    assert_ok!(child.kill_tree().await);
+
+    let mut msg = String::new();
+    assert_ok!(out.read_to_string(&mut msg).await);
+    assert_eq!("", msg);
+}
+
+#[tokio::test]
+async fn kill_process_group_on_drop_terminates_grandchildren() {
+    let mut cmd = Command::new("bash");
+    cmd.args(["-c", TREE_SCRIPT])
+        .kill_process_group_on_drop(true)
+        .stdout(Stdio::piped());
+
+    let e = cmd.spawn();
+    if e.is_err() && e.as_ref().unwrap_err().kind() == ErrorKind::NotFound {
+        println!("bash not available; skipping test");
+        return;
+    }
+    let mut child = e.unwrap();
+
+    sleep(Duration::from_millis(200)).await;
+
+    let mut out = child.stdout.take().unwrap();
+    drop(child);
+
+    let mut msg = String::new();
+    assert_ok!(out.read_to_string(&mut msg).await);
+    assert_eq!("", msg);
+}
+
+#[tokio::test]
+async fn kill_tree_requires_process_group() {
+    let e = Command::new("sleep").arg("10").spawn();
+    if e.is_err() && e.as_ref().unwrap_err().kind() == ErrorKind::NotFound {
+        println!("sleep not available; skipping test");
+        return;
+    }
+    let mut child = e.unwrap();
+
+    let err = child.kill_tree().await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    assert_ok!(child.kill().await);
+}
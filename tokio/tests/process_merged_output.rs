@@ -0,0 +1,68 @@
+#![cfg(all(unix, feature = "full", not(miri)))]
+#![warn(rust_2018_idioms)]
+
+use std::process::Stdio;
+use tokio::process::{Command, OutputLine};
+use tokio_test::assert_ok;
+
+#[tokio::test]
+async fn merged_output_preserves_arrival_order() {
+    // The sleeps space the writes out so each line arrives on its own.
+    let mut child = Command::new("bash")
+        .args([
+            "-c",
+            "echo out1; sleep 0.15; echo err1 >&2; sleep 0.15; echo out2",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut output = child.merged_output_stream().unwrap();
+
+    let mut lines = Vec::new();
+    while let Some(line) = assert_ok!(output.next_line().await) {
+        lines.push(line);
+    }
+
+    assert_eq!(
+        lines,
+        vec![
+            OutputLine::Stdout("out1".into()),
+            OutputLine::Stderr("err1".into()),
+            OutputLine::Stdout("out2".into()),
+        ]
+    );
+
+    assert_ok!(child.wait().await);
+}
+
+#[tokio::test]
+async fn merged_output_yields_unterminated_final_line() {
+    let mut child = Command::new("bash")
+        .args(["-c", "printf 'no newline'"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut output = child.merged_output_stream().unwrap();
+
+    assert_eq!(
+        assert_ok!(output.next_line().await),
+        Some(OutputLine::Stdout("no newline".into()))
+    );
+    assert_eq!(assert_ok!(output.next_line().await), None);
+
+    assert_ok!(child.wait().await);
+}
+
+#[tokio::test]
+async fn merged_output_requires_piped_handles() {
+    let mut child = Command::new("true").spawn().unwrap();
+
+    let err = child.merged_output_stream().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+    assert_ok!(child.wait().await);
+}
@@ -0,0 +1,39 @@
+#![cfg(all(unix, feature = "full", not(miri)))]
+#![warn(rust_2018_idioms)]
+
+use tokio::process::{Command, Resource};
+
+#[tokio::test]
+async fn rlimit_applies_before_exec() {
+    let output = Command::new("bash")
+        .args(["-c", "ulimit -n"])
+        .rlimit(Resource::Nofile, 64, 64)
+        .output()
+        .await
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "64");
+}
+
+#[tokio::test]
+async fn rlimit_soft_above_hard_fails_spawn() {
+    let err = Command::new("true")
+        .rlimit(Resource::Nofile, 128, 64)
+        .spawn()
+        .unwrap_err();
+
+    assert_eq!(err.raw_os_error(), Some(libc::EINVAL));
+}
+
+#[tokio::test]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+async fn cgroup_missing_path_fails_spawn() {
+    let err = Command::new("true")
+        .cgroup("/sys/fs/cgroup/tokio-test-does-not-exist")
+        .unwrap()
+        .spawn()
+        .unwrap_err();
+
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
@@ -0,0 +1,54 @@
+#![cfg(all(unix, feature = "full", not(miri)))]
+#![warn(rust_2018_idioms)]
+
+use std::time::Duration;
+use tokio::process::Command;
+use tokio_test::assert_ok;
+
+#[tokio::test]
+async fn wait_timeout_returns_status_in_time() {
+    let mut child = Command::new("true").spawn().unwrap();
+
+    let status = assert_ok!(child.wait_timeout(Duration::from_secs(30)).await);
+    assert!(status.expect("child should have exited").success());
+}
+
+#[tokio::test]
+async fn wait_timeout_kills_on_expiry() {
+    let mut child = Command::new("sleep").arg("100").spawn().unwrap();
+
+    let status = assert_ok!(child.wait_timeout(Duration::from_millis(100)).await);
+    assert!(status.is_none());
+
+    // The child has been reaped; a later wait returns immediately.
+    assert_ok!(child.wait().await);
+}
+
+#[tokio::test]
+async fn output_timeout_collects_partial_output() {
+    use std::os::unix::process::ExitStatusExt;
+
+    // The process group ensures the `sleep` forked by bash is killed too;
+    // otherwise it would hold the stdout pipe open for the full 10 seconds.
+    let output = Command::new("bash")
+        .args(["-c", "echo started; sleep 10; echo done"])
+        .process_group(0)
+        .output_timeout(Duration::from_millis(200))
+        .await
+        .unwrap();
+
+    assert_eq!(output.status.signal(), Some(libc::SIGKILL));
+    assert_eq!(output.stdout, b"started\n");
+}
+
+#[tokio::test]
+async fn output_timeout_completes_in_time() {
+    let output = Command::new("bash")
+        .args(["-c", "echo fast"])
+        .output_timeout(Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"fast\n");
+}
@@ -819,3 +819,33 @@ fn threaded_no_lifo() -> Runtime {
 fn us(n: u64) -> Duration {
     Duration::from_micros(n)
 }
+
+#[test]
+fn timer_lifetime_counts() {
+    let rt = current_thread();
+    let metrics = rt.metrics();
+
+    assert_eq!(0, metrics.timers_created_count());
+
+    rt.block_on(async {
+        // A timer that fires.
+        time::sleep(Duration::from_millis(1)).await;
+
+        // A timer that is cancelled before firing. It must be polled once to
+        // register with the driver.
+        let sleep = time::sleep(Duration::from_secs(1));
+        tokio::pin!(sleep);
+        poll_fn(|cx| {
+            assert!(sleep.as_mut().poll(cx).is_pending());
+            Poll::Ready(())
+        })
+        .await;
+    });
+
+    assert_eq!(2, metrics.timers_created_count());
+    assert_eq!(1, metrics.timers_fired_count());
+    assert_eq!(1, metrics.timers_cancelled_count());
+
+    // created - fired - cancelled timers are still alive.
+    assert_eq!(0, metrics.timer_count());
+}
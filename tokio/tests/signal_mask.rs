@@ -0,0 +1,32 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+#![cfg(unix)]
+#![cfg(not(miri))] // No `sigaction` in Miri.
+
+use tokio::signal::unix::{mask, signal, SignalKind};
+use tokio::time::{timeout, Duration};
+use tokio_test::assert_ok;
+
+// `raise` directs the signal at the calling thread, so with that thread
+// masked the signal stays pending until the guard is dropped — regardless of
+// what other threads the test harness is running.
+#[tokio::test]
+async fn mask_defers_delivery_until_restored() {
+    let mut sig = assert_ok!(signal(SignalKind::user_defined2()));
+
+    let guard = assert_ok!(mask(&[SignalKind::user_defined2()]));
+    assert_eq!(unsafe { libc::raise(libc::SIGUSR2) }, 0);
+
+    let res = timeout(Duration::from_millis(200), sig.recv()).await;
+    assert!(res.is_err(), "signal delivered while masked");
+
+    drop(guard);
+
+    let res = assert_ok!(timeout(Duration::from_secs(5), sig.recv()).await);
+    assert!(res.is_some());
+}
+
+#[tokio::test]
+async fn mask_rejects_invalid_signal() {
+    assert!(mask(&[SignalKind::from_raw(-1)]).is_err());
+}
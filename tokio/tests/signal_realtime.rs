@@ -88,6 +88,24 @@ async fn signal_realtime() {
     panic!("{}", msg);
 }
 
+#[tokio::test]
+#[cfg(target_os = "linux")]
+async fn signal_realtime_constructor() {
+    assert_eq!(SignalKind::realtime(0).as_raw_value(), libc::SIGRTMIN());
+    assert_eq!(SignalKind::realtime(2).as_raw_value(), libc::SIGRTMIN() + 2);
+
+    let mut sig = assert_ok!(signal(SignalKind::realtime(1)));
+    send_signal(libc::SIGRTMIN() + 1);
+    sig.recv().await;
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+#[should_panic(expected = "outside the supported real-time signal range")]
+fn signal_realtime_constructor_rejects_out_of_range() {
+    let _ = SignalKind::realtime(libc::SIGRTMAX());
+}
+
 fn sigrt_max() -> c_int {
     // Generally, you would expect this to be SIGRTMAX. But QEMU only supports
     // 28 real-time signals even though it might report SIGRTMAX to be higher.
@@ -0,0 +1,43 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+#![cfg(any(target_os = "android", target_os = "linux"))]
+#![cfg(not(miri))] // No `sigaction` in Miri.
+
+mod support {
+    pub mod signal;
+}
+use support::signal::send_signal;
+
+use tokio::signal::unix::{signal, SignalKind};
+use tokio_test::assert_ok;
+
+#[tokio::test]
+async fn recv_info_reports_sender() {
+    let mut signal = assert_ok!(
+        signal(SignalKind::user_defined1()),
+        "failed to create signal"
+    );
+
+    send_signal(libc::SIGUSR1);
+
+    let info = signal.recv_info().await.expect("signal stream closed");
+    assert_eq!(info.sender_pid(), Some(unsafe { libc::getpid() }));
+    assert_eq!(info.sender_uid(), Some(unsafe { libc::getuid() }));
+    assert_eq!(info.child_status(), None);
+}
+
+#[tokio::test]
+async fn recv_info_reports_child_exit_status() {
+    let mut signal = assert_ok!(signal(SignalKind::child()), "failed to create signal");
+
+    let mut child = std::process::Command::new("sh")
+        .args(["-c", "exit 7"])
+        .spawn()
+        .unwrap();
+
+    let info = signal.recv_info().await.expect("signal stream closed");
+    assert_eq!(info.sender_pid(), Some(child.id() as i32));
+    assert_eq!(info.child_status(), Some(7));
+
+    child.wait().unwrap();
+}
@@ -0,0 +1,54 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+#![cfg(unix)]
+#![cfg(not(miri))] // No `sigaction` in Miri.
+
+mod support {
+    pub mod signal;
+}
+use support::signal::send_signal;
+
+use tokio::signal::Reloader;
+use tokio::time::{timeout, Duration};
+use tokio_test::assert_ok;
+
+// The tests share the process-wide SIGHUP, so generation numbers are only
+// compared with `>=` to stay robust against signals raised by a concurrently
+// running test.
+
+#[tokio::test]
+async fn reloader_broadcasts_to_all_subscriptions() {
+    let reloader = assert_ok!(Reloader::new());
+    let mut sub_a = reloader.subscribe();
+    let mut sub_b = reloader.subscribe();
+
+    send_signal(libc::SIGHUP);
+
+    let gen_a = sub_a.changed().await.expect("listener gone");
+    let gen_b = sub_b.changed().await.expect("listener gone");
+    assert!(gen_a >= 1);
+    assert!(gen_b >= 1);
+
+    sub_a.acknowledge();
+    sub_b.acknowledge();
+
+    let acked = gen_a.min(gen_b);
+    assert_ok!(timeout(Duration::from_secs(5), reloader.acknowledged(acked)).await);
+}
+
+#[tokio::test]
+async fn trigger_notifies_and_dropped_subscription_does_not_block() {
+    let reloader = assert_ok!(Reloader::new());
+    let mut sub = reloader.subscribe();
+    let dropped = reloader.subscribe();
+
+    reloader.trigger();
+
+    let generation = sub.changed().await.expect("listener gone");
+    assert!(generation >= 1);
+    sub.acknowledge();
+
+    drop(dropped);
+
+    assert_ok!(timeout(Duration::from_secs(5), reloader.acknowledged(generation)).await);
+}
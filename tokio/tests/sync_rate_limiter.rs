@@ -0,0 +1,109 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", not(target_os = "wasi")))] // Wasi does not support threads
+
+use tokio::sync::RateLimiter;
+use tokio::time::{self, Duration};
+use tokio_test::{assert_pending, assert_ready, task};
+
+use std::sync::Arc;
+
+#[tokio::test(start_paused = true)]
+async fn burst_then_steady_rate() {
+    let limiter = RateLimiter::new(2, Duration::from_secs(2));
+
+    // The burst capacity is available immediately.
+    assert!(limiter.try_acquire());
+    assert!(limiter.try_acquire());
+    assert!(!limiter.try_acquire());
+
+    // One permit is released every second.
+    time::advance(Duration::from_secs(1)).await;
+    assert!(limiter.try_acquire());
+    assert!(!limiter.try_acquire());
+}
+
+#[tokio::test(start_paused = true)]
+async fn acquire_waits_for_refill() {
+    let limiter = RateLimiter::new(1, Duration::from_secs(1));
+    limiter.acquire().await;
+
+    let mut acquire = task::spawn(limiter.acquire());
+    assert_pending!(acquire.poll());
+
+    time::advance(Duration::from_secs(1)).await;
+    assert!(acquire.is_woken());
+    assert_ready!(acquire.poll());
+}
+
+#[tokio::test(start_paused = true)]
+async fn unused_permits_accumulate_up_to_burst() {
+    let limiter = RateLimiter::with_burst(1, Duration::from_secs(1), 3);
+
+    for _ in 0..3 {
+        assert!(limiter.try_acquire());
+    }
+    assert!(!limiter.try_acquire());
+
+    // Ten seconds of inactivity only accumulates the burst capacity.
+    time::advance(Duration::from_secs(10)).await;
+    for _ in 0..3 {
+        assert!(limiter.try_acquire());
+    }
+    assert!(!limiter.try_acquire());
+}
+
+#[tokio::test(start_paused = true)]
+async fn acquire_many_drains_and_waits() {
+    let limiter = RateLimiter::with_burst(1, Duration::from_secs(1), 3);
+
+    // Three permits are available immediately; the remaining two arrive at
+    // the steady rate of one per second.
+    let mut acquire = task::spawn(limiter.acquire_many(5));
+    assert_pending!(acquire.poll());
+
+    time::advance(Duration::from_secs(1)).await;
+    assert_pending!(acquire.poll());
+
+    time::advance(Duration::from_secs(1)).await;
+    assert!(acquire.is_woken());
+    assert_ready!(acquire.poll());
+
+    assert!(!limiter.try_acquire());
+}
+
+#[tokio::test(start_paused = true)]
+async fn acquire_many_within_burst_is_immediate() {
+    let limiter = RateLimiter::with_burst(1, Duration::from_secs(1), 3);
+
+    let mut acquire = task::spawn(limiter.acquire_many(3));
+    assert_ready!(acquire.poll());
+    assert!(!limiter.try_acquire());
+}
+
+#[tokio::test(start_paused = true)]
+async fn shared_between_tasks() {
+    let limiter = Arc::new(RateLimiter::new(1, Duration::from_secs(1)));
+    limiter.acquire().await;
+
+    // Both waiters draw from the same quota, so only one proceeds per second.
+    let mut first = task::spawn(limiter.clone().acquire_owned());
+    let mut second = task::spawn(limiter.clone().acquire_owned());
+    assert_pending!(first.poll());
+    assert_pending!(second.poll());
+
+    time::advance(Duration::from_secs(1)).await;
+    let progressed = usize::from(first.poll().is_ready()) + usize::from(second.poll().is_ready());
+    assert_eq!(progressed, 1);
+}
+
+#[test]
+#[should_panic = "`rate` must be non-zero."]
+fn zero_rate_panics() {
+    let _ = RateLimiter::new(0, Duration::from_secs(1));
+}
+
+#[test]
+#[should_panic = "`per` must be non-zero."]
+fn zero_duration_panics() {
+    let _ = RateLimiter::new(1, Duration::ZERO);
+}
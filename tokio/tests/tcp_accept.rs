@@ -155,3 +155,25 @@ async fn accept_many() {
         notified_rx.recv().await.unwrap();
     }
 }
+
+#[tokio::test]
+async fn accept_builder_applies_options() {
+    use std::time::Duration;
+
+    let listener = assert_ok!(TcpListener::bind("127.0.0.1:0").await);
+    let addr = listener.local_addr().unwrap();
+
+    let acceptor = listener
+        .builder()
+        .nodelay(true)
+        .keepalive(Duration::from_secs(60))
+        .recv_buffer_size(64 * 1024);
+
+    let connect = TcpStream::connect(&addr);
+    let ((stream, _), _client) = tokio::try_join!(acceptor.accept(), connect).unwrap();
+
+    assert!(stream.nodelay().unwrap());
+
+    let sock = socket2::SockRef::from(&stream);
+    assert!(sock.keepalive().unwrap());
+}
@@ -73,3 +73,77 @@ async fn basic_linger() {
     srv.set_linger(Some(Duration::new(0, 0))).unwrap();
     assert_eq!(srv.linger().unwrap(), Some(Duration::new(0, 0)));
 }
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn tfo_and_defer_accept_options() {
+    let socket = assert_ok!(TcpSocket::new_v4());
+    assert_ok!(socket.set_tfo(true));
+    assert_ok!(socket.set_tfo(false));
+    assert_ok!(socket.set_defer_accept(3));
+    assert_ok!(socket.set_defer_accept(0));
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn connect_with_data() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let addr = assert_ok!("127.0.0.1:0".parse());
+    let srv = assert_ok!(TcpSocket::new_v4());
+    assert_ok!(srv.bind(addr));
+    let srv = assert_ok!(srv.listen(128));
+    let addr = srv.local_addr().unwrap();
+
+    let cli = assert_ok!(TcpSocket::new_v4());
+    let (mut stream, sent) = assert_ok!(cli.connect_with_data(addr, b"hello").await);
+
+    // Without a cached Fast Open cookie the kernel falls back to a regular
+    // handshake; write whatever did not make it into the SYN.
+    assert!(sent <= 5);
+    assert_ok!(stream.write_all(&b"hello"[sent..]).await);
+
+    let (mut peer, _) = assert_ok!(srv.accept().await);
+    let mut buf = [0u8; 5];
+    assert_ok!(peer.read_exact(&mut buf).await);
+    assert_eq!(&buf, b"hello");
+}
+
+#[cfg(all(
+    unix,
+    not(target_os = "solaris"),
+    not(target_os = "illumos"),
+    not(target_os = "cygwin"),
+))]
+#[tokio::test]
+async fn bind_reuseport_cluster() {
+    use tokio::net::{TcpListener, TcpStream};
+
+    let listeners = assert_ok!(TcpListener::bind_reuseport_cluster("127.0.0.1:0", 4).await);
+    assert_eq!(listeners.len(), 4);
+
+    // All listeners share the port picked by the kernel for the first one.
+    let addr = listeners[0].local_addr().unwrap();
+    for listener in &listeners {
+        assert_eq!(listener.local_addr().unwrap(), addr);
+    }
+
+    // A connection lands on exactly one of the listeners.
+    let connect = TcpStream::connect(addr);
+    let accept_any = async {
+        let mut accepts: Vec<_> = listeners
+            .iter()
+            .map(|listener| Box::pin(listener.accept()))
+            .collect();
+        futures::future::select_all(accepts.drain(..)).await.0
+    };
+
+    let (client, accepted) = tokio::join!(connect, accept_any);
+    let client = assert_ok!(client);
+    let (_server, peer) = assert_ok!(accepted);
+    assert_eq!(peer, client.local_addr().unwrap());
+
+    assert!(TcpListener::bind_reuseport_cluster("127.0.0.1:0", 0)
+        .await
+        .is_err());
+}
@@ -404,3 +404,153 @@ async fn write_closed() {
 
     assert!(!ready_event.is_write_closed());
 }
+
+#[tokio::test]
+async fn poll_read_vectored_scatters_across_buffers() {
+    use std::pin::Pin;
+    use tokio::io::{AsyncRead, ReadBuf};
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut stream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    let (mut peer, _) = listener.accept().await.unwrap();
+
+    assert!(stream.is_read_vectored());
+
+    peer.write_all(b"abcdefgh").await.unwrap();
+
+    let mut first = [0; 3];
+    let mut second = [0; 16];
+    let mut bufs = [ReadBuf::new(&mut first), ReadBuf::new(&mut second)];
+
+    let n = poll_fn(|cx| Pin::new(&mut stream).poll_read_vectored(cx, &mut bufs))
+        .await
+        .unwrap();
+
+    assert_eq!(n, 8);
+    assert_eq!(bufs[0].filled(), b"abc");
+    assert_eq!(bufs[1].filled(), b"defgh");
+}
+
+#[tokio::test]
+#[cfg(target_os = "linux")]
+async fn tcp_info() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut client = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    let (mut server, _) = listener.accept().await.unwrap();
+
+    client.write_all(b"ping").await.unwrap();
+    let mut buf = [0; 4];
+    server.read_exact(&mut buf).await.unwrap();
+
+    let info = client.tcp_info().unwrap();
+    assert_eq!(info.retransmits(), 0);
+    assert!(info.congestion_window() > 0);
+    assert!(info.bytes_acked() >= 1);
+}
+
+#[tokio::test]
+async fn connect_happy_eyeballs() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let good = listener.local_addr().unwrap();
+
+    // Bind and drop to obtain a port that is very likely closed.
+    let bad = {
+        let l = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        l.local_addr().unwrap()
+    };
+
+    // The first attempt is refused; the second wins the race.
+    let stream = TcpStream::connect_happy_eyeballs(&[bad, good][..])
+        .await
+        .unwrap();
+    assert_eq!(stream.peer_addr().unwrap(), good);
+
+    let (_, peer) = listener.accept().await.unwrap();
+    assert_eq!(peer, stream.local_addr().unwrap());
+}
+
+#[tokio::test]
+async fn connect_happy_eyeballs_all_fail() {
+    let bad = {
+        let l = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        l.local_addr().unwrap()
+    };
+
+    let err = TcpStream::connect_happy_eyeballs_with_delay(bad, Duration::from_millis(10))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+}
+
+#[tokio::test]
+async fn peek_timeout() {
+    use std::time::Duration;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let client = TcpStream::connect(&addr).await.unwrap();
+    let (mut server, _) = listener.accept().await.unwrap();
+
+    // Nothing has been sent yet, so the peek must time out.
+    let err = client
+        .peek_timeout(&mut [0u8; 4], Duration::from_millis(10))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+
+    server.write_all(b"ping").await.unwrap();
+
+    let mut buf = [0u8; 4];
+    let n = client
+        .peek_timeout(&mut buf, Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(&buf[..n], &b"ping"[..n]);
+}
+
+#[tokio::test]
+async fn close_graceful() {
+    use std::time::Duration;
+    use tokio::io::AsyncReadExt;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let client = TcpStream::connect(&addr).await.unwrap();
+    let (mut server, _) = listener.accept().await.unwrap();
+
+    // The peer sends some data the client never reads, then closes once it
+    // sees EOF; `close_graceful` must drain it instead of resetting.
+    let peer = tokio::spawn(async move {
+        server.write_all(&[0u8; 8192]).await.unwrap();
+        let mut buf = [0u8; 64];
+        while server.read(&mut buf).await.unwrap() != 0 {}
+    });
+
+    client.close_graceful(Duration::from_secs(5)).await.unwrap();
+    peer.await.unwrap();
+}
+
+#[tokio::test]
+async fn close_graceful_timeout() {
+    use std::time::Duration;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let client = TcpStream::connect(&addr).await.unwrap();
+    // The server never closes its side.
+    let (server, _) = listener.accept().await.unwrap();
+
+    let err = client
+        .close_graceful(Duration::from_millis(50))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    drop(server);
+}
@@ -467,7 +467,7 @@ async fn stream_with_interval_poll_tick_no_waking() {
     // We expect the stream to stall because it does not reschedule itself on
     // `Poll::Pending` and neither does [tokio::time::Interval] reschedule the
     // task when returning `Poll::Ready`.
-    assert_eq!(items, vec![]);
+    assert!(items.is_empty());
 }
 
 #[tokio::test(start_paused = true)]
@@ -475,3 +475,23 @@ async fn interval_doesnt_panic_max_duration_when_polling() {
     let mut timer = task::spawn(time::interval(Duration::MAX));
     assert_ready!(timer.enter(|cx, mut timer| timer.poll_tick(cx)));
 }
+
+#[tokio::test(start_paused = true)]
+async fn tick_info_reports_skipped_ticks() {
+    let start = Instant::now();
+    let mut interval = task::spawn(time::interval_at(start, ms(300)));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    // First tick fires immediately and skips nothing.
+    let tick = assert_ready!(interval.enter(|cx, mut i| i.poll_tick_info(cx)));
+    assert_eq!(tick.scheduled(), start);
+    assert_eq!(tick.skipped(), 0);
+
+    // Fall behind by a bit over two periods; those ticks are skipped.
+    time::advance(ms(950)).await;
+
+    let tick = assert_ready!(interval.enter(|cx, mut i| i.poll_tick_info(cx)));
+    assert_eq!(tick.scheduled(), start + ms(300));
+    assert_eq!(tick.skipped(), 2);
+    assert!(tick.delay() >= ms(600));
+}
@@ -331,3 +331,33 @@ fn poll_next(interval: &mut task::Spawn<time::Interval>) -> Poll<Instant> {
 fn ms(n: u64) -> Duration {
     Duration::from_millis(n)
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn pause_with_step_advances_exactly() {
+    time::pause_with(time::AutoAdvance::Step(ms(10)));
+
+    let start = Instant::now();
+    time::sleep(ms(95)).await;
+
+    // The clock is advanced in 10ms steps, with the final step clamped to
+    // the timer deadline. Allow for the timer's 1ms rounding.
+    let elapsed = start.elapsed();
+    assert!(elapsed >= ms(95) && elapsed <= ms(100), "{elapsed:?}");
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn pause_with_never_requires_manual_advance() {
+    time::pause_with(time::AutoAdvance::Never);
+
+    let start = Instant::now();
+    let mut sleep = task::spawn(time::sleep_until(start + ms(10)));
+
+    assert_pending!(sleep.poll());
+
+    // The clock does not move on its own; only an explicit advance fires
+    // the timer. Factor in jitter, like `paused_time_*` above.
+    time::advance(ms(11)).await;
+    assert_eq!(start.elapsed(), ms(11));
+    assert!(sleep.is_woken());
+    assert_ready!(sleep.poll());
+}
@@ -364,3 +364,22 @@ async fn drop_from_wake() {
         }
     }
 }
+
+#[tokio::test]
+async fn sleep_until_system_past_deadline() {
+    use std::time::SystemTime;
+
+    // A deadline in the past completes immediately.
+    let now = Instant::now();
+    time::sleep_until_system(SystemTime::now() - Duration::from_secs(1)).await;
+    assert!(now.elapsed() < Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn sleep_until_system_short() {
+    use std::time::SystemTime;
+
+    let deadline = SystemTime::now() + Duration::from_millis(50);
+    time::sleep_until_system(deadline).await;
+    assert!(SystemTime::now() >= deadline);
+}
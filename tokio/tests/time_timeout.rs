@@ -149,3 +149,86 @@ async fn timeout_is_not_exhausted_by_future() {
 
     assert!(fut.await.is_err());
 }
+
+#[tokio::test]
+async fn timeout_inherits_task_deadline() {
+    time::pause();
+
+    // The inner timeout asks for an hour, but the task-local deadline caps
+    // it at ten milliseconds.
+    let res = time::Deadline::after(ms(10))
+        .scope(timeout(ms(3_600_000), futures::future::pending::<()>()))
+        .await;
+
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn nested_deadline_scopes_only_tighten() {
+    time::pause();
+
+    time::Deadline::after(ms(10))
+        .scope(async {
+            // The outer (earlier) deadline wins over the later inner one.
+            let current = time::Deadline::current().unwrap();
+            time::Deadline::after(ms(3_600_000))
+                .scope(async move {
+                    assert_eq!(time::Deadline::current(), Some(current));
+                })
+                .await;
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn elapsed_reports_time_ran() {
+    time::pause();
+
+    let err = timeout(ms(10), pending::<()>()).await.unwrap_err();
+
+    // The inner future ran from the first poll until the deadline.
+    let ran = err.ran().unwrap();
+    assert!(ran >= ms(10) && ran <= ms(20), "ran = {ran:?}");
+}
+
+#[tokio::test]
+async fn extend_timeout_deadline() {
+    time::pause();
+
+    let mut fut = task::spawn(timeout(ms(10), pending::<()>()));
+
+    assert_pending!(fut.poll());
+
+    // Grant the operation another 15 milliseconds.
+    fut.enter(|_, f| f.extend(ms(15)));
+
+    // The original deadline passes without the timeout firing.
+    time::advance(ms(11)).await;
+    assert_pending!(fut.poll());
+
+    // The extended deadline does fire.
+    time::advance(ms(15)).await;
+    assert!(fut.is_woken());
+    assert_ready_err!(fut.poll());
+}
+
+#[tokio::test]
+async fn budget_shared_across_operations() {
+    time::pause();
+
+    let budget = time::budget(ms(100));
+
+    // The first operation consumes half the budget.
+    budget
+        .timeout_remaining(time::sleep(ms(50)))
+        .await
+        .unwrap();
+    assert!(budget.remaining() <= ms(50));
+
+    // The second operation only gets what is left.
+    budget
+        .timeout_remaining(pending::<()>())
+        .await
+        .unwrap_err();
+    assert!(budget.is_exhausted());
+}
@@ -644,3 +644,117 @@ async fn poll_ready() {
         }
     }
 }
+
+#[tokio::test]
+async fn send_many_recv_many() -> std::io::Result<()> {
+    use tokio::net::BufAddr;
+
+    let sender = UdpSocket::bind("127.0.0.1:0").await?;
+    let receiver = UdpSocket::bind("127.0.0.1:0").await?;
+    let dst = receiver.local_addr()?;
+
+    let sent = sender
+        .send_many(&[(&b"one"[..], dst), (&b"two"[..], dst), (&b"three"[..], dst)])
+        .await?;
+    assert_eq!(sent, 3);
+
+    let mut bufs = [[0u8; 32]; 3];
+    let mut slots: Vec<BufAddr<'_>> = bufs.iter_mut().map(|buf| BufAddr::new(buf)).collect();
+
+    let mut received = 0;
+    while received < 3 {
+        received += receiver.recv_many(&mut slots[received..]).await?;
+    }
+
+    assert_eq!(slots[0].payload(), b"one");
+    assert_eq!(slots[1].payload(), b"two");
+    assert_eq!(slots[2].payload(), b"three");
+    for slot in &slots {
+        assert_eq!(slot.addr(), Some(sender.local_addr()?));
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_many_recv_many_empty() -> std::io::Result<()> {
+    let socket = UdpSocket::bind("127.0.0.1:0").await?;
+    assert_eq!(socket.send_many(&[]).await?, 0);
+    assert_eq!(socket.recv_many(&mut []).await?, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn multicast_group_join_leave() -> std::io::Result<()> {
+    use tokio::net::MulticastGroup;
+
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+
+    let group = MulticastGroup::new(&socket, "239.255.42.42".parse().unwrap())
+        .interface_v4("127.0.0.1".parse().unwrap())
+        .ttl(1)
+        .loopback(true)
+        .join()?;
+    assert_eq!(group.group(), "239.255.42.42".parse::<std::net::IpAddr>().unwrap());
+    group.leave()?;
+
+    // Dropping an unjoined configuration must not attempt to leave.
+    let _unjoined = MulticastGroup::new(&socket, "239.255.42.42".parse().unwrap());
+    Ok(())
+}
+
+#[tokio::test]
+async fn multicast_group_invalid_config() -> std::io::Result<()> {
+    use tokio::net::MulticastGroup;
+
+    let socket = UdpSocket::bind("[::]:0").await?;
+
+    // Source filtering is IGMPv3, i.e. IPv4 only.
+    let err = MulticastGroup::new(&socket, "ff12::1".parse().unwrap())
+        .source("10.0.0.1".parse().unwrap())
+        .join()
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+    // IPv6 groups select interfaces by index.
+    let err = MulticastGroup::new(&socket, "ff12::1".parse().unwrap())
+        .interface_v4("127.0.0.1".parse().unwrap())
+        .join()
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    Ok(())
+}
+
+#[tokio::test]
+async fn peek_len() -> std::io::Result<()> {
+    let sender = UdpSocket::bind("127.0.0.1:0").await?;
+    let receiver = UdpSocket::bind("127.0.0.1:0").await?;
+
+    sender.send_to(MSG, receiver.local_addr()?).await?;
+
+    // Peeking must not consume the datagram.
+    assert_eq!(receiver.peek_len().await?, MSG_LEN);
+    assert_eq!(receiver.peek_len().await?, MSG_LEN);
+
+    let mut buf = vec![0u8; MSG_LEN];
+    let (n, _) = receiver.recv_from(&mut buf).await?;
+    assert_eq!(n, MSG_LEN);
+    assert_eq!(&buf[..n], MSG);
+    Ok(())
+}
+
+#[tokio::test]
+async fn try_peek_len() -> std::io::Result<()> {
+    let sender = UdpSocket::bind("127.0.0.1:0").await?;
+    let receiver = UdpSocket::bind("127.0.0.1:0").await?;
+
+    match receiver.try_peek_len() {
+        Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+        res => panic!("expected WouldBlock, got {res:?}"),
+    }
+
+    sender.send_to(MSG, receiver.local_addr()?).await?;
+    receiver.readable().await?;
+
+    assert_eq!(receiver.try_peek_len()?, MSG_LEN);
+    Ok(())
+}
@@ -24,3 +24,17 @@ async fn test_socket_pair() {
     assert_eq!(cred_a.uid(), uid);
     assert_eq!(cred_a.gid(), gid);
 }
+
+#[tokio::test]
+async fn test_accept_with_cred() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("accept_cred.sock");
+
+    let listener = tokio::net::UnixListener::bind(&path).unwrap();
+    let connect = UnixStream::connect(&path);
+    let ((_server, _addr, cred), _client) =
+        tokio::try_join!(listener.accept_with_cred(), connect).unwrap();
+
+    assert_eq!(cred.uid(), unsafe { geteuid() });
+    assert_eq!(cred.gid(), unsafe { getegid() });
+}
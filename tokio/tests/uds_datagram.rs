@@ -423,3 +423,57 @@ async fn poll_ready() -> io::Result<()> {
 
     Ok(())
 }
+
+#[cfg(not(miri))] // No `socket` in miri.
+#[tokio::test]
+async fn send_recv_fds() -> io::Result<()> {
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsFd;
+
+    let (left, right) = UnixDatagram::pair()?;
+
+    let (sent, kept) = std::os::unix::net::UnixStream::pair()?;
+    left.send_with_fds(b"fd", &[sent.as_fd()]).await?;
+
+    let mut buf = [0u8; 8];
+    let mut fds = Vec::new();
+    let n = right.recv_with_fds(&mut buf, &mut fds).await?;
+    assert_eq!(&buf[..n], b"fd");
+    assert_eq!(fds.len(), 1);
+
+    let received = std::os::unix::net::UnixStream::from(fds.pop().unwrap());
+    (&kept).write_all(b"ping")?;
+    let mut got = [0u8; 4];
+    (&received).read_exact(&mut got)?;
+    assert_eq!(&got, b"ping");
+
+    Ok(())
+}
+
+#[cfg(all(not(miri), any(target_os = "linux", target_os = "android")))]
+#[tokio::test]
+async fn bind_abstract() -> io::Result<()> {
+    use std::os::linux::net::SocketAddrExt;
+
+    let receiver = UnixDatagram::bind_abstract(b"tokio-uds-dgram-abstract")?;
+
+    let sender = std::os::unix::net::UnixDatagram::unbound()?;
+    let addr = std::os::unix::net::SocketAddr::from_abstract_name(b"tokio-uds-dgram-abstract")?;
+    sender.send_to_addr(b"hello", &addr)?;
+
+    let mut buf = [0u8; 8];
+    let n = receiver.recv(&mut buf).await?;
+    assert_eq!(&buf[..n], b"hello");
+    Ok(())
+}
+
+#[cfg(all(not(miri), any(target_os = "linux", target_os = "android")))]
+#[tokio::test]
+async fn bind_abstract_autobind() -> io::Result<()> {
+    // An empty name asks the kernel to pick a unique abstract address.
+    let receiver = UnixDatagram::bind_abstract([])?;
+    let local_addr = receiver.local_addr()?;
+    let name = local_addr.as_abstract_name().unwrap();
+    assert!(!name.is_empty());
+    Ok(())
+}
@@ -444,3 +444,67 @@ async fn abstract_socket_name() {
     // `as_abstract_name` removes leading zero bytes
     assert_eq!(abstract_path_name, b"aaa");
 }
+
+#[tokio::test]
+async fn send_recv_fds() -> std::io::Result<()> {
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsFd;
+
+    let (left, right) = UnixStream::pair()?;
+
+    // Ship one end of a std socket pair across the tokio stream.
+    let (sent, kept) = std::os::unix::net::UnixStream::pair()?;
+    let n = left.send_with_fds(b"fd", &[sent.as_fd()]).await?;
+    assert_eq!(n, 2);
+
+    let mut buf = [0u8; 8];
+    let mut fds = Vec::new();
+    let n = right.recv_with_fds(&mut buf, &mut fds).await?;
+    assert_eq!(&buf[..n], b"fd");
+    assert_eq!(fds.len(), 1);
+
+    // The received descriptor must be a working duplicate of `sent`.
+    let received = std::os::unix::net::UnixStream::from(fds.pop().unwrap());
+    (&kept).write_all(b"ping")?;
+    let mut got = [0u8; 4];
+    (&received).read_exact(&mut got)?;
+    assert_eq!(&got, b"ping");
+
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+async fn bind_abstract_and_connect() {
+    // Abstract names are byte strings and need not be valid UTF-8.
+    let name = b"tokio-uds-bind-abstract\xff";
+    let listener = UnixListener::bind_abstract(name).unwrap();
+
+    let accept = listener.accept();
+    let connect = UnixStream::connect_abstract(name);
+
+    let ((mut server, _), mut client) = try_join(accept, connect).await.unwrap();
+
+    assert_ok!(client.write_all(b"ping").await);
+    let mut buf = [0u8; 4];
+    assert_ok!(server.read_exact(&mut buf).await);
+    assert_eq!(&buf, b"ping");
+}
+
+#[tokio::test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+async fn bind_abstract_autobind() {
+    // An empty name asks the kernel to pick a unique abstract address.
+    let listener = UnixListener::bind_abstract([]).unwrap();
+    let name = {
+        let local_addr = listener.local_addr().unwrap();
+        local_addr.as_abstract_name().unwrap().to_vec()
+    };
+    assert!(!name.is_empty());
+
+    let accept = listener.accept();
+    let connect = UnixStream::connect_abstract(&name);
+
+    let ((_, _), client) = try_join(accept, connect).await.unwrap();
+    drop(client);
+}
@@ -0,0 +1,45 @@
+#![warn(rust_2018_idioms)]
+#![cfg(all(
+    feature = "full",
+    any(target_os = "android", target_os = "linux", target_os = "macos"),
+    not(miri)
+))]
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{VsockAddr, VsockListener, VsockStream};
+
+#[tokio::test]
+async fn addr_accessors() {
+    let addr = VsockAddr::new(VsockAddr::CID_HOST, 1234);
+    assert_eq!(addr.cid(), 2);
+    assert_eq!(addr.port(), 1234);
+    assert_eq!(addr.to_string(), "2:1234");
+}
+
+#[tokio::test]
+async fn loopback_round_trip() {
+    // AF_VSOCK and its loopback transport depend on kernel support that not
+    // every environment provides; skip instead of failing there.
+    let listener = match VsockListener::bind(VsockAddr::new(VsockAddr::CID_ANY, 52342)) {
+        Ok(listener) => listener,
+        Err(_) => return,
+    };
+    let port = listener.local_addr().unwrap().port();
+
+    let accept = listener.accept();
+    let connect = VsockStream::connect(VsockAddr::new(VsockAddr::CID_LOCAL, port));
+
+    let (accepted, connected) = match futures::future::try_join(accept, connect).await {
+        Ok(((accepted, _), connected)) => (accepted, connected),
+        // No loopback transport available.
+        Err(_) => return,
+    };
+
+    let (mut server, mut client) = (accepted, connected);
+    client.write_all(b"ping").await.unwrap();
+    let mut buf = [0u8; 4];
+    server.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"ping");
+
+    assert_eq!(server.peer_addr().unwrap().cid(), VsockAddr::CID_LOCAL);
+}